@@ -76,6 +76,9 @@ async fn main() -> Result<(), Box<dyn Error>> {
                     BybitWebSocketMessage::Response(msg) => {
                         tracing::debug!(?msg, "response frame");
                     }
+                    BybitWebSocketMessage::OrderResponse(msg) => {
+                        tracing::debug!(?msg, "order response frame");
+                    }
                     BybitWebSocketMessage::Subscription(msg) => {
                         tracing::info!(op = %msg.op, success = msg.success, "subscription ack");
                     }
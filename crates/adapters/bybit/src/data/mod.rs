@@ -14,3 +14,1142 @@
 // -------------------------------------------------------------------------------------------------
 
 //! Data client implementation for the Bybit adapter.
+
+use std::{
+    future::Future,
+    sync::{
+        Arc, RwLock,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+use ahash::{AHashMap, AHashSet};
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use futures_util::StreamExt;
+use nautilus_common::{
+    messages::{
+        DataEvent,
+        data::{
+            BarsResponse, DataResponse, InstrumentResponse, InstrumentsResponse, RequestBars,
+            RequestInstrument, RequestInstruments, RequestTrades, SubscribeBars,
+            SubscribeBookDeltas, SubscribeFundingRates, SubscribeQuotes, SubscribeTrades,
+            TradesResponse, UnsubscribeBars, UnsubscribeBookDeltas, UnsubscribeFundingRates,
+            UnsubscribeQuotes, UnsubscribeTrades,
+        },
+    },
+    runner::get_data_event_sender,
+};
+use nautilus_core::{
+    MUTEX_POISONED, UnixNanos,
+    time::{AtomicTime, get_atomic_clock_realtime},
+};
+use nautilus_data::client::DataClient;
+use nautilus_model::{
+    data::{BarType, Data, OrderBookDeltas_API, QuoteTick},
+    enums::{BarAggregation, BookType, PriceType},
+    identifiers::{ClientId, InstrumentId, Symbol, Venue},
+    instruments::{Instrument, InstrumentAny},
+};
+use tokio::{task::JoinHandle, time::Duration};
+use tokio_util::sync::CancellationToken;
+
+use crate::{
+    common::{
+        consts::BYBIT_VENUE,
+        enums::BybitProductType,
+        parse::{bar_spec_to_bybit_interval, make_bybit_symbol},
+        symbol::BybitSymbol,
+    },
+    config::BybitDataClientConfig,
+    http::client::BybitHttpClient,
+    websocket::{
+        cache::QuoteCache,
+        client::BybitWebSocketClient,
+        messages::BybitWebSocketMessage,
+        parse::{
+            parse_kline_topic, parse_millis_i64, parse_orderbook_deltas, parse_orderbook_quote,
+            parse_ticker_linear_funding, parse_topic, parse_ws_kline_bar, parse_ws_trade_tick,
+        },
+    },
+};
+
+/// Order book depths supported by the spot, linear and inverse public streams.
+const BYBIT_BOOK_DEPTHS: [u32; 4] = [1, 50, 200, 1000];
+/// Order book depths supported by the option public stream.
+const BYBIT_OPTION_BOOK_DEPTHS: [u32; 2] = [25, 100];
+/// Order book depth used for spot quotes (Bybit spot tickers carry no top of book).
+const BYBIT_QUOTE_BOOK_DEPTH: u32 = 1;
+
+/// Subscription state shared between the client and its websocket stream tasks.
+#[derive(Clone, Debug, Default)]
+struct SubscriptionRoutes {
+    quotes: Arc<RwLock<AHashSet<InstrumentId>>>,
+    funding_rates: Arc<RwLock<AHashSet<InstrumentId>>>,
+    book_depths: Arc<RwLock<AHashMap<InstrumentId, u32>>>,
+    bar_types: Arc<RwLock<AHashMap<(InstrumentId, String), BarType>>>,
+}
+
+impl SubscriptionRoutes {
+    fn clear(&self) {
+        self.quotes.write().expect(MUTEX_POISONED).clear();
+        self.funding_rates.write().expect(MUTEX_POISONED).clear();
+        self.book_depths.write().expect(MUTEX_POISONED).clear();
+        self.bar_types.write().expect(MUTEX_POISONED).clear();
+    }
+}
+
+#[derive(Debug)]
+pub struct BybitDataClient {
+    client_id: ClientId,
+    config: BybitDataClientConfig,
+    http_client: BybitHttpClient,
+    ws_clients: AHashMap<BybitProductType, BybitWebSocketClient>,
+    is_connected: AtomicBool,
+    cancellation_token: CancellationToken,
+    tasks: Vec<JoinHandle<()>>,
+    data_sender: tokio::sync::mpsc::UnboundedSender<DataEvent>,
+    instruments: Arc<RwLock<AHashMap<InstrumentId, InstrumentAny>>>,
+    routes: SubscriptionRoutes,
+    clock: &'static AtomicTime,
+    instrument_refresh_active: bool,
+}
+
+impl BybitDataClient {
+    /// Creates a new [`BybitDataClient`] instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client fails to initialize.
+    pub fn new(client_id: ClientId, config: BybitDataClientConfig) -> anyhow::Result<Self> {
+        let clock = get_atomic_clock_realtime();
+        let data_sender = get_data_event_sender();
+
+        let http_client = if config.has_api_credentials() {
+            BybitHttpClient::with_credentials(
+                config.api_key.clone().unwrap_or_default(),
+                config.api_secret.clone().unwrap_or_default(),
+                Some(config.http_base_url()),
+                config.http_timeout_secs,
+                config.max_retries,
+                config.retry_delay_initial_ms,
+                config.retry_delay_max_ms,
+            )?
+        } else {
+            BybitHttpClient::new(
+                Some(config.http_base_url()),
+                config.http_timeout_secs,
+                config.max_retries,
+                config.retry_delay_initial_ms,
+                config.retry_delay_max_ms,
+            )?
+        };
+
+        let ws_clients = product_types(&config)
+            .into_iter()
+            .map(|product_type| {
+                let ws = BybitWebSocketClient::new_public_with(
+                    product_type,
+                    config.environment,
+                    Some(config.ws_public_url_for(product_type)),
+                    config.heartbeat_interval_secs,
+                );
+                (product_type, ws)
+            })
+            .collect();
+
+        Ok(Self {
+            client_id,
+            config,
+            http_client,
+            ws_clients,
+            is_connected: AtomicBool::new(false),
+            cancellation_token: CancellationToken::new(),
+            tasks: Vec::new(),
+            data_sender,
+            instruments: Arc::new(RwLock::new(AHashMap::new())),
+            routes: SubscriptionRoutes::default(),
+            clock,
+            instrument_refresh_active: false,
+        })
+    }
+
+    fn venue(&self) -> Venue {
+        *BYBIT_VENUE
+    }
+
+    fn ws_for(
+        &self,
+        instrument_id: InstrumentId,
+    ) -> anyhow::Result<(BybitProductType, BybitWebSocketClient)> {
+        let product_type = BybitSymbol::new(instrument_id.symbol.as_str())?.product_type();
+        let ws = self.ws_clients.get(&product_type).with_context(|| {
+            format!("no public websocket for {product_type:?} (not in configured product types)")
+        })?;
+        Ok((product_type, ws.clone()))
+    }
+
+    fn spawn_ws<F>(&self, fut: F, context: &'static str)
+    where
+        F: Future<Output = anyhow::Result<()>> + Send + 'static,
+    {
+        tokio::spawn(async move {
+            if let Err(e) = fut.await {
+                tracing::error!("{context}: {e:?}");
+            }
+        });
+    }
+
+    async fn bootstrap_instruments(&mut self) -> anyhow::Result<Vec<InstrumentAny>> {
+        let mut collected: Vec<InstrumentAny> = Vec::new();
+
+        for product_type in product_types(&self.config) {
+            let instruments = self
+                .http_client
+                .request_instruments(product_type, None)
+                .await
+                .with_context(|| format!("failed to load instruments for {product_type:?}"))?;
+            tracing::debug!(
+                "loaded {count} instruments for {product_type:?}",
+                count = instruments.len()
+            );
+            collected.extend(instruments);
+        }
+
+        if collected.is_empty() {
+            tracing::warn!("No Bybit instruments were loaded");
+            return Ok(collected);
+        }
+
+        for instrument in &collected {
+            self.http_client.add_instrument(instrument.clone());
+            for ws in self.ws_clients.values() {
+                ws.add_instrument(instrument.clone());
+            }
+        }
+
+        {
+            let mut guard = self.instruments.write().expect(MUTEX_POISONED);
+            guard.clear();
+            for instrument in &collected {
+                guard.insert(instrument.id(), instrument.clone());
+            }
+        }
+
+        Ok(collected)
+    }
+
+    fn spawn_stream_task(&mut self, product_type: BybitProductType) -> anyhow::Result<()> {
+        let ws = self
+            .ws_clients
+            .get_mut(&product_type)
+            .with_context(|| format!("no public websocket for {product_type:?}"))?;
+        let stream = ws.stream();
+
+        let mut handler = PublicStreamHandler {
+            product_type,
+            data_sender: self.data_sender.clone(),
+            instruments: Arc::clone(&self.instruments),
+            routes: self.routes.clone(),
+            quote_cache: QuoteCache::new(),
+            book_quotes: AHashMap::new(),
+            clock: self.clock,
+        };
+        let cancellation = self.cancellation_token.clone();
+
+        let handle = tokio::spawn(async move {
+            tokio::pin!(stream);
+
+            loop {
+                tokio::select! {
+                    maybe_msg = stream.next() => {
+                        match maybe_msg {
+                            Some(msg) => handler.handle(msg),
+                            None => {
+                                tracing::debug!("Websocket stream ended");
+                                break;
+                            }
+                        }
+                    }
+                    _ = cancellation.cancelled() => {
+                        tracing::debug!("Websocket stream task cancelled");
+                        break;
+                    }
+                }
+            }
+        });
+
+        self.tasks.push(handle);
+        Ok(())
+    }
+
+    fn maybe_spawn_instrument_refresh(&mut self) {
+        let Some(minutes) = self.config.update_instruments_interval_mins else {
+            return;
+        };
+
+        if minutes == 0 || self.instrument_refresh_active {
+            return;
+        }
+
+        let interval = Duration::from_secs(minutes.saturating_mul(60));
+        let cancellation = self.cancellation_token.clone();
+        let instruments_cache = Arc::clone(&self.instruments);
+        let http_client = self.http_client.clone();
+        let ws_clients: Vec<BybitWebSocketClient> = self.ws_clients.values().cloned().collect();
+        let product_types = product_types(&self.config);
+        let client_id = self.client_id;
+
+        let handle = tokio::spawn(async move {
+            loop {
+                let sleep = tokio::time::sleep(interval);
+                tokio::pin!(sleep);
+                tokio::select! {
+                    _ = cancellation.cancelled() => {
+                        tracing::debug!("Bybit instrument refresh task cancelled");
+                        break;
+                    }
+                    _ = &mut sleep => {
+                        let mut collected: Vec<InstrumentAny> = Vec::new();
+
+                        for product_type in &product_types {
+                            match http_client.request_instruments(*product_type, None).await {
+                                Ok(instruments) => collected.extend(instruments),
+                                Err(e) => {
+                                    tracing::warn!(client_id=%client_id, product_type=?product_type, error=?e, "Failed to refresh Bybit instruments for product type");
+                                }
+                            }
+                        }
+
+                        if collected.is_empty() {
+                            tracing::debug!(client_id=%client_id, "Bybit instrument refresh yielded no instruments");
+                            continue;
+                        }
+
+                        for instrument in &collected {
+                            http_client.add_instrument(instrument.clone());
+                            for ws in &ws_clients {
+                                ws.add_instrument(instrument.clone());
+                            }
+                        }
+
+                        {
+                            let mut guard = instruments_cache.write().expect(MUTEX_POISONED);
+                            for instrument in &collected {
+                                guard.insert(instrument.id(), instrument.clone());
+                            }
+                        }
+
+                        tracing::debug!(client_id=%client_id, count=collected.len(), "Bybit instruments refreshed");
+                    }
+                }
+            }
+        });
+
+        self.tasks.push(handle);
+        self.instrument_refresh_active = true;
+    }
+}
+
+/// Routes messages from a single product type's public stream to the data event channel.
+#[derive(Debug)]
+struct PublicStreamHandler {
+    product_type: BybitProductType,
+    data_sender: tokio::sync::mpsc::UnboundedSender<DataEvent>,
+    instruments: Arc<RwLock<AHashMap<InstrumentId, InstrumentAny>>>,
+    routes: SubscriptionRoutes,
+    quote_cache: QuoteCache,
+    book_quotes: AHashMap<InstrumentId, QuoteTick>,
+    clock: &'static AtomicTime,
+}
+
+impl PublicStreamHandler {
+    fn handle(&mut self, message: BybitWebSocketMessage) {
+        match message {
+            BybitWebSocketMessage::Orderbook(msg) => {
+                let Some(instrument) = self.instrument(msg.data.s.as_str()) else {
+                    return;
+                };
+                let instrument_id = instrument.id();
+                let Some(depth) = parse_topic(&msg.topic)
+                    .ok()
+                    .and_then(|parts| parts.get(1).and_then(|d| d.parse::<u32>().ok()))
+                else {
+                    tracing::warn!("Failed to parse order book depth from topic {}", msg.topic);
+                    return;
+                };
+                let ts_init = self.clock.get_time_ns();
+
+                let book_depth = self
+                    .routes
+                    .book_depths
+                    .read()
+                    .expect(MUTEX_POISONED)
+                    .get(&instrument_id)
+                    .copied();
+                if book_depth == Some(depth) {
+                    match parse_orderbook_deltas(&msg, &instrument, ts_init) {
+                        Ok(deltas) => {
+                            self.send_data(Data::Deltas(OrderBookDeltas_API::new(deltas)));
+                        }
+                        Err(e) => tracing::error!("Error parsing order book deltas: {e}"),
+                    }
+                }
+
+                if self.product_type == BybitProductType::Spot
+                    && depth == BYBIT_QUOTE_BOOK_DEPTH
+                    && self.is_subscribed_quotes(&instrument_id)
+                {
+                    let last_quote = self.book_quotes.get(&instrument_id);
+                    match parse_orderbook_quote(&msg, &instrument, last_quote, ts_init) {
+                        Ok(quote) => {
+                            self.book_quotes.insert(instrument_id, quote);
+                            self.send_data(Data::Quote(quote));
+                        }
+                        Err(e) => tracing::debug!("Skipping partial order book quote: {e}"),
+                    }
+                }
+            }
+            BybitWebSocketMessage::TickerLinear(msg) => {
+                let Some(instrument) = self.instrument(msg.data.symbol.as_str()) else {
+                    return;
+                };
+                let instrument_id = instrument.id();
+                let ts_init = self.clock.get_time_ns();
+                let ts_event = parse_millis_i64(msg.ts, "ticker.ts").unwrap_or(ts_init);
+
+                // Keep the cache current even without a quote subscription, as ticker
+                // deltas only carry changed fields and need the last full state.
+                let quote = self.quote_cache.process_linear_ticker(
+                    &msg.data,
+                    instrument_id,
+                    &instrument,
+                    ts_event,
+                    ts_init,
+                );
+                if self.product_type != BybitProductType::Spot
+                    && self.is_subscribed_quotes(&instrument_id)
+                {
+                    match quote {
+                        Ok(quote) => self.send_data(Data::Quote(quote)),
+                        Err(e) => tracing::debug!("Skipping partial ticker update: {e}"),
+                    }
+                }
+
+                if self
+                    .routes
+                    .funding_rates
+                    .read()
+                    .expect(MUTEX_POISONED)
+                    .contains(&instrument_id)
+                {
+                    match parse_ticker_linear_funding(&msg, instrument_id, ts_init) {
+                        Ok(Some(update)) => {
+                            if let Err(e) = self.data_sender.send(DataEvent::FundingRate(update)) {
+                                tracing::error!("Failed to emit funding rate event: {e}");
+                            }
+                        }
+                        Ok(None) => {}
+                        Err(e) => tracing::error!("Error parsing funding rate: {e}"),
+                    }
+                }
+            }
+            BybitWebSocketMessage::TickerOption(msg) => {
+                let Some(instrument) = self.instrument(msg.data.symbol.as_str()) else {
+                    return;
+                };
+                let instrument_id = instrument.id();
+                let ts_init = self.clock.get_time_ns();
+                let ts_event = parse_millis_i64(msg.ts, "ticker.ts").unwrap_or(ts_init);
+
+                let quote = self.quote_cache.process_option_ticker(
+                    &msg.data,
+                    instrument_id,
+                    &instrument,
+                    ts_event,
+                    ts_init,
+                );
+                if self.is_subscribed_quotes(&instrument_id) {
+                    match quote {
+                        Ok(quote) => self.send_data(Data::Quote(quote)),
+                        Err(e) => tracing::debug!("Skipping partial ticker update: {e}"),
+                    }
+                }
+            }
+            BybitWebSocketMessage::Trade(msg) => {
+                for trade in &msg.data {
+                    let Some(instrument) = self.instrument(trade.s.as_str()) else {
+                        continue;
+                    };
+                    let ts_init = self.clock.get_time_ns();
+                    match parse_ws_trade_tick(trade, &instrument, ts_init) {
+                        Ok(tick) => self.send_data(Data::Trade(tick)),
+                        Err(e) => tracing::error!("Error parsing trade tick: {e}"),
+                    }
+                }
+            }
+            BybitWebSocketMessage::Kline(msg) => {
+                let (interval, raw_symbol) = match parse_kline_topic(&msg.topic) {
+                    Ok(parts) => parts,
+                    Err(e) => {
+                        tracing::warn!("Failed to parse kline topic: {e}");
+                        return;
+                    }
+                };
+                let Some(instrument) = self.instrument(raw_symbol) else {
+                    return;
+                };
+                let Some(bar_type) = self
+                    .routes
+                    .bar_types
+                    .read()
+                    .expect(MUTEX_POISONED)
+                    .get(&(instrument.id(), interval.to_string()))
+                    .copied()
+                else {
+                    tracing::debug!("No bar subscription for topic {}", msg.topic);
+                    return;
+                };
+                let ts_init = self.clock.get_time_ns();
+
+                // Only closed klines are emitted as bars
+                for kline in msg.data.iter().filter(|kline| kline.confirm) {
+                    match parse_ws_kline_bar(kline, &instrument, bar_type, true, ts_init) {
+                        Ok(bar) => self.send_data(Data::Bar(bar)),
+                        Err(e) => tracing::error!("Error parsing kline to bar: {e}"),
+                    }
+                }
+            }
+            BybitWebSocketMessage::AccountOrder(_)
+            | BybitWebSocketMessage::AccountExecution(_)
+            | BybitWebSocketMessage::AccountWallet(_)
+            | BybitWebSocketMessage::AccountPosition(_) => {
+                tracing::debug!("Ignoring trading message on data client");
+            }
+            BybitWebSocketMessage::Error(e) => {
+                tracing::error!(
+                    product_type = ?self.product_type,
+                    code = e.code,
+                    "Bybit websocket error: {}",
+                    e.message
+                );
+            }
+            BybitWebSocketMessage::Reconnected => {
+                // Subscriptions are restored by the websocket client and fresh snapshots follow
+                self.quote_cache.clear();
+                self.book_quotes.clear();
+                tracing::info!(product_type = ?self.product_type, "Websocket reconnected");
+            }
+            BybitWebSocketMessage::Raw(value) => {
+                tracing::debug!("Unhandled websocket payload: {value:?}");
+            }
+            BybitWebSocketMessage::Response(_)
+            | BybitWebSocketMessage::OrderResponse(_)
+            | BybitWebSocketMessage::Auth(_)
+            | BybitWebSocketMessage::Subscription(_)
+            | BybitWebSocketMessage::Pong => {}
+        }
+    }
+
+    fn instrument(&self, raw_symbol: &str) -> Option<InstrumentAny> {
+        let symbol = make_bybit_symbol(raw_symbol, self.product_type);
+        let instrument_id = InstrumentId::new(Symbol::from_ustr_unchecked(symbol), *BYBIT_VENUE);
+        let instrument = self
+            .instruments
+            .read()
+            .expect(MUTEX_POISONED)
+            .get(&instrument_id)
+            .cloned();
+
+        if instrument.is_none() {
+            tracing::warn!(raw_symbol = %raw_symbol, "No instrument found for {instrument_id}");
+        }
+        instrument
+    }
+
+    fn is_subscribed_quotes(&self, instrument_id: &InstrumentId) -> bool {
+        self.routes
+            .quotes
+            .read()
+            .expect(MUTEX_POISONED)
+            .contains(instrument_id)
+    }
+
+    fn send_data(&self, data: Data) {
+        if let Err(e) = self.data_sender.send(DataEvent::Data(data)) {
+            tracing::error!("Failed to emit data event: {e}");
+        }
+    }
+}
+
+fn product_types(config: &BybitDataClientConfig) -> Vec<BybitProductType> {
+    if config.product_types.is_empty() {
+        vec![BybitProductType::Linear]
+    } else {
+        config.product_types.clone()
+    }
+}
+
+/// Resolves the Bybit order book depth for a deltas subscription, validating any requested depth.
+fn resolve_book_depth(product_type: BybitProductType, depth: Option<usize>) -> anyhow::Result<u32> {
+    let supported: &[u32] = match product_type {
+        BybitProductType::Option => &BYBIT_OPTION_BOOK_DEPTHS,
+        _ => &BYBIT_BOOK_DEPTHS,
+    };
+
+    let Some(depth) = depth else {
+        return Ok(match product_type {
+            BybitProductType::Option => 25,
+            _ => 50,
+        });
+    };
+
+    match u32::try_from(depth) {
+        Ok(depth) if supported.contains(&depth) => Ok(depth),
+        _ => anyhow::bail!(
+            "invalid depth {depth} for {product_type:?}; valid values are {supported:?}"
+        ),
+    }
+}
+
+/// Returns the Bybit kline interval for a bar type supported by the public kline stream.
+fn bar_type_to_interval(bar_type: BarType) -> anyhow::Result<String> {
+    let spec = bar_type.spec();
+    if spec.price_type != PriceType::Last {
+        anyhow::bail!(
+            "Bybit klines only support LAST price bars, was {}",
+            spec.price_type
+        );
+    }
+    if !matches!(
+        spec.aggregation,
+        BarAggregation::Minute | BarAggregation::Hour | BarAggregation::Day
+    ) {
+        anyhow::bail!(
+            "Bybit bar subscriptions only support MINUTE, HOUR or DAY aggregation, was {:?}",
+            spec.aggregation
+        );
+    }
+    bar_spec_to_bybit_interval(spec.aggregation, spec.step.get() as u64)
+}
+
+fn datetime_to_unix_nanos(value: Option<DateTime<Utc>>) -> Option<UnixNanos> {
+    value
+        .and_then(|dt| dt.timestamp_nanos_opt())
+        .and_then(|nanos| u64::try_from(nanos).ok())
+        .map(UnixNanos::from)
+}
+
+#[async_trait::async_trait]
+impl DataClient for BybitDataClient {
+    fn client_id(&self) -> ClientId {
+        self.client_id
+    }
+
+    fn venue(&self) -> Option<Venue> {
+        Some(self.venue())
+    }
+
+    fn start(&mut self) -> anyhow::Result<()> {
+        tracing::info!(
+            client_id = %self.client_id,
+            product_types = ?self.config.product_types,
+            environment = ?self.config.environment,
+            "Starting Bybit data client"
+        );
+        Ok(())
+    }
+
+    fn stop(&mut self) -> anyhow::Result<()> {
+        tracing::info!("Stopping Bybit data client {id}", id = self.client_id);
+        self.cancellation_token.cancel();
+        self.is_connected.store(false, Ordering::Relaxed);
+        self.instrument_refresh_active = false;
+        Ok(())
+    }
+
+    fn reset(&mut self) -> anyhow::Result<()> {
+        tracing::debug!("Resetting Bybit data client {id}", id = self.client_id);
+        self.is_connected.store(false, Ordering::Relaxed);
+        self.cancellation_token = CancellationToken::new();
+        self.tasks.clear();
+        self.routes.clear();
+        self.instrument_refresh_active = false;
+        Ok(())
+    }
+
+    fn dispose(&mut self) -> anyhow::Result<()> {
+        tracing::debug!("Disposing Bybit data client {id}", id = self.client_id);
+        self.stop()
+    }
+
+    async fn connect(&mut self) -> anyhow::Result<()> {
+        if self.is_connected() {
+            return Ok(());
+        }
+
+        self.bootstrap_instruments().await?;
+
+        let product_types: Vec<BybitProductType> = self.ws_clients.keys().copied().collect();
+        for product_type in product_types {
+            if let Some(ws) = self.ws_clients.get_mut(&product_type) {
+                ws.connect().await.with_context(|| {
+                    format!("failed to connect Bybit {product_type:?} public websocket")
+                })?;
+                ws.wait_until_active(10.0).await.with_context(|| {
+                    format!("{product_type:?} public websocket did not become active")
+                })?;
+            }
+            self.spawn_stream_task(product_type)?;
+        }
+
+        self.maybe_spawn_instrument_refresh();
+
+        self.is_connected.store(true, Ordering::Relaxed);
+        tracing::info!("Bybit data client connected");
+        Ok(())
+    }
+
+    async fn disconnect(&mut self) -> anyhow::Result<()> {
+        if self.is_disconnected() {
+            return Ok(());
+        }
+
+        self.cancellation_token.cancel();
+
+        for ws in self.ws_clients.values_mut() {
+            let _ = ws.close().await;
+        }
+
+        for handle in self.tasks.drain(..) {
+            if let Err(e) = handle.await {
+                tracing::error!("Error joining websocket task: {e}");
+            }
+        }
+
+        self.cancellation_token = CancellationToken::new();
+        self.is_connected.store(false, Ordering::Relaxed);
+        self.routes.clear();
+        self.instrument_refresh_active = false;
+        tracing::info!("Bybit data client disconnected");
+        Ok(())
+    }
+
+    fn is_connected(&self) -> bool {
+        self.is_connected.load(Ordering::Relaxed)
+    }
+
+    fn is_disconnected(&self) -> bool {
+        !self.is_connected()
+    }
+
+    fn subscribe_book_deltas(&mut self, cmd: &SubscribeBookDeltas) -> anyhow::Result<()> {
+        if cmd.book_type != BookType::L2_MBP {
+            anyhow::bail!("Bybit only supports L2_MBP order book deltas");
+        }
+
+        let instrument_id = cmd.instrument_id;
+        let (product_type, ws) = self.ws_for(instrument_id)?;
+        let depth = resolve_book_depth(product_type, cmd.depth.map(|d| d.get()))?;
+
+        self.routes
+            .book_depths
+            .write()
+            .expect(MUTEX_POISONED)
+            .insert(instrument_id, depth);
+        self.spawn_ws(
+            async move {
+                ws.subscribe_orderbook(instrument_id, depth)
+                    .await
+                    .context("orderbook subscription")
+            },
+            "order book delta subscription",
+        );
+        Ok(())
+    }
+
+    fn subscribe_quotes(&mut self, cmd: &SubscribeQuotes) -> anyhow::Result<()> {
+        let instrument_id = cmd.instrument_id;
+        let (product_type, ws) = self.ws_for(instrument_id)?;
+
+        self.routes
+            .quotes
+            .write()
+            .expect(MUTEX_POISONED)
+            .insert(instrument_id);
+        self.spawn_ws(
+            async move {
+                if product_type == BybitProductType::Spot {
+                    ws.subscribe_orderbook(instrument_id, BYBIT_QUOTE_BOOK_DEPTH)
+                        .await
+                        .context("orderbook quote subscription")
+                } else {
+                    ws.subscribe_ticker(instrument_id)
+                        .await
+                        .context("ticker subscription")
+                }
+            },
+            "quote subscription",
+        );
+        Ok(())
+    }
+
+    fn subscribe_trades(&mut self, cmd: &SubscribeTrades) -> anyhow::Result<()> {
+        let instrument_id = cmd.instrument_id;
+        let (_, ws) = self.ws_for(instrument_id)?;
+        self.spawn_ws(
+            async move {
+                ws.subscribe_trades(instrument_id)
+                    .await
+                    .context("trades subscription")
+            },
+            "trade subscription",
+        );
+        Ok(())
+    }
+
+    fn subscribe_funding_rates(&mut self, cmd: &SubscribeFundingRates) -> anyhow::Result<()> {
+        let instrument_id = cmd.instrument_id;
+        let (product_type, ws) = self.ws_for(instrument_id)?;
+        if !matches!(
+            product_type,
+            BybitProductType::Linear | BybitProductType::Inverse
+        ) {
+            anyhow::bail!("Bybit funding rates are only available for LINEAR and INVERSE products");
+        }
+
+        self.routes
+            .funding_rates
+            .write()
+            .expect(MUTEX_POISONED)
+            .insert(instrument_id);
+        self.spawn_ws(
+            async move {
+                ws.subscribe_ticker(instrument_id)
+                    .await
+                    .context("funding rate subscription")
+            },
+            "funding rate subscription",
+        );
+        Ok(())
+    }
+
+    fn subscribe_bars(&mut self, cmd: &SubscribeBars) -> anyhow::Result<()> {
+        let bar_type = cmd.bar_type;
+        let instrument_id = bar_type.instrument_id();
+        let (_, ws) = self.ws_for(instrument_id)?;
+        let interval = bar_type_to_interval(bar_type)?;
+
+        self.routes
+            .bar_types
+            .write()
+            .expect(MUTEX_POISONED)
+            .insert((instrument_id, interval.clone()), bar_type);
+        self.spawn_ws(
+            async move {
+                ws.subscribe_klines(instrument_id, interval)
+                    .await
+                    .context("klines subscription")
+            },
+            "bar subscription",
+        );
+        Ok(())
+    }
+
+    fn unsubscribe_book_deltas(&mut self, cmd: &UnsubscribeBookDeltas) -> anyhow::Result<()> {
+        let instrument_id = cmd.instrument_id;
+        let (_, ws) = self.ws_for(instrument_id)?;
+        let Some(depth) = self
+            .routes
+            .book_depths
+            .write()
+            .expect(MUTEX_POISONED)
+            .remove(&instrument_id)
+        else {
+            tracing::warn!("No order book subscription found for {instrument_id}");
+            return Ok(());
+        };
+
+        self.spawn_ws(
+            async move {
+                ws.unsubscribe_orderbook(instrument_id, depth)
+                    .await
+                    .context("orderbook unsubscribe")
+            },
+            "order book unsubscribe",
+        );
+        Ok(())
+    }
+
+    fn unsubscribe_quotes(&mut self, cmd: &UnsubscribeQuotes) -> anyhow::Result<()> {
+        let instrument_id = cmd.instrument_id;
+        let (product_type, ws) = self.ws_for(instrument_id)?;
+
+        self.routes
+            .quotes
+            .write()
+            .expect(MUTEX_POISONED)
+            .remove(&instrument_id);
+        self.spawn_ws(
+            async move {
+                if product_type == BybitProductType::Spot {
+                    ws.unsubscribe_orderbook(instrument_id, BYBIT_QUOTE_BOOK_DEPTH)
+                        .await
+                        .context("orderbook quote unsubscribe")
+                } else {
+                    ws.unsubscribe_ticker(instrument_id)
+                        .await
+                        .context("ticker unsubscribe")
+                }
+            },
+            "quote unsubscribe",
+        );
+        Ok(())
+    }
+
+    fn unsubscribe_trades(&mut self, cmd: &UnsubscribeTrades) -> anyhow::Result<()> {
+        let instrument_id = cmd.instrument_id;
+        let (_, ws) = self.ws_for(instrument_id)?;
+        self.spawn_ws(
+            async move {
+                ws.unsubscribe_trades(instrument_id)
+                    .await
+                    .context("trades unsubscribe")
+            },
+            "trade unsubscribe",
+        );
+        Ok(())
+    }
+
+    fn unsubscribe_funding_rates(&mut self, cmd: &UnsubscribeFundingRates) -> anyhow::Result<()> {
+        let instrument_id = cmd.instrument_id;
+        let (_, ws) = self.ws_for(instrument_id)?;
+
+        self.routes
+            .funding_rates
+            .write()
+            .expect(MUTEX_POISONED)
+            .remove(&instrument_id);
+        self.spawn_ws(
+            async move {
+                ws.unsubscribe_ticker(instrument_id)
+                    .await
+                    .context("funding rate unsubscribe")
+            },
+            "funding rate unsubscribe",
+        );
+        Ok(())
+    }
+
+    fn unsubscribe_bars(&mut self, cmd: &UnsubscribeBars) -> anyhow::Result<()> {
+        let bar_type = cmd.bar_type;
+        let instrument_id = bar_type.instrument_id();
+        let (_, ws) = self.ws_for(instrument_id)?;
+        let interval = bar_type_to_interval(bar_type)?;
+
+        self.routes
+            .bar_types
+            .write()
+            .expect(MUTEX_POISONED)
+            .remove(&(instrument_id, interval.clone()));
+        self.spawn_ws(
+            async move {
+                ws.unsubscribe_klines(instrument_id, interval)
+                    .await
+                    .context("klines unsubscribe")
+            },
+            "bar unsubscribe",
+        );
+        Ok(())
+    }
+
+    fn request_instruments(&self, request: &RequestInstruments) -> anyhow::Result<()> {
+        let instruments = {
+            let guard = self.instruments.read().expect(MUTEX_POISONED);
+            guard.values().cloned().collect::<Vec<_>>()
+        };
+
+        let response = DataResponse::Instruments(InstrumentsResponse::new(
+            request.request_id,
+            request.client_id.unwrap_or(self.client_id),
+            self.venue(),
+            instruments,
+            datetime_to_unix_nanos(request.start),
+            datetime_to_unix_nanos(request.end),
+            self.clock.get_time_ns(),
+            request.params.clone(),
+        ));
+
+        if let Err(e) = self.data_sender.send(DataEvent::Response(response)) {
+            tracing::error!("Failed to send instruments response: {e}");
+        }
+
+        Ok(())
+    }
+
+    fn request_instrument(&self, request: &RequestInstrument) -> anyhow::Result<()> {
+        let instrument = {
+            let guard = self.instruments.read().expect(MUTEX_POISONED);
+            guard
+                .get(&request.instrument_id)
+                .cloned()
+                .context("instrument not found in cache")?
+        };
+
+        let response = DataResponse::Instrument(Box::new(InstrumentResponse::new(
+            request.request_id,
+            request.client_id.unwrap_or(self.client_id),
+            instrument.id(),
+            instrument,
+            datetime_to_unix_nanos(request.start),
+            datetime_to_unix_nanos(request.end),
+            self.clock.get_time_ns(),
+            request.params.clone(),
+        )));
+
+        if let Err(e) = self.data_sender.send(DataEvent::Response(response)) {
+            tracing::error!("Failed to send instrument response: {e}");
+        }
+
+        Ok(())
+    }
+
+    fn request_trades(&self, request: &RequestTrades) -> anyhow::Result<()> {
+        let http = self.http_client.clone();
+        let sender = self.data_sender.clone();
+        let instrument_id = request.instrument_id;
+        let product_type = BybitSymbol::new(instrument_id.symbol.as_str())?.product_type();
+        let limit = request.limit.map(|n| n.get() as u32);
+        let request_id = request.request_id;
+        let client_id = request.client_id.unwrap_or(self.client_id);
+        let params = request.params.clone();
+        let clock = self.clock;
+        let start_nanos = datetime_to_unix_nanos(request.start);
+        let end_nanos = datetime_to_unix_nanos(request.end);
+
+        tokio::spawn(async move {
+            match http
+                .request_trades(product_type, instrument_id, limit)
+                .await
+                .context("failed to request trades from Bybit")
+            {
+                Ok(trades) => {
+                    let response = DataResponse::Trades(TradesResponse::new(
+                        request_id,
+                        client_id,
+                        instrument_id,
+                        trades,
+                        start_nanos,
+                        end_nanos,
+                        clock.get_time_ns(),
+                        params,
+                    ));
+                    if let Err(e) = sender.send(DataEvent::Response(response)) {
+                        tracing::error!("Failed to send trades response: {e}");
+                    }
+                }
+                Err(e) => tracing::error!("Trade request failed: {e:?}"),
+            }
+        });
+
+        Ok(())
+    }
+
+    fn request_bars(&self, request: &RequestBars) -> anyhow::Result<()> {
+        let http = self.http_client.clone();
+        let sender = self.data_sender.clone();
+        let bar_type = request.bar_type;
+        let product_type =
+            BybitSymbol::new(bar_type.instrument_id().symbol.as_str())?.product_type();
+        let start_ms = request.start.map(|dt| dt.timestamp_millis());
+        let end_ms = request.end.map(|dt| dt.timestamp_millis());
+        let limit = request.limit.map(|n| n.get() as u32);
+        let request_id = request.request_id;
+        let client_id = request.client_id.unwrap_or(self.client_id);
+        let params = request.params.clone();
+        let clock = self.clock;
+        let start_nanos = datetime_to_unix_nanos(request.start);
+        let end_nanos = datetime_to_unix_nanos(request.end);
+
+        tokio::spawn(async move {
+            match http
+                .request_bars(product_type, bar_type, start_ms, end_ms, limit)
+                .await
+                .context("failed to request bars from Bybit")
+            {
+                Ok(bars) => {
+                    let response = DataResponse::Bars(BarsResponse::new(
+                        request_id,
+                        client_id,
+                        bar_type,
+                        bars,
+                        start_nanos,
+                        end_nanos,
+                        clock.get_time_ns(),
+                        params,
+                    ));
+                    if let Err(e) = sender.send(DataEvent::Response(response)) {
+                        tracing::error!("Failed to send bars response: {e}");
+                    }
+                }
+                Err(e) => tracing::error!("Bar request failed: {e:?}"),
+            }
+        });
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(BybitProductType::Linear, None, 50)]
+    #[case(BybitProductType::Spot, Some(1), 1)]
+    #[case(BybitProductType::Inverse, Some(200), 200)]
+    #[case(BybitProductType::Option, None, 25)]
+    #[case(BybitProductType::Option, Some(100), 100)]
+    fn test_resolve_book_depth(
+        #[case] product_type: BybitProductType,
+        #[case] depth: Option<usize>,
+        #[case] expected: u32,
+    ) {
+        assert_eq!(resolve_book_depth(product_type, depth).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case(BybitProductType::Linear, 25)]
+    #[case(BybitProductType::Spot, 10)]
+    #[case(BybitProductType::Option, 50)]
+    fn test_resolve_book_depth_invalid(
+        #[case] product_type: BybitProductType,
+        #[case] depth: usize,
+    ) {
+        assert!(resolve_book_depth(product_type, Some(depth)).is_err());
+    }
+
+    #[rstest]
+    #[case("BTCUSDT-LINEAR.BYBIT-1-MINUTE-LAST-EXTERNAL", "1")]
+    #[case("BTCUSDT-LINEAR.BYBIT-4-HOUR-LAST-EXTERNAL", "240")]
+    #[case("BTCUSDT-SPOT.BYBIT-1-DAY-LAST-EXTERNAL", "D")]
+    fn test_bar_type_to_interval(#[case] bar_type: &str, #[case] expected: &str) {
+        let bar_type = BarType::from(bar_type);
+        assert_eq!(bar_type_to_interval(bar_type).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case("BTCUSDT-LINEAR.BYBIT-1-MINUTE-MID-EXTERNAL")]
+    #[case("BTCUSDT-LINEAR.BYBIT-1-WEEK-LAST-EXTERNAL")]
+    #[case("BTCUSDT-LINEAR.BYBIT-2-MINUTE-LAST-EXTERNAL")]
+    fn test_bar_type_to_interval_unsupported(#[case] bar_type: &str) {
+        let bar_type = BarType::from(bar_type);
+        assert!(bar_type_to_interval(bar_type).is_err());
+    }
+}
@@ -14,3 +14,2089 @@
 // -------------------------------------------------------------------------------------------------
 
 //! Execution client implementation for the Bybit adapter.
+//!
+//! Orders are placed, amended and canceled over the authenticated trade WebSocket (including the
+//! batch endpoints), while the private WebSocket `order`, `execution`, `position` and `wallet`
+//! topics are translated into order events, reports and account state updates.
+
+use std::{
+    cell::Ref,
+    future::Future,
+    sync::{Arc, Mutex},
+};
+
+use ahash::{AHashMap, AHashSet};
+use anyhow::Context;
+use async_trait::async_trait;
+use dashmap::DashMap;
+use futures_util::{StreamExt, pin_mut};
+use nautilus_common::{
+    clock::Clock,
+    messages::{
+        ExecutionEvent, ExecutionReport,
+        execution::{
+            BatchCancelOrders, CancelAllOrders, CancelOrder, GenerateFillReports,
+            GenerateOrderStatusReport, GeneratePositionReports, ModifyOrder, QueryAccount,
+            QueryOrder, SubmitOrder, SubmitOrderList,
+        },
+    },
+    msgbus,
+    runner::get_exec_event_sender,
+    runtime::get_runtime,
+};
+use nautilus_core::{
+    MUTEX_POISONED, UUID4, UnixNanos,
+    time::{AtomicTime, get_atomic_clock_realtime},
+};
+use nautilus_execution::client::{ExecutionClient, LiveExecutionClient, base::ExecutionClientCore};
+use nautilus_live::execution::LiveExecutionClientExt;
+use nautilus_model::{
+    accounts::AccountAny,
+    enums::{OmsType, OrderSide, OrderStatus, OrderType},
+    events::{
+        AccountState, OrderAccepted, OrderCancelRejected, OrderCanceled, OrderEventAny,
+        OrderFilled, OrderModifyRejected, OrderRejected, OrderTriggered, OrderUpdated,
+    },
+    identifiers::{
+        AccountId, ClientId, ClientOrderId, InstrumentId, StrategyId, Symbol, TradeId, TraderId,
+        Venue, VenueOrderId,
+    },
+    instruments::{Instrument, InstrumentAny},
+    orders::{Order, OrderAny},
+    reports::{ExecutionMassStatus, FillReport, OrderStatusReport, PositionStatusReport},
+    types::{AccountBalance, Currency, MarginBalance, Price, Quantity},
+};
+use tokio::{sync::mpsc::UnboundedSender, task::JoinHandle};
+use ustr::Ustr;
+
+use crate::{
+    common::{
+        consts::BYBIT_VENUE,
+        credential::Credential,
+        enums::{BybitAccountType, BybitExecType, BybitProductType},
+        parse::make_bybit_symbol,
+        symbol::BybitSymbol,
+    },
+    config::BybitExecClientConfig,
+    http::client::BybitHttpClient,
+    websocket::{
+        client::BybitWebSocketClient,
+        messages::{
+            BybitWebSocketError, BybitWebSocketMessage, BybitWsAccountExecution,
+            BybitWsAccountOrder, BybitWsAccountPosition, BybitWsAccountWalletMsg,
+            BybitWsAmendOrderParams, BybitWsCancelOrderParams, BybitWsOrderResponse,
+            BybitWsPlaceOrderParams,
+        },
+        parse::{
+            parse_millis_i64, parse_ws_account_state, parse_ws_fill_report,
+            parse_ws_order_status_report, parse_ws_position_status_report,
+        },
+    },
+};
+
+/// Maximum number of orders accepted by the Bybit batch endpoints per request.
+const BYBIT_BATCH_LIMIT: usize = 20;
+
+/// Live execution client for Bybit.
+#[derive(Debug)]
+pub struct BybitExecutionClient {
+    core: ExecutionClientCore,
+    config: BybitExecClientConfig,
+    http_client: BybitHttpClient,
+    ws_private: BybitWebSocketClient,
+    ws_trade: BybitWebSocketClient,
+    exec_sender: UnboundedSender<ExecutionEvent>,
+    events: OrderEventFactory,
+    orders: Arc<DashMap<ClientOrderId, TrackedOrder>>,
+    pending_requests: Arc<DashMap<String, PendingRequest>>,
+    started: bool,
+    connected: bool,
+    instruments_initialized: bool,
+    ws_stream_handles: Vec<JoinHandle<()>>,
+    pending_tasks: Mutex<Vec<JoinHandle<()>>>,
+}
+
+impl BybitExecutionClient {
+    /// Creates a new [`BybitExecutionClient`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if API credentials are missing or the HTTP client fails to initialize.
+    pub fn new(core: ExecutionClientCore, config: BybitExecClientConfig) -> anyhow::Result<Self> {
+        let (Some(api_key), Some(api_secret)) = (config.api_key.clone(), config.api_secret.clone())
+        else {
+            anyhow::bail!("Bybit execution client requires API credentials");
+        };
+
+        let http_client = BybitHttpClient::with_credentials(
+            api_key.clone(),
+            api_secret.clone(),
+            Some(config.http_base_url()),
+            config.http_timeout_secs,
+            config.max_retries,
+            config.retry_delay_initial_ms,
+            config.retry_delay_max_ms,
+        )?;
+
+        let credential = Credential::new(api_key, api_secret);
+        let mut ws_private = BybitWebSocketClient::new_private(
+            config.environment,
+            credential.clone(),
+            Some(config.ws_private_url()),
+            config.heartbeat_interval_secs,
+        );
+        ws_private.set_account_id(core.account_id);
+
+        let ws_trade = BybitWebSocketClient::new_trade(
+            config.environment,
+            credential,
+            Some(config.ws_trade_url()),
+            config.heartbeat_interval_secs,
+        );
+
+        let events = OrderEventFactory {
+            trader_id: core.trader_id,
+            account_id: core.account_id,
+            clock: get_atomic_clock_realtime(),
+        };
+
+        Ok(Self {
+            core,
+            config,
+            http_client,
+            ws_private,
+            ws_trade,
+            exec_sender: get_exec_event_sender(),
+            events,
+            orders: Arc::new(DashMap::new()),
+            pending_requests: Arc::new(DashMap::new()),
+            started: false,
+            connected: false,
+            instruments_initialized: false,
+            ws_stream_handles: Vec::new(),
+            pending_tasks: Mutex::new(Vec::new()),
+        })
+    }
+
+    fn product_types(&self) -> Vec<BybitProductType> {
+        if self.config.product_types.is_empty() {
+            vec![BybitProductType::Linear]
+        } else {
+            self.config.product_types.clone()
+        }
+    }
+
+    /// Product types which carry positions (spot balances are reported through the wallet).
+    fn position_product_types(&self) -> Vec<BybitProductType> {
+        self.product_types()
+            .into_iter()
+            .filter(|product_type| !product_type.is_spot())
+            .collect()
+    }
+
+    async fn ensure_instruments_initialized_async(&mut self) -> anyhow::Result<()> {
+        if self.instruments_initialized {
+            return Ok(());
+        }
+
+        for product_type in self.product_types() {
+            let instruments = self
+                .http_client
+                .request_instruments(product_type, None)
+                .await
+                .with_context(|| {
+                    format!("failed to request Bybit instruments for {product_type:?}")
+                })?;
+
+            if instruments.is_empty() {
+                tracing::warn!("No instruments returned for {product_type:?}");
+                continue;
+            }
+
+            for instrument in instruments {
+                self.http_client.add_instrument(instrument.clone());
+                self.ws_trade.add_instrument(instrument.clone());
+                self.ws_private.add_instrument(instrument);
+            }
+        }
+
+        self.instruments_initialized = true;
+        Ok(())
+    }
+
+    fn ensure_instruments_initialized(&mut self) -> anyhow::Result<()> {
+        if self.instruments_initialized {
+            return Ok(());
+        }
+
+        let runtime = get_runtime();
+        runtime.block_on(self.ensure_instruments_initialized_async())
+    }
+
+    async fn refresh_account_state(&self) -> anyhow::Result<()> {
+        let account_state = self
+            .http_client
+            .request_account_state(BybitAccountType::Unified, self.core.account_id)
+            .await
+            .context("failed to request Bybit account state")?;
+
+        self.core.generate_account_state(
+            account_state.balances.clone(),
+            account_state.margins.clone(),
+            account_state.is_reported,
+            account_state.ts_event,
+        )
+    }
+
+    fn update_account_state(&self) -> anyhow::Result<()> {
+        let runtime = get_runtime();
+        runtime.block_on(self.refresh_account_state())
+    }
+
+    fn reject_order(&self, order: &OrderAny, reason: &str, ts_event: UnixNanos) {
+        self.core.generate_order_rejected(
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            reason,
+            ts_event,
+            false,
+        );
+    }
+
+    fn spawn_task<F>(&self, description: &'static str, fut: F)
+    where
+        F: Future<Output = anyhow::Result<()>> + Send + 'static,
+    {
+        let runtime = get_runtime();
+        let handle = runtime.spawn(async move {
+            if let Err(e) = fut.await {
+                tracing::warn!("{description} failed: {e:?}");
+            }
+        });
+
+        let mut tasks = self.pending_tasks.lock().expect(MUTEX_POISONED);
+        tasks.retain(|handle| !handle.is_finished());
+        tasks.push(handle);
+    }
+
+    fn abort_pending_tasks(&self) {
+        let mut tasks = self.pending_tasks.lock().expect(MUTEX_POISONED);
+        for handle in tasks.drain(..) {
+            handle.abort();
+        }
+    }
+
+    fn batch_cancel(&self, cancels: Vec<CancelOrder>) {
+        for (product_type, batch) in group_by_product_type(cancels, |cancel| cancel.instrument_id) {
+            for chunk in batch.chunks(BYBIT_BATCH_LIMIT) {
+                let chunk = chunk.to_vec();
+                let params = chunk
+                    .iter()
+                    .map(|cancel| cancel_order_params(product_type, cancel))
+                    .collect::<anyhow::Result<Vec<_>>>();
+
+                let params = match params {
+                    Ok(params) => params,
+                    Err(e) => {
+                        for cancel in &chunk {
+                            self.core.generate_order_cancel_rejected(
+                                cancel.strategy_id,
+                                cancel.instrument_id,
+                                cancel.client_order_id,
+                                cancel.venue_order_id,
+                                &format!("batch-cancel-error: {e}"),
+                                cancel.ts_init,
+                            );
+                        }
+                        continue;
+                    }
+                };
+
+                let req_id = UUID4::new().to_string();
+                self.pending_requests
+                    .insert(req_id.clone(), PendingRequest::Cancel(chunk.clone()));
+
+                let ws_trade = self.ws_trade.clone();
+                let pending_requests = Arc::clone(&self.pending_requests);
+                let events = self.events;
+                let sender = self.exec_sender.clone();
+
+                self.spawn_task("batch_cancel_orders", async move {
+                    if let Err(e) = ws_trade
+                        .batch_cancel_orders(params, Some(req_id.clone()))
+                        .await
+                    {
+                        pending_requests.remove(&req_id);
+                        let reason = format!("batch-cancel-error: {e}");
+                        for cancel in &chunk {
+                            send_order_event(
+                                &sender,
+                                events.cancel_rejected(
+                                    cancel.strategy_id,
+                                    cancel.instrument_id,
+                                    cancel.client_order_id,
+                                    cancel.venue_order_id,
+                                    &reason,
+                                ),
+                            );
+                        }
+                        return Err(e.into());
+                    }
+                    Ok(())
+                });
+            }
+        }
+    }
+
+    fn start_ws_streams(&mut self) {
+        if !self.ws_stream_handles.is_empty() {
+            return;
+        }
+
+        let handler = PrivateStreamHandler {
+            events: self.events,
+            account_id: self.core.account_id,
+            position_product_types: self.position_product_types(),
+            instruments: Arc::clone(self.ws_private.instruments()),
+            orders: Arc::clone(&self.orders),
+            sender: self.exec_sender.clone(),
+        };
+
+        let runtime = get_runtime();
+        let stream = self.ws_private.stream();
+        self.ws_stream_handles.push(runtime.spawn(async move {
+            pin_mut!(stream);
+            while let Some(message) = stream.next().await {
+                handler.handle(message);
+            }
+        }));
+
+        let handler = TradeStreamHandler {
+            events: self.events,
+            orders: Arc::clone(&self.orders),
+            pending_requests: Arc::clone(&self.pending_requests),
+            sender: self.exec_sender.clone(),
+        };
+
+        let stream = self.ws_trade.stream();
+        self.ws_stream_handles.push(runtime.spawn(async move {
+            pin_mut!(stream);
+            while let Some(message) = stream.next().await {
+                handler.handle(message);
+            }
+        }));
+    }
+
+    fn abort_ws_streams(&mut self) {
+        for handle in self.ws_stream_handles.drain(..) {
+            handle.abort();
+        }
+    }
+}
+
+impl ExecutionClient for BybitExecutionClient {
+    fn is_connected(&self) -> bool {
+        self.connected
+    }
+
+    fn client_id(&self) -> ClientId {
+        self.core.client_id
+    }
+
+    fn account_id(&self) -> AccountId {
+        self.core.account_id
+    }
+
+    fn venue(&self) -> Venue {
+        *BYBIT_VENUE
+    }
+
+    fn oms_type(&self) -> OmsType {
+        self.core.oms_type
+    }
+
+    fn get_account(&self) -> Option<AccountAny> {
+        self.core.get_account()
+    }
+
+    fn generate_account_state(
+        &self,
+        balances: Vec<AccountBalance>,
+        margins: Vec<MarginBalance>,
+        reported: bool,
+        ts_event: UnixNanos,
+    ) -> anyhow::Result<()> {
+        self.core
+            .generate_account_state(balances, margins, reported, ts_event)
+    }
+
+    fn start(&mut self) -> anyhow::Result<()> {
+        if self.started {
+            return Ok(());
+        }
+
+        self.ensure_instruments_initialized()?;
+        self.started = true;
+        tracing::info!(
+            client_id = %self.core.client_id,
+            account_id = %self.core.account_id,
+            product_types = ?self.product_types(),
+            environment = ?self.config.environment,
+            "Bybit execution client started"
+        );
+        Ok(())
+    }
+
+    fn stop(&mut self) -> anyhow::Result<()> {
+        if !self.started {
+            return Ok(());
+        }
+
+        self.started = false;
+        self.connected = false;
+        self.abort_ws_streams();
+        self.abort_pending_tasks();
+        tracing::info!("Bybit execution client {} stopped", self.core.client_id);
+        Ok(())
+    }
+
+    fn submit_order(&self, cmd: &SubmitOrder) -> anyhow::Result<()> {
+        let order = &cmd.order;
+
+        if order.is_closed() {
+            tracing::warn!("Cannot submit closed order {}", order.client_order_id());
+            return Ok(());
+        }
+
+        self.core.generate_order_submitted(
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            cmd.ts_init,
+        );
+
+        let params = match place_order_params(order) {
+            Ok(params) => params,
+            Err(e) => {
+                self.reject_order(order, &format!("submit-order-error: {e}"), cmd.ts_init);
+                return Err(e);
+            }
+        };
+
+        let client_order_id = order.client_order_id();
+        let tracked = TrackedOrder::from_order(order);
+        self.orders.insert(client_order_id, tracked.clone());
+
+        let req_id = UUID4::new().to_string();
+        self.pending_requests.insert(
+            req_id.clone(),
+            PendingRequest::Submit(vec![(client_order_id, tracked.clone())]),
+        );
+
+        let ws_trade = self.ws_trade.clone();
+        let orders = Arc::clone(&self.orders);
+        let pending_requests = Arc::clone(&self.pending_requests);
+        let events = self.events;
+        let sender = self.exec_sender.clone();
+
+        self.spawn_task("submit_order", async move {
+            if let Err(e) = ws_trade.place_order(params, Some(req_id.clone())).await {
+                pending_requests.remove(&req_id);
+                orders.remove(&client_order_id);
+                send_order_event(
+                    &sender,
+                    events.rejected(
+                        &tracked,
+                        client_order_id,
+                        &format!("submit-order-error: {e}"),
+                    ),
+                );
+                return Err(e.into());
+            }
+            Ok(())
+        });
+
+        Ok(())
+    }
+
+    fn submit_order_list(&self, cmd: &SubmitOrderList) -> anyhow::Result<()> {
+        let mut accepted = Vec::with_capacity(cmd.order_list.orders.len());
+
+        for order in &cmd.order_list.orders {
+            if order.is_closed() {
+                tracing::warn!("Cannot submit closed order {}", order.client_order_id());
+                continue;
+            }
+
+            self.core.generate_order_submitted(
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                cmd.ts_init,
+            );
+
+            match place_order_params(order) {
+                Ok(params) => accepted.push((TrackedOrder::from_order(order), order, params)),
+                Err(e) => {
+                    self.reject_order(order, &format!("submit-order-error: {e}"), cmd.ts_init);
+                }
+            }
+        }
+
+        for (_, batch) in group_by_product_type(accepted, |(tracked, _, _)| tracked.instrument_id) {
+            for chunk in batch.chunks(BYBIT_BATCH_LIMIT) {
+                let mut tracked_orders = Vec::with_capacity(chunk.len());
+                let mut params = Vec::with_capacity(chunk.len());
+
+                for (tracked, order, order_params) in chunk {
+                    self.orders.insert(order.client_order_id(), tracked.clone());
+                    tracked_orders.push((order.client_order_id(), tracked.clone()));
+                    params.push(order_params.clone());
+                }
+
+                let req_id = UUID4::new().to_string();
+                self.pending_requests.insert(
+                    req_id.clone(),
+                    PendingRequest::Submit(tracked_orders.clone()),
+                );
+
+                let ws_trade = self.ws_trade.clone();
+                let orders = Arc::clone(&self.orders);
+                let pending_requests = Arc::clone(&self.pending_requests);
+                let events = self.events;
+                let sender = self.exec_sender.clone();
+
+                self.spawn_task("batch_place_orders", async move {
+                    if let Err(e) = ws_trade
+                        .batch_place_orders(params, Some(req_id.clone()))
+                        .await
+                    {
+                        pending_requests.remove(&req_id);
+                        let reason = format!("submit-order-error: {e}");
+                        for (client_order_id, tracked) in &tracked_orders {
+                            orders.remove(client_order_id);
+                            send_order_event(
+                                &sender,
+                                events.rejected(tracked, *client_order_id, &reason),
+                            );
+                        }
+                        return Err(e.into());
+                    }
+                    Ok(())
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn modify_order(&self, cmd: &ModifyOrder) -> anyhow::Result<()> {
+        let params = match amend_order_params(cmd) {
+            Ok(params) => params,
+            Err(e) => {
+                self.core.generate_order_modify_rejected(
+                    cmd.strategy_id,
+                    cmd.instrument_id,
+                    cmd.client_order_id,
+                    cmd.venue_order_id,
+                    &format!("modify-order-error: {e}"),
+                    cmd.ts_init,
+                );
+                return Err(e);
+            }
+        };
+
+        let req_id = UUID4::new().to_string();
+        self.pending_requests.insert(
+            req_id.clone(),
+            PendingRequest::Modify(Box::new(cmd.clone())),
+        );
+
+        let ws_trade = self.ws_trade.clone();
+        let pending_requests = Arc::clone(&self.pending_requests);
+        let events = self.events;
+        let sender = self.exec_sender.clone();
+        let command = cmd.clone();
+
+        self.spawn_task("modify_order", async move {
+            if let Err(e) = ws_trade.amend_order(params, Some(req_id.clone())).await {
+                pending_requests.remove(&req_id);
+                send_order_event(
+                    &sender,
+                    events.modify_rejected(&command, &format!("modify-order-error: {e}")),
+                );
+                return Err(e.into());
+            }
+            Ok(())
+        });
+
+        Ok(())
+    }
+
+    fn cancel_order(&self, cmd: &CancelOrder) -> anyhow::Result<()> {
+        let params = BybitSymbol::new(cmd.instrument_id.symbol.as_str())
+            .and_then(|symbol| cancel_order_params(symbol.product_type(), cmd));
+
+        let params = match params {
+            Ok(params) => params,
+            Err(e) => {
+                self.core.generate_order_cancel_rejected(
+                    cmd.strategy_id,
+                    cmd.instrument_id,
+                    cmd.client_order_id,
+                    cmd.venue_order_id,
+                    &format!("cancel-order-error: {e}"),
+                    cmd.ts_init,
+                );
+                return Err(e);
+            }
+        };
+
+        let req_id = UUID4::new().to_string();
+        self.pending_requests
+            .insert(req_id.clone(), PendingRequest::Cancel(vec![cmd.clone()]));
+
+        let ws_trade = self.ws_trade.clone();
+        let pending_requests = Arc::clone(&self.pending_requests);
+        let events = self.events;
+        let sender = self.exec_sender.clone();
+        let command = cmd.clone();
+
+        self.spawn_task("cancel_order", async move {
+            if let Err(e) = ws_trade.cancel_order(params, Some(req_id.clone())).await {
+                pending_requests.remove(&req_id);
+                send_order_event(
+                    &sender,
+                    events.cancel_rejected(
+                        command.strategy_id,
+                        command.instrument_id,
+                        command.client_order_id,
+                        command.venue_order_id,
+                        &format!("cancel-order-error: {e}"),
+                    ),
+                );
+                return Err(e.into());
+            }
+            Ok(())
+        });
+
+        Ok(())
+    }
+
+    fn cancel_all_orders(&self, cmd: &CancelAllOrders) -> anyhow::Result<()> {
+        // Bybit cancels all orders for a symbol regardless of side, so a sided request is
+        // translated into a batch cancel of the matching open orders known to the cache
+        if cmd.order_side != OrderSide::NoOrderSide {
+            let cancels: Vec<CancelOrder> = self
+                .core
+                .cache()
+                .borrow()
+                .orders_open(
+                    Some(&self.venue()),
+                    Some(&cmd.instrument_id),
+                    None,
+                    Some(cmd.order_side),
+                )
+                .into_iter()
+                .map(|order| CancelOrder {
+                    trader_id: cmd.trader_id,
+                    client_id: cmd.client_id,
+                    strategy_id: order.strategy_id(),
+                    instrument_id: order.instrument_id(),
+                    client_order_id: order.client_order_id(),
+                    venue_order_id: order.venue_order_id().unwrap_or_default(),
+                    command_id: UUID4::new(),
+                    ts_init: cmd.ts_init,
+                })
+                .collect();
+
+            self.batch_cancel(cancels);
+            return Ok(());
+        }
+
+        let product_type = BybitSymbol::new(cmd.instrument_id.symbol.as_str())?.product_type();
+        let http_client = self.http_client.clone();
+        let instrument_id = cmd.instrument_id;
+
+        self.spawn_task("cancel_all_orders", async move {
+            http_client
+                .cancel_all_orders(product_type, instrument_id)
+                .await?;
+            Ok(())
+        });
+
+        Ok(())
+    }
+
+    fn batch_cancel_orders(&self, cmd: &BatchCancelOrders) -> anyhow::Result<()> {
+        self.batch_cancel(cmd.cancels.clone());
+        Ok(())
+    }
+
+    fn query_account(&self, _cmd: &QueryAccount) -> anyhow::Result<()> {
+        self.update_account_state()
+    }
+
+    fn query_order(&self, cmd: &QueryOrder) -> anyhow::Result<()> {
+        let product_type = BybitSymbol::new(cmd.instrument_id.symbol.as_str())?.product_type();
+        let http_client = self.http_client.clone();
+        let account_id = self.core.account_id;
+        let sender = self.exec_sender.clone();
+        let command = cmd.clone();
+
+        self.spawn_task("query_order", async move {
+            let report = http_client
+                .query_order(
+                    account_id,
+                    product_type,
+                    command.instrument_id,
+                    Some(command.client_order_id),
+                    Some(command.venue_order_id),
+                )
+                .await?;
+
+            match report {
+                Some(report) => {
+                    send_report(&sender, ExecutionReport::OrderStatus(Box::new(report)));
+                }
+                None => tracing::warn!("Order {} not found on Bybit", command.client_order_id),
+            }
+            Ok(())
+        });
+
+        Ok(())
+    }
+}
+
+#[async_trait(?Send)]
+impl LiveExecutionClient for BybitExecutionClient {
+    async fn connect(&mut self) -> anyhow::Result<()> {
+        if self.connected {
+            return Ok(());
+        }
+
+        self.ensure_instruments_initialized_async().await?;
+
+        self.ws_private.connect().await?;
+        self.ws_private.wait_until_active(10.0).await?;
+        self.ws_trade.connect().await?;
+        self.ws_trade.wait_until_active(10.0).await?;
+
+        self.ws_private.subscribe_orders().await?;
+        self.ws_private.subscribe_executions().await?;
+        self.ws_private.subscribe_positions().await?;
+        self.ws_private.subscribe_wallet().await?;
+
+        self.start_ws_streams();
+        self.refresh_account_state().await?;
+
+        self.connected = true;
+        tracing::info!("Bybit execution client {} connected", self.core.client_id);
+
+        Ok(())
+    }
+
+    async fn disconnect(&mut self) -> anyhow::Result<()> {
+        if !self.connected {
+            return Ok(());
+        }
+
+        self.http_client.cancel_all_requests();
+
+        if let Err(e) = self.ws_private.close().await {
+            tracing::warn!("Error while closing Bybit private websocket: {e:?}");
+        }
+
+        if let Err(e) = self.ws_trade.close().await {
+            tracing::warn!("Error while closing Bybit trade websocket: {e:?}");
+        }
+
+        self.abort_ws_streams();
+        self.abort_pending_tasks();
+
+        self.connected = false;
+        tracing::info!(
+            "Bybit execution client {} disconnected",
+            self.core.client_id
+        );
+        Ok(())
+    }
+
+    async fn generate_order_status_report(
+        &self,
+        cmd: &GenerateOrderStatusReport,
+    ) -> anyhow::Result<Option<OrderStatusReport>> {
+        let Some(instrument_id) = cmd.instrument_id else {
+            tracing::warn!("generate_order_status_report requires instrument_id: {cmd:?}");
+            return Ok(None);
+        };
+
+        let product_type = BybitSymbol::new(instrument_id.symbol.as_str())?.product_type();
+        let venue_order_id = cmd.venue_order_id.map(|id| VenueOrderId::new(id.as_str()));
+
+        self.http_client
+            .query_order(
+                self.core.account_id,
+                product_type,
+                instrument_id,
+                cmd.client_order_id,
+                venue_order_id,
+            )
+            .await
+    }
+
+    async fn generate_order_status_reports(
+        &self,
+        cmd: &GenerateOrderStatusReport,
+    ) -> anyhow::Result<Vec<OrderStatusReport>> {
+        let mut reports = Vec::new();
+
+        if let Some(instrument_id) = cmd.instrument_id {
+            let product_type = BybitSymbol::new(instrument_id.symbol.as_str())?.product_type();
+            let mut fetched = self
+                .http_client
+                .request_order_status_reports(
+                    self.core.account_id,
+                    product_type,
+                    Some(instrument_id),
+                    false,
+                    None,
+                )
+                .await?;
+            reports.append(&mut fetched);
+        } else {
+            for product_type in self.product_types() {
+                let mut fetched = self
+                    .http_client
+                    .request_order_status_reports(
+                        self.core.account_id,
+                        product_type,
+                        None,
+                        false,
+                        None,
+                    )
+                    .await?;
+                reports.append(&mut fetched);
+            }
+        }
+
+        if let Some(client_order_id) = cmd.client_order_id {
+            reports.retain(|report| report.client_order_id == Some(client_order_id));
+        }
+
+        if let Some(venue_order_id) = cmd.venue_order_id {
+            reports.retain(|report| report.venue_order_id.as_str() == venue_order_id.as_str());
+        }
+
+        Ok(reports)
+    }
+
+    async fn generate_fill_reports(
+        &self,
+        cmd: GenerateFillReports,
+    ) -> anyhow::Result<Vec<FillReport>> {
+        let start_ms = nanos_to_millis(cmd.start);
+        let end_ms = nanos_to_millis(cmd.end);
+        let mut reports = Vec::new();
+
+        if let Some(instrument_id) = cmd.instrument_id {
+            let product_type = BybitSymbol::new(instrument_id.symbol.as_str())?.product_type();
+            let mut fetched = self
+                .http_client
+                .request_fill_reports(
+                    self.core.account_id,
+                    product_type,
+                    Some(instrument_id),
+                    start_ms,
+                    end_ms,
+                    None,
+                )
+                .await?;
+            reports.append(&mut fetched);
+        } else {
+            for product_type in self.product_types() {
+                let mut fetched = self
+                    .http_client
+                    .request_fill_reports(
+                        self.core.account_id,
+                        product_type,
+                        None,
+                        start_ms,
+                        end_ms,
+                        None,
+                    )
+                    .await?;
+                reports.append(&mut fetched);
+            }
+        }
+
+        if let Some(venue_order_id) = cmd.venue_order_id {
+            reports.retain(|report| report.venue_order_id.as_str() == venue_order_id.as_str());
+        }
+
+        Ok(reports)
+    }
+
+    async fn generate_position_status_reports(
+        &self,
+        cmd: &GeneratePositionReports,
+    ) -> anyhow::Result<Vec<PositionStatusReport>> {
+        let mut reports = Vec::new();
+
+        if let Some(instrument_id) = cmd.instrument_id {
+            let product_type = BybitSymbol::new(instrument_id.symbol.as_str())?.product_type();
+            if product_type.is_spot() {
+                return Ok(reports);
+            }
+
+            let mut fetched = self
+                .http_client
+                .request_position_status_reports(
+                    self.core.account_id,
+                    product_type,
+                    Some(instrument_id),
+                )
+                .await?;
+            reports.append(&mut fetched);
+        } else {
+            for product_type in self.position_product_types() {
+                let mut fetched = self
+                    .http_client
+                    .request_position_status_reports(self.core.account_id, product_type, None)
+                    .await?;
+                reports.append(&mut fetched);
+            }
+        }
+
+        Ok(reports)
+    }
+
+    async fn generate_mass_status(
+        &self,
+        lookback_mins: Option<u64>,
+    ) -> anyhow::Result<Option<ExecutionMassStatus>> {
+        let ts_now = self.events.clock.get_time_ns();
+        let start_ms = lookback_mins.map(|mins| {
+            let lookback_ns = mins * 60 * 1_000_000_000;
+            UnixNanos::from(ts_now.as_u64().saturating_sub(lookback_ns))
+                .to_datetime_utc()
+                .timestamp_millis()
+        });
+
+        let mut mass_status = ExecutionMassStatus::new(
+            self.core.client_id,
+            self.core.account_id,
+            *BYBIT_VENUE,
+            ts_now,
+            None,
+        );
+
+        for product_type in self.product_types() {
+            let order_reports = self
+                .http_client
+                .request_order_status_reports(self.core.account_id, product_type, None, true, None)
+                .await
+                .with_context(|| format!("failed to request {product_type:?} open orders"))?;
+
+            let fill_reports = self
+                .http_client
+                .request_fill_reports(
+                    self.core.account_id,
+                    product_type,
+                    None,
+                    start_ms,
+                    None,
+                    None,
+                )
+                .await
+                .with_context(|| format!("failed to request {product_type:?} fills"))?;
+
+            tracing::info!(
+                "Received {} order and {} fill reports for {product_type:?}",
+                order_reports.len(),
+                fill_reports.len(),
+            );
+
+            mass_status.add_order_reports(order_reports);
+            mass_status.add_fill_reports(fill_reports);
+
+            if product_type.is_spot() {
+                continue;
+            }
+
+            let position_reports = self
+                .http_client
+                .request_position_status_reports(self.core.account_id, product_type, None)
+                .await
+                .with_context(|| format!("failed to request {product_type:?} positions"))?;
+
+            tracing::info!(
+                "Received {} position reports for {product_type:?}",
+                position_reports.len()
+            );
+
+            mass_status.add_position_reports(position_reports);
+        }
+
+        Ok(Some(mass_status))
+    }
+}
+
+impl LiveExecutionClientExt for BybitExecutionClient {
+    fn get_message_channel(&self) -> UnboundedSender<ExecutionEvent> {
+        self.exec_sender.clone()
+    }
+
+    fn get_clock(&self) -> Ref<'_, dyn Clock> {
+        self.core.clock().borrow()
+    }
+}
+
+/// Local view of an order submitted through this client, used to turn private stream updates
+/// into order events.
+#[derive(Clone, Debug)]
+struct TrackedOrder {
+    strategy_id: StrategyId,
+    instrument_id: InstrumentId,
+    order_side: OrderSide,
+    order_type: OrderType,
+    quantity: Quantity,
+    price: Option<Price>,
+    trigger_price: Option<Price>,
+    filled_qty: Quantity,
+    trade_ids: AHashSet<TradeId>,
+    accepted: bool,
+}
+
+impl TrackedOrder {
+    fn from_order(order: &OrderAny) -> Self {
+        let quantity = order.quantity();
+        Self {
+            strategy_id: order.strategy_id(),
+            instrument_id: order.instrument_id(),
+            order_side: order.order_side(),
+            order_type: order.order_type(),
+            quantity,
+            price: order.price(),
+            trigger_price: order.trigger_price(),
+            filled_qty: Quantity::zero(quantity.precision),
+            trade_ids: AHashSet::new(),
+            accepted: false,
+        }
+    }
+
+    fn is_filled(&self) -> bool {
+        self.filled_qty >= self.quantity
+    }
+}
+
+/// Builds order events outside the execution client core, which cannot be moved into tasks.
+#[derive(Clone, Copy, Debug)]
+struct OrderEventFactory {
+    trader_id: TraderId,
+    account_id: AccountId,
+    clock: &'static AtomicTime,
+}
+
+impl OrderEventFactory {
+    fn accepted(
+        &self,
+        tracked: &TrackedOrder,
+        client_order_id: ClientOrderId,
+        venue_order_id: VenueOrderId,
+        ts_event: UnixNanos,
+    ) -> OrderEventAny {
+        OrderEventAny::Accepted(OrderAccepted::new(
+            self.trader_id,
+            tracked.strategy_id,
+            tracked.instrument_id,
+            client_order_id,
+            venue_order_id,
+            self.account_id,
+            UUID4::new(),
+            ts_event,
+            self.clock.get_time_ns(),
+            false,
+        ))
+    }
+
+    fn rejected(
+        &self,
+        tracked: &TrackedOrder,
+        client_order_id: ClientOrderId,
+        reason: &str,
+    ) -> OrderEventAny {
+        let ts_now = self.clock.get_time_ns();
+        OrderEventAny::Rejected(OrderRejected::new(
+            self.trader_id,
+            tracked.strategy_id,
+            tracked.instrument_id,
+            client_order_id,
+            self.account_id,
+            Ustr::from(reason),
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            false,
+        ))
+    }
+
+    fn canceled(
+        &self,
+        tracked: &TrackedOrder,
+        client_order_id: ClientOrderId,
+        venue_order_id: VenueOrderId,
+        ts_event: UnixNanos,
+    ) -> OrderEventAny {
+        OrderEventAny::Canceled(OrderCanceled::new(
+            self.trader_id,
+            tracked.strategy_id,
+            tracked.instrument_id,
+            client_order_id,
+            UUID4::new(),
+            ts_event,
+            self.clock.get_time_ns(),
+            false,
+            Some(venue_order_id),
+            Some(self.account_id),
+        ))
+    }
+
+    fn triggered(
+        &self,
+        tracked: &TrackedOrder,
+        client_order_id: ClientOrderId,
+        venue_order_id: VenueOrderId,
+        ts_event: UnixNanos,
+    ) -> OrderEventAny {
+        OrderEventAny::Triggered(OrderTriggered::new(
+            self.trader_id,
+            tracked.strategy_id,
+            tracked.instrument_id,
+            client_order_id,
+            UUID4::new(),
+            ts_event,
+            self.clock.get_time_ns(),
+            false,
+            Some(venue_order_id),
+            Some(self.account_id),
+        ))
+    }
+
+    fn updated(
+        &self,
+        tracked: &TrackedOrder,
+        client_order_id: ClientOrderId,
+        venue_order_id: VenueOrderId,
+        ts_event: UnixNanos,
+    ) -> OrderEventAny {
+        OrderEventAny::Updated(OrderUpdated::new(
+            self.trader_id,
+            tracked.strategy_id,
+            tracked.instrument_id,
+            client_order_id,
+            tracked.quantity,
+            UUID4::new(),
+            ts_event,
+            self.clock.get_time_ns(),
+            false,
+            Some(venue_order_id),
+            Some(self.account_id),
+            tracked.price,
+            tracked.trigger_price,
+        ))
+    }
+
+    fn filled(
+        &self,
+        tracked: &TrackedOrder,
+        client_order_id: ClientOrderId,
+        fill: &FillReport,
+        currency: Currency,
+    ) -> OrderEventAny {
+        OrderEventAny::Filled(OrderFilled::new(
+            self.trader_id,
+            tracked.strategy_id,
+            tracked.instrument_id,
+            client_order_id,
+            fill.venue_order_id,
+            self.account_id,
+            fill.trade_id,
+            tracked.order_side,
+            tracked.order_type,
+            fill.last_qty,
+            fill.last_px,
+            currency,
+            fill.liquidity_side,
+            UUID4::new(),
+            fill.ts_event,
+            self.clock.get_time_ns(),
+            false,
+            None,
+            Some(fill.commission),
+        ))
+    }
+
+    fn modify_rejected(&self, cmd: &ModifyOrder, reason: &str) -> OrderEventAny {
+        let ts_now = self.clock.get_time_ns();
+        OrderEventAny::ModifyRejected(OrderModifyRejected::new(
+            self.trader_id,
+            cmd.strategy_id,
+            cmd.instrument_id,
+            cmd.client_order_id,
+            Ustr::from(reason),
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            Some(cmd.venue_order_id),
+            Some(self.account_id),
+        ))
+    }
+
+    fn cancel_rejected(
+        &self,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        venue_order_id: VenueOrderId,
+        reason: &str,
+    ) -> OrderEventAny {
+        let ts_now = self.clock.get_time_ns();
+        OrderEventAny::CancelRejected(OrderCancelRejected::new(
+            self.trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            Ustr::from(reason),
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            Some(venue_order_id),
+            Some(self.account_id),
+        ))
+    }
+}
+
+/// Translates a venue order update for a tracked order into order events.
+///
+/// Fills are not emitted here as they arrive on the `execution` topic, but an order which is
+/// first seen partially filled, triggered or canceled is accepted beforehand so the order state
+/// machine transitions remain valid.
+fn order_events_from_report(
+    events: &OrderEventFactory,
+    client_order_id: ClientOrderId,
+    tracked: &mut TrackedOrder,
+    report: &OrderStatusReport,
+) -> Vec<OrderEventAny> {
+    let venue_order_id = report.venue_order_id;
+    let ts_event = report.ts_last;
+    let mut out = Vec::new();
+
+    let was_accepted = tracked.accepted;
+    if !was_accepted && report.order_status != OrderStatus::Rejected {
+        tracked.accepted = true;
+        out.push(events.accepted(tracked, client_order_id, venue_order_id, ts_event));
+    }
+
+    match report.order_status {
+        OrderStatus::Accepted | OrderStatus::PartiallyFilled if was_accepted => {
+            let changed = report.quantity != tracked.quantity
+                || (report.price.is_some() && report.price != tracked.price)
+                || (report.trigger_price.is_some()
+                    && report.trigger_price != tracked.trigger_price);
+
+            if changed {
+                tracked.quantity = report.quantity;
+                tracked.price = report.price.or(tracked.price);
+                tracked.trigger_price = report.trigger_price.or(tracked.trigger_price);
+                out.push(events.updated(tracked, client_order_id, venue_order_id, ts_event));
+            }
+        }
+        OrderStatus::Triggered => {
+            out.push(events.triggered(tracked, client_order_id, venue_order_id, ts_event));
+        }
+        OrderStatus::Canceled => {
+            out.push(events.canceled(tracked, client_order_id, venue_order_id, ts_event));
+        }
+        OrderStatus::Rejected if was_accepted => {
+            out.push(events.canceled(tracked, client_order_id, venue_order_id, ts_event));
+        }
+        OrderStatus::Rejected => {
+            let reason = report.cancel_reason.as_deref().unwrap_or("UNKNOWN");
+            out.push(events.rejected(tracked, client_order_id, reason));
+        }
+        _ => {}
+    }
+
+    out
+}
+
+/// Translates a venue execution for a tracked order into order events.
+///
+/// Executions already seen for the order (e.g. replayed after a reconnect) are ignored.
+fn order_events_from_fill(
+    events: &OrderEventFactory,
+    client_order_id: ClientOrderId,
+    tracked: &mut TrackedOrder,
+    fill: &FillReport,
+    currency: Currency,
+) -> Vec<OrderEventAny> {
+    if !tracked.trade_ids.insert(fill.trade_id) {
+        tracing::debug!(
+            "Duplicate execution {} for {client_order_id}",
+            fill.trade_id
+        );
+        return Vec::new();
+    }
+
+    let mut out = Vec::with_capacity(2);
+
+    if !tracked.accepted {
+        tracked.accepted = true;
+        out.push(events.accepted(tracked, client_order_id, fill.venue_order_id, fill.ts_event));
+    }
+
+    tracked.filled_qty += fill.last_qty;
+    out.push(events.filled(tracked, client_order_id, fill, currency));
+    out
+}
+
+/// An order request sent on the trade WebSocket which is awaiting its response, holding the
+/// requested items in request order.
+#[derive(Clone, Debug)]
+enum PendingRequest {
+    Submit(Vec<(ClientOrderId, TrackedOrder)>),
+    Modify(Box<ModifyOrder>),
+    Cancel(Vec<CancelOrder>),
+}
+
+impl PendingRequest {
+    fn len(&self) -> usize {
+        match self {
+            Self::Submit(orders) => orders.len(),
+            Self::Modify(_) => 1,
+            Self::Cancel(cancels) => cancels.len(),
+        }
+    }
+
+    fn position(&self, client_order_id: &ClientOrderId) -> Option<usize> {
+        match self {
+            Self::Submit(orders) => orders.iter().position(|(id, _)| id == client_order_id),
+            Self::Modify(cmd) => (cmd.client_order_id == *client_order_id).then_some(0),
+            Self::Cancel(cancels) => cancels
+                .iter()
+                .position(|cancel| cancel.client_order_id == *client_order_id),
+        }
+    }
+}
+
+/// Translates the failure of a request item into its rejection event.
+fn rejection_event(
+    events: &OrderEventFactory,
+    request: &PendingRequest,
+    index: usize,
+    reason: &str,
+) -> Option<OrderEventAny> {
+    match request {
+        PendingRequest::Submit(orders) => {
+            let (client_order_id, tracked) = orders.get(index)?;
+            Some(events.rejected(tracked, *client_order_id, reason))
+        }
+        PendingRequest::Modify(cmd) => Some(events.modify_rejected(cmd, reason)),
+        PendingRequest::Cancel(cancels) => {
+            let cancel = cancels.get(index)?;
+            Some(events.cancel_rejected(
+                cancel.strategy_id,
+                cancel.instrument_id,
+                cancel.client_order_id,
+                cancel.venue_order_id,
+                reason,
+            ))
+        }
+    }
+}
+
+/// Returns the rejection events for a trade WebSocket order response.
+///
+/// A request with a non-zero `retCode` failed as a whole, otherwise each failed item of a batch
+/// request is reported in `retExtInfo`, matched to the request by `orderLinkId` when returned
+/// and by position otherwise.
+fn order_response_rejections(
+    events: &OrderEventFactory,
+    request: &PendingRequest,
+    response: &BybitWsOrderResponse,
+) -> Vec<OrderEventAny> {
+    if response.ret_code != 0 {
+        let reason = format!("{}: {}", response.ret_code, response.ret_msg);
+        return (0..request.len())
+            .filter_map(|index| rejection_event(events, request, index, &reason))
+            .collect();
+    }
+
+    response
+        .ret_ext_info
+        .list
+        .iter()
+        .enumerate()
+        .filter(|(_, result)| result.code != 0)
+        .filter_map(|(index, result)| {
+            let index = response
+                .data
+                .list
+                .get(index)
+                .and_then(|data| data.order_link_id.as_deref())
+                .filter(|order_link_id| !order_link_id.is_empty())
+                .and_then(|order_link_id| request.position(&ClientOrderId::new(order_link_id)))
+                .unwrap_or(index);
+            let reason = format!("{}: {}", result.code, result.msg);
+            rejection_event(events, request, index, &reason)
+        })
+        .collect()
+}
+
+/// Handles messages from the trade WebSocket stream.
+struct TradeStreamHandler {
+    events: OrderEventFactory,
+    orders: Arc<DashMap<ClientOrderId, TrackedOrder>>,
+    pending_requests: Arc<DashMap<String, PendingRequest>>,
+    sender: UnboundedSender<ExecutionEvent>,
+}
+
+impl TradeStreamHandler {
+    fn handle(&self, message: BybitWebSocketMessage) {
+        match message {
+            BybitWebSocketMessage::OrderResponse(response) => self.handle_response(&response),
+            BybitWebSocketMessage::Error(e) => self.handle_error(&e),
+            BybitWebSocketMessage::Reconnected => {
+                tracing::info!("Bybit trade websocket reconnected");
+
+                // Responses to requests in flight when the connection dropped are lost
+                if !self.pending_requests.is_empty() {
+                    tracing::warn!(
+                        "Clearing {} unanswered order requests after reconnect",
+                        self.pending_requests.len()
+                    );
+                    self.pending_requests.clear();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_response(&self, response: &BybitWsOrderResponse) {
+        let Some((_, request)) = response
+            .req_id
+            .as_ref()
+            .and_then(|req_id| self.pending_requests.remove(req_id))
+        else {
+            tracing::debug!("Unmatched Bybit order response: {response:?}");
+            return;
+        };
+
+        if response.ret_code != 0 {
+            tracing::warn!(
+                "Bybit {:?} request rejected: code={} message={}",
+                response.op,
+                response.ret_code,
+                response.ret_msg
+            );
+        }
+
+        self.send_rejections(
+            &request,
+            order_response_rejections(&self.events, &request, response),
+        );
+    }
+
+    fn handle_error(&self, error: &BybitWebSocketError) {
+        tracing::warn!(
+            "Bybit trade websocket error: code={} message={}",
+            error.code,
+            error.message
+        );
+
+        let Some((_, request)) = error
+            .req_id
+            .as_ref()
+            .and_then(|req_id| self.pending_requests.remove(req_id))
+        else {
+            return;
+        };
+
+        let reason = format!("{}: {}", error.code, error.message);
+        let rejections = (0..request.len())
+            .filter_map(|index| rejection_event(&self.events, &request, index, &reason))
+            .collect();
+        self.send_rejections(&request, rejections);
+    }
+
+    fn send_rejections(&self, request: &PendingRequest, rejections: Vec<OrderEventAny>) {
+        for event in rejections {
+            if matches!(request, PendingRequest::Submit(_)) {
+                self.orders.remove(&event.client_order_id());
+            }
+            send_order_event(&self.sender, event);
+        }
+    }
+}
+
+/// Handles messages from the private WebSocket stream.
+struct PrivateStreamHandler {
+    events: OrderEventFactory,
+    account_id: AccountId,
+    position_product_types: Vec<BybitProductType>,
+    instruments: Arc<DashMap<InstrumentId, InstrumentAny>>,
+    orders: Arc<DashMap<ClientOrderId, TrackedOrder>>,
+    sender: UnboundedSender<ExecutionEvent>,
+}
+
+impl PrivateStreamHandler {
+    fn handle(&self, message: BybitWebSocketMessage) {
+        match message {
+            BybitWebSocketMessage::AccountOrder(msg) => {
+                for order in &msg.data {
+                    self.handle_order(order);
+                }
+            }
+            BybitWebSocketMessage::AccountExecution(msg) => {
+                for execution in &msg.data {
+                    self.handle_execution(execution);
+                }
+            }
+            BybitWebSocketMessage::AccountPosition(msg) => {
+                for position in &msg.data {
+                    self.handle_position(position);
+                }
+            }
+            BybitWebSocketMessage::AccountWallet(msg) => self.handle_wallet(&msg),
+            BybitWebSocketMessage::Error(e) => {
+                tracing::warn!(
+                    "Bybit private websocket error: code={} message={}",
+                    e.code,
+                    e.message
+                );
+            }
+            BybitWebSocketMessage::Reconnected => {
+                tracing::info!("Bybit private websocket reconnected");
+            }
+            _ => {}
+        }
+    }
+
+    fn instrument(
+        &self,
+        product_type: BybitProductType,
+        raw_symbol: &str,
+    ) -> Option<InstrumentAny> {
+        let symbol = make_bybit_symbol(raw_symbol, product_type);
+        let instrument_id = InstrumentId::new(Symbol::from_ustr_unchecked(symbol), *BYBIT_VENUE);
+        self.instruments
+            .get(&instrument_id)
+            .map(|entry| entry.value().clone())
+    }
+
+    fn handle_order(&self, order: &BybitWsAccountOrder) {
+        let Some(instrument) = self.instrument(order.category, &order.symbol) else {
+            tracing::warn!("No instrument for Bybit order update on {}", order.symbol);
+            return;
+        };
+
+        let ts_init = self.events.clock.get_time_ns();
+        let report =
+            match parse_ws_order_status_report(order, &instrument, self.account_id, ts_init) {
+                Ok(report) => report,
+                Err(e) => {
+                    tracing::error!("Failed to parse Bybit order update: {e}");
+                    return;
+                }
+            };
+
+        if let Some(client_order_id) = report.client_order_id
+            && let Some(mut tracked) = self.orders.get_mut(&client_order_id)
+        {
+            let events =
+                order_events_from_report(&self.events, client_order_id, &mut tracked, &report);
+            drop(tracked);
+
+            if matches!(
+                report.order_status,
+                OrderStatus::Canceled | OrderStatus::Rejected
+            ) {
+                self.orders.remove(&client_order_id);
+            }
+
+            for event in events {
+                send_order_event(&self.sender, event);
+            }
+            return;
+        }
+
+        send_report(&self.sender, ExecutionReport::OrderStatus(Box::new(report)));
+    }
+
+    fn handle_execution(&self, execution: &BybitWsAccountExecution) {
+        if !matches!(
+            execution.exec_type,
+            BybitExecType::Trade
+                | BybitExecType::AdlTrade
+                | BybitExecType::BustTrade
+                | BybitExecType::BlockTrade
+        ) {
+            return;
+        }
+
+        let Some(instrument) = self.instrument(execution.category, &execution.symbol) else {
+            tracing::warn!("No instrument for Bybit execution on {}", execution.symbol);
+            return;
+        };
+
+        let ts_init = self.events.clock.get_time_ns();
+        let fill = match parse_ws_fill_report(execution, self.account_id, &instrument, ts_init) {
+            Ok(fill) => fill,
+            Err(e) => {
+                tracing::error!("Failed to parse Bybit execution: {e}");
+                return;
+            }
+        };
+
+        if let Some(client_order_id) = fill.client_order_id
+            && let Some(mut tracked) = self.orders.get_mut(&client_order_id)
+        {
+            let events = order_events_from_fill(
+                &self.events,
+                client_order_id,
+                &mut tracked,
+                &fill,
+                instrument.quote_currency(),
+            );
+            let is_filled = tracked.is_filled();
+            drop(tracked);
+
+            if is_filled {
+                self.orders.remove(&client_order_id);
+            }
+
+            for event in events {
+                send_order_event(&self.sender, event);
+            }
+            return;
+        }
+
+        send_report(&self.sender, ExecutionReport::Fill(Box::new(fill)));
+    }
+
+    fn handle_position(&self, position: &BybitWsAccountPosition) {
+        let instrument = match position.category {
+            Some(product_type) => self.instrument(product_type, &position.symbol),
+            None => self
+                .position_product_types
+                .iter()
+                .find_map(|product_type| self.instrument(*product_type, &position.symbol)),
+        };
+
+        let Some(instrument) = instrument else {
+            tracing::warn!(
+                "No instrument for Bybit position update on {}",
+                position.symbol
+            );
+            return;
+        };
+
+        let ts_init = self.events.clock.get_time_ns();
+        match parse_ws_position_status_report(position, self.account_id, &instrument, ts_init) {
+            Ok(report) => send_report(&self.sender, ExecutionReport::Position(Box::new(report))),
+            Err(e) => tracing::error!("Failed to parse Bybit position update: {e}"),
+        }
+    }
+
+    fn handle_wallet(&self, msg: &BybitWsAccountWalletMsg) {
+        let ts_init = self.events.clock.get_time_ns();
+        let ts_event =
+            parse_millis_i64(msg.creation_time, "wallet.creationTime").unwrap_or(ts_init);
+
+        for wallet in &msg.data {
+            match parse_ws_account_state(wallet, self.account_id, ts_event, ts_init) {
+                Ok(state) => dispatch_account_state(state),
+                Err(e) => tracing::error!("Failed to parse Bybit wallet update: {e}"),
+            }
+        }
+    }
+}
+
+fn place_order_params(order: &OrderAny) -> anyhow::Result<BybitWsPlaceOrderParams> {
+    let instrument_id = order.instrument_id();
+    let product_type = BybitSymbol::new(instrument_id.symbol.as_str())?.product_type();
+
+    let params = BybitWebSocketClient::build_place_order_params(
+        product_type,
+        instrument_id,
+        order.client_order_id(),
+        order.order_side(),
+        order.order_type(),
+        order.quantity(),
+        Some(order.time_in_force()),
+        order.price(),
+        order.trigger_price(),
+        Some(order.is_post_only()),
+        Some(order.is_reduce_only()),
+    )?;
+
+    Ok(params)
+}
+
+fn amend_order_params(cmd: &ModifyOrder) -> anyhow::Result<BybitWsAmendOrderParams> {
+    let symbol = BybitSymbol::new(cmd.instrument_id.symbol.as_str())?;
+
+    Ok(BybitWsAmendOrderParams {
+        category: symbol.product_type(),
+        symbol: Ustr::from(symbol.raw_symbol()),
+        order_id: Some(cmd.venue_order_id.to_string()),
+        order_link_id: Some(cmd.client_order_id.to_string()),
+        qty: cmd.quantity.map(|qty| qty.to_string()),
+        price: cmd.price.map(|price| price.to_string()),
+        trigger_price: cmd.trigger_price.map(|price| price.to_string()),
+        take_profit: None,
+        stop_loss: None,
+        tp_trigger_by: None,
+        sl_trigger_by: None,
+    })
+}
+
+fn cancel_order_params(
+    product_type: BybitProductType,
+    cmd: &CancelOrder,
+) -> anyhow::Result<BybitWsCancelOrderParams> {
+    let symbol = BybitSymbol::new(cmd.instrument_id.symbol.as_str())?;
+
+    Ok(BybitWsCancelOrderParams {
+        category: product_type,
+        symbol: Ustr::from(symbol.raw_symbol()),
+        order_id: Some(cmd.venue_order_id.to_string()),
+        order_link_id: Some(cmd.client_order_id.to_string()),
+    })
+}
+
+/// Groups items by product type, since each Bybit batch request targets a single category.
+///
+/// Items whose instrument is not a Bybit symbol are dropped with a warning.
+fn group_by_product_type<T>(
+    items: Vec<T>,
+    instrument_id: impl Fn(&T) -> InstrumentId,
+) -> Vec<(BybitProductType, Vec<T>)> {
+    let mut groups: AHashMap<BybitProductType, Vec<T>> = AHashMap::new();
+    let mut order = Vec::new();
+
+    for item in items {
+        let id = instrument_id(&item);
+        match BybitSymbol::new(id.symbol.as_str()) {
+            Ok(symbol) => {
+                let product_type = symbol.product_type();
+                if !groups.contains_key(&product_type) {
+                    order.push(product_type);
+                }
+                groups.entry(product_type).or_default().push(item);
+            }
+            Err(e) => tracing::warn!("Skipping {id} in batch request: {e}"),
+        }
+    }
+
+    order
+        .into_iter()
+        .filter_map(|product_type| groups.remove(&product_type).map(|g| (product_type, g)))
+        .collect()
+}
+
+fn dispatch_account_state(state: AccountState) {
+    msgbus::send_any(
+        "Portfolio.update_account".into(),
+        &state as &dyn std::any::Any,
+    );
+}
+
+fn send_report(sender: &UnboundedSender<ExecutionEvent>, report: ExecutionReport) {
+    if let Err(e) = sender.send(ExecutionEvent::Report(report)) {
+        tracing::warn!("Failed to send execution report: {e}");
+    }
+}
+
+fn send_order_event(sender: &UnboundedSender<ExecutionEvent>, event: OrderEventAny) {
+    if let Err(e) = sender.send(ExecutionEvent::Order(event)) {
+        tracing::warn!("Failed to send order event: {e}");
+    }
+}
+
+fn nanos_to_millis(value: Option<UnixNanos>) -> Option<i64> {
+    value.map(|nanos| nanos.to_datetime_utc().timestamp_millis())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::{LiquiditySide, TimeInForce},
+        identifiers::TradeId,
+        types::Money,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn factory() -> OrderEventFactory {
+        OrderEventFactory {
+            trader_id: TraderId::from("TRADER-001"),
+            account_id: AccountId::from("BYBIT-001"),
+            clock: get_atomic_clock_realtime(),
+        }
+    }
+
+    fn tracked_order() -> TrackedOrder {
+        TrackedOrder {
+            strategy_id: StrategyId::from("S-001"),
+            instrument_id: InstrumentId::from("BTCUSDT-LINEAR.BYBIT"),
+            order_side: OrderSide::Buy,
+            order_type: OrderType::Limit,
+            quantity: Quantity::from("0.010"),
+            price: Some(Price::from("30000.0")),
+            trigger_price: None,
+            filled_qty: Quantity::from("0.000"),
+            trade_ids: AHashSet::new(),
+            accepted: false,
+        }
+    }
+
+    fn report(status: OrderStatus, quantity: &str, price: &str) -> OrderStatusReport {
+        OrderStatusReport::new(
+            AccountId::from("BYBIT-001"),
+            InstrumentId::from("BTCUSDT-LINEAR.BYBIT"),
+            Some(ClientOrderId::from("O-001")),
+            VenueOrderId::from("V-001"),
+            OrderSide::Buy,
+            OrderType::Limit,
+            TimeInForce::Gtc,
+            status,
+            Quantity::from(quantity),
+            Quantity::from("0.000"),
+            UnixNanos::from(1),
+            UnixNanos::from(2),
+            UnixNanos::from(3),
+            None,
+        )
+        .with_price(Price::from(price))
+    }
+
+    fn fill(trade_id: &str, last_qty: &str) -> FillReport {
+        FillReport::new(
+            AccountId::from("BYBIT-001"),
+            InstrumentId::from("BTCUSDT-LINEAR.BYBIT"),
+            VenueOrderId::from("V-001"),
+            TradeId::from(trade_id),
+            OrderSide::Buy,
+            Quantity::from(last_qty),
+            Price::from("30000.0"),
+            Money::from("0.1 USDT"),
+            LiquiditySide::Maker,
+            Some(ClientOrderId::from("O-001")),
+            None,
+            UnixNanos::from(5),
+            UnixNanos::from(6),
+            None,
+        )
+    }
+
+    #[rstest]
+    fn test_new_order_is_accepted_once() {
+        let events = factory();
+        let client_order_id = ClientOrderId::from("O-001");
+        let mut tracked = tracked_order();
+        let report = report(OrderStatus::Accepted, "0.010", "30000.0");
+
+        let first = order_events_from_report(&events, client_order_id, &mut tracked, &report);
+        let second = order_events_from_report(&events, client_order_id, &mut tracked, &report);
+
+        assert_eq!(first.len(), 1);
+        assert!(matches!(first[0], OrderEventAny::Accepted(_)));
+        assert!(second.is_empty());
+    }
+
+    #[rstest]
+    #[case(OrderStatus::Accepted)]
+    #[case(OrderStatus::PartiallyFilled)]
+    fn test_amended_order_emits_updated(#[case] status: OrderStatus) {
+        let events = factory();
+        let client_order_id = ClientOrderId::from("O-001");
+        let mut tracked = tracked_order();
+        tracked.accepted = true;
+
+        let report = report(status, "0.020", "29000.0");
+        let out = order_events_from_report(&events, client_order_id, &mut tracked, &report);
+
+        assert_eq!(out.len(), 1);
+        let OrderEventAny::Updated(updated) = &out[0] else {
+            panic!("expected OrderUpdated, got {:?}", out[0]);
+        };
+        assert_eq!(updated.quantity, Quantity::from("0.020"));
+        assert_eq!(updated.price, Some(Price::from("29000.0")));
+        assert_eq!(tracked.quantity, Quantity::from("0.020"));
+    }
+
+    #[rstest]
+    fn test_canceled_before_accepted_emits_accepted_then_canceled() {
+        let events = factory();
+        let mut tracked = tracked_order();
+        let report = report(OrderStatus::Canceled, "0.010", "30000.0");
+
+        let out =
+            order_events_from_report(&events, ClientOrderId::from("O-001"), &mut tracked, &report);
+
+        assert_eq!(out.len(), 2);
+        assert!(matches!(out[0], OrderEventAny::Accepted(_)));
+        assert!(matches!(out[1], OrderEventAny::Canceled(_)));
+    }
+
+    #[rstest]
+    #[case(false, "Rejected")]
+    #[case(true, "Canceled")]
+    fn test_rejected_update(#[case] accepted: bool, #[case] expected: &str) {
+        let events = factory();
+        let mut tracked = tracked_order();
+        tracked.accepted = accepted;
+        let report = report(OrderStatus::Rejected, "0.010", "30000.0");
+
+        let out =
+            order_events_from_report(&events, ClientOrderId::from("O-001"), &mut tracked, &report);
+
+        assert_eq!(out.len(), 1);
+        let kind = match out[0] {
+            OrderEventAny::Rejected(_) => "Rejected",
+            OrderEventAny::Canceled(_) => "Canceled",
+            _ => "Other",
+        };
+        assert_eq!(kind, expected);
+    }
+
+    #[rstest]
+    fn test_fills_accumulate_until_filled() {
+        let events = factory();
+        let client_order_id = ClientOrderId::from("O-001");
+        let mut tracked = tracked_order();
+        let currency = Currency::from("USDT");
+
+        let first = order_events_from_fill(
+            &events,
+            client_order_id,
+            &mut tracked,
+            &fill("T-001", "0.004"),
+            currency,
+        );
+        assert_eq!(first.len(), 2);
+        assert!(matches!(first[0], OrderEventAny::Accepted(_)));
+        let OrderEventAny::Filled(filled) = &first[1] else {
+            panic!("expected OrderFilled, got {:?}", first[1]);
+        };
+        assert_eq!(filled.last_qty, Quantity::from("0.004"));
+        assert_eq!(filled.commission, Some(Money::from("0.1 USDT")));
+        assert!(!tracked.is_filled());
+
+        let second = order_events_from_fill(
+            &events,
+            client_order_id,
+            &mut tracked,
+            &fill("T-002", "0.006"),
+            currency,
+        );
+        assert_eq!(second.len(), 1);
+        assert!(tracked.is_filled());
+    }
+
+    #[rstest]
+    fn test_duplicate_fill_is_ignored() {
+        let events = factory();
+        let client_order_id = ClientOrderId::from("O-001");
+        let mut tracked = tracked_order();
+        let currency = Currency::from("USDT");
+        let fill = fill("T-001", "0.004");
+
+        let first = order_events_from_fill(&events, client_order_id, &mut tracked, &fill, currency);
+        let replayed =
+            order_events_from_fill(&events, client_order_id, &mut tracked, &fill, currency);
+
+        assert_eq!(first.len(), 2);
+        assert!(replayed.is_empty());
+        assert_eq!(tracked.filled_qty, Quantity::from("0.004"));
+    }
+
+    fn order_response(json: &str) -> BybitWsOrderResponse {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[rstest]
+    fn test_rejected_request_rejects_all_orders() {
+        let events = factory();
+        let request = PendingRequest::Submit(vec![
+            (ClientOrderId::from("O-001"), tracked_order()),
+            (ClientOrderId::from("O-002"), tracked_order()),
+        ]);
+        let response = order_response(
+            r#"{"reqId":"req-1","retCode":10001,"retMsg":"params error","op":"order.create-batch","data":{},"retExtInfo":{}}"#,
+        );
+
+        let out = order_response_rejections(&events, &request, &response);
+
+        assert_eq!(out.len(), 2);
+        let OrderEventAny::Rejected(rejected) = &out[1] else {
+            panic!("expected OrderRejected, got {:?}", out[1]);
+        };
+        assert_eq!(rejected.client_order_id, ClientOrderId::from("O-002"));
+        assert_eq!(rejected.reason.as_str(), "10001: params error");
+    }
+
+    #[rstest]
+    fn test_batch_item_failure_is_matched_by_order_link_id() {
+        let events = factory();
+        let request = PendingRequest::Submit(vec![
+            (ClientOrderId::from("O-001"), tracked_order()),
+            (ClientOrderId::from("O-002"), tracked_order()),
+        ]);
+        let response = order_response(
+            r#"{
+                "reqId":"req-1","retCode":0,"retMsg":"OK","op":"order.create-batch",
+                "data":{"list":[{"orderId":"","orderLinkId":"O-002"},{"orderId":"1","orderLinkId":"O-001"}]},
+                "retExtInfo":{"list":[{"code":170131,"msg":"Insufficient balance."},{"code":0,"msg":"OK"}]}
+            }"#,
+        );
+
+        let out = order_response_rejections(&events, &request, &response);
+
+        assert_eq!(out.len(), 1);
+        let OrderEventAny::Rejected(rejected) = &out[0] else {
+            panic!("expected OrderRejected, got {:?}", out[0]);
+        };
+        assert_eq!(rejected.client_order_id, ClientOrderId::from("O-002"));
+        assert_eq!(rejected.reason.as_str(), "170131: Insufficient balance.");
+    }
+
+    #[rstest]
+    fn test_group_by_product_type_preserves_order_and_drops_invalid() {
+        let ids = vec![
+            InstrumentId::from("BTCUSDT-LINEAR.BYBIT"),
+            InstrumentId::from("ETHUSDT-SPOT.BYBIT"),
+            InstrumentId::from("ETHUSDT-LINEAR.BYBIT"),
+            InstrumentId::from("BTCUSDT.BINANCE"),
+        ];
+
+        let groups = group_by_product_type(ids, |id| *id);
+
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].0, BybitProductType::Linear);
+        assert_eq!(groups[0].1.len(), 2);
+        assert_eq!(groups[1].0, BybitProductType::Spot);
+        assert_eq!(groups[1].1.len(), 1);
+    }
+}
@@ -576,6 +576,9 @@ impl BybitWebSocketClient {
                         BybitWebSocketMessage::Response(msg) => {
                             tracing::debug!("Received response message: {:?}", msg);
                         }
+                        BybitWebSocketMessage::OrderResponse(msg) => {
+                            tracing::debug!("Received order response message: {:?}", msg);
+                        }
                         BybitWebSocketMessage::Auth(msg) => {
                             tracing::debug!("Received auth message: {:?}", msg);
                         }
@@ -67,9 +67,10 @@ use crate::{
             BybitAuthRequest, BybitSubscription, BybitWebSocketError, BybitWebSocketMessage,
             BybitWsAccountExecutionMsg, BybitWsAccountOrderMsg, BybitWsAccountPositionMsg,
             BybitWsAccountWalletMsg, BybitWsAmendOrderParams, BybitWsAuthResponse,
-            BybitWsCancelOrderParams, BybitWsHeader, BybitWsKlineMsg, BybitWsOrderbookDepthMsg,
-            BybitWsPlaceOrderParams, BybitWsRequest, BybitWsResponse, BybitWsSubscriptionMsg,
-            BybitWsTickerLinearMsg, BybitWsTickerOptionMsg, BybitWsTradeMsg,
+            BybitWsCancelOrderParams, BybitWsHeader, BybitWsKlineMsg, BybitWsOrderResponse,
+            BybitWsOrderbookDepthMsg, BybitWsPlaceOrderParams, BybitWsRequest, BybitWsResponse,
+            BybitWsSubscriptionMsg, BybitWsTickerLinearMsg, BybitWsTickerOptionMsg,
+            BybitWsTradeMsg,
         },
         subscription::SubscriptionState,
     },
@@ -836,7 +837,11 @@ impl BybitWebSocketClient {
     /// # References
     ///
     /// <https://bybit-exchange.github.io/docs/v5/websocket/trade/guideline>
-    pub async fn place_order(&self, params: BybitWsPlaceOrderParams) -> BybitWsResult<()> {
+    pub async fn place_order(
+        &self,
+        params: BybitWsPlaceOrderParams,
+        req_id: Option<String>,
+    ) -> BybitWsResult<()> {
         if !self.is_authenticated.load(Ordering::Relaxed) {
             return Err(BybitWsError::Authentication(
                 "Must be authenticated to place orders".to_string(),
@@ -848,8 +853,10 @@ impl BybitWebSocketClient {
                 "place_order",
                 || {
                     let params = params.clone();
+                    let req_id = req_id.clone();
                     async move {
                         let request = BybitWsRequest {
+                            req_id,
                             op: BybitWsOrderRequestOp::Create,
                             header: BybitWsHeader::now(),
                             args: vec![params],
@@ -877,7 +884,11 @@ impl BybitWebSocketClient {
     /// # References
     ///
     /// <https://bybit-exchange.github.io/docs/v5/websocket/trade/guideline>
-    pub async fn amend_order(&self, params: BybitWsAmendOrderParams) -> BybitWsResult<()> {
+    pub async fn amend_order(
+        &self,
+        params: BybitWsAmendOrderParams,
+        req_id: Option<String>,
+    ) -> BybitWsResult<()> {
         if !self.is_authenticated.load(Ordering::Relaxed) {
             return Err(BybitWsError::Authentication(
                 "Must be authenticated to amend orders".to_string(),
@@ -889,8 +900,10 @@ impl BybitWebSocketClient {
                 "amend_order",
                 || {
                     let params = params.clone();
+                    let req_id = req_id.clone();
                     async move {
                         let request = BybitWsRequest {
+                            req_id,
                             op: BybitWsOrderRequestOp::Amend,
                             header: BybitWsHeader::now(),
                             args: vec![params],
@@ -917,7 +930,11 @@ impl BybitWebSocketClient {
     /// # References
     ///
     /// <https://bybit-exchange.github.io/docs/v5/websocket/trade/guideline>
-    pub async fn cancel_order(&self, params: BybitWsCancelOrderParams) -> BybitWsResult<()> {
+    pub async fn cancel_order(
+        &self,
+        params: BybitWsCancelOrderParams,
+        req_id: Option<String>,
+    ) -> BybitWsResult<()> {
         if !self.is_authenticated.load(Ordering::Relaxed) {
             return Err(BybitWsError::Authentication(
                 "Must be authenticated to cancel orders".to_string(),
@@ -929,8 +946,10 @@ impl BybitWebSocketClient {
                 "cancel_order",
                 || {
                     let params = params.clone();
+                    let req_id = req_id.clone();
                     async move {
                         let request = BybitWsRequest {
+                            req_id,
                             op: BybitWsOrderRequestOp::Cancel,
                             header: BybitWsHeader::now(),
                             args: vec![params],
@@ -960,6 +979,7 @@ impl BybitWebSocketClient {
     pub async fn batch_place_orders(
         &self,
         orders: Vec<BybitWsPlaceOrderParams>,
+        req_id: Option<String>,
     ) -> BybitWsResult<()> {
         if !self.is_authenticated.load(Ordering::Relaxed) {
             return Err(BybitWsError::Authentication(
@@ -974,6 +994,7 @@ impl BybitWebSocketClient {
         }
 
         let request = BybitWsRequest {
+            req_id,
             op: BybitWsOrderRequestOp::CreateBatch,
             header: BybitWsHeader::now(),
             args: orders,
@@ -991,6 +1012,7 @@ impl BybitWebSocketClient {
     pub async fn batch_amend_orders(
         &self,
         orders: Vec<BybitWsAmendOrderParams>,
+        req_id: Option<String>,
     ) -> BybitWsResult<()> {
         if !self.is_authenticated.load(Ordering::Relaxed) {
             return Err(BybitWsError::Authentication(
@@ -1005,6 +1027,7 @@ impl BybitWebSocketClient {
         }
 
         let request = BybitWsRequest {
+            req_id,
             op: BybitWsOrderRequestOp::AmendBatch,
             header: BybitWsHeader::now(),
             args: orders,
@@ -1022,6 +1045,7 @@ impl BybitWebSocketClient {
     pub async fn batch_cancel_orders(
         &self,
         orders: Vec<BybitWsCancelOrderParams>,
+        req_id: Option<String>,
     ) -> BybitWsResult<()> {
         if !self.is_authenticated.load(Ordering::Relaxed) {
             return Err(BybitWsError::Authentication(
@@ -1036,6 +1060,7 @@ impl BybitWebSocketClient {
         }
 
         let request = BybitWsRequest {
+            req_id,
             op: BybitWsOrderRequestOp::CancelBatch,
             header: BybitWsHeader::now(),
             args: orders,
@@ -1065,6 +1090,43 @@ impl BybitWebSocketClient {
         post_only: Option<bool>,
         reduce_only: Option<bool>,
     ) -> BybitWsResult<()> {
+        let params = Self::build_place_order_params(
+            product_type,
+            instrument_id,
+            client_order_id,
+            order_side,
+            order_type,
+            quantity,
+            time_in_force,
+            price,
+            trigger_price,
+            post_only,
+            reduce_only,
+        )?;
+
+        self.place_order(params, None).await
+    }
+
+    /// Builds the place-order parameters for a Nautilus order, as used by both single and
+    /// batch order submission.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the symbol, side, order type or time in force is not supported.
+    #[allow(clippy::too_many_arguments)]
+    pub fn build_place_order_params(
+        product_type: BybitProductType,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        order_side: OrderSide,
+        order_type: OrderType,
+        quantity: Quantity,
+        time_in_force: Option<TimeInForce>,
+        price: Option<Price>,
+        trigger_price: Option<Price>,
+        post_only: Option<bool>,
+        reduce_only: Option<bool>,
+    ) -> BybitWsResult<BybitWsPlaceOrderParams> {
         let bybit_symbol = BybitSymbol::new(instrument_id.symbol.as_str())
             .map_err(|e| BybitWsError::ClientError(e.to_string()))?;
         let raw_symbol = Ustr::from(bybit_symbol.raw_symbol());
@@ -1170,7 +1232,7 @@ impl BybitWebSocketClient {
             }
         };
 
-        self.place_order(params).await
+        Ok(params)
     }
 
     /// Modifies an existing order using Nautilus domain objects.
@@ -1205,7 +1267,7 @@ impl BybitWebSocketClient {
             sl_trigger_by: None,
         };
 
-        self.amend_order(params).await
+        self.amend_order(params, None).await
     }
 
     /// Cancels an order using Nautilus domain objects.
@@ -1231,7 +1293,7 @@ impl BybitWebSocketClient {
             order_link_id: client_order_id.map(|id| id.to_string()),
         };
 
-        self.cancel_order(params).await
+        self.cancel_order(params, None).await
     }
 
     fn default_headers() -> Vec<(String, String)> {
@@ -1498,6 +1560,15 @@ impl BybitWebSocketClient {
             }
         }
 
+        if let Some(op) = value.get("op").and_then(Value::as_str)
+            && op.starts_with("order.")
+        {
+            match serde_json::from_value::<BybitWsOrderResponse>(value.clone()) {
+                Ok(msg) => return Some(BybitWebSocketMessage::OrderResponse(msg)),
+                Err(e) => tracing::warn!("Failed to deserialize order response: {e}\n{value}"),
+            }
+        }
+
         if (value.get("ret_code").is_some() || value.get("retCode").is_some())
             && let Ok(resp) = serde_json::from_value::<BybitWsResponse>(value.clone())
         {
@@ -1681,4 +1752,41 @@ mod tests {
             BybitWebSocketClient::classify_message(&json).expect("expected ticker message");
         assert!(matches!(message, BybitWebSocketMessage::TickerOption(_)));
     }
+
+    #[rstest]
+    fn classify_order_batch_response() {
+        let json: Value =
+            serde_json::from_str(&load_test_json("ws_order_create_batch_response.json"))
+                .expect("invalid fixture");
+        let message =
+            BybitWebSocketClient::classify_message(&json).expect("expected order response");
+        let BybitWebSocketMessage::OrderResponse(response) = message else {
+            panic!("expected order response, was {message:?}");
+        };
+        assert_eq!(response.op, BybitWsOrderRequestOp::CreateBatch);
+        assert_eq!(response.req_id.as_deref(), Some("batch-1"));
+        assert_eq!(response.data.list.len(), 2);
+        assert_eq!(response.ret_ext_info.list[1].code, 10001);
+    }
+
+    #[rstest]
+    fn classify_order_rejected_response() {
+        let json = serde_json::json!({
+            "reqId": "req-1",
+            "retCode": 10404,
+            "retMsg": "Order does not exist",
+            "op": "order.cancel",
+            "data": {},
+            "header": {},
+            "connId": "conn-1"
+        });
+        let message =
+            BybitWebSocketClient::classify_message(&json).expect("expected order response");
+        let BybitWebSocketMessage::OrderResponse(response) = message else {
+            panic!("expected order response, was {message:?}");
+        };
+        assert_eq!(response.op, BybitWsOrderRequestOp::Cancel);
+        assert_eq!(response.ret_code, 10404);
+        assert!(response.data.order_link_id.is_none());
+    }
 }
@@ -68,6 +68,8 @@ pub enum BybitWebSocketMessage {
     AccountWallet(BybitWsAccountWalletMsg),
     /// Position updates from private channel.
     AccountPosition(BybitWsAccountPositionMsg),
+    /// Response to an order request on the trade channel.
+    OrderResponse(BybitWsOrderResponse),
     /// Error received from the venue or client lifecycle.
     Error(BybitWebSocketError),
     /// Raw message payload that does not yet have a typed representation.
@@ -154,6 +156,9 @@ impl BybitWebSocketError {
 /// Generic WebSocket request for Bybit trading commands.
 #[derive(Debug, Clone, Serialize)]
 pub struct BybitWsRequest<T> {
+    /// Optional request identifier echoed back in the response.
+    #[serde(rename = "reqId", skip_serializing_if = "Option::is_none")]
+    pub req_id: Option<String>,
     /// Operation type (order.create, order.amend, order.cancel, etc.).
     pub op: BybitWsOrderRequestOp,
     /// Request header containing timestamp and other metadata.
@@ -162,6 +167,64 @@ pub struct BybitWsRequest<T> {
     pub args: Vec<T>,
 }
 
+/// Response to a WebSocket trade request.
+///
+/// # References
+///
+/// <https://bybit-exchange.github.io/docs/v5/websocket/trade/guideline>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitWsOrderResponse {
+    /// Operation the response is for.
+    pub op: BybitWsOrderRequestOp,
+    /// Request identifier from the originating request.
+    #[serde(default)]
+    pub req_id: Option<String>,
+    /// Return code, non-zero when the whole request failed.
+    pub ret_code: i64,
+    /// Return message.
+    #[serde(default)]
+    pub ret_msg: String,
+    /// Order identifiers for the request.
+    #[serde(default)]
+    pub data: BybitWsOrderResponseData,
+    /// Per-item results for batch requests.
+    #[serde(default)]
+    pub ret_ext_info: BybitWsOrderResponseExtInfo,
+    /// Connection identifier.
+    #[serde(default)]
+    pub conn_id: Option<String>,
+}
+
+/// Order identifiers returned for a WebSocket trade request.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitWsOrderResponseData {
+    #[serde(default)]
+    pub order_id: Option<String>,
+    #[serde(default)]
+    pub order_link_id: Option<String>,
+    /// Orders of a batch request, in request order.
+    #[serde(default)]
+    pub list: Vec<Self>,
+}
+
+/// Per-item results of a WebSocket batch trade request.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BybitWsOrderResponseExtInfo {
+    /// Results in request order.
+    #[serde(default)]
+    pub list: Vec<BybitWsOrderResponseItemResult>,
+}
+
+/// Result of a single item of a WebSocket batch trade request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BybitWsOrderResponseItemResult {
+    pub code: i64,
+    #[serde(default)]
+    pub msg: String,
+}
+
 /// Header for WebSocket trade requests.
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "SCREAMING-KEBAB-CASE")]
@@ -679,6 +742,9 @@ pub struct BybitWsAccountWalletMsg {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BybitWsAccountPosition {
+    /// Product category, included on unified account position updates.
+    #[serde(default)]
+    pub category: Option<BybitProductType>,
     pub position_idx: i32,
     pub risk_id: i64,
     pub risk_limit_value: String,
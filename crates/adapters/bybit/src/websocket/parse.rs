@@ -20,13 +20,16 @@ use std::convert::TryFrom;
 use anyhow::Context;
 use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
 use nautilus_model::{
-    data::{Bar, BarType, BookOrder, OrderBookDelta, OrderBookDeltas, QuoteTick, TradeTick},
+    data::{
+        Bar, BarType, BookOrder, FundingRateUpdate, OrderBookDelta, OrderBookDeltas, QuoteTick,
+        TradeTick,
+    },
     enums::{
         AccountType, AggressorSide, BookAction, LiquiditySide, OrderSide, OrderStatus, OrderType,
         PositionSideSpecified, RecordFlag, TimeInForce,
     },
     events::account::state::AccountState,
-    identifiers::{AccountId, ClientOrderId, TradeId, VenueOrderId},
+    identifiers::{AccountId, ClientOrderId, InstrumentId, TradeId, VenueOrderId},
     instruments::{Instrument, any::InstrumentAny},
     reports::{FillReport, OrderStatusReport, PositionStatusReport},
     types::{AccountBalance, Currency, Money, Price, Quantity},
@@ -279,6 +282,44 @@ pub fn parse_ticker_linear_quote(
     .context("failed to construct QuoteTick from Bybit linear ticker message")
 }
 
+/// Parses the funding fields of a linear or inverse ticker payload into a [`FundingRateUpdate`].
+///
+/// Returns `Ok(None)` when the payload does not carry a funding rate (e.g. partial deltas).
+///
+/// # Errors
+///
+/// Returns an error if the funding rate or timestamps cannot be parsed.
+pub fn parse_ticker_linear_funding(
+    msg: &BybitWsTickerLinearMsg,
+    instrument_id: InstrumentId,
+    ts_init: UnixNanos,
+) -> anyhow::Result<Option<FundingRateUpdate>> {
+    let Some(rate) = msg.data.funding_rate.as_deref().filter(|s| !s.is_empty()) else {
+        return Ok(None);
+    };
+
+    let rate = rate
+        .parse::<Decimal>()
+        .with_context(|| format!("Failed to parse fundingRate '{rate}'"))?;
+    let next_funding_ns = msg
+        .data
+        .next_funding_time
+        .as_deref()
+        .filter(|s| !s.is_empty())
+        .map(|s| parse_millis_timestamp(s, "ticker.nextFundingTime"))
+        .transpose()?;
+    let ts_event = parse_millis_i64(msg.ts, "ticker.ts")?;
+    let ts_init = if ts_init.is_zero() { ts_event } else { ts_init };
+
+    Ok(Some(FundingRateUpdate::new(
+        instrument_id,
+        rate,
+        next_funding_ns,
+        ts_event,
+        ts_init,
+    )))
+}
+
 /// Parses an option ticker payload into a [`QuoteTick`].
 pub fn parse_ticker_option_quote(
     msg: &BybitWsTickerOptionMsg,
@@ -902,6 +943,38 @@ mod tests {
         assert_eq!(quote.ts_init, TS);
     }
 
+    #[rstest]
+    fn parse_linear_ticker_funding_rate() {
+        let instrument = linear_instrument();
+        let json = load_test_json("ws_ticker_linear.json");
+        let msg: BybitWsTickerLinearMsg = serde_json::from_str(&json).unwrap();
+
+        let update = parse_ticker_linear_funding(&msg, instrument.id(), TS)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(update.instrument_id, instrument.id());
+        assert_eq!(update.rate, Decimal::new(-212, 6));
+        assert_eq!(
+            update.next_funding_ns,
+            Some(UnixNanos::new(1_673_280_000_000_000_000))
+        );
+        assert_eq!(update.ts_event, UnixNanos::new(1_673_272_861_686_000_000));
+        assert_eq!(update.ts_init, TS);
+    }
+
+    #[rstest]
+    fn parse_linear_ticker_funding_rate_missing_returns_none() {
+        let instrument = linear_instrument();
+        let json = load_test_json("ws_ticker_linear.json");
+        let mut msg: BybitWsTickerLinearMsg = serde_json::from_str(&json).unwrap();
+        msg.data.funding_rate = None;
+
+        let update = parse_ticker_linear_funding(&msg, instrument.id(), TS).unwrap();
+
+        assert!(update.is_none());
+    }
+
     #[rstest]
     fn parse_option_ticker_quote_to_quote_tick() {
         let instrument = option_instrument();
@@ -12,3 +12,161 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
+
+//! Configuration structures for the Coinbase International adapter.
+
+use crate::common::consts::{
+    COINBASE_INTX_FIX_DROP_COPY, COINBASE_INTX_REST_SANDBOX_URL, COINBASE_INTX_REST_URL,
+    COINBASE_INTX_WS_SANDBOX_URL, COINBASE_INTX_WS_URL,
+};
+
+/// Configuration for the Coinbase International data client.
+#[derive(Clone, Debug)]
+pub struct CoinbaseIntxDataClientConfig {
+    /// Optional API key (falls back to `COINBASE_INTX_API_KEY`).
+    pub api_key: Option<String>,
+    /// Optional API secret (falls back to `COINBASE_INTX_API_SECRET`).
+    pub api_secret: Option<String>,
+    /// Optional API passphrase (falls back to `COINBASE_INTX_API_PASSPHRASE`).
+    pub api_passphrase: Option<String>,
+    /// Optional override for the HTTP base URL.
+    pub base_url_http: Option<String>,
+    /// Optional override for the WebSocket URL.
+    pub base_url_ws: Option<String>,
+    /// When true the client will use the Coinbase International sandbox endpoints.
+    pub is_sandbox: bool,
+    /// Optional HTTP timeout in seconds.
+    pub http_timeout_secs: Option<u64>,
+    /// Optional WebSocket heartbeat interval in seconds.
+    pub heartbeat_interval_secs: Option<u64>,
+    /// Optional interval for refreshing instruments.
+    pub update_instruments_interval_mins: Option<u64>,
+}
+
+impl Default for CoinbaseIntxDataClientConfig {
+    fn default() -> Self {
+        Self {
+            api_key: None,
+            api_secret: None,
+            api_passphrase: None,
+            base_url_http: None,
+            base_url_ws: None,
+            is_sandbox: false,
+            http_timeout_secs: Some(60),
+            heartbeat_interval_secs: Some(10),
+            update_instruments_interval_mins: Some(60),
+        }
+    }
+}
+
+impl CoinbaseIntxDataClientConfig {
+    /// Creates a new configuration with default settings.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` when all API credential fields are populated.
+    #[must_use]
+    pub fn has_api_credentials(&self) -> bool {
+        self.api_key.is_some() && self.api_secret.is_some() && self.api_passphrase.is_some()
+    }
+
+    /// Returns the HTTP base URL, respecting the sandbox flag and overrides.
+    #[must_use]
+    pub fn http_base_url(&self) -> String {
+        self.base_url_http
+            .clone()
+            .unwrap_or_else(|| get_http_base_url(self.is_sandbox))
+    }
+
+    /// Returns the WebSocket URL, respecting the sandbox flag and overrides.
+    #[must_use]
+    pub fn ws_url(&self) -> String {
+        self.base_url_ws
+            .clone()
+            .unwrap_or_else(|| get_ws_url(self.is_sandbox))
+    }
+}
+
+/// Configuration for the Coinbase International execution client.
+#[derive(Clone, Debug)]
+pub struct CoinbaseIntxExecClientConfig {
+    /// Optional API key (falls back to `COINBASE_INTX_API_KEY`).
+    pub api_key: Option<String>,
+    /// Optional API secret (falls back to `COINBASE_INTX_API_SECRET`).
+    pub api_secret: Option<String>,
+    /// Optional API passphrase (falls back to `COINBASE_INTX_API_PASSPHRASE`).
+    pub api_passphrase: Option<String>,
+    /// Optional portfolio to trade (falls back to `COINBASE_INTX_PORTFOLIO_ID`).
+    pub portfolio_id: Option<String>,
+    /// Optional override for the HTTP base URL.
+    pub base_url_http: Option<String>,
+    /// Optional override for the FIX drop copy endpoint (`host:port`).
+    pub base_url_fix: Option<String>,
+    /// When true the client will use the Coinbase International sandbox HTTP endpoint.
+    pub is_sandbox: bool,
+    /// Optional HTTP timeout in seconds.
+    pub http_timeout_secs: Option<u64>,
+}
+
+impl Default for CoinbaseIntxExecClientConfig {
+    fn default() -> Self {
+        Self {
+            api_key: None,
+            api_secret: None,
+            api_passphrase: None,
+            portfolio_id: None,
+            base_url_http: None,
+            base_url_fix: None,
+            is_sandbox: false,
+            http_timeout_secs: Some(60),
+        }
+    }
+}
+
+impl CoinbaseIntxExecClientConfig {
+    /// Creates a new configuration with default settings.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` when all API credential fields are populated.
+    #[must_use]
+    pub fn has_api_credentials(&self) -> bool {
+        self.api_key.is_some() && self.api_secret.is_some() && self.api_passphrase.is_some()
+    }
+
+    /// Returns the HTTP base URL, respecting the sandbox flag and overrides.
+    #[must_use]
+    pub fn http_base_url(&self) -> String {
+        self.base_url_http
+            .clone()
+            .unwrap_or_else(|| get_http_base_url(self.is_sandbox))
+    }
+
+    /// Returns the FIX drop copy endpoint, falling back to the default when unset.
+    #[must_use]
+    pub fn fix_endpoint(&self) -> String {
+        self.base_url_fix
+            .clone()
+            .unwrap_or_else(|| COINBASE_INTX_FIX_DROP_COPY.to_string())
+    }
+}
+
+fn get_http_base_url(is_sandbox: bool) -> String {
+    if is_sandbox {
+        COINBASE_INTX_REST_SANDBOX_URL.to_string()
+    } else {
+        COINBASE_INTX_REST_URL.to_string()
+    }
+}
+
+fn get_ws_url(is_sandbox: bool) -> String {
+    if is_sandbox {
+        COINBASE_INTX_WS_SANDBOX_URL.to_string()
+    } else {
+        COINBASE_INTX_WS_URL.to_string()
+    }
+}
@@ -0,0 +1,794 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Data client implementation for the Coinbase International adapter.
+//!
+//! Market data is streamed over the Coinbase International WebSocket feed, with instrument
+//! definitions bootstrapped (and optionally refreshed) over HTTP.
+
+use std::{
+    future::Future,
+    sync::{
+        Arc, RwLock,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+use ahash::{AHashMap, AHashSet};
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use futures_util::StreamExt;
+use nautilus_common::{
+    messages::{
+        DataEvent,
+        data::{
+            DataResponse, InstrumentResponse, InstrumentsResponse, RequestInstrument,
+            RequestInstruments, SubscribeBars, SubscribeBookDeltas, SubscribeIndexPrices,
+            SubscribeInstrument, SubscribeInstruments, SubscribeMarkPrices, SubscribeQuotes,
+            SubscribeTrades, UnsubscribeBars, UnsubscribeBookDeltas, UnsubscribeIndexPrices,
+            UnsubscribeInstrument, UnsubscribeInstruments, UnsubscribeMarkPrices,
+            UnsubscribeQuotes, UnsubscribeTrades,
+        },
+    },
+    runner::get_data_event_sender,
+};
+use nautilus_core::{
+    MUTEX_POISONED, UnixNanos,
+    time::{AtomicTime, get_atomic_clock_realtime},
+};
+use nautilus_data::client::DataClient;
+use nautilus_model::{
+    data::{Data, OrderBookDeltas_API},
+    enums::BookType,
+    identifiers::{ClientId, InstrumentId, Venue},
+    instruments::{Instrument, InstrumentAny},
+};
+use tokio::{task::JoinHandle, time::Duration};
+use tokio_util::sync::CancellationToken;
+
+use crate::{
+    common::consts::COINBASE_INTX_VENUE,
+    config::CoinbaseIntxDataClientConfig,
+    http::client::CoinbaseIntxHttpClient,
+    websocket::{client::CoinbaseIntxWebSocketClient, messages::NautilusWsMessage},
+};
+
+/// Mark and index price subscriptions, which share the venue `RISK` channel.
+#[derive(Clone, Debug, Default)]
+struct PriceRoutes {
+    mark_prices: Arc<RwLock<AHashSet<InstrumentId>>>,
+    index_prices: Arc<RwLock<AHashSet<InstrumentId>>>,
+}
+
+impl PriceRoutes {
+    fn has_mark_price(&self, instrument_id: &InstrumentId) -> bool {
+        self.mark_prices
+            .read()
+            .expect(MUTEX_POISONED)
+            .contains(instrument_id)
+    }
+
+    fn has_index_price(&self, instrument_id: &InstrumentId) -> bool {
+        self.index_prices
+            .read()
+            .expect(MUTEX_POISONED)
+            .contains(instrument_id)
+    }
+
+    /// Returns `true` when neither mark nor index prices remain subscribed for the instrument.
+    fn is_unused(&self, instrument_id: &InstrumentId) -> bool {
+        !self.has_mark_price(instrument_id) && !self.has_index_price(instrument_id)
+    }
+
+    fn clear(&self) {
+        self.mark_prices.write().expect(MUTEX_POISONED).clear();
+        self.index_prices.write().expect(MUTEX_POISONED).clear();
+    }
+}
+
+/// Live data client for Coinbase International.
+#[derive(Debug)]
+pub struct CoinbaseIntxDataClient {
+    client_id: ClientId,
+    config: CoinbaseIntxDataClientConfig,
+    http_client: CoinbaseIntxHttpClient,
+    ws_client: CoinbaseIntxWebSocketClient,
+    is_connected: AtomicBool,
+    cancellation_token: CancellationToken,
+    tasks: Vec<JoinHandle<()>>,
+    data_sender: tokio::sync::mpsc::UnboundedSender<DataEvent>,
+    instruments: Arc<RwLock<AHashMap<InstrumentId, InstrumentAny>>>,
+    routes: PriceRoutes,
+    clock: &'static AtomicTime,
+    instrument_refresh_active: bool,
+}
+
+impl CoinbaseIntxDataClient {
+    /// Creates a new [`CoinbaseIntxDataClient`] instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if API credentials are missing from both the config and environment.
+    pub fn new(client_id: ClientId, config: CoinbaseIntxDataClientConfig) -> anyhow::Result<Self> {
+        let clock = get_atomic_clock_realtime();
+        let data_sender = get_data_event_sender();
+
+        // The market data feed is authenticated, so credentials are always required
+        let http_client = CoinbaseIntxHttpClient::with_credentials(
+            config.api_key.clone(),
+            config.api_secret.clone(),
+            config.api_passphrase.clone(),
+            Some(config.http_base_url()),
+            config.http_timeout_secs,
+        )
+        .context("failed to construct Coinbase International HTTP client")?;
+
+        let ws_client = CoinbaseIntxWebSocketClient::new(
+            Some(config.ws_url()),
+            config.api_key.clone(),
+            config.api_secret.clone(),
+            config.api_passphrase.clone(),
+            config.heartbeat_interval_secs,
+        )
+        .context("failed to construct Coinbase International websocket client")?;
+
+        Ok(Self {
+            client_id,
+            config,
+            http_client,
+            ws_client,
+            is_connected: AtomicBool::new(false),
+            cancellation_token: CancellationToken::new(),
+            tasks: Vec::new(),
+            data_sender,
+            instruments: Arc::new(RwLock::new(AHashMap::new())),
+            routes: PriceRoutes::default(),
+            clock,
+            instrument_refresh_active: false,
+        })
+    }
+
+    fn venue(&self) -> Venue {
+        *COINBASE_INTX_VENUE
+    }
+
+    fn send_data(sender: &tokio::sync::mpsc::UnboundedSender<DataEvent>, data: Data) {
+        if let Err(e) = sender.send(DataEvent::Data(data)) {
+            tracing::error!("Failed to emit data event: {e}");
+        }
+    }
+
+    fn spawn_ws<F>(&self, fut: F, context: &'static str)
+    where
+        F: Future<Output = anyhow::Result<()>> + Send + 'static,
+    {
+        tokio::spawn(async move {
+            if let Err(e) = fut.await {
+                tracing::error!("{context}: {e:?}");
+            }
+        });
+    }
+
+    async fn bootstrap_instruments(&mut self) -> anyhow::Result<Vec<InstrumentAny>> {
+        let instruments = self
+            .http_client
+            .request_instruments()
+            .await
+            .context("failed to load Coinbase International instruments")?;
+
+        if instruments.is_empty() {
+            tracing::warn!("No Coinbase International instruments were loaded");
+            return Ok(instruments);
+        }
+
+        tracing::debug!("Loaded {} instruments", instruments.len());
+
+        self.http_client.add_instruments(instruments.clone());
+        self.ws_client
+            .initialize_instruments_cache(instruments.clone());
+        replace_instruments(&self.instruments, &instruments);
+
+        Ok(instruments)
+    }
+
+    fn handle_ws_message(
+        message: NautilusWsMessage,
+        data_sender: &tokio::sync::mpsc::UnboundedSender<DataEvent>,
+        instruments: &Arc<RwLock<AHashMap<InstrumentId, InstrumentAny>>>,
+        routes: &PriceRoutes,
+    ) {
+        match message {
+            NautilusWsMessage::Data(data) => Self::send_data(data_sender, data),
+            NautilusWsMessage::DataVec(payloads) => {
+                for data in payloads {
+                    Self::send_data(data_sender, data);
+                }
+            }
+            NautilusWsMessage::Deltas(deltas) => {
+                Self::send_data(data_sender, Data::Deltas(OrderBookDeltas_API::new(deltas)));
+            }
+            NautilusWsMessage::Instrument(instrument) => {
+                upsert_instrument(instruments, instrument);
+            }
+            NautilusWsMessage::MarkPrice(mark) => {
+                if routes.has_mark_price(&mark.instrument_id) {
+                    Self::send_data(data_sender, Data::MarkPriceUpdate(mark));
+                }
+            }
+            NautilusWsMessage::IndexPrice(index) => {
+                if routes.has_index_price(&index.instrument_id) {
+                    Self::send_data(data_sender, Data::IndexPriceUpdate(index));
+                }
+            }
+            NautilusWsMessage::MarkAndIndex((mark, index)) => {
+                if routes.has_mark_price(&mark.instrument_id) {
+                    Self::send_data(data_sender, Data::MarkPriceUpdate(mark));
+                }
+                if routes.has_index_price(&index.instrument_id) {
+                    Self::send_data(data_sender, Data::IndexPriceUpdate(index));
+                }
+            }
+            NautilusWsMessage::OrderEvent(_) => {
+                tracing::debug!("Ignoring trading message on data client");
+            }
+        }
+    }
+
+    fn spawn_stream_task(&mut self) {
+        let stream = self.ws_client.stream();
+        let data_sender = self.data_sender.clone();
+        let instruments = self.instruments.clone();
+        let routes = self.routes.clone();
+        let cancellation = self.cancellation_token.clone();
+
+        let handle = tokio::spawn(async move {
+            tokio::pin!(stream);
+
+            loop {
+                tokio::select! {
+                    maybe_msg = stream.next() => {
+                        match maybe_msg {
+                            Some(msg) => {
+                                Self::handle_ws_message(msg, &data_sender, &instruments, &routes);
+                            }
+                            None => {
+                                tracing::debug!("Websocket stream ended");
+                                break;
+                            }
+                        }
+                    }
+                    _ = cancellation.cancelled() => {
+                        tracing::debug!("Websocket stream task cancelled");
+                        break;
+                    }
+                }
+            }
+        });
+
+        self.tasks.push(handle);
+    }
+
+    fn maybe_spawn_instrument_refresh(&mut self) {
+        let Some(minutes) = self.config.update_instruments_interval_mins else {
+            return;
+        };
+
+        if minutes == 0 || self.instrument_refresh_active {
+            return;
+        }
+
+        let interval = Duration::from_secs(minutes.saturating_mul(60));
+        let cancellation = self.cancellation_token.clone();
+        let instruments_cache = Arc::clone(&self.instruments);
+        let mut http_client = self.http_client.clone();
+        let client_id = self.client_id;
+
+        let handle = tokio::spawn(async move {
+            loop {
+                let sleep = tokio::time::sleep(interval);
+                tokio::pin!(sleep);
+                tokio::select! {
+                    _ = cancellation.cancelled() => {
+                        tracing::debug!("Coinbase International instrument refresh task cancelled");
+                        break;
+                    }
+                    _ = &mut sleep => {
+                        match http_client.request_instruments().await {
+                            Ok(instruments) if instruments.is_empty() => {
+                                tracing::debug!(client_id=%client_id, "Instrument refresh yielded no instruments");
+                            }
+                            Ok(instruments) => {
+                                http_client.add_instruments(instruments.clone());
+                                replace_instruments(&instruments_cache, &instruments);
+                                tracing::debug!(client_id=%client_id, count=instruments.len(), "Instruments refreshed");
+                            }
+                            Err(e) => {
+                                tracing::warn!(client_id=%client_id, error=?e, "Failed to refresh instruments");
+                            }
+                        }
+                    }
+                }
+            }
+        });
+
+        self.tasks.push(handle);
+        self.instrument_refresh_active = true;
+    }
+}
+
+fn upsert_instrument(
+    cache: &Arc<RwLock<AHashMap<InstrumentId, InstrumentAny>>>,
+    instrument: InstrumentAny,
+) {
+    let mut guard = cache.write().expect(MUTEX_POISONED);
+    guard.insert(instrument.id(), instrument);
+}
+
+fn replace_instruments(
+    cache: &Arc<RwLock<AHashMap<InstrumentId, InstrumentAny>>>,
+    instruments: &[InstrumentAny],
+) {
+    let mut guard = cache.write().expect(MUTEX_POISONED);
+    guard.clear();
+    for instrument in instruments {
+        guard.insert(instrument.id(), instrument.clone());
+    }
+}
+
+fn datetime_to_unix_nanos(value: Option<DateTime<Utc>>) -> Option<UnixNanos> {
+    value
+        .and_then(|dt| dt.timestamp_nanos_opt())
+        .and_then(|nanos| u64::try_from(nanos).ok())
+        .map(UnixNanos::from)
+}
+
+#[async_trait::async_trait]
+impl DataClient for CoinbaseIntxDataClient {
+    fn client_id(&self) -> ClientId {
+        self.client_id
+    }
+
+    fn venue(&self) -> Option<Venue> {
+        Some(self.venue())
+    }
+
+    fn start(&mut self) -> anyhow::Result<()> {
+        tracing::info!(
+            client_id = %self.client_id,
+            is_sandbox = self.config.is_sandbox,
+            "Starting Coinbase International data client"
+        );
+        Ok(())
+    }
+
+    fn stop(&mut self) -> anyhow::Result<()> {
+        tracing::info!(
+            "Stopping Coinbase International data client {id}",
+            id = self.client_id
+        );
+        self.cancellation_token.cancel();
+        self.is_connected.store(false, Ordering::Relaxed);
+        self.instrument_refresh_active = false;
+        Ok(())
+    }
+
+    fn reset(&mut self) -> anyhow::Result<()> {
+        tracing::debug!(
+            "Resetting Coinbase International data client {id}",
+            id = self.client_id
+        );
+        self.is_connected.store(false, Ordering::Relaxed);
+        self.cancellation_token = CancellationToken::new();
+        self.tasks.clear();
+        self.routes.clear();
+        self.instrument_refresh_active = false;
+        Ok(())
+    }
+
+    fn dispose(&mut self) -> anyhow::Result<()> {
+        tracing::debug!(
+            "Disposing Coinbase International data client {id}",
+            id = self.client_id
+        );
+        self.stop()
+    }
+
+    async fn connect(&mut self) -> anyhow::Result<()> {
+        if self.is_connected() {
+            return Ok(());
+        }
+
+        // Instruments must be cached before connecting, the websocket handler takes a snapshot
+        self.bootstrap_instruments().await?;
+
+        self.ws_client
+            .connect()
+            .await
+            .context("failed to connect Coinbase International websocket")?;
+        self.ws_client
+            .wait_until_active(10.0)
+            .await
+            .context("websocket did not become active")?;
+
+        self.spawn_stream_task();
+
+        let ws = self.ws_client.clone();
+        self.spawn_ws(
+            async move {
+                ws.subscribe_instruments(vec![])
+                    .await
+                    .context("instruments subscription")
+            },
+            "instrument subscription",
+        );
+
+        self.maybe_spawn_instrument_refresh();
+
+        self.is_connected.store(true, Ordering::Relaxed);
+        tracing::info!("Coinbase International data client connected");
+        Ok(())
+    }
+
+    async fn disconnect(&mut self) -> anyhow::Result<()> {
+        if self.is_disconnected() {
+            return Ok(());
+        }
+
+        self.cancellation_token.cancel();
+
+        if let Err(e) = self.ws_client.close().await {
+            tracing::warn!("Error while closing websocket: {e:?}");
+        }
+
+        for handle in self.tasks.drain(..) {
+            if let Err(e) = handle.await {
+                tracing::error!("Error joining websocket task: {e}");
+            }
+        }
+
+        self.cancellation_token = CancellationToken::new();
+        self.is_connected.store(false, Ordering::Relaxed);
+        self.routes.clear();
+        self.instrument_refresh_active = false;
+        tracing::info!("Coinbase International data client disconnected");
+        Ok(())
+    }
+
+    fn is_connected(&self) -> bool {
+        self.is_connected.load(Ordering::Relaxed)
+    }
+
+    fn is_disconnected(&self) -> bool {
+        !self.is_connected()
+    }
+
+    fn subscribe_instruments(&mut self, _cmd: &SubscribeInstruments) -> anyhow::Result<()> {
+        // All instrument updates are subscribed on connect
+        Ok(())
+    }
+
+    fn subscribe_instrument(&mut self, _cmd: &SubscribeInstrument) -> anyhow::Result<()> {
+        // All instrument updates are subscribed on connect
+        Ok(())
+    }
+
+    fn subscribe_book_deltas(&mut self, cmd: &SubscribeBookDeltas) -> anyhow::Result<()> {
+        if cmd.book_type != BookType::L2_MBP {
+            anyhow::bail!("Coinbase International only supports L2_MBP order book deltas");
+        }
+
+        let ws = self.ws_client.clone();
+        let instrument_id = cmd.instrument_id;
+        self.spawn_ws(
+            async move {
+                ws.subscribe_book(vec![instrument_id])
+                    .await
+                    .context("book deltas subscription")
+            },
+            "order book subscription",
+        );
+        Ok(())
+    }
+
+    fn subscribe_quotes(&mut self, cmd: &SubscribeQuotes) -> anyhow::Result<()> {
+        let ws = self.ws_client.clone();
+        let instrument_id = cmd.instrument_id;
+        self.spawn_ws(
+            async move {
+                ws.subscribe_quotes(vec![instrument_id])
+                    .await
+                    .context("quotes subscription")
+            },
+            "quote subscription",
+        );
+        Ok(())
+    }
+
+    fn subscribe_trades(&mut self, cmd: &SubscribeTrades) -> anyhow::Result<()> {
+        let ws = self.ws_client.clone();
+        let instrument_id = cmd.instrument_id;
+        self.spawn_ws(
+            async move {
+                ws.subscribe_trades(vec![instrument_id])
+                    .await
+                    .context("trades subscription")
+            },
+            "trade subscription",
+        );
+        Ok(())
+    }
+
+    fn subscribe_mark_prices(&mut self, cmd: &SubscribeMarkPrices) -> anyhow::Result<()> {
+        let instrument_id = cmd.instrument_id;
+        self.routes
+            .mark_prices
+            .write()
+            .expect(MUTEX_POISONED)
+            .insert(instrument_id);
+
+        let ws = self.ws_client.clone();
+        self.spawn_ws(
+            async move {
+                ws.subscribe_mark_prices(vec![instrument_id])
+                    .await
+                    .context("mark price subscription")
+            },
+            "mark price subscription",
+        );
+        Ok(())
+    }
+
+    fn subscribe_index_prices(&mut self, cmd: &SubscribeIndexPrices) -> anyhow::Result<()> {
+        let instrument_id = cmd.instrument_id;
+        self.routes
+            .index_prices
+            .write()
+            .expect(MUTEX_POISONED)
+            .insert(instrument_id);
+
+        let ws = self.ws_client.clone();
+        self.spawn_ws(
+            async move {
+                ws.subscribe_index_prices(vec![instrument_id])
+                    .await
+                    .context("index price subscription")
+            },
+            "index price subscription",
+        );
+        Ok(())
+    }
+
+    fn subscribe_bars(&mut self, cmd: &SubscribeBars) -> anyhow::Result<()> {
+        let ws = self.ws_client.clone();
+        let bar_type = cmd.bar_type;
+        self.spawn_ws(
+            async move {
+                ws.subscribe_bars(bar_type)
+                    .await
+                    .context("bars subscription")
+            },
+            "bar subscription",
+        );
+        Ok(())
+    }
+
+    fn unsubscribe_instruments(&mut self, _cmd: &UnsubscribeInstruments) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn unsubscribe_instrument(&mut self, _cmd: &UnsubscribeInstrument) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn unsubscribe_book_deltas(&mut self, cmd: &UnsubscribeBookDeltas) -> anyhow::Result<()> {
+        let ws = self.ws_client.clone();
+        let instrument_id = cmd.instrument_id;
+        self.spawn_ws(
+            async move {
+                ws.unsubscribe_book(vec![instrument_id])
+                    .await
+                    .context("book deltas unsubscribe")
+            },
+            "order book unsubscribe",
+        );
+        Ok(())
+    }
+
+    fn unsubscribe_quotes(&mut self, cmd: &UnsubscribeQuotes) -> anyhow::Result<()> {
+        let ws = self.ws_client.clone();
+        let instrument_id = cmd.instrument_id;
+        self.spawn_ws(
+            async move {
+                ws.unsubscribe_quotes(vec![instrument_id])
+                    .await
+                    .context("quotes unsubscribe")
+            },
+            "quote unsubscribe",
+        );
+        Ok(())
+    }
+
+    fn unsubscribe_trades(&mut self, cmd: &UnsubscribeTrades) -> anyhow::Result<()> {
+        let ws = self.ws_client.clone();
+        let instrument_id = cmd.instrument_id;
+        self.spawn_ws(
+            async move {
+                ws.unsubscribe_trades(vec![instrument_id])
+                    .await
+                    .context("trades unsubscribe")
+            },
+            "trade unsubscribe",
+        );
+        Ok(())
+    }
+
+    fn unsubscribe_mark_prices(&mut self, cmd: &UnsubscribeMarkPrices) -> anyhow::Result<()> {
+        let instrument_id = cmd.instrument_id;
+        self.routes
+            .mark_prices
+            .write()
+            .expect(MUTEX_POISONED)
+            .remove(&instrument_id);
+
+        // The risk channel also carries index prices, so keep it while those are subscribed
+        if !self.routes.is_unused(&instrument_id) {
+            return Ok(());
+        }
+
+        let ws = self.ws_client.clone();
+        self.spawn_ws(
+            async move {
+                ws.unsubscribe_mark_prices(vec![instrument_id])
+                    .await
+                    .context("mark price unsubscribe")
+            },
+            "mark price unsubscribe",
+        );
+        Ok(())
+    }
+
+    fn unsubscribe_index_prices(&mut self, cmd: &UnsubscribeIndexPrices) -> anyhow::Result<()> {
+        let instrument_id = cmd.instrument_id;
+        self.routes
+            .index_prices
+            .write()
+            .expect(MUTEX_POISONED)
+            .remove(&instrument_id);
+
+        // The risk channel also carries mark prices, so keep it while those are subscribed
+        if !self.routes.is_unused(&instrument_id) {
+            return Ok(());
+        }
+
+        let ws = self.ws_client.clone();
+        self.spawn_ws(
+            async move {
+                ws.unsubscribe_index_prices(vec![instrument_id])
+                    .await
+                    .context("index price unsubscribe")
+            },
+            "index price unsubscribe",
+        );
+        Ok(())
+    }
+
+    fn unsubscribe_bars(&mut self, cmd: &UnsubscribeBars) -> anyhow::Result<()> {
+        let ws = self.ws_client.clone();
+        let bar_type = cmd.bar_type;
+        self.spawn_ws(
+            async move {
+                ws.unsubscribe_bars(bar_type)
+                    .await
+                    .context("bars unsubscribe")
+            },
+            "bar unsubscribe",
+        );
+        Ok(())
+    }
+
+    fn request_instruments(&self, request: &RequestInstruments) -> anyhow::Result<()> {
+        let instruments = {
+            let guard = self.instruments.read().expect(MUTEX_POISONED);
+            guard.values().cloned().collect::<Vec<_>>()
+        };
+
+        let response = DataResponse::Instruments(InstrumentsResponse::new(
+            request.request_id,
+            request.client_id.unwrap_or(self.client_id),
+            self.venue(),
+            instruments,
+            datetime_to_unix_nanos(request.start),
+            datetime_to_unix_nanos(request.end),
+            self.clock.get_time_ns(),
+            request.params.clone(),
+        ));
+
+        if let Err(e) = self.data_sender.send(DataEvent::Response(response)) {
+            tracing::error!("Failed to send instruments response: {e}");
+        }
+
+        Ok(())
+    }
+
+    fn request_instrument(&self, request: &RequestInstrument) -> anyhow::Result<()> {
+        let instrument = {
+            let guard = self.instruments.read().expect(MUTEX_POISONED);
+            guard
+                .get(&request.instrument_id)
+                .cloned()
+                .context("instrument not found in cache")?
+        };
+
+        let response = DataResponse::Instrument(Box::new(InstrumentResponse::new(
+            request.request_id,
+            request.client_id.unwrap_or(self.client_id),
+            instrument.id(),
+            instrument,
+            datetime_to_unix_nanos(request.start),
+            datetime_to_unix_nanos(request.end),
+            self.clock.get_time_ns(),
+            request.params.clone(),
+        )));
+
+        if let Err(e) = self.data_sender.send(DataEvent::Response(response)) {
+            tracing::error!("Failed to send instrument response: {e}");
+        }
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use nautilus_model::identifiers::InstrumentId;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_price_routes_share_risk_channel() {
+        let routes = PriceRoutes::default();
+        let instrument_id = InstrumentId::from("BTC-PERP.COINBASE_INTX");
+        assert!(routes.is_unused(&instrument_id));
+
+        routes.mark_prices.write().unwrap().insert(instrument_id);
+        routes.index_prices.write().unwrap().insert(instrument_id);
+        routes.mark_prices.write().unwrap().remove(&instrument_id);
+
+        assert!(!routes.has_mark_price(&instrument_id));
+        assert!(routes.has_index_price(&instrument_id));
+        assert!(!routes.is_unused(&instrument_id));
+
+        routes.clear();
+        assert!(routes.is_unused(&instrument_id));
+    }
+
+    #[rstest]
+    fn test_datetime_to_unix_nanos() {
+        let dt = DateTime::parse_from_rfc3339("2025-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        assert_eq!(
+            datetime_to_unix_nanos(Some(dt)),
+            Some(UnixNanos::from(1_735_689_600_000_000_000))
+        );
+        assert_eq!(datetime_to_unix_nanos(None), None);
+    }
+}
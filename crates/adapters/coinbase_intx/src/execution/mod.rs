@@ -0,0 +1,1458 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Execution client implementation for the Coinbase International adapter.
+//!
+//! Orders are submitted and canceled over the REST API, while execution reports (cancels,
+//! expiries, replacements and fills) are received from the FIX drop copy session and translated
+//! into order events for orders submitted through this client.
+
+use std::{
+    cell::Ref,
+    future::Future,
+    sync::{Arc, Mutex},
+};
+
+use ahash::AHashSet;
+use anyhow::Context;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use dashmap::DashMap;
+use nautilus_common::{
+    clock::Clock,
+    messages::{
+        ExecutionEvent, ExecutionReport,
+        execution::{
+            BatchCancelOrders, CancelAllOrders, CancelOrder, GenerateFillReports,
+            GenerateOrderStatusReport, GeneratePositionReports, ModifyOrder, QueryAccount,
+            QueryOrder, SubmitOrder, SubmitOrderList,
+        },
+    },
+    runner::get_exec_event_sender,
+    runtime::get_runtime,
+};
+use nautilus_core::{
+    MUTEX_POISONED, UUID4, UnixNanos,
+    time::{AtomicTime, get_atomic_clock_realtime},
+};
+use nautilus_execution::client::{ExecutionClient, LiveExecutionClient, base::ExecutionClientCore};
+use nautilus_live::execution::LiveExecutionClientExt;
+use nautilus_model::{
+    accounts::AccountAny,
+    enums::{OmsType, OrderSide, OrderStatus, OrderType, TimeInForce},
+    events::{
+        OrderAccepted, OrderCancelRejected, OrderCanceled, OrderEventAny, OrderExpired,
+        OrderFilled, OrderRejected,
+    },
+    identifiers::{
+        AccountId, ClientId, ClientOrderId, InstrumentId, StrategyId, Symbol, TradeId, TraderId,
+        Venue, VenueOrderId,
+    },
+    orders::{Order, OrderAny},
+    reports::{ExecutionMassStatus, FillReport, OrderStatusReport, PositionStatusReport},
+    types::{AccountBalance, MarginBalance, Price, Quantity},
+};
+use tokio::{sync::mpsc::UnboundedSender, task::JoinHandle};
+use ustr::Ustr;
+
+use crate::{
+    common::consts::COINBASE_INTX_VENUE,
+    config::CoinbaseIntxExecClientConfig,
+    fix::client::{CoinbaseIntxFixClient, FixReportHandler},
+    http::client::CoinbaseIntxHttpClient,
+};
+
+/// Order types which can be submitted to Coinbase International.
+const SUPPORTED_ORDER_TYPES: [OrderType; 4] = [
+    OrderType::Market,
+    OrderType::Limit,
+    OrderType::StopMarket,
+    OrderType::StopLimit,
+];
+
+/// Time in force values which can be submitted to Coinbase International.
+const SUPPORTED_TIME_IN_FORCE: [TimeInForce; 4] = [
+    TimeInForce::Gtc,
+    TimeInForce::Gtd,
+    TimeInForce::Ioc,
+    TimeInForce::Fok,
+];
+
+/// Live execution client for Coinbase International.
+#[derive(Debug)]
+pub struct CoinbaseIntxExecutionClient {
+    core: ExecutionClientCore,
+    config: CoinbaseIntxExecClientConfig,
+    http_client: CoinbaseIntxHttpClient,
+    fix_client: CoinbaseIntxFixClient,
+    exec_sender: UnboundedSender<ExecutionEvent>,
+    events: OrderEventFactory,
+    orders: Arc<DashMap<ClientOrderId, TrackedOrder>>,
+    started: bool,
+    connected: bool,
+    instruments_initialized: bool,
+    pending_tasks: Mutex<Vec<JoinHandle<()>>>,
+}
+
+impl CoinbaseIntxExecutionClient {
+    /// Creates a new [`CoinbaseIntxExecutionClient`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if API credentials or the portfolio ID are missing from both the config
+    /// and environment, or if the core account ID does not match the configured portfolio.
+    pub fn new(
+        core: ExecutionClientCore,
+        config: CoinbaseIntxExecClientConfig,
+    ) -> anyhow::Result<Self> {
+        let http_client = CoinbaseIntxHttpClient::with_credentials(
+            config.api_key.clone(),
+            config.api_secret.clone(),
+            config.api_passphrase.clone(),
+            Some(config.http_base_url()),
+            config.http_timeout_secs,
+        )
+        .context("failed to construct Coinbase International HTTP client")?;
+
+        let fix_client = CoinbaseIntxFixClient::new(
+            Some(config.fix_endpoint()),
+            config.api_key.clone(),
+            config.api_secret.clone(),
+            config.api_passphrase.clone(),
+            config.portfolio_id.clone(),
+        )
+        .context("failed to construct Coinbase International FIX client")?;
+
+        // The REST API addresses the portfolio through the account ID issuer
+        let expected_account_id = fix_client.account_id();
+        if core.account_id != expected_account_id {
+            anyhow::bail!(
+                "Account ID {} does not match the Coinbase International portfolio (expected {expected_account_id})",
+                core.account_id
+            );
+        }
+
+        let events = OrderEventFactory {
+            trader_id: core.trader_id,
+            account_id: core.account_id,
+            clock: get_atomic_clock_realtime(),
+        };
+
+        Ok(Self {
+            core,
+            config,
+            http_client,
+            fix_client,
+            exec_sender: get_exec_event_sender(),
+            events,
+            orders: Arc::new(DashMap::new()),
+            started: false,
+            connected: false,
+            instruments_initialized: false,
+            pending_tasks: Mutex::new(Vec::new()),
+        })
+    }
+
+    async fn ensure_instruments_initialized_async(&mut self) -> anyhow::Result<()> {
+        if self.instruments_initialized {
+            return Ok(());
+        }
+
+        // Instruments provide the price and size precisions for parsing responses
+        let instruments = self
+            .http_client
+            .request_instruments()
+            .await
+            .context("failed to request Coinbase International instruments")?;
+
+        if instruments.is_empty() {
+            tracing::warn!("No instruments returned from Coinbase International");
+        }
+
+        self.http_client.add_instruments(instruments);
+        self.instruments_initialized = true;
+        Ok(())
+    }
+
+    fn ensure_instruments_initialized(&mut self) -> anyhow::Result<()> {
+        if self.instruments_initialized {
+            return Ok(());
+        }
+
+        let runtime = get_runtime();
+        runtime.block_on(self.ensure_instruments_initialized_async())
+    }
+
+    async fn refresh_account_state(&self) -> anyhow::Result<()> {
+        let account_state = self
+            .http_client
+            .request_account_state(self.core.account_id)
+            .await
+            .context("failed to request Coinbase International account state")?;
+
+        self.core.generate_account_state(
+            account_state.balances.clone(),
+            account_state.margins.clone(),
+            account_state.is_reported,
+            account_state.ts_event,
+        )
+    }
+
+    fn update_account_state(&self) -> anyhow::Result<()> {
+        let runtime = get_runtime();
+        runtime.block_on(self.refresh_account_state())
+    }
+
+    fn spawn_task<F>(&self, description: &'static str, fut: F)
+    where
+        F: Future<Output = anyhow::Result<()>> + Send + 'static,
+    {
+        let runtime = get_runtime();
+        let handle = runtime.spawn(async move {
+            if let Err(e) = fut.await {
+                tracing::warn!("{description} failed: {e:?}");
+            }
+        });
+
+        let mut tasks = self.pending_tasks.lock().expect(MUTEX_POISONED);
+        tasks.retain(|handle| !handle.is_finished());
+        tasks.push(handle);
+    }
+
+    fn abort_pending_tasks(&self) {
+        let mut tasks = self.pending_tasks.lock().expect(MUTEX_POISONED);
+        for handle in tasks.drain(..) {
+            handle.abort();
+        }
+    }
+
+    fn submit(&self, order: &OrderAny, ts_init: UnixNanos) {
+        if order.is_closed() {
+            tracing::warn!("Cannot submit closed order {}", order.client_order_id());
+            return;
+        }
+
+        self.core.generate_order_submitted(
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            ts_init,
+        );
+
+        let request = match OrderRequest::from_order(order) {
+            Ok(request) => request,
+            Err(e) => {
+                self.core.generate_order_rejected(
+                    order.strategy_id(),
+                    order.instrument_id(),
+                    order.client_order_id(),
+                    &format!("submit-order-error: {e}"),
+                    ts_init,
+                    false,
+                );
+                return;
+            }
+        };
+
+        let client_order_id = order.client_order_id();
+        self.orders
+            .insert(client_order_id, TrackedOrder::from_order(order));
+
+        let http_client = self.http_client.clone();
+        let account_id = self.core.account_id;
+        let orders = Arc::clone(&self.orders);
+        let events = self.events;
+        let sender = self.exec_sender.clone();
+
+        self.spawn_task("submit_order", async move {
+            let result = http_client
+                .submit_order(
+                    account_id,
+                    request.client_order_id,
+                    request.symbol,
+                    request.order_side,
+                    request.order_type,
+                    request.quantity,
+                    request.time_in_force,
+                    request.expire_time,
+                    request.price,
+                    request.trigger_price,
+                    request.post_only,
+                    request.reduce_only,
+                )
+                .await;
+
+            match result {
+                Ok(report) => {
+                    // Fills may already have arrived over the drop copy session
+                    if let Some(mut tracked) = orders.get_mut(&client_order_id)
+                        && !tracked.accepted
+                    {
+                        tracked.accepted = true;
+                        send_order_event(
+                            &sender,
+                            events.accepted(
+                                &tracked,
+                                client_order_id,
+                                report.venue_order_id,
+                                report.ts_last,
+                            ),
+                        );
+                    }
+                    Ok(())
+                }
+                Err(e) => {
+                    if let Some((_, tracked)) = orders.remove(&client_order_id) {
+                        send_order_event(
+                            &sender,
+                            events.rejected(
+                                &tracked,
+                                client_order_id,
+                                &format!("submit-order-error: {e}"),
+                            ),
+                        );
+                    }
+                    Err(e)
+                }
+            }
+        });
+    }
+
+    fn cancel(&self, cmd: &CancelOrder) {
+        let http_client = self.http_client.clone();
+        let account_id = self.core.account_id;
+        let events = self.events;
+        let sender = self.exec_sender.clone();
+        let command = cmd.clone();
+
+        self.spawn_task("cancel_order", async move {
+            if let Err(e) = http_client
+                .cancel_order(account_id, command.client_order_id)
+                .await
+            {
+                send_order_event(
+                    &sender,
+                    events.cancel_rejected(
+                        command.strategy_id,
+                        command.instrument_id,
+                        command.client_order_id,
+                        command.venue_order_id,
+                        &format!("cancel-order-error: {e}"),
+                    ),
+                );
+                return Err(e);
+            }
+            Ok(())
+        });
+    }
+
+    /// Returns the symbols with open orders or positions known to the cache.
+    fn cache_active_symbols(&self) -> AHashSet<Symbol> {
+        let venue = self.venue();
+        let cache = self.core.cache().borrow();
+
+        let mut symbols: AHashSet<Symbol> = cache
+            .orders_open(Some(&venue), None, None, None)
+            .into_iter()
+            .map(|order| order.instrument_id().symbol)
+            .collect();
+        symbols.extend(
+            cache
+                .positions_open(Some(&venue), None, None, None)
+                .into_iter()
+                .map(|position| position.instrument_id.symbol),
+        );
+
+        symbols
+    }
+
+    /// Returns the symbols with open orders or positions, from both the cache and the venue.
+    async fn active_symbols(&self) -> anyhow::Result<AHashSet<Symbol>> {
+        let mut symbols = self.cache_active_symbols();
+
+        let positions = self
+            .http_client
+            .request_position_status_reports(self.core.account_id)
+            .await
+            .context("failed to request Coinbase International positions")?;
+        symbols.extend(
+            positions
+                .iter()
+                .filter(|report| !report.is_flat())
+                .map(|report| report.instrument_id.symbol),
+        );
+
+        Ok(symbols)
+    }
+
+    async fn open_order_reports(
+        &self,
+        symbols: AHashSet<Symbol>,
+    ) -> anyhow::Result<Vec<OrderStatusReport>> {
+        let mut reports = Vec::new();
+        for symbol in symbols {
+            let mut fetched = self
+                .http_client
+                .request_order_status_reports(self.core.account_id, symbol)
+                .await
+                .with_context(|| format!("failed to request open orders for {symbol}"))?;
+            reports.append(&mut fetched);
+        }
+        Ok(reports)
+    }
+}
+
+impl ExecutionClient for CoinbaseIntxExecutionClient {
+    fn is_connected(&self) -> bool {
+        self.connected
+    }
+
+    fn client_id(&self) -> ClientId {
+        self.core.client_id
+    }
+
+    fn account_id(&self) -> AccountId {
+        self.core.account_id
+    }
+
+    fn venue(&self) -> Venue {
+        *COINBASE_INTX_VENUE
+    }
+
+    fn oms_type(&self) -> OmsType {
+        self.core.oms_type
+    }
+
+    fn get_account(&self) -> Option<AccountAny> {
+        self.core.get_account()
+    }
+
+    fn generate_account_state(
+        &self,
+        balances: Vec<AccountBalance>,
+        margins: Vec<MarginBalance>,
+        reported: bool,
+        ts_event: UnixNanos,
+    ) -> anyhow::Result<()> {
+        self.core
+            .generate_account_state(balances, margins, reported, ts_event)
+    }
+
+    fn start(&mut self) -> anyhow::Result<()> {
+        if self.started {
+            return Ok(());
+        }
+
+        self.ensure_instruments_initialized()?;
+        self.started = true;
+        tracing::info!(
+            client_id = %self.core.client_id,
+            account_id = %self.core.account_id,
+            is_sandbox = self.config.is_sandbox,
+            "Coinbase International execution client started"
+        );
+        Ok(())
+    }
+
+    fn stop(&mut self) -> anyhow::Result<()> {
+        if !self.started {
+            return Ok(());
+        }
+
+        self.started = false;
+        self.connected = false;
+        self.abort_pending_tasks();
+        tracing::info!(
+            "Coinbase International execution client {} stopped",
+            self.core.client_id
+        );
+        Ok(())
+    }
+
+    fn submit_order(&self, cmd: &SubmitOrder) -> anyhow::Result<()> {
+        self.submit(&cmd.order, cmd.ts_init);
+        Ok(())
+    }
+
+    fn submit_order_list(&self, cmd: &SubmitOrderList) -> anyhow::Result<()> {
+        // Coinbase International has no batch order endpoint
+        for order in &cmd.order_list.orders {
+            self.submit(order, cmd.ts_init);
+        }
+        Ok(())
+    }
+
+    fn modify_order(&self, cmd: &ModifyOrder) -> anyhow::Result<()> {
+        // Modifying an order requires a new client order ID, which does not map onto the
+        // order model, so strategies should cancel and replace instead
+        self.core.generate_order_modify_rejected(
+            cmd.strategy_id,
+            cmd.instrument_id,
+            cmd.client_order_id,
+            cmd.venue_order_id,
+            "modify-order-error: order modification unsupported (use cancel and replace)",
+            cmd.ts_init,
+        );
+        Ok(())
+    }
+
+    fn cancel_order(&self, cmd: &CancelOrder) -> anyhow::Result<()> {
+        self.cancel(cmd);
+        Ok(())
+    }
+
+    fn cancel_all_orders(&self, cmd: &CancelAllOrders) -> anyhow::Result<()> {
+        let order_side = match cmd.order_side {
+            OrderSide::NoOrderSide => None,
+            side => Some(side),
+        };
+
+        // Collected up front so a failed request can be rejected outside the cache
+        let open_orders: Vec<(StrategyId, ClientOrderId, VenueOrderId)> = self
+            .core
+            .cache()
+            .borrow()
+            .orders_open(
+                Some(&self.venue()),
+                Some(&cmd.instrument_id),
+                None,
+                order_side,
+            )
+            .into_iter()
+            .map(|order| {
+                (
+                    order.strategy_id(),
+                    order.client_order_id(),
+                    order.venue_order_id().unwrap_or_default(),
+                )
+            })
+            .collect();
+
+        let http_client = self.http_client.clone();
+        let account_id = self.core.account_id;
+        let instrument_id = cmd.instrument_id;
+        let events = self.events;
+        let sender = self.exec_sender.clone();
+
+        self.spawn_task("cancel_all_orders", async move {
+            if let Err(e) = http_client
+                .cancel_orders(account_id, instrument_id.symbol, order_side)
+                .await
+            {
+                let reason = format!("cancel-all-orders-error: {e}");
+                for (strategy_id, client_order_id, venue_order_id) in open_orders {
+                    send_order_event(
+                        &sender,
+                        events.cancel_rejected(
+                            strategy_id,
+                            instrument_id,
+                            client_order_id,
+                            venue_order_id,
+                            &reason,
+                        ),
+                    );
+                }
+                return Err(e);
+            }
+            Ok(())
+        });
+
+        Ok(())
+    }
+
+    fn batch_cancel_orders(&self, cmd: &BatchCancelOrders) -> anyhow::Result<()> {
+        // Coinbase International has no batch cancel endpoint
+        for cancel in &cmd.cancels {
+            self.cancel(cancel);
+        }
+        Ok(())
+    }
+
+    fn query_account(&self, _cmd: &QueryAccount) -> anyhow::Result<()> {
+        self.update_account_state()
+    }
+
+    fn query_order(&self, cmd: &QueryOrder) -> anyhow::Result<()> {
+        let http_client = self.http_client.clone();
+        let account_id = self.core.account_id;
+        let sender = self.exec_sender.clone();
+        let venue_order_id = cmd.venue_order_id;
+
+        self.spawn_task("query_order", async move {
+            let report = http_client
+                .request_order_status_report(account_id, venue_order_id)
+                .await?;
+            send_report(&sender, ExecutionReport::OrderStatus(Box::new(report)));
+            Ok(())
+        });
+
+        Ok(())
+    }
+}
+
+#[async_trait(?Send)]
+impl LiveExecutionClient for CoinbaseIntxExecutionClient {
+    async fn connect(&mut self) -> anyhow::Result<()> {
+        if self.connected {
+            return Ok(());
+        }
+
+        self.ensure_instruments_initialized_async().await?;
+        self.refresh_account_state().await?;
+
+        let handler = DropCopyHandler {
+            events: self.events,
+            orders: Arc::clone(&self.orders),
+            sender: self.exec_sender.clone(),
+        };
+        let report_handler: FixReportHandler = Arc::new(move |report| handler.handle(report));
+
+        self.fix_client
+            .connect_with_handler(report_handler)
+            .await
+            .context("failed to connect Coinbase International FIX drop copy session")?;
+
+        self.connected = true;
+        tracing::info!(
+            "Coinbase International execution client {} connected",
+            self.core.client_id
+        );
+
+        Ok(())
+    }
+
+    async fn disconnect(&mut self) -> anyhow::Result<()> {
+        if !self.connected {
+            return Ok(());
+        }
+
+        if let Err(e) = self.fix_client.close().await {
+            tracing::warn!("Error while closing FIX drop copy session: {e:?}");
+        }
+
+        self.abort_pending_tasks();
+
+        self.connected = false;
+        tracing::info!(
+            "Coinbase International execution client {} disconnected",
+            self.core.client_id
+        );
+        Ok(())
+    }
+
+    async fn generate_order_status_report(
+        &self,
+        cmd: &GenerateOrderStatusReport,
+    ) -> anyhow::Result<Option<OrderStatusReport>> {
+        let Some(venue_order_id) = cmd.venue_order_id else {
+            tracing::warn!(
+                "Cannot request order status report for {:?}, no venue order ID",
+                cmd.client_order_id
+            );
+            return Ok(None);
+        };
+
+        let report = self
+            .http_client
+            .request_order_status_report(
+                self.core.account_id,
+                VenueOrderId::new(venue_order_id.as_str()),
+            )
+            .await?;
+
+        Ok(Some(report))
+    }
+
+    async fn generate_order_status_reports(
+        &self,
+        cmd: &GenerateOrderStatusReport,
+    ) -> anyhow::Result<Vec<OrderStatusReport>> {
+        let symbols = match cmd.instrument_id {
+            Some(instrument_id) => AHashSet::from([instrument_id.symbol]),
+            None => self.active_symbols().await?,
+        };
+
+        let mut reports = self.open_order_reports(symbols).await?;
+
+        if let Some(client_order_id) = cmd.client_order_id {
+            reports.retain(|report| report.client_order_id == Some(client_order_id));
+        }
+
+        if let Some(venue_order_id) = cmd.venue_order_id {
+            reports.retain(|report| report.venue_order_id.as_str() == venue_order_id.as_str());
+        }
+
+        Ok(reports)
+    }
+
+    async fn generate_fill_reports(
+        &self,
+        cmd: GenerateFillReports,
+    ) -> anyhow::Result<Vec<FillReport>> {
+        let start = cmd.start.map(|nanos| nanos.to_datetime_utc());
+
+        let mut reports = self
+            .http_client
+            .request_fill_reports(self.core.account_id, None, start)
+            .await?;
+
+        if let Some(instrument_id) = cmd.instrument_id {
+            reports.retain(|report| report.instrument_id == instrument_id);
+        }
+
+        if let Some(venue_order_id) = cmd.venue_order_id {
+            reports.retain(|report| report.venue_order_id.as_str() == venue_order_id.as_str());
+        }
+
+        if let Some(end) = cmd.end {
+            reports.retain(|report| report.ts_event <= end);
+        }
+
+        Ok(reports)
+    }
+
+    async fn generate_position_status_reports(
+        &self,
+        cmd: &GeneratePositionReports,
+    ) -> anyhow::Result<Vec<PositionStatusReport>> {
+        if let Some(instrument_id) = cmd.instrument_id {
+            let report = self
+                .http_client
+                .request_position_status_report(self.core.account_id, instrument_id.symbol)
+                .await?;
+            return Ok(vec![report]);
+        }
+
+        self.http_client
+            .request_position_status_reports(self.core.account_id)
+            .await
+    }
+
+    async fn generate_mass_status(
+        &self,
+        lookback_mins: Option<u64>,
+    ) -> anyhow::Result<Option<ExecutionMassStatus>> {
+        let ts_now = self.events.clock.get_time_ns();
+        let start = lookback_mins.map(|mins| lookback_start(ts_now, mins));
+
+        let mut mass_status = ExecutionMassStatus::new(
+            self.core.client_id,
+            self.core.account_id,
+            *COINBASE_INTX_VENUE,
+            ts_now,
+            None,
+        );
+
+        let position_reports = self
+            .http_client
+            .request_position_status_reports(self.core.account_id)
+            .await
+            .context("failed to request Coinbase International positions")?;
+
+        let mut symbols = self.cache_active_symbols();
+        symbols.extend(
+            position_reports
+                .iter()
+                .filter(|report| !report.is_flat())
+                .map(|report| report.instrument_id.symbol),
+        );
+
+        let order_reports = self.open_order_reports(symbols).await?;
+
+        let fill_reports = self
+            .http_client
+            .request_fill_reports(self.core.account_id, None, start)
+            .await
+            .context("failed to request Coinbase International fills")?;
+
+        tracing::info!(
+            "Received {} order, {} fill and {} position reports",
+            order_reports.len(),
+            fill_reports.len(),
+            position_reports.len(),
+        );
+
+        mass_status.add_order_reports(order_reports);
+        mass_status.add_fill_reports(fill_reports);
+        mass_status.add_position_reports(position_reports);
+
+        Ok(Some(mass_status))
+    }
+}
+
+impl LiveExecutionClientExt for CoinbaseIntxExecutionClient {
+    fn get_message_channel(&self) -> UnboundedSender<ExecutionEvent> {
+        self.exec_sender.clone()
+    }
+
+    fn get_clock(&self) -> Ref<'_, dyn Clock> {
+        self.core.clock().borrow()
+    }
+}
+
+/// Order submission parameters for the REST API, validated against venue capabilities.
+#[derive(Clone, Debug, PartialEq)]
+struct OrderRequest {
+    client_order_id: ClientOrderId,
+    symbol: Symbol,
+    order_side: OrderSide,
+    order_type: OrderType,
+    quantity: Quantity,
+    time_in_force: TimeInForce,
+    expire_time: Option<DateTime<Utc>>,
+    price: Option<Price>,
+    trigger_price: Option<Price>,
+    post_only: Option<bool>,
+    reduce_only: Option<bool>,
+}
+
+impl OrderRequest {
+    fn from_order(order: &OrderAny) -> anyhow::Result<Self> {
+        let order_type = order.order_type();
+        if !SUPPORTED_ORDER_TYPES.contains(&order_type) {
+            anyhow::bail!("UNSUPPORTED_ORDER_TYPE: {order_type}");
+        }
+
+        if order.is_quote_quantity() {
+            anyhow::bail!("UNSUPPORTED_QUOTE_QUANTITY");
+        }
+
+        let mut time_in_force = order.time_in_force();
+        if !SUPPORTED_TIME_IN_FORCE.contains(&time_in_force) {
+            anyhow::bail!("UNSUPPORTED_TIME_IN_FORCE: {time_in_force}");
+        }
+
+        // Market orders must be IOC or FOK on Coinbase International
+        if order_type == OrderType::Market
+            && !matches!(time_in_force, TimeInForce::Ioc | TimeInForce::Fok)
+        {
+            tracing::warn!("Submitting MARKET order as IOC, was {time_in_force}");
+            time_in_force = TimeInForce::Ioc;
+        }
+
+        let expire_time = match time_in_force {
+            TimeInForce::Gtd => order.expire_time().map(|nanos| nanos.to_datetime_utc()),
+            _ => None,
+        };
+
+        Ok(Self {
+            client_order_id: order.client_order_id(),
+            symbol: order.instrument_id().symbol,
+            order_side: order.order_side(),
+            order_type,
+            quantity: order.quantity(),
+            time_in_force,
+            expire_time,
+            price: order.price(),
+            trigger_price: order.trigger_price(),
+            post_only: order.is_post_only().then_some(true),
+            reduce_only: order.is_reduce_only().then_some(true),
+        })
+    }
+}
+
+/// Local view of an order submitted through this client, used to turn drop copy execution
+/// reports into order events.
+#[derive(Clone, Debug)]
+struct TrackedOrder {
+    strategy_id: StrategyId,
+    instrument_id: InstrumentId,
+    order_side: OrderSide,
+    order_type: OrderType,
+    quantity: Quantity,
+    filled_qty: Quantity,
+    accepted: bool,
+    trade_ids: AHashSet<TradeId>,
+}
+
+impl TrackedOrder {
+    fn from_order(order: &OrderAny) -> Self {
+        let quantity = order.quantity();
+        Self {
+            strategy_id: order.strategy_id(),
+            instrument_id: order.instrument_id(),
+            order_side: order.order_side(),
+            order_type: order.order_type(),
+            quantity,
+            filled_qty: Quantity::zero(quantity.precision),
+            accepted: false,
+            trade_ids: AHashSet::new(),
+        }
+    }
+
+    fn is_filled(&self) -> bool {
+        self.filled_qty >= self.quantity
+    }
+}
+
+/// Builds order events outside the execution client core, which cannot be moved into tasks.
+#[derive(Clone, Copy, Debug)]
+struct OrderEventFactory {
+    trader_id: TraderId,
+    account_id: AccountId,
+    clock: &'static AtomicTime,
+}
+
+impl OrderEventFactory {
+    fn accepted(
+        &self,
+        tracked: &TrackedOrder,
+        client_order_id: ClientOrderId,
+        venue_order_id: VenueOrderId,
+        ts_event: UnixNanos,
+    ) -> OrderEventAny {
+        OrderEventAny::Accepted(OrderAccepted::new(
+            self.trader_id,
+            tracked.strategy_id,
+            tracked.instrument_id,
+            client_order_id,
+            venue_order_id,
+            self.account_id,
+            UUID4::new(),
+            ts_event,
+            self.clock.get_time_ns(),
+            false,
+        ))
+    }
+
+    fn rejected(
+        &self,
+        tracked: &TrackedOrder,
+        client_order_id: ClientOrderId,
+        reason: &str,
+    ) -> OrderEventAny {
+        let ts_now = self.clock.get_time_ns();
+        OrderEventAny::Rejected(OrderRejected::new(
+            self.trader_id,
+            tracked.strategy_id,
+            tracked.instrument_id,
+            client_order_id,
+            self.account_id,
+            Ustr::from(reason),
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            false,
+        ))
+    }
+
+    fn canceled(
+        &self,
+        tracked: &TrackedOrder,
+        client_order_id: ClientOrderId,
+        venue_order_id: VenueOrderId,
+        ts_event: UnixNanos,
+    ) -> OrderEventAny {
+        OrderEventAny::Canceled(OrderCanceled::new(
+            self.trader_id,
+            tracked.strategy_id,
+            tracked.instrument_id,
+            client_order_id,
+            UUID4::new(),
+            ts_event,
+            self.clock.get_time_ns(),
+            false,
+            Some(venue_order_id),
+            Some(self.account_id),
+        ))
+    }
+
+    fn expired(
+        &self,
+        tracked: &TrackedOrder,
+        client_order_id: ClientOrderId,
+        venue_order_id: VenueOrderId,
+        ts_event: UnixNanos,
+    ) -> OrderEventAny {
+        OrderEventAny::Expired(OrderExpired::new(
+            self.trader_id,
+            tracked.strategy_id,
+            tracked.instrument_id,
+            client_order_id,
+            UUID4::new(),
+            ts_event,
+            self.clock.get_time_ns(),
+            false,
+            Some(venue_order_id),
+            Some(self.account_id),
+        ))
+    }
+
+    fn filled(
+        &self,
+        tracked: &TrackedOrder,
+        client_order_id: ClientOrderId,
+        fill: &FillReport,
+    ) -> OrderEventAny {
+        OrderEventAny::Filled(OrderFilled::new(
+            self.trader_id,
+            tracked.strategy_id,
+            tracked.instrument_id,
+            client_order_id,
+            fill.venue_order_id,
+            self.account_id,
+            fill.trade_id,
+            tracked.order_side,
+            tracked.order_type,
+            fill.last_qty,
+            fill.last_px,
+            fill.commission.currency,
+            fill.liquidity_side,
+            UUID4::new(),
+            fill.ts_event,
+            self.clock.get_time_ns(),
+            false,
+            None,
+            Some(fill.commission),
+        ))
+    }
+
+    fn cancel_rejected(
+        &self,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        venue_order_id: VenueOrderId,
+        reason: &str,
+    ) -> OrderEventAny {
+        let ts_now = self.clock.get_time_ns();
+        OrderEventAny::CancelRejected(OrderCancelRejected::new(
+            self.trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            Ustr::from(reason),
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            Some(venue_order_id),
+            Some(self.account_id),
+        ))
+    }
+}
+
+/// Routes drop copy execution reports to order events for tracked orders, and forwards all
+/// other reports for reconciliation.
+#[derive(Debug)]
+struct DropCopyHandler {
+    events: OrderEventFactory,
+    orders: Arc<DashMap<ClientOrderId, TrackedOrder>>,
+    sender: UnboundedSender<ExecutionEvent>,
+}
+
+impl DropCopyHandler {
+    fn handle(&self, report: ExecutionReport) {
+        let events = match &report {
+            ExecutionReport::OrderStatus(status) => status
+                .client_order_id
+                .and_then(|client_order_id| self.order_events(client_order_id, status)),
+            ExecutionReport::Fill(fill) => fill
+                .client_order_id
+                .and_then(|client_order_id| self.fill_events(client_order_id, fill)),
+            _ => None,
+        };
+
+        match events {
+            Some(events) => {
+                for event in events {
+                    send_order_event(&self.sender, event);
+                }
+            }
+            None => send_report(&self.sender, report),
+        }
+    }
+
+    /// Returns `None` when the order is not tracked, or the report has no event equivalent.
+    fn order_events(
+        &self,
+        client_order_id: ClientOrderId,
+        report: &OrderStatusReport,
+    ) -> Option<Vec<OrderEventAny>> {
+        let mut tracked = self.orders.get_mut(&client_order_id)?;
+        let events = order_events_from_report(&self.events, client_order_id, &mut tracked, report)?;
+        drop(tracked);
+
+        // Only cancels and expiries produce events, both of which close the order
+        self.orders.remove(&client_order_id);
+
+        Some(events)
+    }
+
+    fn fill_events(
+        &self,
+        client_order_id: ClientOrderId,
+        fill: &FillReport,
+    ) -> Option<Vec<OrderEventAny>> {
+        let mut tracked = self.orders.get_mut(&client_order_id)?;
+        let events = order_events_from_fill(&self.events, client_order_id, &mut tracked, fill);
+        let is_filled = tracked.is_filled();
+        drop(tracked);
+
+        if is_filled {
+            self.orders.remove(&client_order_id);
+        }
+
+        Some(events)
+    }
+}
+
+/// Translates a drop copy order status report for a tracked order into order events.
+///
+/// Replacements are left to reconciliation (returning `None`), and an order which is canceled or
+/// expired before its submit response arrived is accepted beforehand so the order state machine
+/// transitions remain valid.
+fn order_events_from_report(
+    events: &OrderEventFactory,
+    client_order_id: ClientOrderId,
+    tracked: &mut TrackedOrder,
+    report: &OrderStatusReport,
+) -> Option<Vec<OrderEventAny>> {
+    let venue_order_id = report.venue_order_id;
+    let ts_event = report.ts_last;
+
+    let event = match report.order_status {
+        OrderStatus::Canceled => {
+            events.canceled(tracked, client_order_id, venue_order_id, ts_event)
+        }
+        OrderStatus::Expired => events.expired(tracked, client_order_id, venue_order_id, ts_event),
+        _ => return None,
+    };
+
+    let mut out = Vec::with_capacity(2);
+    if !tracked.accepted {
+        tracked.accepted = true;
+        out.push(events.accepted(tracked, client_order_id, venue_order_id, ts_event));
+    }
+    out.push(event);
+
+    Some(out)
+}
+
+/// Translates a drop copy fill for a tracked order into order events.
+fn order_events_from_fill(
+    events: &OrderEventFactory,
+    client_order_id: ClientOrderId,
+    tracked: &mut TrackedOrder,
+    fill: &FillReport,
+) -> Vec<OrderEventAny> {
+    if !tracked.trade_ids.insert(fill.trade_id) {
+        tracing::debug!(
+            "Duplicate execution {} for {client_order_id}",
+            fill.trade_id
+        );
+        return Vec::new();
+    }
+
+    let mut out = Vec::with_capacity(2);
+
+    if !tracked.accepted {
+        tracked.accepted = true;
+        out.push(events.accepted(tracked, client_order_id, fill.venue_order_id, fill.ts_event));
+    }
+
+    tracked.filled_qty += fill.last_qty;
+    out.push(events.filled(tracked, client_order_id, fill));
+
+    out
+}
+
+fn lookback_start(ts_now: UnixNanos, lookback_mins: u64) -> DateTime<Utc> {
+    let lookback_ns = lookback_mins.saturating_mul(60 * 1_000_000_000);
+    UnixNanos::from(ts_now.as_u64().saturating_sub(lookback_ns)).to_datetime_utc()
+}
+
+fn send_report(sender: &UnboundedSender<ExecutionEvent>, report: ExecutionReport) {
+    if let Err(e) = sender.send(ExecutionEvent::Report(report)) {
+        tracing::warn!("Failed to send execution report: {e}");
+    }
+}
+
+fn send_order_event(sender: &UnboundedSender<ExecutionEvent>, event: OrderEventAny) {
+    if let Err(e) = sender.send(ExecutionEvent::Order(event)) {
+        tracing::warn!("Failed to send order event: {e}");
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::{LiquiditySide, TimeInForce},
+        orders::OrderTestBuilder,
+        types::Money,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn factory() -> OrderEventFactory {
+        OrderEventFactory {
+            trader_id: TraderId::from("TRADER-001"),
+            account_id: AccountId::from("COINBASE_INTX-PORTFOLIO"),
+            clock: get_atomic_clock_realtime(),
+        }
+    }
+
+    fn instrument_id() -> InstrumentId {
+        InstrumentId::from("BTC-PERP.COINBASE_INTX")
+    }
+
+    fn limit_order() -> OrderAny {
+        OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_id())
+            .client_order_id(ClientOrderId::from("O-001"))
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("2.0"))
+            .price(Price::from("50000.0"))
+            .build()
+    }
+
+    fn order_status_report(order_status: OrderStatus) -> OrderStatusReport {
+        OrderStatusReport::new(
+            AccountId::from("COINBASE_INTX-PORTFOLIO"),
+            instrument_id(),
+            Some(ClientOrderId::from("O-001")),
+            VenueOrderId::from("V-001"),
+            OrderSide::Buy,
+            OrderType::Limit,
+            TimeInForce::Gtc,
+            order_status,
+            Quantity::from("2.0"),
+            Quantity::from("0.0"),
+            UnixNanos::from(1),
+            UnixNanos::from(2),
+            UnixNanos::from(3),
+            None,
+        )
+    }
+
+    fn fill_report(trade_id: &str, last_qty: &str) -> FillReport {
+        FillReport::new(
+            AccountId::from("COINBASE_INTX-PORTFOLIO"),
+            instrument_id(),
+            VenueOrderId::from("V-001"),
+            TradeId::from(trade_id),
+            OrderSide::Buy,
+            Quantity::from(last_qty),
+            Price::from("50000.0"),
+            Money::from("0.5 USDC"),
+            LiquiditySide::Maker,
+            Some(ClientOrderId::from("O-001")),
+            None,
+            UnixNanos::from(4),
+            UnixNanos::from(5),
+            None,
+        )
+    }
+
+    #[rstest]
+    fn test_order_request_from_limit_order() {
+        let request = OrderRequest::from_order(&limit_order()).unwrap();
+
+        assert_eq!(request.symbol, Symbol::from("BTC-PERP"));
+        assert_eq!(request.order_type, OrderType::Limit);
+        assert_eq!(request.time_in_force, TimeInForce::Gtc);
+        assert_eq!(request.price, Some(Price::from("50000.0")));
+        assert_eq!(request.expire_time, None);
+        assert_eq!(request.post_only, None);
+        assert_eq!(request.reduce_only, None);
+    }
+
+    #[rstest]
+    fn test_order_request_coerces_market_time_in_force() {
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument_id())
+            .quantity(Quantity::from("1.0"))
+            .time_in_force(TimeInForce::Gtc)
+            .build();
+
+        let request = OrderRequest::from_order(&order).unwrap();
+
+        assert_eq!(request.time_in_force, TimeInForce::Ioc);
+    }
+
+    #[rstest]
+    #[case(OrderType::MarketToLimit)]
+    #[case(OrderType::TrailingStopMarket)]
+    fn test_order_request_rejects_unsupported_order_types(#[case] order_type: OrderType) {
+        let mut builder = OrderTestBuilder::new(order_type);
+        builder
+            .instrument_id(instrument_id())
+            .quantity(Quantity::from("1.0"));
+        if order_type == OrderType::TrailingStopMarket {
+            builder
+                .trigger_price(Price::from("49000.0"))
+                .trailing_offset(rust_decimal::Decimal::ONE);
+        }
+
+        let result = OrderRequest::from_order(&builder.build());
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_order_request_rejects_unsupported_time_in_force() {
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_id())
+            .quantity(Quantity::from("1.0"))
+            .price(Price::from("50000.0"))
+            .time_in_force(TimeInForce::Day)
+            .build();
+
+        let err = OrderRequest::from_order(&order).unwrap_err();
+
+        assert!(err.to_string().contains("UNSUPPORTED_TIME_IN_FORCE"));
+    }
+
+    #[rstest]
+    fn test_cancel_report_accepts_before_canceling() {
+        let mut tracked = TrackedOrder::from_order(&limit_order());
+        let report = order_status_report(OrderStatus::Canceled);
+
+        let events = order_events_from_report(
+            &factory(),
+            ClientOrderId::from("O-001"),
+            &mut tracked,
+            &report,
+        )
+        .unwrap();
+
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[0], OrderEventAny::Accepted(_)));
+        assert!(matches!(events[1], OrderEventAny::Canceled(_)));
+        assert!(tracked.accepted);
+    }
+
+    #[rstest]
+    fn test_replace_report_is_left_to_reconciliation() {
+        let mut tracked = TrackedOrder::from_order(&limit_order());
+        tracked.accepted = true;
+        let report = order_status_report(OrderStatus::PendingUpdate);
+
+        let events = order_events_from_report(
+            &factory(),
+            ClientOrderId::from("O-001"),
+            &mut tracked,
+            &report,
+        );
+
+        assert!(events.is_none());
+    }
+
+    #[rstest]
+    fn test_fills_accumulate_until_filled() {
+        let mut tracked = TrackedOrder::from_order(&limit_order());
+        tracked.accepted = true;
+        let client_order_id = ClientOrderId::from("O-001");
+
+        let events = order_events_from_fill(
+            &factory(),
+            client_order_id,
+            &mut tracked,
+            &fill_report("T-001", "0.5"),
+        );
+        assert_eq!(events.len(), 1);
+        assert!(!tracked.is_filled());
+
+        let events = order_events_from_fill(
+            &factory(),
+            client_order_id,
+            &mut tracked,
+            &fill_report("T-002", "1.5"),
+        );
+        match &events[0] {
+            OrderEventAny::Filled(filled) => {
+                assert_eq!(filled.last_qty, Quantity::from("1.5"));
+                assert_eq!(filled.commission, Some(Money::from("0.5 USDC")));
+            }
+            other => panic!("Expected filled event, was {other:?}"),
+        }
+        assert!(tracked.is_filled());
+    }
+
+    #[rstest]
+    fn test_duplicate_fill_is_ignored() {
+        let mut tracked = TrackedOrder::from_order(&limit_order());
+        let client_order_id = ClientOrderId::from("O-001");
+        let fill = fill_report("T-001", "0.5");
+
+        let events = order_events_from_fill(&factory(), client_order_id, &mut tracked, &fill);
+        assert_eq!(events.len(), 2);
+
+        let events = order_events_from_fill(&factory(), client_order_id, &mut tracked, &fill);
+        assert!(events.is_empty());
+        assert_eq!(tracked.filled_qty, Quantity::from("0.5"));
+    }
+
+    #[rstest]
+    fn test_drop_copy_forwards_untracked_reports() {
+        let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+        let handler = DropCopyHandler {
+            events: factory(),
+            orders: Arc::new(DashMap::new()),
+            sender,
+        };
+
+        handler.handle(ExecutionReport::Fill(Box::new(fill_report("T-001", "1.0"))));
+
+        assert!(matches!(
+            receiver.try_recv().unwrap(),
+            ExecutionEvent::Report(ExecutionReport::Fill(_))
+        ));
+    }
+
+    #[rstest]
+    fn test_drop_copy_untracks_closed_orders() {
+        let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+        let orders = Arc::new(DashMap::new());
+        orders.insert(
+            ClientOrderId::from("O-001"),
+            TrackedOrder::from_order(&limit_order()),
+        );
+        let handler = DropCopyHandler {
+            events: factory(),
+            orders: Arc::clone(&orders),
+            sender,
+        };
+
+        handler.handle(ExecutionReport::OrderStatus(Box::new(order_status_report(
+            OrderStatus::Expired,
+        ))));
+
+        assert!(matches!(
+            receiver.try_recv().unwrap(),
+            ExecutionEvent::Order(OrderEventAny::Accepted(_))
+        ));
+        assert!(matches!(
+            receiver.try_recv().unwrap(),
+            ExecutionEvent::Order(OrderEventAny::Expired(_))
+        ));
+        assert!(orders.is_empty());
+    }
+
+    #[rstest]
+    fn test_lookback_start() {
+        let ts_now = UnixNanos::from(1_735_689_600_000_000_000);
+
+        let start = lookback_start(ts_now, 60);
+
+        assert_eq!(start.timestamp(), 1_735_689_600 - 3_600);
+    }
+}
@@ -15,45 +15,47 @@
 
 //! FIX Client for the Coinbase International Drop Copy Endpoint.
 //!
-//! This implementation focuses specifically on processing execution reports
-//! via the FIX protocol, leveraging the existing `SocketClient` for TCP/TLS connectivity.
-//!
-//! # Warning
-//!
-//! **Not a full FIX engine**: This client supports only the Coinbase International Drop Copy
-//! endpoint and lacks general-purpose FIX functionality.
+//! This implementation focuses specifically on processing execution reports via the FIX
+//! protocol. The FIX 5.0 SP2 session (logon, heartbeats, sequencing and recovery) is managed
+//! by the generic [`FixClient`] from `nautilus_network`, with the venue specific logon
+//! signature applied by a logon hook.
 use std::{
-    sync::{
-        Arc,
-        atomic::{AtomicBool, AtomicUsize, Ordering},
-    },
+    sync::{Arc, Mutex},
     time::Duration,
 };
 
 use aws_lc_rs::hmac;
 use base64::prelude::*;
-use nautilus_common::logging::{log_task_started, log_task_stopped};
+use nautilus_common::messages::ExecutionReport;
 #[cfg(feature = "python")]
 use nautilus_core::python::IntoPyObjectNautilusExt;
-use nautilus_core::{env::get_or_env_var, time::get_atomic_clock_realtime};
+use nautilus_core::{MUTEX_POISONED, env::get_or_env_var, time::get_atomic_clock_realtime};
 use nautilus_model::identifiers::AccountId;
-use nautilus_network::socket::{SocketClient, SocketConfig, WriterCommand};
+use nautilus_network::{
+    fix::{
+        client::{FixClient, FixMessageHandler},
+        dictionary::FixVersion,
+        message::{FixMessage, SOH, msg_types, tags},
+        messages::FixExecType,
+        session::{FixLogonHook, FixSession, FixSessionConfig, FixSessionState},
+        store::MemoryMessageStore,
+    },
+    socket::SocketConfig,
+};
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
-use tokio::task::JoinHandle;
 use tokio_tungstenite::tungstenite::stream::Mode;
 
 use super::{
-    messages::{FIX_DELIMITER, FixMessage},
-    parse::convert_to_order_status_report,
-};
-use crate::{
-    common::consts::COINBASE_INTX,
-    fix::{
-        messages::{fix_exec_type, fix_message_type, fix_tag},
-        parse::convert_to_fill_report,
-    },
+    messages::{DEFAULT_FIX_ENDPOINT, DROP_COPY_TARGET_COMP_ID},
+    parse::{convert_to_fill_report, convert_to_order_status_report},
 };
+use crate::common::consts::COINBASE_INTX;
+
+const LOGON_TIMEOUT_SECS: u64 = 10;
+
+/// Callback invoked with each execution report received from the drop copy session.
+pub type FixReportHandler = Arc<dyn Fn(ExecutionReport) + Send + Sync>;
 
 #[cfg_attr(
     feature = "python",
@@ -68,14 +70,8 @@ pub struct CoinbaseIntxFixClient {
     portfolio_id: String,
     sender_comp_id: String,
     target_comp_id: String,
-    socket: Option<Arc<SocketClient>>,
-    connected: Arc<AtomicBool>,
-    logged_on: Arc<AtomicBool>,
-    seq_num: Arc<AtomicUsize>,
-    received_seq_num: Arc<AtomicUsize>,
     heartbeat_secs: u64,
-    processing_task: Option<Arc<JoinHandle<()>>>,
-    heartbeat_task: Option<Arc<JoinHandle<()>>>,
+    client: Arc<Mutex<Option<Arc<FixClient>>>>,
 }
 
 impl CoinbaseIntxFixClient {
@@ -91,13 +87,13 @@ impl CoinbaseIntxFixClient {
         api_passphrase: Option<String>,
         portfolio_id: Option<String>,
     ) -> anyhow::Result<Self> {
-        let endpoint = endpoint.unwrap_or("fix.international.coinbase.com:6130".to_string());
+        let endpoint = endpoint.unwrap_or(DEFAULT_FIX_ENDPOINT.to_string());
         let api_key = get_or_env_var(api_key, "COINBASE_INTX_API_KEY")?;
         let api_secret = get_or_env_var(api_secret, "COINBASE_INTX_API_SECRET")?;
         let api_passphrase = get_or_env_var(api_passphrase, "COINBASE_INTX_API_PASSPHRASE")?;
         let portfolio_id = get_or_env_var(portfolio_id, "COINBASE_INTX_PORTFOLIO_ID")?;
         let sender_comp_id = api_key.clone();
-        let target_comp_id = "CBINTLDC".to_string(); // Drop Copy endpoint
+        let target_comp_id = DROP_COPY_TARGET_COMP_ID.to_string();
 
         Ok(Self {
             endpoint,
@@ -107,14 +103,8 @@ impl CoinbaseIntxFixClient {
             portfolio_id,
             sender_comp_id,
             target_comp_id,
-            socket: None,
-            connected: Arc::new(AtomicBool::new(false)),
-            logged_on: Arc::new(AtomicBool::new(false)),
-            seq_num: Arc::new(AtomicUsize::new(1)),
-            received_seq_num: Arc::new(AtomicUsize::new(0)),
             heartbeat_secs: 10, // Default (probably no need to change)
-            processing_task: None,
-            heartbeat_task: None,
+            client: Arc::new(Mutex::new(None)),
         })
     }
 
@@ -158,23 +148,37 @@ impl CoinbaseIntxFixClient {
         self.target_comp_id.as_str()
     }
 
+    /// Returns the account ID for the portfolio being used by the client.
+    #[must_use]
+    pub fn account_id(&self) -> AccountId {
+        AccountId::new(format!("{COINBASE_INTX}-{}", self.portfolio_id))
+    }
+
     /// Checks if the client is connected.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the client mutex is poisoned.
     #[must_use]
     pub fn is_connected(&self) -> bool {
-        self.connected.load(Ordering::SeqCst)
+        self.fix_client()
+            .is_some_and(|client| client.state() != FixSessionState::Disconnected)
     }
 
     /// Checks if the client is logged on.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the client mutex is poisoned.
     #[must_use]
     pub fn is_logged_on(&self) -> bool {
-        self.logged_on.load(Ordering::SeqCst)
+        self.fix_client()
+            .is_some_and(|client| client.is_logged_on())
     }
 
     /// Connects to the Coinbase International FIX Drop Copy endpoint.
     ///
-    /// # Panics
-    ///
-    /// Panics if time calculation or unwrap logic inside fails during logon retry setup.
+    /// Order status and fill reports are passed to the given Python `handler`.
     ///
     /// # Errors
     ///
@@ -184,120 +188,70 @@ impl CoinbaseIntxFixClient {
         #[cfg(feature = "python")] handler: Py<PyAny>,
         #[cfg(not(feature = "python"))] _handler: (),
     ) -> anyhow::Result<()> {
-        let logged_on = self.logged_on.clone();
-        let seq_num = self.seq_num.clone();
-        let received_seq_num = self.received_seq_num.clone();
-        let account_id = AccountId::new(format!("{COINBASE_INTX}-{}", self.portfolio_id));
-
-        let handle_message = Arc::new(move |data: &[u8]| {
-            if let Ok(message) = FixMessage::parse(data) {
-                // Update received sequence number
-                if let Some(msg_seq) = message.msg_seq_num() {
-                    received_seq_num.store(msg_seq, Ordering::SeqCst);
-                }
-
-                // Process message based on type
-                if let Some(msg_type) = message.msg_type() {
-                    match msg_type {
-                        fix_message_type::LOGON => {
-                            tracing::info!("Logon successful");
-                            logged_on.store(true, Ordering::SeqCst);
-                        }
-                        fix_message_type::LOGOUT => {
-                            tracing::info!("Received logout");
-                            logged_on.store(false, Ordering::SeqCst);
-                        }
-                        fix_message_type::EXECUTION_REPORT => {
-                            if let Some(exec_type) = message.get_field(fix_tag::EXEC_TYPE) {
-                                if matches!(
-                                    exec_type,
-                                    fix_exec_type::REJECTED
-                                        | fix_exec_type::NEW
-                                        | fix_exec_type::PENDING_NEW
-                                ) {
-                                    // These order events are already handled by the client
-                                    tracing::debug!(
-                                        "Received execution report for EXEC_TYPE {exec_type} (not handling here)"
-                                    );
-                                } else if matches!(
-                                    exec_type,
-                                    fix_exec_type::CANCELED
-                                        | fix_exec_type::EXPIRED
-                                        | fix_exec_type::REPLACED
-                                ) {
-                                    let clock = get_atomic_clock_realtime(); // TODO: Optimize
-                                    let ts_init = clock.get_time_ns();
-                                    match convert_to_order_status_report(
-                                        &message, account_id, ts_init,
-                                    ) {
-                                        #[cfg(feature = "python")]
-                                        Ok(report) => Python::attach(|py| {
-                                            call_python(
-                                                py,
-                                                &handler,
-                                                report.into_py_any_unwrap(py),
-                                            );
-                                        }),
-                                        #[cfg(not(feature = "python"))]
-                                        Ok(_report) => {
-                                            tracing::debug!(
-                                                "Order status report handled (Python disabled)"
-                                            );
-                                        }
-                                        Err(e) => {
-                                            tracing::error!(
-                                                "Failed to parse FIX execution report: {e}"
-                                            );
-                                        }
-                                    }
-                                } else if exec_type == fix_exec_type::PARTIAL_FILL
-                                    || exec_type == fix_exec_type::FILL
-                                {
-                                    let clock = get_atomic_clock_realtime(); // TODO: Optimize
-                                    let ts_init = clock.get_time_ns();
-                                    match convert_to_fill_report(&message, account_id, ts_init) {
-                                        #[cfg(feature = "python")]
-                                        Ok(report) => Python::attach(|py| {
-                                            call_python(
-                                                py,
-                                                &handler,
-                                                report.into_py_any_unwrap(py),
-                                            );
-                                        }),
-                                        #[cfg(not(feature = "python"))]
-                                        Ok(_report) => {
-                                            tracing::debug!(
-                                                "Fill report handled (Python disabled)"
-                                            );
-                                        }
-                                        Err(e) => {
-                                            tracing::error!(
-                                                "Failed to parse FIX execution report: {e}"
-                                            );
-                                        }
-                                    }
-                                } else {
-                                    tracing::warn!("Unhandled EXEC_TYPE {exec_type}: {message:?}");
-                                }
-                            }
-                        }
-                        // These can be HEARTBEAT or TEST_REQUEST messages,
-                        // ideally we'd respond to these with a heartbeat
-                        // including tag 112 TestReqID.
-                        _ => tracing::trace!("Received unexpected {message:?}"),
+        #[cfg(feature = "python")]
+        let report_handler: FixReportHandler = Arc::new(move |report| {
+            Python::attach(|py| {
+                let py_obj = match report {
+                    ExecutionReport::OrderStatus(report) => (*report).into_py_any_unwrap(py),
+                    ExecutionReport::Fill(report) => (*report).into_py_any_unwrap(py),
+                    other => {
+                        tracing::debug!("Unexpected execution report variant {other}");
+                        return;
                     }
-                }
-            } else {
-                tracing::error!("Failed to parse FIX message");
-            }
+                };
+                call_python(py, &handler, py_obj);
+            });
+        });
+
+        #[cfg(not(feature = "python"))]
+        let report_handler: FixReportHandler = Arc::new(|report| {
+            tracing::debug!("Execution report {report} handled (Python disabled)");
         });
 
-        let config = SocketConfig {
+        self.connect_with_handler(report_handler).await
+    }
+
+    /// Connects to the Coinbase International FIX Drop Copy endpoint, passing order status
+    /// and fill reports to the given `handler`.
+    ///
+    /// The session logs on again (resetting sequence numbers) whenever the socket reconnects
+    /// or the session is ended by the venue.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API secret is invalid, or network connection or FIX logon fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the client mutex is poisoned.
+    pub async fn connect_with_handler(&mut self, handler: FixReportHandler) -> anyhow::Result<()> {
+        let secret = BASE64_STANDARD
+            .decode(&self.api_secret)
+            .map_err(|e| anyhow::anyhow!("Invalid base64 secret key: {e}"))?;
+
+        let mut config = FixSessionConfig::new(&self.sender_comp_id, &self.target_comp_id)
+            .with_version(FixVersion::Fix50Sp2);
+        config.heartbeat_interval_secs = self.heartbeat_secs;
+        config.reset_seq_num_on_logon = true;
+        config.logon_fields = vec![
+            (tags::USERNAME, self.api_key.clone()),
+            (tags::PASSWORD, self.api_passphrase.clone()),
+        ];
+
+        let hook = logon_hook(
+            hmac::Key::new(hmac::HMAC_SHA256, &secret),
+            format!("{}{}", self.api_key, self.target_comp_id),
+            self.api_passphrase.clone(),
+        );
+        let session =
+            FixSession::new(config, Box::new(MemoryMessageStore::new())).with_logon_hook(hook);
+
+        let socket_config = SocketConfig {
             url: self.endpoint.clone(),
             mode: Mode::Tls,
-            suffix: vec![FIX_DELIMITER],
-            message_handler: Some(handle_message),
-            heartbeat: None, // Using FIX heartbeats
+            suffix: vec![SOH],
+            message_handler: None, // Replaced by the FIX session handler
+            heartbeat: None,       // Using FIX heartbeats
             reconnect_timeout_ms: Some(10000),
             reconnect_delay_initial_ms: Some(5000),
             reconnect_delay_max_ms: Some(30000),
@@ -306,90 +260,25 @@ impl CoinbaseIntxFixClient {
             certs_dir: None,
         };
 
-        let socket = match SocketClient::connect(
-            config, None, // post_connection
-            None, // post_reconnection
-            None, // post_disconnection
+        tracing::info!("Logging on...");
+        let client = FixClient::connect(
+            socket_config,
+            session,
+            message_handler(self.account_id(), handler),
+            Duration::from_secs(LOGON_TIMEOUT_SECS),
         )
         .await
-        {
-            Ok(socket) => socket,
-            Err(e) => anyhow::bail!("Failed to connect to FIX endpoint: {e:?}"),
-        };
-
-        let writer_tx = socket.writer_tx.clone();
-
-        self.socket = Some(Arc::new(socket));
-
-        self.send_logon().await?;
-
-        // Create task to monitor connection and send logon after reconnect
-        let connected_clone = self.connected.clone();
-        let logged_on_clone = self.logged_on.clone();
-        let heartbeat_secs = self.heartbeat_secs;
-        let client_clone = self.clone();
-
-        self.processing_task = Some(Arc::new(tokio::spawn(async move {
-            log_task_started("maintain-fix-connection");
-
-            let mut last_logon_attempt = std::time::Instant::now()
-                .checked_sub(Duration::from_secs(10))
-                .unwrap();
-
-            loop {
-                tokio::time::sleep(Duration::from_millis(100)).await;
-
-                // Check if connected but not logged on
-                if connected_clone.load(Ordering::SeqCst) && !logged_on_clone.load(Ordering::SeqCst)
-                {
-                    // Rate limit logon attempts
-                    if last_logon_attempt.elapsed() > Duration::from_secs(10) {
-                        tracing::info!("Connected without logon");
-                        last_logon_attempt = std::time::Instant::now();
-
-                        if let Err(e) = client_clone.send_logon().await {
-                            tracing::error!("Failed to send logon: {e}");
-                        }
-                    }
-                }
-            }
-        })));
-
-        let logged_on_clone = self.logged_on.clone();
-        let sender_comp_id = self.sender_comp_id.clone();
-        let target_comp_id = self.target_comp_id.clone();
-
-        self.heartbeat_task = Some(Arc::new(tokio::spawn(async move {
-            log_task_started("heartbeat");
-            tracing::debug!("Heartbeat at {heartbeat_secs}s intervals");
-
-            let interval = Duration::from_secs(heartbeat_secs);
-
-            loop {
-                if logged_on_clone.load(Ordering::SeqCst) {
-                    // Create new heartbeat message
-                    let seq = seq_num.fetch_add(1, Ordering::SeqCst) + 1;
-                    let now = chrono::Utc::now();
-                    let msg =
-                        FixMessage::create_heartbeat(seq, &sender_comp_id, &target_comp_id, &now);
-
-                    if let Err(e) = writer_tx.send(WriterCommand::Send(msg.to_bytes().into())) {
-                        tracing::error!("Failed to send heartbeat: {e}");
-                        break;
-                    }
-
-                    tracing::trace!("Sent heartbeat");
-                } else {
-                    // No longer logged on
-                    tracing::debug!("No longer logged on, stopping heartbeat task");
-                    break;
-                }
-
-                tokio::time::sleep(interval).await;
-            }
-
-            log_task_stopped("heartbeat");
-        })));
+        .map_err(|e| anyhow::anyhow!("Failed to connect to FIX endpoint: {e}"))?;
+
+        let previous = self
+            .client
+            .lock()
+            .expect(MUTEX_POISONED)
+            .replace(Arc::new(client));
+        if let Some(previous) = previous {
+            tracing::warn!("Replacing existing FIX session");
+            previous.close().await;
+        }
 
         Ok(())
     }
@@ -399,126 +288,83 @@ impl CoinbaseIntxFixClient {
     /// # Errors
     ///
     /// Returns an error if logout or socket closure fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the client mutex is poisoned.
     pub async fn close(&mut self) -> anyhow::Result<()> {
-        // Send logout message if connected
-        if self.is_logged_on()
-            && let Err(e) = self.send_logout("Normal logout").await
-        {
-            tracing::warn!("Failed to send logout message: {e}");
+        let client = self.client.lock().expect(MUTEX_POISONED).take();
+        if let Some(client) = client {
+            client.close().await;
         }
 
-        // Close socket
-        if let Some(socket) = &self.socket {
-            socket.close().await;
-        }
-
-        // Cancel processing task
-        if let Some(task) = self.processing_task.take() {
-            task.abort();
-        }
-
-        // Cancel heartbeat task
-        if let Some(task) = self.heartbeat_task.take() {
-            task.abort();
-        }
-
-        self.connected.store(false, Ordering::SeqCst);
-        self.logged_on.store(false, Ordering::SeqCst);
-
         Ok(())
     }
 
-    /// Send a logon message
-    async fn send_logon(&self) -> anyhow::Result<()> {
-        if self.socket.is_none() {
-            anyhow::bail!("Socket not connected".to_string());
-        }
-
-        // Reset sequence number
-        self.seq_num.store(1, Ordering::SeqCst);
-
-        let now = chrono::Utc::now();
-        let timestamp = now.format("%Y%m%d-%H:%M:%S.%3f").to_string();
-        let passphrase = self.api_passphrase.clone();
-
-        let message = format!(
-            "{}{}{}{}",
-            timestamp, self.api_key, self.target_comp_id, passphrase
-        );
-
-        // Create signature
-        let decoded_secret = BASE64_STANDARD
-            .decode(&self.api_secret)
-            .map_err(|e| anyhow::anyhow!("Invalid base64 secret key: {e}"))?;
-
-        let key = hmac::Key::new(hmac::HMAC_SHA256, &decoded_secret);
-        let tag = hmac::sign(&key, message.as_bytes());
-        let encoded_signature = BASE64_STANDARD.encode(tag.as_ref());
-
-        let logon_msg = FixMessage::create_logon(
-            1, // Always use 1 for new logon with reset
-            &self.sender_comp_id,
-            &self.target_comp_id,
-            self.heartbeat_secs,
-            &self.api_key,
-            &passphrase,
-            &encoded_signature,
-            &now,
-        );
-
-        if let Some(socket) = &self.socket {
-            tracing::info!("Logging on...");
-
-            match socket.send_bytes(logon_msg.to_bytes()).await {
-                Ok(()) => tracing::debug!("Sent logon message"),
-                Err(e) => tracing::error!("Error on logon: {e}"),
-            }
-        } else {
-            anyhow::bail!("Socket not connected".to_string());
-        }
-
-        let start = std::time::Instant::now();
-        while !self.is_logged_on() {
-            tokio::time::sleep(Duration::from_millis(100)).await;
-
-            if start.elapsed() > Duration::from_secs(10) {
-                anyhow::bail!("Logon timeout".to_string());
-            }
-        }
-
-        self.logged_on.store(true, Ordering::SeqCst);
+    fn fix_client(&self) -> Option<Arc<FixClient>> {
+        self.client.lock().expect(MUTEX_POISONED).clone()
+    }
+}
 
+/// Returns the hook signing each `Logon` with the HMAC-SHA256 of
+/// `SendingTime + API key + TargetCompID + passphrase`, as `Text(58)`.
+fn logon_hook(key: hmac::Key, api_key_and_target: String, passphrase: String) -> FixLogonHook {
+    Arc::new(move |message: &mut FixMessage| {
+        let sending_time = message.get_required(tags::SENDING_TIME)?;
+        let prehash = format!("{sending_time}{api_key_and_target}{passphrase}");
+        let signature = hmac::sign(&key, prehash.as_bytes());
+        message.set(tags::TEXT, BASE64_STANDARD.encode(signature.as_ref()));
         Ok(())
-    }
+    })
+}
 
-    /// Sends a logout message.
-    async fn send_logout(&self, text: &str) -> anyhow::Result<()> {
-        if self.socket.is_none() {
-            anyhow::bail!("Socket not connected".to_string());
+fn message_handler(account_id: AccountId, handler: FixReportHandler) -> FixMessageHandler {
+    Arc::new(move |message: FixMessage| {
+        if message.msg_type() != msg_types::EXECUTION_REPORT {
+            tracing::trace!("Received unexpected {message}");
+            return;
         }
 
-        let seq_num = self.seq_num.fetch_add(1, Ordering::SeqCst);
-        let now = chrono::Utc::now();
-
-        let logout_msg = FixMessage::create_logout(
-            seq_num,
-            &self.sender_comp_id,
-            &self.target_comp_id,
-            Some(text),
-            &now,
-        );
+        let exec_type = match message.get_parsed::<FixExecType>(tags::EXEC_TYPE) {
+            Ok(Some(exec_type)) => exec_type,
+            Ok(None) => {
+                tracing::error!("Execution report missing ExecType(150): {message}");
+                return;
+            }
+            Err(e) => {
+                tracing::warn!("Unhandled execution report: {e}");
+                return;
+            }
+        };
 
-        if let Some(socket) = &self.socket {
-            match socket.send_bytes(logout_msg.to_bytes()).await {
-                Ok(()) => tracing::debug!("Sent logout message"),
-                Err(e) => tracing::error!("Error on logout: {e}"),
+        let ts_init = get_atomic_clock_realtime().get_time_ns();
+        let result = match exec_type {
+            // These order events are already handled by the client
+            FixExecType::New | FixExecType::PendingNew | FixExecType::Rejected => {
+                tracing::debug!(
+                    "Received execution report for ExecType {exec_type:?} (not handling here)"
+                );
+                return;
             }
-        } else {
-            anyhow::bail!("Socket not connected".to_string());
-        }
+            FixExecType::Canceled | FixExecType::Expired | FixExecType::Replaced => {
+                convert_to_order_status_report(&message, account_id, ts_init)
+                    .map(|report| ExecutionReport::OrderStatus(Box::new(report)))
+            }
+            exec_type if exec_type.is_trade() => {
+                convert_to_fill_report(&message, account_id, ts_init)
+                    .map(|report| ExecutionReport::Fill(Box::new(report)))
+            }
+            _ => {
+                tracing::warn!("Unhandled ExecType {exec_type:?}: {message}");
+                return;
+            }
+        };
 
-        Ok(())
-    }
+        match result {
+            Ok(report) => handler(report),
+            Err(e) => tracing::error!("Failed to parse FIX execution report: {e}"),
+        }
+    })
 }
 
 // Can't be moved to core because we don't want to depend on tracing there
@@ -528,3 +374,30 @@ pub fn call_python(py: Python, callback: &Py<PyAny>, py_obj: Py<PyAny>) {
         tracing::error!("Error calling Python: {e}");
     }
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_logon_hook_signs_sending_time() {
+        let key = hmac::Key::new(hmac::HMAC_SHA256, b"secret");
+        let hook = logon_hook(key.clone(), "KEYCBINTLDC".to_string(), "pass".to_string());
+        let mut message = FixMessage::new(msg_types::LOGON);
+        message.set(tags::SENDING_TIME, "20250322-12:34:56.789");
+
+        hook(&mut message).unwrap();
+
+        let expected = hmac::sign(&key, b"20250322-12:34:56.789KEYCBINTLDCpass");
+        assert_eq!(
+            message.get(tags::TEXT),
+            Some(BASE64_STANDARD.encode(expected.as_ref()).as_str())
+        );
+        assert!(hook(&mut FixMessage::new(msg_types::LOGON)).is_err());
+    }
+}
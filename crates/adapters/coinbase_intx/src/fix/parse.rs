@@ -13,17 +13,16 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use chrono::{DateTime, Utc};
-use nautilus_core::{UnixNanos, time::get_atomic_clock_realtime};
+use nautilus_core::UnixNanos;
 use nautilus_model::{
     enums::{LiquiditySide, OrderSide, OrderStatus, OrderType, TimeInForce, TriggerType},
     identifiers::{AccountId, ClientOrderId, InstrumentId, Symbol, TradeId, VenueOrderId},
     reports::{FillReport, OrderStatusReport},
     types::{Currency, Money, Price, Quantity},
 };
+use nautilus_network::fix::message::{FixMessage, parse_timestamp, tags};
 use ustr::Ustr;
 
-use super::messages::{FixMessage, fix_tag};
 use crate::common::{consts::COINBASE_INTX_VENUE, parse::parse_instrument_id};
 
 // Reasonable default precision for now, as reports will be converted in the clients.
@@ -39,22 +38,20 @@ pub fn convert_to_order_status_report(
     account_id: AccountId,
     ts_init: UnixNanos,
 ) -> anyhow::Result<OrderStatusReport> {
-    let venue_order_id = VenueOrderId::new(message.get_field_checked(fix_tag::ORDER_ID)?);
-    let client_order_id = message
-        .get_field(fix_tag::CL_ORD_ID)
-        .map(ClientOrderId::new); // Can be missing
+    let venue_order_id = VenueOrderId::new(message.get_required(tags::ORDER_ID)?);
+    let client_order_id = message.get(tags::CL_ORD_ID).map(ClientOrderId::new); // Can be missing
 
-    let symbol = message.get_field_checked(fix_tag::SYMBOL)?;
+    let symbol = message.get_required(tags::SYMBOL)?;
     let instrument_id = parse_instrument_id(Ustr::from(symbol));
 
-    let side = message.get_field_checked(fix_tag::SIDE)?;
+    let side = message.get_required(tags::SIDE)?;
     let order_side = match side {
         "1" => OrderSide::Buy,
         "2" => OrderSide::Sell,
         _ => anyhow::bail!("Unknown order side: {side}"),
     };
 
-    let ord_type = message.get_field_checked(fix_tag::ORD_TYPE)?;
+    let ord_type = message.get_required(tags::ORD_TYPE)?;
     let order_type = match ord_type {
         "1" => OrderType::Market,
         "2" => OrderType::Limit,
@@ -63,7 +60,7 @@ pub fn convert_to_order_status_report(
         _ => anyhow::bail!("Unknown order type: {ord_type}"),
     };
 
-    let tif = message.get_field_checked(fix_tag::TIME_IN_FORCE)?;
+    let tif = message.get_required(tags::TIME_IN_FORCE)?;
     let time_in_force = match tif {
         "1" => TimeInForce::Gtc, // Good Till Cancel
         "3" => TimeInForce::Ioc, // Immediate or Cancel
@@ -72,7 +69,7 @@ pub fn convert_to_order_status_report(
         _ => anyhow::bail!("Unknown time in force: {tif}"),
     };
 
-    let status = message.get_field_checked(fix_tag::ORD_STATUS)?;
+    let status = message.get_required(tags::ORD_STATUS)?;
     let order_status = match status {
         "0" => OrderStatus::Accepted, // New
         "1" => OrderStatus::PartiallyFilled,
@@ -87,19 +84,17 @@ pub fn convert_to_order_status_report(
         _ => anyhow::bail!("Unknown order status: {status}"),
     };
 
-    let order_qty = message.get_field_checked(fix_tag::ORDER_QTY)?;
+    let order_qty = message.get_required(tags::ORDER_QTY)?;
     let quantity = Quantity::new(order_qty.parse::<f64>()?, DEFAULT_PRECISION);
 
-    let _leaves_qty = message.get_field_checked(fix_tag::LEAVES_QTY)?;
-    let cum_qty = message.get_field_checked(fix_tag::CUM_QTY)?;
+    let _leaves_qty = message.get_required(tags::LEAVES_QTY)?;
+    let cum_qty = message.get_required(tags::CUM_QTY)?;
     let filled_qty = Quantity::new(cum_qty.parse::<f64>()?, DEFAULT_PRECISION);
 
-    // Use TransactTime as the event time if provided
     // Use TransactTime as the event time if provided, error on invalid format
-    let ts_last = if let Some(transact_time) = message.get_field(fix_tag::TRANSACT_TIME) {
-        parse_fix_timestamp(transact_time)?
-    } else {
-        ts_init
+    let ts_last = match message.get(tags::TRANSACT_TIME) {
+        Some(transact_time) => parse_timestamp(transact_time)?,
+        None => ts_init,
     };
 
     // For ts_accepted, we can only estimate based on available data
@@ -124,20 +119,20 @@ pub fn convert_to_order_status_report(
         None, // Report ID will be generated
     );
 
-    if let Some(price_str) = message.get_field(fix_tag::PRICE)
+    if let Some(price_str) = message.get(tags::PRICE)
         && let Ok(price_val) = price_str.parse::<f64>()
     {
         report = report.with_price(Price::new(price_val, DEFAULT_PRECISION));
     }
 
-    if let Some(stop_px) = message.get_field(fix_tag::STOP_PX)
+    if let Some(stop_px) = message.get(tags::STOP_PX)
         && let Ok(stop_val) = stop_px.parse::<f64>()
     {
         report = report.with_trigger_price(Price::new(stop_val, DEFAULT_PRECISION));
         report = report.with_trigger_type(TriggerType::LastPrice);
     }
 
-    if let Some(avg_px) = message.get_field(fix_tag::AVG_PX)
+    if let Some(avg_px) = message.get(tags::AVG_PX)
         && let Ok(avg_val) = avg_px.parse::<f64>()
         && avg_val > 0.0
     {
@@ -145,7 +140,7 @@ pub fn convert_to_order_status_report(
     }
 
     // Execution instructions
-    if let Some(exec_inst) = message.get_field(fix_tag::EXEC_INST) {
+    if let Some(exec_inst) = message.get(tags::EXEC_INST) {
         // Parse space-delimited flags
         let flags: Vec<&str> = exec_inst.split(' ').collect();
         for flag in flags {
@@ -157,13 +152,13 @@ pub fn convert_to_order_status_report(
         }
     }
 
-    if let Some(expire_time) = message.get_field(fix_tag::EXPIRE_TIME)
-        && let Ok(dt) = parse_fix_timestamp(expire_time)
+    if let Some(expire_time) = message.get(tags::EXPIRE_TIME)
+        && let Ok(dt) = parse_timestamp(expire_time)
     {
         report = report.with_expire_time(dt);
     }
 
-    if let Some(text) = message.get_field(fix_tag::TEXT)
+    if let Some(text) = message.get(tags::TEXT)
         && !text.is_empty()
     {
         report = report.with_cancel_reason(text.to_string());
@@ -182,26 +177,26 @@ pub fn convert_to_fill_report(
     account_id: AccountId,
     ts_init: UnixNanos,
 ) -> anyhow::Result<FillReport> {
-    let client_order_id = message.get_field_checked(fix_tag::CL_ORD_ID)?;
-    let venue_order_id = message.get_field_checked(fix_tag::ORDER_ID)?;
-    let trade_id = message.get_field_checked(fix_tag::TRD_MATCH_ID)?;
-    let symbol = message.get_field_checked(fix_tag::SYMBOL)?;
-    let side_str = message.get_field_checked(fix_tag::SIDE)?;
-    let last_qty_str = message.get_field_checked(fix_tag::LAST_QTY)?;
-    let last_px_str = message.get_field_checked(fix_tag::LAST_PX)?;
-    let currency = message.get_field_checked(fix_tag::CURRENCY)?.parse()?;
-    let liquidity_indicator = message.get_field(fix_tag::LAST_LIQUIDITY_IND);
+    let client_order_id = message.get_required(tags::CL_ORD_ID)?;
+    let venue_order_id = message.get_required(tags::ORDER_ID)?;
+    let trade_id = message.get_required(tags::TRD_MATCH_ID)?;
+    let symbol = message.get_required(tags::SYMBOL)?;
+    let side_str = message.get_required(tags::SIDE)?;
+    let last_qty_str = message.get_required(tags::LAST_QTY)?;
+    let last_px_str = message.get_required(tags::LAST_PX)?;
+    let currency = message.get_required(tags::CURRENCY)?.parse()?;
+    let liquidity_indicator = message.get(tags::LAST_LIQUIDITY_IND);
 
     let mut commission = Money::new(0.0, currency);
 
-    if let Some(num_fees) = message.get_field(fix_tag::NO_MISC_FEES)
+    if let Some(num_fees) = message.get(tags::NO_MISC_FEES)
         && let Ok(n) = num_fees.parse::<usize>()
     {
         // For simplicity, we'll just use the first fee
         if n > 0
             && let (Some(fee_amt), Some(fee_curr)) = (
-                message.get_field(fix_tag::MISC_FEE_AMT),
-                message.get_field(fix_tag::MISC_FEE_CURR),
+                message.get(tags::MISC_FEE_AMT),
+                message.get(tags::MISC_FEE_CURR),
             )
             && let Ok(amt) = fee_amt.parse::<f64>()
         {
@@ -240,15 +235,10 @@ pub fn convert_to_fill_report(
     };
 
     // Parse transaction time if available
-    let ts_event = if let Some(transact_time) = message.get_field(fix_tag::TRANSACT_TIME) {
-        if let Ok(dt) = DateTime::parse_from_str(transact_time, "%Y%m%d-%H:%M:%S%.3f") {
-            UnixNanos::from(dt.with_timezone(&Utc))
-        } else {
-            ts_init
-        }
-    } else {
-        ts_init
-    };
+    let ts_event = message
+        .get(tags::TRANSACT_TIME)
+        .and_then(|transact_time| parse_timestamp(transact_time).ok())
+        .unwrap_or(ts_init);
 
     let instrument_id = InstrumentId::new(Symbol::from_str_unchecked(symbol), *COINBASE_INTX_VENUE);
 
@@ -265,15 +255,80 @@ pub fn convert_to_fill_report(
         Some(client_order_id),
         None, // Position ID not applicable
         ts_event,
-        get_atomic_clock_realtime().get_time_ns(),
+        ts_init,
         None, // UUID will be generated
     );
 
     Ok(report)
 }
 
-/// Parse a FIX timestamp in format YYYYMMDDd-HH:MM:SS.sss
-fn parse_fix_timestamp(timestamp: &str) -> Result<UnixNanos, anyhow::Error> {
-    let dt = DateTime::parse_from_str(timestamp, "%Y%m%d-%H:%M:%S%.3f")?;
-    Ok(UnixNanos::from(dt.with_timezone(&Utc)))
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_network::fix::message::msg_types;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn execution_report(exec_type: &str, ord_status: &str) -> FixMessage {
+        let mut message = FixMessage::new(msg_types::EXECUTION_REPORT);
+        message
+            .set(tags::ORDER_ID, "V-1")
+            .set(tags::CL_ORD_ID, "O-1")
+            .set(tags::EXEC_TYPE, exec_type)
+            .set(tags::ORD_STATUS, ord_status)
+            .set(tags::SYMBOL, "BTC-PERP")
+            .set(tags::SIDE, "2")
+            .set(tags::ORDER_QTY, "0.5")
+            .set(tags::ORD_TYPE, "2")
+            .set(tags::PRICE, "50000.5")
+            .set(tags::TIME_IN_FORCE, "1")
+            .set(tags::LEAVES_QTY, "0")
+            .set(tags::CUM_QTY, "0.5")
+            .set(tags::TRANSACT_TIME, "20250322-12:34:56.789");
+        message
+    }
+
+    #[rstest]
+    fn test_convert_to_order_status_report() {
+        let mut message = execution_report("4", "4");
+        message.set(tags::TEXT, "User canceled");
+        let account_id = AccountId::new("COINBASE_INTX-001");
+
+        let report =
+            convert_to_order_status_report(&message, account_id, UnixNanos::default()).unwrap();
+
+        assert_eq!(report.venue_order_id, VenueOrderId::new("V-1"));
+        assert_eq!(report.order_side, OrderSide::Sell);
+        assert_eq!(report.order_status, OrderStatus::Canceled);
+        assert_eq!(report.price, Some(Price::new(50_000.5, DEFAULT_PRECISION)));
+        assert_eq!(report.cancel_reason, Some("User canceled".to_string()));
+        assert_eq!(report.ts_last, UnixNanos::from(1_742_646_896_789_000_000));
+    }
+
+    #[rstest]
+    fn test_convert_to_fill_report() {
+        let mut message = execution_report("F", "2");
+        message
+            .set(tags::TRD_MATCH_ID, "T-1")
+            .set(tags::LAST_QTY, "0.5")
+            .set(tags::LAST_PX, "50000.5")
+            .set(tags::CURRENCY, "USDC")
+            .set(tags::LAST_LIQUIDITY_IND, "1")
+            .set(tags::NO_MISC_FEES, 1)
+            .set(tags::MISC_FEE_AMT, "0.25")
+            .set(tags::MISC_FEE_CURR, "USDC");
+        let ts_init = UnixNanos::from(1_742_646_900_000_000_000);
+
+        let report =
+            convert_to_fill_report(&message, AccountId::new("COINBASE_INTX-001"), ts_init).unwrap();
+
+        assert_eq!(report.trade_id, TradeId::new("T-1"));
+        assert_eq!(report.liquidity_side, LiquiditySide::Maker);
+        assert_eq!(report.commission, Money::new(0.25, Currency::USDC()));
+        assert_eq!(report.ts_event, UnixNanos::from(1_742_646_896_789_000_000));
+        assert_eq!(report.ts_init, ts_init);
+    }
 }
@@ -48,6 +48,8 @@
 
 pub mod common;
 pub mod config;
+pub mod data;
+pub mod execution;
 pub mod fix;
 pub mod http;
 pub mod websocket;
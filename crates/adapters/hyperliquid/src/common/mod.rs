@@ -27,10 +27,12 @@ pub use converters::{
     nautilus_time_in_force_to_hyperliquid, nautilus_to_hyperliquid_conditional,
 };
 pub use enums::{HyperliquidOrderStatus, hyperliquid_status_to_order_status};
+#[allow(deprecated)]
 pub use models::{
     ConversionError, HyperliquidAccountEvent, HyperliquidAccountState, HyperliquidBalance,
     HyperliquidDataConverter, HyperliquidInstrumentCache, HyperliquidInstrumentInfo,
-    HyperliquidPositionData, HyperliquidTradeKey, LatencyModel, parse_position_status_report,
+    HyperliquidPositionData, HyperliquidTradeKey, LatencyModel, StaticLatencyModel,
+    parse_position_status_report,
 };
 pub use parse::{
     deserialize_decimal_from_str, deserialize_optional_decimal_from_str, ensure_min_notional,
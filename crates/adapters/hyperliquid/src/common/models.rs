@@ -16,7 +16,8 @@
 use std::{collections::HashMap, fmt::Display, str::FromStr};
 
 use nautilus_core::{UUID4, UnixNanos};
-pub use nautilus_execution::models::latency::LatencyModel;
+#[allow(deprecated)]
+pub use nautilus_execution::models::latency::{LatencyModel, StaticLatencyModel};
 use nautilus_model::{
     data::{delta::OrderBookDelta, deltas::OrderBookDeltas, order::BookOrder},
     enums::{AccountType, BookAction, OrderSide, PositionSide, RecordFlag},
@@ -185,7 +186,7 @@ impl HyperliquidDataConverter {
 
     /// Create a latency model for order processing simulation
     ///
-    /// This uses the execution crate's LatencyModel for simulating order processing latencies.
+    /// This uses the execution crate's StaticLatencyModel for simulating order processing latencies.
     /// For real-time latency monitoring, use standard `tracing` macros.
     pub fn create_latency_model(
         &self,
@@ -193,8 +194,8 @@ impl HyperliquidDataConverter {
         insert_latency_ns: u64,
         update_latency_ns: u64,
         delete_latency_ns: u64,
-    ) -> LatencyModel {
-        LatencyModel::new(
+    ) -> StaticLatencyModel {
+        StaticLatencyModel::new(
             UnixNanos::from(base_latency_ns),
             UnixNanos::from(insert_latency_ns),
             UnixNanos::from(update_latency_ns),
@@ -203,7 +204,7 @@ impl HyperliquidDataConverter {
     }
 
     /// Create a default latency model for Hyperliquid (typical network latencies)
-    pub fn create_default_latency_model(&self) -> LatencyModel {
+    pub fn create_default_latency_model(&self) -> StaticLatencyModel {
         // Typical latencies for crypto exchanges (in nanoseconds)
         self.create_latency_model(
             50_000_000, // 50ms base latency
@@ -1254,7 +1255,7 @@ mod tests {
 
         // Test that Display trait works
         let display_str = format!("{}", default_model);
-        assert_eq!(display_str, "LatencyModel()");
+        assert_eq!(display_str, "StaticLatencyModel()");
     }
 
     #[rstest]
@@ -15,7 +15,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use super::machine::types::ReplayNormalizedRequestOptions;
+use super::machine::types::{ReplayNormalizedRequestOptions, StreamNormalizedRequestOptions};
 
 /// Provides a configuration for a Tarid Machine -> Nautilus data -> Parquet replay run.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -29,3 +29,160 @@ pub struct TardisReplayConfig {
     /// The Tardis Machine replay options.
     pub options: Vec<ReplayNormalizedRequestOptions>,
 }
+
+/// Provides a configuration for a Tardis Machine -> Nautilus live data client.
+///
+/// Exactly one of `replay_options` (historical replay) or `stream_options` (real-time stream)
+/// must be provided.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TardisDataClientConfig {
+    /// The Tardis Machine websocket url.
+    pub tardis_ws_url: Option<String>,
+    /// The Tardis API key for requesting instrument definitions.
+    pub api_key: Option<String>,
+    /// If symbols should be normalized with Nautilus conventions.
+    pub normalize_symbols: Option<bool>,
+    /// The Tardis Machine replay options.
+    #[serde(default)]
+    pub replay_options: Vec<ReplayNormalizedRequestOptions>,
+    /// The Tardis Machine stream options.
+    #[serde(default)]
+    pub stream_options: Vec<StreamNormalizedRequestOptions>,
+    /// The replay speed multiplier relative to the recorded timestamps (1.0 is real-time speed).
+    /// If `None` then replayed data is emitted as fast as it is received.
+    pub replay_speed: Option<f64>,
+}
+
+impl TardisDataClientConfig {
+    /// Returns `true` if the client replays historical data rather than streaming real-time data.
+    #[must_use]
+    pub fn is_replay(&self) -> bool {
+        !self.replay_options.is_empty()
+    }
+
+    /// Validates the configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if neither or both of the replay and stream options are provided,
+    /// or if `replay_speed` is not a positive finite number.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        match (
+            self.replay_options.is_empty(),
+            self.stream_options.is_empty(),
+        ) {
+            (true, true) => {
+                anyhow::bail!("Either `replay_options` or `stream_options` must be provided")
+            }
+            (false, false) => {
+                anyhow::bail!("Only one of `replay_options` or `stream_options` can be provided")
+            }
+            _ => {}
+        }
+
+        if let Some(speed) = self.replay_speed {
+            anyhow::ensure!(
+                speed.is_finite() && speed > 0.0,
+                "`replay_speed` must be a positive finite number, was {speed}"
+            );
+            if !self.is_replay() {
+                tracing::warn!("`replay_speed` has no effect when streaming real-time data");
+            }
+        }
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::enums::TardisExchange;
+
+    fn replay_options() -> ReplayNormalizedRequestOptions {
+        ReplayNormalizedRequestOptions {
+            exchange: TardisExchange::BinanceFutures,
+            symbols: Some(vec!["BTCUSDT".to_string()]),
+            from: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            to: NaiveDate::from_ymd_opt(2024, 1, 2).unwrap(),
+            data_types: vec!["trade".to_string()],
+            with_disconnect_messages: None,
+        }
+    }
+
+    fn stream_options() -> StreamNormalizedRequestOptions {
+        StreamNormalizedRequestOptions {
+            exchange: TardisExchange::BinanceFutures,
+            symbols: Some(vec!["BTCUSDT".to_string()]),
+            data_types: vec!["trade".to_string()],
+            with_disconnect_messages: None,
+            timeout_interval_ms: None,
+        }
+    }
+
+    #[rstest]
+    fn test_data_client_config_validate() {
+        let config = TardisDataClientConfig {
+            replay_options: vec![replay_options()],
+            replay_speed: Some(1.0),
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+        assert!(config.is_replay());
+
+        let config = TardisDataClientConfig {
+            stream_options: vec![stream_options()],
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+        assert!(!config.is_replay());
+    }
+
+    #[rstest]
+    #[case(vec![], vec![], None)]
+    #[case(vec![replay_options()], vec![stream_options()], None)]
+    #[case(vec![replay_options()], vec![], Some(0.0))]
+    #[case(vec![replay_options()], vec![], Some(f64::NAN))]
+    fn test_data_client_config_validate_invalid(
+        #[case] replay_options: Vec<ReplayNormalizedRequestOptions>,
+        #[case] stream_options: Vec<StreamNormalizedRequestOptions>,
+        #[case] replay_speed: Option<f64>,
+    ) {
+        let config = TardisDataClientConfig {
+            replay_options,
+            stream_options,
+            replay_speed,
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[rstest]
+    fn test_data_client_config_deserialize() {
+        let json = r#"{
+            "tardis_ws_url": "ws://localhost:8001",
+            "replay_options": [{
+                "exchange": "binance-futures",
+                "symbols": ["BTCUSDT"],
+                "from": "2024-01-01",
+                "to": "2024-01-02",
+                "data_types": ["trade", "book_change"]
+            }],
+            "replay_speed": 2.0
+        }"#;
+        let config: TardisDataClientConfig = serde_json::from_str(json).unwrap();
+
+        assert_eq!(config.tardis_ws_url.as_deref(), Some("ws://localhost:8001"));
+        assert_eq!(config.replay_options.len(), 1);
+        assert!(config.stream_options.is_empty());
+        assert_eq!(config.replay_speed, Some(2.0));
+        assert!(config.validate().is_ok());
+    }
+}
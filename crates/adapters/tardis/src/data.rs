@@ -0,0 +1,718 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Live data client for a [Tardis Machine Server](https://docs.tardis.dev/api/tardis-machine).
+//!
+//! The client replays historical exchange feeds (optionally paced at their recorded speed), or
+//! streams real-time feeds, through the live data stack. The feed contents are fixed by the
+//! configured request options, so subscriptions only register interest with the data engine.
+
+use std::{
+    sync::{
+        Arc, RwLock,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+use ahash::AHashMap;
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use futures_util::{StreamExt, future::join_all, stream::BoxStream};
+use nautilus_common::{
+    messages::{
+        DataEvent,
+        data::{
+            DataResponse, InstrumentResponse, InstrumentsResponse, RequestInstrument,
+            RequestInstruments, SubscribeBars, SubscribeBookDeltas, SubscribeBookDepth10,
+            SubscribeInstrument, SubscribeInstruments, SubscribeQuotes, SubscribeTrades,
+            UnsubscribeBars, UnsubscribeBookDeltas, UnsubscribeBookDepth10, UnsubscribeInstrument,
+            UnsubscribeInstruments, UnsubscribeQuotes, UnsubscribeTrades,
+        },
+    },
+    runner::get_data_event_sender,
+};
+use nautilus_core::{
+    MUTEX_POISONED, UnixNanos,
+    time::{AtomicTime, get_atomic_clock_realtime},
+};
+use nautilus_data::client::DataClient;
+use nautilus_model::{
+    data::{Data, HasTsInit},
+    identifiers::{ClientId, InstrumentId, Venue},
+    instruments::{Instrument, InstrumentAny},
+};
+use nautilus_network::backoff::ExponentialBackoff;
+use thousands::Separable;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+
+use crate::{
+    config::TardisDataClientConfig,
+    enums::TardisExchange,
+    http::{TardisHttpClient, models::TardisInstrumentInfo, parse::parse_instrument_any},
+    machine::{
+        TardisMachineClient,
+        types::{StreamNormalizedRequestOptions, TardisInstrumentMiniInfo},
+    },
+};
+
+type DataStream = BoxStream<'static, Result<Data, crate::machine::Error>>;
+
+const RECONNECT_DELAY_INITIAL: Duration = Duration::from_millis(500);
+const RECONNECT_DELAY_MAX: Duration = Duration::from_secs(30);
+const RECONNECT_BACKOFF_FACTOR: f64 = 2.0;
+const RECONNECT_JITTER_MS: u64 = 500;
+
+/// Re-opens a real-time stream which ended or failed, retrying with exponential backoff.
+#[derive(Debug)]
+struct StreamReconnector {
+    machine_client: TardisMachineClient,
+    options: Vec<StreamNormalizedRequestOptions>,
+    backoff: ExponentialBackoff,
+}
+
+impl StreamReconnector {
+    fn new(
+        machine_client: TardisMachineClient,
+        options: Vec<StreamNormalizedRequestOptions>,
+    ) -> anyhow::Result<Self> {
+        let backoff = ExponentialBackoff::new(
+            RECONNECT_DELAY_INITIAL,
+            RECONNECT_DELAY_MAX,
+            RECONNECT_BACKOFF_FACTOR,
+            RECONNECT_JITTER_MS,
+            true,
+        )?;
+
+        Ok(Self {
+            machine_client,
+            options,
+            backoff,
+        })
+    }
+
+    /// Returns a new stream once reconnected, or `None` if cancelled first.
+    async fn reconnect(&mut self, cancellation: &CancellationToken) -> Option<DataStream> {
+        loop {
+            let delay = self.backoff.next_duration();
+            tokio::select! {
+                () = tokio::time::sleep(delay) => {}
+                () = cancellation.cancelled() => return None,
+            }
+
+            tracing::info!("Reconnecting Tardis Machine stream");
+            match self.machine_client.stream_all(self.options.clone()).await {
+                Ok(stream) => {
+                    self.backoff.reset();
+                    tracing::info!("Tardis Machine stream reconnected");
+                    return Some(stream.boxed());
+                }
+                Err(e) => tracing::warn!("Failed to reconnect Tardis Machine stream: {e}"),
+            }
+        }
+    }
+}
+
+/// Paces replayed data so it is emitted at the speed it was originally recorded.
+#[derive(Debug)]
+struct ReplayPacer {
+    speed: f64,
+    origin: Option<(UnixNanos, Instant)>,
+}
+
+impl ReplayPacer {
+    const fn new(speed: f64) -> Self {
+        Self {
+            speed,
+            origin: None,
+        }
+    }
+
+    /// Returns how long to wait at `now` before emitting data initialized at `ts_init`.
+    ///
+    /// The first call anchors the recorded timeline to `now`.
+    fn delay(&mut self, ts_init: UnixNanos, now: Instant) -> Duration {
+        let (origin_ts, origin_instant) = *self.origin.get_or_insert((ts_init, now));
+        let elapsed_ns = ts_init.as_u64().saturating_sub(origin_ts.as_u64());
+        let offset = Duration::from_nanos((elapsed_ns as f64 / self.speed) as u64);
+        (origin_instant + offset).saturating_duration_since(now)
+    }
+}
+
+/// Live data client which sources market data from a Tardis Machine Server.
+#[derive(Debug)]
+pub struct TardisDataClient {
+    client_id: ClientId,
+    config: TardisDataClientConfig,
+    http_client: TardisHttpClient,
+    machine_client: Option<TardisMachineClient>,
+    is_connected: Arc<AtomicBool>,
+    cancellation_token: CancellationToken,
+    tasks: Vec<JoinHandle<()>>,
+    data_sender: tokio::sync::mpsc::UnboundedSender<DataEvent>,
+    instruments: Arc<RwLock<AHashMap<InstrumentId, InstrumentAny>>>,
+    clock: &'static AtomicTime,
+}
+
+impl TardisDataClient {
+    /// Creates a new [`TardisDataClient`] instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the config is invalid, or if the Tardis API key is missing from both
+    /// the config and the `TARDIS_API_KEY` environment variable.
+    pub fn new(client_id: ClientId, config: TardisDataClientConfig) -> anyhow::Result<Self> {
+        config.validate()?;
+
+        let http_client = TardisHttpClient::new(
+            config.api_key.as_deref(),
+            None,
+            None,
+            config.normalize_symbols.unwrap_or(true),
+        )
+        .context("failed to construct Tardis HTTP client")?;
+
+        Ok(Self {
+            client_id,
+            config,
+            http_client,
+            machine_client: None,
+            is_connected: Arc::new(AtomicBool::new(false)),
+            cancellation_token: CancellationToken::new(),
+            tasks: Vec::new(),
+            data_sender: get_data_event_sender(),
+            instruments: Arc::new(RwLock::new(AHashMap::new())),
+            clock: get_atomic_clock_realtime(),
+        })
+    }
+
+    /// Returns the instrument definitions effective time for each configured exchange.
+    ///
+    /// Replays use the earliest replay date, real-time streams use the current time.
+    fn effective_times(&self) -> AHashMap<TardisExchange, UnixNanos> {
+        let mut effective: AHashMap<TardisExchange, UnixNanos> = AHashMap::new();
+
+        if self.config.is_replay() {
+            for options in &self.config.replay_options {
+                // SAFETY: Midnight is always a valid time
+                let from = options.from.and_hms_opt(0, 0, 0).unwrap().and_utc();
+                let from_ns = datetime_to_unix_nanos(Some(from)).unwrap_or_default();
+                effective
+                    .entry(options.exchange)
+                    .and_modify(|ts| *ts = (*ts).min(from_ns))
+                    .or_insert(from_ns);
+            }
+        } else {
+            let now_ns = self.clock.get_time_ns();
+            for options in &self.config.stream_options {
+                effective.insert(options.exchange, now_ns);
+            }
+        }
+
+        effective
+    }
+
+    async fn bootstrap_instruments(
+        &self,
+        machine_client: &mut TardisMachineClient,
+    ) -> anyhow::Result<()> {
+        let normalize_symbols = self.config.normalize_symbols.unwrap_or(true);
+        let ts_init = self.clock.get_time_ns();
+
+        let futures = self
+            .effective_times()
+            .into_iter()
+            .map(|(exchange, effective)| async move {
+                tracing::info!("Requesting instruments for {exchange}");
+                let infos = self
+                    .http_client
+                    .instruments_info(exchange, None, None)
+                    .await
+                    .with_context(|| format!("failed to fetch instruments for {exchange}"))?;
+                anyhow::Ok((effective, infos))
+            });
+
+        let mut count = 0;
+        for result in join_all(futures).await {
+            let (effective, infos) = result?;
+            for (instrument, info) in
+                parse_instruments(infos, effective, ts_init, normalize_symbols)
+            {
+                machine_client.add_instrument_info(info);
+                self.instruments
+                    .write()
+                    .expect(MUTEX_POISONED)
+                    .insert(instrument.id(), instrument);
+                count += 1;
+            }
+        }
+
+        tracing::info!("Loaded {count} instruments");
+        Ok(())
+    }
+
+    async fn start_stream(
+        &self,
+        machine_client: &TardisMachineClient,
+    ) -> anyhow::Result<DataStream> {
+        let stream: DataStream = if self.config.is_replay() {
+            machine_client
+                .replay(self.config.replay_options.clone())
+                .await
+                .context("failed to start Tardis Machine replay")?
+                .boxed()
+        } else {
+            machine_client
+                .stream_all(self.config.stream_options.clone())
+                .await
+                .context("failed to start Tardis Machine stream")?
+                .boxed()
+        };
+
+        Ok(stream)
+    }
+
+    fn spawn_stream_task(
+        &mut self,
+        mut stream: DataStream,
+        machine_client: &TardisMachineClient,
+    ) -> anyhow::Result<()> {
+        // Replays end with the requested range, only real-time streams are re-opened
+        let mut reconnector = if self.config.is_replay() {
+            None
+        } else {
+            Some(StreamReconnector::new(
+                machine_client.clone(),
+                self.config.stream_options.clone(),
+            )?)
+        };
+        let data_sender = self.data_sender.clone();
+        let cancellation = self.cancellation_token.clone();
+        let is_connected = self.is_connected.clone();
+        let mut pacer = self
+            .config
+            .replay_speed
+            .filter(|_| self.config.is_replay())
+            .map(ReplayPacer::new);
+
+        let handle = tokio::spawn(async move {
+            let mut count: u64 = 0;
+
+            loop {
+                let maybe_data = tokio::select! {
+                    maybe_data = stream.next() => match maybe_data {
+                        Some(Ok(data)) => Some(data),
+                        Some(Err(e)) => {
+                            tracing::error!("Tardis Machine stream error: {e}");
+                            None
+                        }
+                        None => {
+                            tracing::info!(
+                                "Tardis Machine stream completed after {} messages",
+                                count.separate_with_commas()
+                            );
+                            None
+                        }
+                    },
+                    () = cancellation.cancelled() => {
+                        tracing::debug!("Tardis Machine stream task cancelled");
+                        break;
+                    }
+                };
+
+                let Some(data) = maybe_data else {
+                    if let Some(reconnector) = reconnector.as_mut()
+                        && let Some(reconnected) = reconnector.reconnect(&cancellation).await
+                    {
+                        stream = reconnected;
+                        continue;
+                    }
+                    break;
+                };
+
+                if let Some(pacer) = pacer.as_mut() {
+                    let delay = pacer.delay(data.ts_init(), Instant::now());
+                    if !delay.is_zero() {
+                        tokio::select! {
+                            () = tokio::time::sleep(delay) => {}
+                            () = cancellation.cancelled() => {
+                                tracing::debug!("Tardis Machine stream task cancelled");
+                                break;
+                            }
+                        }
+                    }
+                }
+
+                if let Err(e) = data_sender.send(DataEvent::Data(data)) {
+                    tracing::error!("Failed to emit data event: {e}");
+                }
+                count += 1;
+            }
+
+            is_connected.store(false, Ordering::Relaxed);
+        });
+
+        self.tasks.push(handle);
+        Ok(())
+    }
+
+    fn check_instrument(&self, instrument_id: &InstrumentId) {
+        if !self
+            .instruments
+            .read()
+            .expect(MUTEX_POISONED)
+            .contains_key(instrument_id)
+        {
+            tracing::warn!("{instrument_id} is not an instrument of the Tardis Machine feed");
+        }
+    }
+}
+
+/// Parses Tardis instrument definitions into instruments effective at `effective`, paired with
+/// the mini info required to parse their Tardis Machine messages.
+fn parse_instruments(
+    infos: Vec<TardisInstrumentInfo>,
+    effective: UnixNanos,
+    ts_init: UnixNanos,
+    normalize_symbols: bool,
+) -> Vec<(InstrumentAny, TardisInstrumentMiniInfo)> {
+    infos
+        .into_iter()
+        .filter_map(|info| {
+            let raw_symbol = info.id;
+            let exchange = info.exchange;
+            parse_instrument_any(info, Some(effective), Some(ts_init), normalize_symbols)
+                .pop()
+                .map(|instrument| {
+                    let mini_info = TardisInstrumentMiniInfo::new(
+                        instrument.id(),
+                        Some(raw_symbol),
+                        exchange,
+                        instrument.price_precision(),
+                        instrument.size_precision(),
+                    );
+                    (instrument, mini_info)
+                })
+        })
+        .collect()
+}
+
+fn datetime_to_unix_nanos(value: Option<DateTime<Utc>>) -> Option<UnixNanos> {
+    value
+        .and_then(|dt| dt.timestamp_nanos_opt())
+        .and_then(|nanos| u64::try_from(nanos).ok())
+        .map(UnixNanos::from)
+}
+
+#[async_trait::async_trait]
+impl DataClient for TardisDataClient {
+    fn client_id(&self) -> ClientId {
+        self.client_id
+    }
+
+    fn venue(&self) -> Option<Venue> {
+        // The feed can span multiple exchanges
+        None
+    }
+
+    fn start(&mut self) -> anyhow::Result<()> {
+        tracing::info!(
+            client_id = %self.client_id,
+            is_replay = self.config.is_replay(),
+            replay_speed = ?self.config.replay_speed,
+            "Starting Tardis data client"
+        );
+        Ok(())
+    }
+
+    fn stop(&mut self) -> anyhow::Result<()> {
+        tracing::info!("Stopping Tardis data client {id}", id = self.client_id);
+        self.cancellation_token.cancel();
+        if let Some(machine_client) = self.machine_client.as_mut() {
+            machine_client.close();
+        }
+        self.is_connected.store(false, Ordering::Relaxed);
+        Ok(())
+    }
+
+    fn reset(&mut self) -> anyhow::Result<()> {
+        tracing::debug!("Resetting Tardis data client {id}", id = self.client_id);
+        self.is_connected.store(false, Ordering::Relaxed);
+        self.cancellation_token = CancellationToken::new();
+        self.tasks.clear();
+        self.machine_client = None;
+        Ok(())
+    }
+
+    fn dispose(&mut self) -> anyhow::Result<()> {
+        tracing::debug!("Disposing Tardis data client {id}", id = self.client_id);
+        self.stop()
+    }
+
+    async fn connect(&mut self) -> anyhow::Result<()> {
+        if self.is_connected() {
+            return Ok(());
+        }
+
+        // A closed machine client cannot be reopened, so each connection uses a new one
+        let mut machine_client = TardisMachineClient::new(
+            self.config.tardis_ws_url.as_deref(),
+            self.config.normalize_symbols.unwrap_or(true),
+        )?;
+
+        // Instrument info must be added before streaming, the stream takes a snapshot
+        self.bootstrap_instruments(&mut machine_client).await?;
+
+        let stream = self.start_stream(&machine_client).await?;
+        self.spawn_stream_task(stream, &machine_client)?;
+        self.machine_client = Some(machine_client);
+
+        self.is_connected.store(true, Ordering::Relaxed);
+        tracing::info!("Tardis data client connected");
+        Ok(())
+    }
+
+    async fn disconnect(&mut self) -> anyhow::Result<()> {
+        if self.is_disconnected() && self.tasks.is_empty() {
+            return Ok(());
+        }
+
+        self.cancellation_token.cancel();
+
+        if let Some(mut machine_client) = self.machine_client.take() {
+            machine_client.close();
+        }
+
+        for handle in self.tasks.drain(..) {
+            if let Err(e) = handle.await {
+                tracing::error!("Error joining stream task: {e}");
+            }
+        }
+
+        self.cancellation_token = CancellationToken::new();
+        self.is_connected.store(false, Ordering::Relaxed);
+        tracing::info!("Tardis data client disconnected");
+        Ok(())
+    }
+
+    fn is_connected(&self) -> bool {
+        self.is_connected.load(Ordering::Relaxed)
+    }
+
+    fn is_disconnected(&self) -> bool {
+        !self.is_connected()
+    }
+
+    fn subscribe_instruments(&mut self, _cmd: &SubscribeInstruments) -> anyhow::Result<()> {
+        // Instrument definitions are loaded on connect
+        Ok(())
+    }
+
+    fn subscribe_instrument(&mut self, cmd: &SubscribeInstrument) -> anyhow::Result<()> {
+        self.check_instrument(&cmd.instrument_id);
+        Ok(())
+    }
+
+    fn subscribe_book_deltas(&mut self, cmd: &SubscribeBookDeltas) -> anyhow::Result<()> {
+        self.check_instrument(&cmd.instrument_id);
+        Ok(())
+    }
+
+    fn subscribe_book_depth10(&mut self, cmd: &SubscribeBookDepth10) -> anyhow::Result<()> {
+        self.check_instrument(&cmd.instrument_id);
+        Ok(())
+    }
+
+    fn subscribe_quotes(&mut self, cmd: &SubscribeQuotes) -> anyhow::Result<()> {
+        self.check_instrument(&cmd.instrument_id);
+        Ok(())
+    }
+
+    fn subscribe_trades(&mut self, cmd: &SubscribeTrades) -> anyhow::Result<()> {
+        self.check_instrument(&cmd.instrument_id);
+        Ok(())
+    }
+
+    fn subscribe_bars(&mut self, cmd: &SubscribeBars) -> anyhow::Result<()> {
+        self.check_instrument(&cmd.bar_type.instrument_id());
+        Ok(())
+    }
+
+    fn unsubscribe_instruments(&mut self, _cmd: &UnsubscribeInstruments) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn unsubscribe_instrument(&mut self, _cmd: &UnsubscribeInstrument) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn unsubscribe_book_deltas(&mut self, _cmd: &UnsubscribeBookDeltas) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn unsubscribe_book_depth10(&mut self, _cmd: &UnsubscribeBookDepth10) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn unsubscribe_quotes(&mut self, _cmd: &UnsubscribeQuotes) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn unsubscribe_trades(&mut self, _cmd: &UnsubscribeTrades) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn unsubscribe_bars(&mut self, _cmd: &UnsubscribeBars) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn request_instruments(&self, request: &RequestInstruments) -> anyhow::Result<()> {
+        let instruments = {
+            let guard = self.instruments.read().expect(MUTEX_POISONED);
+            guard
+                .values()
+                .filter(|instrument| {
+                    request
+                        .venue
+                        .is_none_or(|venue| instrument.id().venue == venue)
+                })
+                .cloned()
+                .collect::<Vec<_>>()
+        };
+
+        let venue = request
+            .venue
+            .or_else(|| instruments.first().map(|instrument| instrument.id().venue))
+            .context("no venue for instruments response")?;
+
+        let response = DataResponse::Instruments(InstrumentsResponse::new(
+            request.request_id,
+            request.client_id.unwrap_or(self.client_id),
+            venue,
+            instruments,
+            datetime_to_unix_nanos(request.start),
+            datetime_to_unix_nanos(request.end),
+            self.clock.get_time_ns(),
+            request.params.clone(),
+        ));
+
+        if let Err(e) = self.data_sender.send(DataEvent::Response(response)) {
+            tracing::error!("Failed to send instruments response: {e}");
+        }
+
+        Ok(())
+    }
+
+    fn request_instrument(&self, request: &RequestInstrument) -> anyhow::Result<()> {
+        let instrument = {
+            let guard = self.instruments.read().expect(MUTEX_POISONED);
+            guard
+                .get(&request.instrument_id)
+                .cloned()
+                .context("instrument not found in cache")?
+        };
+
+        let response = DataResponse::Instrument(Box::new(InstrumentResponse::new(
+            request.request_id,
+            request.client_id.unwrap_or(self.client_id),
+            instrument.id(),
+            instrument,
+            datetime_to_unix_nanos(request.start),
+            datetime_to_unix_nanos(request.end),
+            self.clock.get_time_ns(),
+            request.params.clone(),
+        )));
+
+        if let Err(e) = self.data_sender.send(DataEvent::Response(response)) {
+            tracing::error!("Failed to send instrument response: {e}");
+        }
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::tests::load_test_json;
+
+    #[rstest]
+    #[case(1.0, 1_000_000_000, Duration::from_secs(1))]
+    #[case(2.0, 1_000_000_000, Duration::from_millis(500))]
+    #[case(0.5, 1_000_000_000, Duration::from_secs(2))]
+    fn test_replay_pacer_delay(
+        #[case] speed: f64,
+        #[case] elapsed_ns: u64,
+        #[case] expected: Duration,
+    ) {
+        let mut pacer = ReplayPacer::new(speed);
+        let start = Instant::now();
+        let ts_start = UnixNanos::from(1_700_000_000_000_000_000);
+
+        assert_eq!(pacer.delay(ts_start, start), Duration::ZERO);
+        assert_eq!(pacer.delay(ts_start + elapsed_ns, start), expected,);
+    }
+
+    #[rstest]
+    fn test_replay_pacer_delay_when_behind_or_out_of_order() {
+        let mut pacer = ReplayPacer::new(1.0);
+        let start = Instant::now();
+        let ts_start = UnixNanos::from(1_700_000_000_000_000_000);
+        pacer.delay(ts_start, start);
+
+        // Processing has fallen behind the recorded timeline
+        let later = start + Duration::from_secs(5);
+        assert_eq!(pacer.delay(ts_start + 1_000_000_000, later), Duration::ZERO);
+
+        // Data initialized before the origin is emitted immediately
+        assert_eq!(
+            pacer.delay(UnixNanos::from(1_600_000_000_000_000_000), start),
+            Duration::ZERO
+        );
+    }
+
+    #[rstest]
+    #[case("2024-01-01T00:00:00Z", 2)] // Before the price increment change
+    #[case("2025-01-01T00:00:00Z", 0)] // After the price increment change
+    fn test_parse_instruments_effective(#[case] effective: &str, #[case] price_precision: u8) {
+        let json_data = load_test_json("instrument_spot.json");
+        let info: TardisInstrumentInfo = serde_json::from_str(&json_data).unwrap();
+        let effective = datetime_to_unix_nanos(Some(
+            DateTime::parse_from_rfc3339(effective)
+                .unwrap()
+                .with_timezone(&Utc),
+        ))
+        .unwrap();
+
+        let parsed = parse_instruments(vec![info], effective, UnixNanos::default(), false);
+
+        assert_eq!(parsed.len(), 1);
+        let (instrument, info) = &parsed[0];
+        assert_eq!(instrument.id(), InstrumentId::from("BTC_USDC.DERIBIT"));
+        assert_eq!(instrument.price_precision(), price_precision);
+        assert_eq!(info.instrument_id, instrument.id());
+        assert_eq!(info.raw_symbol.as_str(), "BTC_USDC");
+        assert_eq!(info.exchange, TardisExchange::Deribit);
+        assert_eq!(info.price_precision, price_precision);
+        assert_eq!(info.size_precision, 4);
+    }
+}
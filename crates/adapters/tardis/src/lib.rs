@@ -48,6 +48,7 @@
 
 pub mod config;
 pub mod csv;
+pub mod data;
 pub mod enums;
 pub mod http;
 pub mod machine;
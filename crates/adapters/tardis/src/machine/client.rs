@@ -103,7 +103,7 @@ impl TardisMachineClient {
     pub async fn replay(
         &self,
         options: Vec<ReplayNormalizedRequestOptions>,
-    ) -> Result<impl Stream<Item = Result<Data, Error>>, Error> {
+    ) -> Result<impl Stream<Item = Result<Data, Error>> + use<>, Error> {
         let stream = replay_normalized(&self.base_url, options, self.replay_signal.clone()).await?;
 
         // We use Box::pin to heap-allocate the stream and ensure it implements
@@ -124,7 +124,7 @@ impl TardisMachineClient {
         &self,
         instrument: TardisInstrumentMiniInfo,
         options: Vec<StreamNormalizedRequestOptions>,
-    ) -> Result<impl Stream<Item = Result<Data, Error>>, Error> {
+    ) -> Result<impl Stream<Item = Result<Data, Error>> + use<>, Error> {
         let stream = stream_normalized(&self.base_url, options, self.stream_signal.clone()).await?;
 
         // We use Box::pin to heap-allocate the stream and ensure it implements
@@ -135,6 +135,26 @@ impl TardisMachineClient {
             None,
         ))
     }
+
+    /// Connects to the Tardis Machine stream WebSocket for all added instruments and yields parsed `Data` items.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the WebSocket connection cannot be established.
+    pub async fn stream_all(
+        &self,
+        options: Vec<StreamNormalizedRequestOptions>,
+    ) -> Result<impl Stream<Item = Result<Data, Error>> + use<>, Error> {
+        let stream = stream_normalized(&self.base_url, options, self.stream_signal.clone()).await?;
+
+        // We use Box::pin to heap-allocate the stream and ensure it implements
+        // Unpin for safe async handling across lifetimes.
+        Ok(handle_ws_stream(
+            Box::pin(stream),
+            None,
+            Some(self.instruments.clone()),
+        ))
+    }
 }
 
 fn handle_ws_stream<S>(
@@ -78,7 +78,7 @@ pub async fn replay_normalized(
     base_url: &str,
     options: Vec<ReplayNormalizedRequestOptions>,
     signal: Arc<AtomicBool>,
-) -> Result<impl Stream<Item = Result<WsMessage>>> {
+) -> Result<impl Stream<Item = Result<WsMessage>> + use<>> {
     if options.is_empty() {
         return Err(Error::EmptyOptions);
     }
@@ -103,7 +103,7 @@ pub async fn stream_normalized(
     base_url: &str,
     options: Vec<StreamNormalizedRequestOptions>,
     signal: Arc<AtomicBool>,
-) -> Result<impl Stream<Item = Result<WsMessage>>> {
+) -> Result<impl Stream<Item = Result<WsMessage>> + use<>> {
     if options.is_empty() {
         return Err(Error::EmptyOptions);
     }
@@ -122,7 +122,7 @@ async fn stream_from_websocket(
     base_url: &str,
     url: String,
     signal: Arc<AtomicBool>,
-) -> Result<impl Stream<Item = Result<WsMessage>>> {
+) -> Result<impl Stream<Item = Result<WsMessage>> + use<>> {
     let (ws_stream, ws_resp) = connect_async(url).await?;
 
     handle_connection_response(ws_resp)?;
@@ -32,12 +32,32 @@ use crate::{
     Returns,
     statistic::PortfolioStatistic,
     statistics::{
-        expectancy::Expectancy, long_ratio::LongRatio, loser_avg::AvgLoser, loser_max::MaxLoser,
-        loser_min::MinLoser, profit_factor::ProfitFactor, returns_avg::ReturnsAverage,
-        returns_avg_loss::ReturnsAverageLoss, returns_avg_win::ReturnsAverageWin,
-        returns_volatility::ReturnsVolatility, risk_return_ratio::RiskReturnRatio,
-        sharpe_ratio::SharpeRatio, sortino_ratio::SortinoRatio, win_rate::WinRate,
-        winner_avg::AvgWinner, winner_max::MaxWinner, winner_min::MinWinner,
+        expectancy::Expectancy,
+        expected_shortfall::ExpectedShortfall,
+        holding_time_avg::AvgHoldingTime,
+        long_ratio::LongRatio,
+        loser_avg::AvgLoser,
+        loser_max::MaxLoser,
+        loser_min::MinLoser,
+        mae_avg::AvgMAE,
+        max_drawdown_duration::{MaxDrawdownDuration, drawdown_durations},
+        mfe_avg::AvgMFE,
+        omega_ratio::OmegaRatio,
+        profit_factor::ProfitFactor,
+        returns_avg::ReturnsAverage,
+        returns_avg_loss::ReturnsAverageLoss,
+        returns_avg_win::ReturnsAverageWin,
+        returns_volatility::ReturnsVolatility,
+        risk_return_ratio::RiskReturnRatio,
+        sharpe_ratio::SharpeRatio,
+        sortino_ratio::SortinoRatio,
+        tail_ratio::TailRatio,
+        ulcer_index::UlcerIndex,
+        value_at_risk::ValueAtRisk,
+        win_rate::WinRate,
+        winner_avg::AvgWinner,
+        winner_max::MaxWinner,
+        winner_min::MinWinner,
     },
 };
 
@@ -83,7 +103,18 @@ impl Default for PortfolioAnalyzer {
         analyzer.register_statistic(Arc::new(SortinoRatio::new(None)));
         analyzer.register_statistic(Arc::new(ProfitFactor {}));
         analyzer.register_statistic(Arc::new(RiskReturnRatio {}));
+        analyzer.register_statistic(Arc::new(OmegaRatio::new(None)));
+        analyzer.register_statistic(Arc::new(TailRatio::new()));
+        analyzer.register_statistic(Arc::new(ValueAtRisk::new(None, Some(false))));
+        analyzer.register_statistic(Arc::new(ValueAtRisk::new(None, Some(true))));
+        analyzer.register_statistic(Arc::new(ExpectedShortfall::new(None, Some(false))));
+        analyzer.register_statistic(Arc::new(ExpectedShortfall::new(None, Some(true))));
+        analyzer.register_statistic(Arc::new(UlcerIndex::new()));
+        analyzer.register_statistic(Arc::new(MaxDrawdownDuration::new()));
         analyzer.register_statistic(Arc::new(LongRatio::new(None)));
+        analyzer.register_statistic(Arc::new(AvgHoldingTime::new()));
+        analyzer.register_statistic(Arc::new(AvgMAE::new()));
+        analyzer.register_statistic(Arc::new(AvgMFE::new()));
         analyzer
     }
 }
@@ -145,6 +176,133 @@ impl PortfolioAnalyzer {
         &self.returns
     }
 
+    /// Returns the cumulative (compounded) returns at each timestamp, starting from 1.0.
+    ///
+    /// This is the equity curve of the portfolio normalized to its starting value.
+    #[must_use]
+    pub fn cumulative_returns(&self) -> Returns {
+        let mut cumulative = 1.0;
+        self.returns
+            .iter()
+            .map(|(&timestamp, &ret)| {
+                cumulative *= 1.0 + ret;
+                (timestamp, cumulative)
+            })
+            .collect()
+    }
+
+    /// Returns the equity curve (starting balance plus cumulative realized PnL) for a currency.
+    ///
+    /// The curve starts at the starting balance when the first position was opened, with a
+    /// point for each time positions were closed. If no `currency` is given the account must
+    /// have a single currency. Returns `None` if there is no starting balance for the currency.
+    #[must_use]
+    pub fn equity_curve(&self, currency: Option<&Currency>) -> Option<BTreeMap<UnixNanos, f64>> {
+        let currency = match currency {
+            Some(currency) => currency,
+            None => {
+                let mut currencies = self.account_balances.keys();
+                let currency = currencies.next()?;
+                if currencies.next().is_some() {
+                    return None;
+                }
+                currency
+            }
+        };
+        let starting = self.account_balances_starting.get(currency)?.as_f64();
+
+        let mut closed: Vec<(UnixNanos, f64)> = self
+            .positions
+            .iter()
+            .filter_map(|position| {
+                let pnl = position.realized_pnl?;
+                let ts_closed = position.ts_closed?;
+                (pnl.currency == *currency).then(|| (ts_closed, pnl.as_f64()))
+            })
+            .collect();
+        closed.sort_by_key(|(ts_closed, _)| *ts_closed);
+
+        let mut curve = BTreeMap::new();
+        if let Some(ts_opened) = self
+            .positions
+            .iter()
+            .filter(|position| position.ts_closed.is_some())
+            .map(|position| position.ts_opened)
+            .min()
+        {
+            curve.insert(ts_opened, starting);
+        }
+
+        let mut equity = starting;
+        for (ts_closed, pnl) in closed {
+            equity += pnl;
+            curve.insert(ts_closed, equity);
+        }
+
+        Some(curve)
+    }
+
+    /// Returns the drawdown from the running peak of the cumulative returns at each timestamp.
+    ///
+    /// Drawdowns are zero or negative fractions, consistent with [`MaxDrawdown`].
+    ///
+    /// [`MaxDrawdown`]: crate::statistics::max_drawdown::MaxDrawdown
+    #[must_use]
+    pub fn drawdown_series(&self) -> Returns {
+        let mut running_max = 1.0_f64;
+        self.cumulative_returns()
+            .into_iter()
+            .map(|(timestamp, cumulative)| {
+                running_max = running_max.max(cumulative);
+                (timestamp, cumulative / running_max - 1.0)
+            })
+            .collect()
+    }
+
+    /// Returns the time (nanoseconds) spent below the previous peak at each timestamp.
+    ///
+    /// The duration is zero whenever the cumulative returns are at a new peak, except for the
+    /// return which regains the previous peak, which reports the full length of the drawdown.
+    /// The longest of these durations is the [`MaxDrawdownDuration`].
+    #[must_use]
+    pub fn underwater_durations(&self) -> BTreeMap<UnixNanos, u64> {
+        drawdown_durations(&self.returns)
+    }
+
+    /// Calculates a returns-based statistic over a rolling window ending at each timestamp.
+    ///
+    /// Each window includes the returns within `window_ns` nanoseconds up to and including the
+    /// timestamp, so windows at the start of the series are only partially filled.
+    #[must_use]
+    pub fn rolling_statistic(&self, statistic: &Statistic, window_ns: u64) -> Returns {
+        let mut window = Returns::new();
+
+        self.returns
+            .iter()
+            .filter_map(|(&timestamp, &ret)| {
+                window.insert(timestamp, ret);
+                while let Some(entry) = window.first_entry()
+                    && entry.key().as_u64().saturating_add(window_ns) <= timestamp.as_u64()
+                {
+                    entry.remove();
+                }
+
+                statistic
+                    .calculate_from_returns(&window)
+                    .map(|value| (timestamp, value))
+            })
+            .collect()
+    }
+
+    /// Gets all return-based performance statistics over a rolling window.
+    #[must_use]
+    pub fn get_rolling_stats_returns(&self, window_ns: u64) -> HashMap<String, Returns> {
+        self.statistics
+            .iter()
+            .map(|(name, stat)| (name.clone(), self.rolling_statistic(stat, window_ns)))
+            .collect()
+    }
+
     /// Calculates statistics based on account and position data.
     pub fn calculate_statistics(&mut self, account: &dyn Account, positions: &[Position]) {
         self.account_balances_starting = account.starting_balances();
@@ -510,6 +668,9 @@ mod tests {
             avg_px_close: None,
             realized_return,
             realized_pnl: Some(Money::new(realized_pnl, currency)),
+            funding_pnl: None,
+            max_px: None,
+            min_px: None,
             trade_ids: Vec::new(),
             buy_qty: Quantity::default(),
             sell_qty: Quantity::default(),
@@ -780,6 +941,45 @@ mod tests {
         assert!(general_stats.contains_key("test_stat"));
     }
 
+    #[rstest]
+    fn test_default_statistics() {
+        let mut analyzer = PortfolioAnalyzer::default();
+        let currency = Currency::USD();
+        let positions = vec![create_mock_position(
+            "AUD/USD".to_owned(),
+            100.0,
+            0.1,
+            currency,
+        )];
+        analyzer.add_positions(&positions);
+        analyzer.add_return(UnixNanos::from(1), 0.01);
+
+        let return_stats = analyzer.get_performance_stats_returns();
+        for name in [
+            "Omega Ratio",
+            "Tail Ratio",
+            "Value at Risk (95%, historical)",
+            "Value at Risk (95%, parametric)",
+            "Expected Shortfall (95%, historical)",
+            "Expected Shortfall (95%, parametric)",
+            "Ulcer Index",
+            "Max Drawdown Duration (days)",
+        ] {
+            assert!(return_stats.contains_key(name), "missing {name}");
+        }
+
+        let general_stats = analyzer.get_performance_stats_general();
+        assert!(general_stats.contains_key("Long Ratio"));
+        for name in ["Avg Holding Time (mins)", "Avg MAE", "Avg MFE"] {
+            assert!(analyzer.statistic(name).is_some(), "missing {name}");
+        }
+
+        let pnl_stats = analyzer
+            .get_performance_stats_pnls(Some(&currency), None)
+            .unwrap();
+        assert!(pnl_stats.contains_key("Win Rate"));
+    }
+
     #[rstest]
     fn test_formatted_output() {
         let mut analyzer = PortfolioAnalyzer::new();
@@ -852,4 +1052,115 @@ mod tests {
         assert!(analyzer.realized_pnls.is_empty());
         assert!(analyzer.returns.is_empty());
     }
+
+    fn analyzer_with_returns(returns: &[(u64, f64)]) -> PortfolioAnalyzer {
+        let mut analyzer = PortfolioAnalyzer::new();
+        for &(timestamp, value) in returns {
+            analyzer.add_return(UnixNanos::from(timestamp), value);
+        }
+        analyzer
+    }
+
+    #[rstest]
+    fn test_cumulative_returns_and_drawdown_series() {
+        let analyzer = analyzer_with_returns(&[(1, 0.10), (2, -0.10), (3, 0.50), (4, -0.20)]);
+
+        let cumulative: Vec<f64> = analyzer.cumulative_returns().into_values().collect();
+        let expected = [1.1, 0.99, 1.485, 1.188];
+        for (value, expected) in cumulative.iter().zip(expected) {
+            assert!(approx_eq!(f64, *value, expected, epsilon = 1e-9));
+        }
+
+        let drawdowns: Vec<f64> = analyzer.drawdown_series().into_values().collect();
+        let expected = [0.0, -0.1, 0.0, -0.2];
+        for (value, expected) in drawdowns.iter().zip(expected) {
+            assert!(approx_eq!(f64, *value, expected, epsilon = 1e-9));
+        }
+    }
+
+    #[rstest]
+    fn test_underwater_durations() {
+        let analyzer =
+            analyzer_with_returns(&[(10, 0.10), (20, -0.05), (35, 0.01), (50, 0.10), (60, -0.01)]);
+
+        let durations: Vec<u64> = analyzer.underwater_durations().into_values().collect();
+
+        assert_eq!(durations, vec![0, 10, 25, 40, 10]);
+    }
+
+    #[rstest]
+    fn test_rolling_statistic() {
+        let analyzer = analyzer_with_returns(&[(10, 0.01), (20, 0.02), (30, 0.03), (45, 0.04)]);
+        let stat: Statistic = Arc::new(MockStatistic::new("sum"));
+
+        let rolling = analyzer.rolling_statistic(&stat, 20);
+
+        // Windows are (t - 20, t], so the return at t - 20 is excluded
+        let values: Vec<f64> = rolling.into_values().collect();
+        let expected = [0.01, 0.03, 0.05, 0.07];
+        for (value, expected) in values.iter().zip(expected) {
+            assert!(approx_eq!(f64, *value, expected, epsilon = 1e-9));
+        }
+    }
+
+    #[rstest]
+    fn test_get_rolling_stats_returns() {
+        let mut analyzer = analyzer_with_returns(&[(10, 0.01), (20, 0.02)]);
+        analyzer.register_statistic(Arc::new(MockStatistic::new("sum")));
+
+        let stats = analyzer.get_rolling_stats_returns(100);
+
+        assert_eq!(stats.len(), 1);
+        assert_eq!(stats["sum"].len(), 2);
+    }
+
+    #[rstest]
+    fn test_equity_curve() {
+        let mut analyzer = PortfolioAnalyzer::new();
+        let currency = Currency::USD();
+
+        let mut position1 = create_mock_position("P-1".to_owned(), 100.0, 0.1, currency);
+        position1.ts_opened = UnixNanos::from(5);
+        position1.ts_closed = Some(UnixNanos::from(20));
+        let mut position2 = create_mock_position("P-2".to_owned(), -30.0, -0.03, currency);
+        position2.ts_opened = UnixNanos::from(8);
+        position2.ts_closed = Some(UnixNanos::from(10));
+
+        let account = MockAccount {
+            starting_balances: HashMap::from([(currency, Money::new(1000.0, currency))]),
+            current_balances: HashMap::from([(currency, Money::new(1070.0, currency))]),
+        };
+        analyzer.calculate_statistics(&account, &[position1, position2]);
+
+        let curve: Vec<(u64, f64)> = analyzer
+            .equity_curve(Some(&currency))
+            .unwrap()
+            .into_iter()
+            .map(|(ts, equity)| (ts.as_u64(), equity))
+            .collect();
+
+        assert_eq!(curve, vec![(5, 1000.0), (10, 970.0), (20, 1070.0)]);
+        assert_eq!(analyzer.equity_curve(None).unwrap().len(), 3);
+        assert!(analyzer.equity_curve(Some(&Currency::EUR())).is_none());
+    }
+
+    #[rstest]
+    fn test_equity_curve_requires_currency_when_ambiguous() {
+        let mut analyzer = PortfolioAnalyzer::new();
+        let (usd, eur) = (Currency::USD(), Currency::EUR());
+        let account = MockAccount {
+            starting_balances: HashMap::from([
+                (usd, Money::new(1000.0, usd)),
+                (eur, Money::new(1000.0, eur)),
+            ]),
+            current_balances: HashMap::from([
+                (usd, Money::new(1000.0, usd)),
+                (eur, Money::new(1000.0, eur)),
+            ]),
+        };
+        analyzer.calculate_statistics(&account, &[]);
+
+        assert!(analyzer.equity_curve(None).is_none());
+        assert!(analyzer.equity_curve(Some(&eur)).is_some());
+    }
 }
@@ -13,7 +13,10 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+};
 
 use nautilus_core::{UnixNanos, python::to_pyvalue_err};
 use nautilus_model::{
@@ -298,6 +301,39 @@ impl PortfolioAnalyzer {
         Ok(dict.into())
     }
 
+    #[pyo3(name = "cumulative_returns")]
+    fn py_cumulative_returns(&self, py: Python) -> PyResult<Py<PyAny>> {
+        timeseries_to_pydict(py, &self.cumulative_returns())
+    }
+
+    #[pyo3(name = "equity_curve")]
+    #[pyo3(signature = (currency=None))]
+    fn py_equity_curve(&self, py: Python, currency: Option<&Currency>) -> PyResult<Py<PyAny>> {
+        match self.equity_curve(currency) {
+            Some(curve) => timeseries_to_pydict(py, &curve),
+            None => Ok(py.None()),
+        }
+    }
+
+    #[pyo3(name = "drawdown_series")]
+    fn py_drawdown_series(&self, py: Python) -> PyResult<Py<PyAny>> {
+        timeseries_to_pydict(py, &self.drawdown_series())
+    }
+
+    #[pyo3(name = "underwater_durations")]
+    fn py_underwater_durations(&self, py: Python) -> PyResult<Py<PyAny>> {
+        timeseries_to_pydict(py, &self.underwater_durations())
+    }
+
+    #[pyo3(name = "get_rolling_stats_returns")]
+    fn py_get_rolling_stats_returns(&self, py: Python, window_ns: u64) -> PyResult<Py<PyAny>> {
+        let dict = pyo3::types::PyDict::new(py);
+        for (name, series) in self.get_rolling_stats_returns(window_ns) {
+            dict.set_item(name, timeseries_to_pydict(py, &series)?)?;
+        }
+        Ok(dict.into())
+    }
+
     #[pyo3(name = "realized_pnls")]
     fn py_realized_pnls(&self, py: Python, currency: Option<&Currency>) -> PyResult<Py<PyAny>> {
         match self.realized_pnls(currency) {
@@ -353,3 +389,14 @@ impl PortfolioAnalyzer {
         self.get_stats_general_formatted()
     }
 }
+
+fn timeseries_to_pydict<T>(py: Python, series: &BTreeMap<UnixNanos, T>) -> PyResult<Py<PyAny>>
+where
+    T: for<'py> IntoPyObject<'py> + Copy,
+{
+    let dict = pyo3::types::PyDict::new(py);
+    for (timestamp, value) in series {
+        dict.set_item(timestamp.as_u64(), *value)?;
+    }
+    Ok(dict.into())
+}
@@ -34,7 +34,10 @@ pub fn analysis(_: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     // Statistics - Returns-based
     m.add_class::<crate::statistics::cagr::CAGR>()?;
     m.add_class::<crate::statistics::calmar_ratio::CalmarRatio>()?;
+    m.add_class::<crate::statistics::expected_shortfall::ExpectedShortfall>()?;
     m.add_class::<crate::statistics::max_drawdown::MaxDrawdown>()?;
+    m.add_class::<crate::statistics::max_drawdown_duration::MaxDrawdownDuration>()?;
+    m.add_class::<crate::statistics::omega_ratio::OmegaRatio>()?;
     m.add_class::<crate::statistics::profit_factor::ProfitFactor>()?;
     m.add_class::<crate::statistics::returns_avg::ReturnsAverage>()?;
     m.add_class::<crate::statistics::returns_avg_loss::ReturnsAverageLoss>()?;
@@ -43,6 +46,9 @@ pub fn analysis(_: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<crate::statistics::risk_return_ratio::RiskReturnRatio>()?;
     m.add_class::<crate::statistics::sharpe_ratio::SharpeRatio>()?;
     m.add_class::<crate::statistics::sortino_ratio::SortinoRatio>()?;
+    m.add_class::<crate::statistics::tail_ratio::TailRatio>()?;
+    m.add_class::<crate::statistics::ulcer_index::UlcerIndex>()?;
+    m.add_class::<crate::statistics::value_at_risk::ValueAtRisk>()?;
 
     // Statistics - PnL-based
     m.add_class::<crate::statistics::expectancy::Expectancy>()?;
@@ -55,7 +61,10 @@ pub fn analysis(_: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<crate::statistics::winner_min::MinWinner>()?;
 
     // Statistics - Position-based
+    m.add_class::<crate::statistics::holding_time_avg::AvgHoldingTime>()?;
     m.add_class::<crate::statistics::long_ratio::LongRatio>()?;
+    m.add_class::<crate::statistics::mae_avg::AvgMAE>()?;
+    m.add_class::<crate::statistics::mfe_avg::AvgMFE>()?;
 
     Ok(())
 }
@@ -0,0 +1,72 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::BTreeMap;
+
+use pyo3::prelude::*;
+
+use crate::{statistic::PortfolioStatistic, statistics::holding_time_avg::AvgHoldingTime};
+
+const NANOS_PER_MINUTE: f64 = 60_000_000_000.0;
+
+#[pymethods]
+impl AvgHoldingTime {
+    #[new]
+    fn py_new() -> Self {
+        Self::new()
+    }
+
+    fn __repr__(&self) -> String {
+        self.to_string()
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    fn py_name(&self) -> String {
+        self.name()
+    }
+
+    #[pyo3(name = "calculate_from_positions")]
+    fn py_calculate_from_positions(
+        &mut self,
+        py: Python,
+        positions: Vec<Py<PyAny>>,
+    ) -> PyResult<Option<f64>> {
+        // Extract the holding times from the closed Cython Position objects
+        let mut durations = Vec::new();
+        for position in &positions {
+            if position.getattr(py, "is_closed")?.extract::<bool>(py)? {
+                durations.push(position.getattr(py, "duration_ns")?.extract::<u64>(py)?);
+            }
+        }
+
+        if durations.is_empty() {
+            return Ok(None);
+        }
+
+        let mean_ns = durations.iter().sum::<u64>() as f64 / durations.len() as f64;
+        Ok(Some(mean_ns / NANOS_PER_MINUTE))
+    }
+
+    #[pyo3(name = "calculate_from_realized_pnls")]
+    fn py_calculate_from_realized_pnls(&mut self, _realized_pnls: Vec<f64>) -> Option<f64> {
+        None
+    }
+
+    #[pyo3(name = "calculate_from_returns")]
+    fn py_calculate_from_returns(&mut self, _returns: BTreeMap<u64, f64>) -> Option<f64> {
+        None
+    }
+}
@@ -107,4 +107,21 @@ pub trait PortfolioStatistic: Debug {
 
         variance.sqrt()
     }
+
+    /// Calculates the `q` quantile (0 to 1) of returns, linearly interpolating between values.
+    fn calculate_quantile(&self, returns: &Returns, q: f64) -> f64 {
+        if returns.is_empty() {
+            return f64::NAN;
+        }
+
+        let mut values: Vec<f64> = returns.values().copied().collect();
+        values.sort_by(f64::total_cmp);
+
+        let rank = q.clamp(0.0, 1.0) * (values.len() - 1) as f64;
+        let lower = rank.floor() as usize;
+        let upper = rank.ceil() as usize;
+        let weight = rank - lower as f64;
+
+        values[lower] + (values[upper] - values[lower]) * weight
+    }
 }
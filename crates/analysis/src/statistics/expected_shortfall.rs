@@ -0,0 +1,202 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Expected Shortfall (ES) statistic.
+
+use std::fmt::{self, Display};
+
+use nautilus_model::position::Position;
+
+use crate::{
+    Returns,
+    statistic::PortfolioStatistic,
+    statistics::value_at_risk::{
+        check_confidence, confidence_pct, method_name, normal_pdf, normal_ppf,
+    },
+};
+
+/// Calculates the Expected Shortfall (ES) of portfolio returns.
+///
+/// Also known as Conditional Value at Risk (CVaR), ES is the average daily return in the
+/// worst `1 - confidence` of cases, reported as a return (negative values are losses).
+///
+/// Two methods are supported:
+/// - Historical: the mean of the daily returns at or below the historical VaR.
+/// - Parametric: `mean - std * pdf(z) / (1 - confidence)` assuming normally distributed
+///   daily returns, where `z` is the standard normal quantile at `confidence`.
+///
+/// # References
+///
+/// - Acerbi, C., & Tasche, D. (2002). "Expected Shortfall: A Natural Coherent Alternative to
+///   Value at Risk". *Economic Notes*, 31(2), 379-388.
+#[repr(C)]
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.analysis")
+)]
+pub struct ExpectedShortfall {
+    confidence: f64,
+    parametric: bool,
+}
+
+impl ExpectedShortfall {
+    /// Creates a new [`ExpectedShortfall`] instance.
+    ///
+    /// The `confidence` defaults to 0.95, and the historical method is used unless
+    /// `parametric` is true.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `confidence` is not in the range (0, 1).
+    #[must_use]
+    pub fn new(confidence: Option<f64>, parametric: Option<bool>) -> Self {
+        let confidence = confidence.unwrap_or(0.95);
+        check_confidence(confidence);
+        Self {
+            confidence,
+            parametric: parametric.unwrap_or(false),
+        }
+    }
+}
+
+impl Display for ExpectedShortfall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Expected Shortfall ({}%, {})",
+            confidence_pct(self.confidence),
+            method_name(self.parametric),
+        )
+    }
+}
+
+impl PortfolioStatistic for ExpectedShortfall {
+    type Item = f64;
+
+    fn name(&self) -> String {
+        self.to_string()
+    }
+
+    fn calculate_from_returns(&self, raw_returns: &Returns) -> Option<Self::Item> {
+        if !self.check_valid_returns(raw_returns) {
+            return Some(f64::NAN);
+        }
+
+        let returns = self.downsample_to_daily_bins(raw_returns);
+        if self.parametric {
+            let mean = returns.values().sum::<f64>() / returns.len() as f64;
+            let std = self.calculate_std(&returns);
+            let tail = normal_pdf(normal_ppf(self.confidence)) / (1.0 - self.confidence);
+            Some(std.mul_add(-tail, mean))
+        } else {
+            let var = self.calculate_quantile(&returns, 1.0 - self.confidence);
+            let (sum, count) = returns
+                .values()
+                .filter(|&&r| r <= var)
+                .fold((0.0, 0_usize), |(sum, count), r| (sum + r, count + 1));
+            Some(sum / count as f64)
+        }
+    }
+
+    fn calculate_from_realized_pnls(&self, _realized_pnls: &[f64]) -> Option<Self::Item> {
+        None
+    }
+
+    fn calculate_from_positions(&self, _positions: &[Position]) -> Option<Self::Item> {
+        None
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use nautilus_core::{UnixNanos, approx_eq};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::statistics::value_at_risk::ValueAtRisk;
+
+    fn create_returns(values: Vec<f64>) -> BTreeMap<UnixNanos, f64> {
+        let one_day_in_nanos = 86_400_000_000_000;
+        let start_time = 1_600_000_000_000_000_000;
+
+        values
+            .into_iter()
+            .enumerate()
+            .map(|(i, v)| (UnixNanos::from(start_time + i as u64 * one_day_in_nanos), v))
+            .collect()
+    }
+
+    #[rstest]
+    fn test_name() {
+        assert_eq!(
+            ExpectedShortfall::new(None, None).name(),
+            "Expected Shortfall (95%, historical)"
+        );
+        assert_eq!(
+            ExpectedShortfall::new(Some(0.975), Some(true)).name(),
+            "Expected Shortfall (97.5%, parametric)"
+        );
+    }
+
+    #[rstest]
+    fn test_empty_returns() {
+        let result = ExpectedShortfall::new(None, None).calculate_from_returns(&BTreeMap::new());
+        assert!(result.unwrap().is_nan());
+    }
+
+    #[rstest]
+    fn test_historical_expected_shortfall() {
+        let es = ExpectedShortfall::new(Some(0.8), None);
+        // 20th percentile of -0.05..=0.04 is -0.032, so the tail is -0.05, -0.04
+        let returns = create_returns((0..10).map(|i| f64::from(i - 5) / 100.0).collect());
+        let result = es.calculate_from_returns(&returns).unwrap();
+        assert!(approx_eq!(f64, result, -0.045, epsilon = 1e-9));
+    }
+
+    #[rstest]
+    fn test_parametric_expected_shortfall() {
+        let es = ExpectedShortfall::new(Some(0.95), Some(true));
+        let returns = create_returns(vec![0.01, -0.01, 0.01, -0.01, 0.0]);
+        let result = es.calculate_from_returns(&returns).unwrap();
+        // Standard normal ES at 95% is 2.0627 standard deviations
+        assert!(approx_eq!(
+            f64,
+            result,
+            -2.062_712_807 * 0.01,
+            epsilon = 1e-8
+        ));
+    }
+
+    #[rstest]
+    #[case(false)]
+    #[case(true)]
+    fn test_expected_shortfall_beyond_var(#[case] parametric: bool) {
+        let returns = create_returns(vec![0.02, -0.03, 0.01, -0.01, 0.005, -0.02, 0.015]);
+        let var = ValueAtRisk::new(None, Some(parametric))
+            .calculate_from_returns(&returns)
+            .unwrap();
+        let es = ExpectedShortfall::new(None, Some(parametric))
+            .calculate_from_returns(&returns)
+            .unwrap();
+        assert!(es <= var);
+    }
+}
@@ -0,0 +1,124 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Average holding time statistic.
+
+use std::fmt::{self, Display};
+
+use nautilus_model::position::Position;
+
+use crate::{Returns, statistic::PortfolioStatistic};
+
+const NANOS_PER_MINUTE: f64 = 60_000_000_000.0;
+
+/// Calculates the average holding time of closed positions in minutes.
+///
+/// The holding time of a position is the duration from when it was opened until it was
+/// closed. Open positions are excluded.
+#[repr(C)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.analysis")
+)]
+pub struct AvgHoldingTime {}
+
+impl AvgHoldingTime {
+    /// Creates a new [`AvgHoldingTime`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Display for AvgHoldingTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Avg Holding Time (mins)")
+    }
+}
+
+impl PortfolioStatistic for AvgHoldingTime {
+    type Item = f64;
+
+    fn name(&self) -> String {
+        self.to_string()
+    }
+
+    fn calculate_from_positions(&self, positions: &[Position]) -> Option<Self::Item> {
+        let durations: Vec<u64> = positions
+            .iter()
+            .filter(|p| p.is_closed())
+            .map(|p| p.duration_ns)
+            .collect();
+
+        if durations.is_empty() {
+            return None;
+        }
+
+        let mean_ns = durations.iter().sum::<u64>() as f64 / durations.len() as f64;
+        Some(mean_ns / NANOS_PER_MINUTE)
+    }
+
+    fn calculate_from_returns(&self, _returns: &Returns) -> Option<Self::Item> {
+        None
+    }
+
+    fn calculate_from_realized_pnls(&self, _realized_pnls: &[f64]) -> Option<Self::Item> {
+        None
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use nautilus_core::UnixNanos;
+    use nautilus_model::{enums::PositionSide, stubs::stub_position_long};
+    use rstest::rstest;
+
+    use super::*;
+
+    fn closed_position(position: &Position, duration_ns: u64) -> Position {
+        let mut position = position.clone();
+        position.side = PositionSide::Flat;
+        position.ts_closed = Some(UnixNanos::from(duration_ns));
+        position.duration_ns = duration_ns;
+        position
+    }
+
+    #[rstest]
+    fn test_name() {
+        assert_eq!(AvgHoldingTime::new().name(), "Avg Holding Time (mins)");
+    }
+
+    #[rstest]
+    fn test_no_closed_positions(stub_position_long: Position) {
+        let result = AvgHoldingTime::new().calculate_from_positions(&[stub_position_long]);
+        assert!(result.is_none());
+    }
+
+    #[rstest]
+    fn test_avg_holding_time(stub_position_long: Position) {
+        let positions = vec![
+            closed_position(&stub_position_long, 60_000_000_000),
+            closed_position(&stub_position_long, 180_000_000_000),
+            stub_position_long, // Open positions are excluded
+        ];
+        let result = AvgHoldingTime::new().calculate_from_positions(&positions);
+        assert_eq!(result, Some(2.0));
+    }
+}
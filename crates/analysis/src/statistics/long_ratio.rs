@@ -128,6 +128,9 @@ mod tests {
             avg_px_close: None,
             realized_return: 0.0,
             realized_pnl: None,
+            funding_pnl: None,
+            max_px: None,
+            min_px: None,
             trade_ids: Vec::new(),
             buy_qty: Quantity::default(),
             sell_qty: Quantity::default(),
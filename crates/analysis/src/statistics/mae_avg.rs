@@ -0,0 +1,128 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Average maximum adverse excursion statistic.
+
+use std::fmt::{self, Display};
+
+use nautilus_model::position::Position;
+
+use crate::{Returns, statistic::PortfolioStatistic};
+
+/// Calculates the average maximum adverse excursion (MAE) of closed positions.
+///
+/// The excursion of each position is a fraction of its average open price, tracked from the
+/// fill and market prices observed while the position was open, so the value is zero or negative.
+/// Positions without observed prices are excluded.
+#[repr(C)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.analysis")
+)]
+pub struct AvgMAE {}
+
+impl AvgMAE {
+    /// Creates a new [`AvgMAE`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Display for AvgMAE {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Avg MAE")
+    }
+}
+
+impl PortfolioStatistic for AvgMAE {
+    type Item = f64;
+
+    fn name(&self) -> String {
+        self.to_string()
+    }
+
+    fn calculate_from_positions(&self, positions: &[Position]) -> Option<Self::Item> {
+        let excursions: Vec<f64> = positions
+            .iter()
+            .filter(|p| p.is_closed())
+            .filter_map(Position::max_adverse_excursion)
+            .collect();
+
+        if excursions.is_empty() {
+            return None;
+        }
+
+        Some(excursions.iter().sum::<f64>() / excursions.len() as f64)
+    }
+
+    fn calculate_from_returns(&self, _returns: &Returns) -> Option<Self::Item> {
+        None
+    }
+
+    fn calculate_from_realized_pnls(&self, _realized_pnls: &[f64]) -> Option<Self::Item> {
+        None
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use nautilus_core::{UnixNanos, approx_eq};
+    use nautilus_model::{enums::PositionSide, stubs::stub_position_long, types::Price};
+    use rstest::rstest;
+
+    use super::*;
+
+    fn closed_position(position: &Position, price: &str) -> Position {
+        let mut position = position.clone();
+        position.update_excursions(Price::from(price));
+        position.side = PositionSide::Flat;
+        position.ts_closed = Some(UnixNanos::from(1));
+        position
+    }
+
+    #[rstest]
+    fn test_name() {
+        assert_eq!(AvgMAE::new().name(), "Avg MAE");
+    }
+
+    #[rstest]
+    fn test_no_closed_positions(stub_position_long: Position) {
+        let result = AvgMAE::new().calculate_from_positions(&[stub_position_long]);
+        assert!(result.is_none());
+    }
+
+    #[rstest]
+    fn test_avg_mae(stub_position_long: Position) {
+        // Opened at 1.0002
+        let positions = vec![
+            closed_position(&stub_position_long, "0.9902"),
+            closed_position(&stub_position_long, "0.9702"),
+            closed_position(&stub_position_long, "1.0102"),
+        ];
+        let result = AvgMAE::new().calculate_from_positions(&positions).unwrap();
+        assert!(approx_eq!(
+            f64,
+            result,
+            -0.04 / 3.0 / 1.0002,
+            epsilon = 1e-9
+        ));
+    }
+}
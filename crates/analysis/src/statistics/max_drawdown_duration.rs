@@ -0,0 +1,179 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Maximum Drawdown Duration statistic.
+
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+};
+
+use nautilus_core::UnixNanos;
+use nautilus_model::position::Position;
+
+use crate::{Returns, statistic::PortfolioStatistic};
+
+const NANOS_PER_DAY: f64 = 86_400_000_000_000.0;
+
+/// Calculates the Maximum Drawdown Duration for returns.
+///
+/// The duration is the longest period in days from a peak of the cumulative returns until
+/// the peak is regained. A drawdown which has not recovered is measured up to the last return.
+#[repr(C)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.analysis")
+)]
+pub struct MaxDrawdownDuration {}
+
+impl MaxDrawdownDuration {
+    /// Creates a new [`MaxDrawdownDuration`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Display for MaxDrawdownDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Max Drawdown Duration (days)")
+    }
+}
+
+impl PortfolioStatistic for MaxDrawdownDuration {
+    type Item = f64;
+
+    fn name(&self) -> String {
+        self.to_string()
+    }
+
+    fn calculate_from_returns(&self, raw_returns: &Returns) -> Option<Self::Item> {
+        if !self.check_valid_returns(raw_returns) {
+            return Some(f64::NAN);
+        }
+
+        let returns = self.downsample_to_daily_bins(raw_returns);
+        let max_duration = drawdown_durations(&returns)
+            .into_values()
+            .max()
+            .unwrap_or(0);
+        Some(max_duration as f64 / NANOS_PER_DAY)
+    }
+
+    fn calculate_from_realized_pnls(&self, _realized_pnls: &[f64]) -> Option<Self::Item> {
+        None
+    }
+
+    fn calculate_from_positions(&self, _positions: &[Position]) -> Option<Self::Item> {
+        None
+    }
+}
+
+/// Returns the time (nanoseconds) since the running peak of the cumulative returns at each timestamp.
+///
+/// The duration is zero at a new peak, except for the return which regains a previous peak,
+/// where it is the full length of the drawdown that return ends.
+#[must_use]
+pub fn drawdown_durations(returns: &Returns) -> BTreeMap<UnixNanos, u64> {
+    let mut cumulative = 1.0;
+    let mut running_max = 1.0;
+    let mut ts_peak: Option<UnixNanos> = None;
+    let mut in_drawdown = false;
+
+    returns
+        .iter()
+        .map(|(&timestamp, &ret)| {
+            cumulative *= 1.0 + ret;
+
+            let peak = ts_peak.get_or_insert(timestamp);
+            let underwater = cumulative < running_max;
+
+            // The duration includes the return which regains the peak
+            let duration = if underwater || in_drawdown {
+                timestamp.as_u64() - peak.as_u64()
+            } else {
+                0
+            };
+
+            if !underwater {
+                running_max = cumulative;
+                *peak = timestamp;
+            }
+            in_drawdown = underwater;
+
+            (timestamp, duration)
+        })
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    fn create_returns(values: Vec<f64>) -> BTreeMap<UnixNanos, f64> {
+        let one_day_in_nanos = 86_400_000_000_000;
+
+        values
+            .into_iter()
+            .enumerate()
+            .map(|(i, v)| (UnixNanos::from(i as u64 * one_day_in_nanos), v))
+            .collect()
+    }
+
+    #[rstest]
+    fn test_name() {
+        assert_eq!(
+            MaxDrawdownDuration::new().name(),
+            "Max Drawdown Duration (days)"
+        );
+    }
+
+    #[rstest]
+    fn test_empty_returns() {
+        let result = MaxDrawdownDuration::new().calculate_from_returns(&BTreeMap::new());
+        assert!(result.unwrap().is_nan());
+    }
+
+    #[rstest]
+    fn test_no_drawdown() {
+        let returns = create_returns(vec![0.01, 0.02, 0.0, 0.01]);
+        let result = MaxDrawdownDuration::new().calculate_from_returns(&returns);
+        assert_eq!(result, Some(0.0));
+    }
+
+    #[rstest]
+    fn test_recovered_drawdown() {
+        // Peak on day 1, underwater days 2-4, recovered on day 5, then a 1 day drawdown
+        let returns = create_returns(vec![0.0, 0.1, -0.1, 0.0, 0.05, 0.2, 0.0, -0.01]);
+        let result = MaxDrawdownDuration::new().calculate_from_returns(&returns);
+        assert_eq!(result, Some(4.0));
+    }
+
+    #[rstest]
+    fn test_unrecovered_drawdown() {
+        let returns = create_returns(vec![0.1, -0.05, 0.01, 0.01]);
+        let result = MaxDrawdownDuration::new().calculate_from_returns(&returns);
+        assert_eq!(result, Some(3.0));
+    }
+}
@@ -0,0 +1,122 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Average maximum favorable excursion statistic.
+
+use std::fmt::{self, Display};
+
+use nautilus_model::position::Position;
+
+use crate::{Returns, statistic::PortfolioStatistic};
+
+/// Calculates the average maximum favorable excursion (MFE) of closed positions.
+///
+/// The excursion of each position is a fraction of its average open price, tracked from the
+/// fill and market prices observed while the position was open, so the value is zero or positive.
+/// Positions without observed prices are excluded.
+#[repr(C)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.analysis")
+)]
+pub struct AvgMFE {}
+
+impl AvgMFE {
+    /// Creates a new [`AvgMFE`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Display for AvgMFE {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Avg MFE")
+    }
+}
+
+impl PortfolioStatistic for AvgMFE {
+    type Item = f64;
+
+    fn name(&self) -> String {
+        self.to_string()
+    }
+
+    fn calculate_from_positions(&self, positions: &[Position]) -> Option<Self::Item> {
+        let excursions: Vec<f64> = positions
+            .iter()
+            .filter(|p| p.is_closed())
+            .filter_map(Position::max_favorable_excursion)
+            .collect();
+
+        if excursions.is_empty() {
+            return None;
+        }
+
+        Some(excursions.iter().sum::<f64>() / excursions.len() as f64)
+    }
+
+    fn calculate_from_returns(&self, _returns: &Returns) -> Option<Self::Item> {
+        None
+    }
+
+    fn calculate_from_realized_pnls(&self, _realized_pnls: &[f64]) -> Option<Self::Item> {
+        None
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use nautilus_core::{UnixNanos, approx_eq};
+    use nautilus_model::{enums::PositionSide, stubs::stub_position_short, types::Price};
+    use rstest::rstest;
+
+    use super::*;
+
+    fn closed_position(position: &Position, price: &str) -> Position {
+        let mut position = position.clone();
+        position.update_excursions(Price::from(price));
+        position.side = PositionSide::Flat;
+        position.ts_closed = Some(UnixNanos::from(1));
+        position
+    }
+
+    #[rstest]
+    fn test_name() {
+        assert_eq!(AvgMFE::new().name(), "Avg MFE");
+    }
+
+    #[rstest]
+    fn test_empty_positions() {
+        let result = AvgMFE::new().calculate_from_positions(&[]);
+        assert!(result.is_none());
+    }
+
+    #[rstest]
+    fn test_avg_mfe_short(stub_position_short: Position) {
+        // Opened short at 22000.0
+        let positions = vec![
+            closed_position(&stub_position_short, "21780.0"),
+            closed_position(&stub_position_short, "21340.0"),
+        ];
+        let result = AvgMFE::new().calculate_from_positions(&positions).unwrap();
+        assert!(approx_eq!(f64, result, 0.02, epsilon = 1e-9));
+    }
+}
@@ -18,11 +18,17 @@
 pub mod cagr;
 pub mod calmar_ratio;
 pub mod expectancy;
+pub mod expected_shortfall;
+pub mod holding_time_avg;
 pub mod long_ratio;
 pub mod loser_avg;
 pub mod loser_max;
 pub mod loser_min;
+pub mod mae_avg;
 pub mod max_drawdown;
+pub mod max_drawdown_duration;
+pub mod mfe_avg;
+pub mod omega_ratio;
 pub mod profit_factor;
 pub mod returns_avg;
 pub mod returns_avg_loss;
@@ -31,6 +37,9 @@ pub mod returns_volatility;
 pub mod risk_return_ratio;
 pub mod sharpe_ratio;
 pub mod sortino_ratio;
+pub mod tail_ratio;
+pub mod ulcer_index;
+pub mod value_at_risk;
 pub mod win_rate;
 pub mod winner_avg;
 pub mod winner_max;
@@ -0,0 +1,166 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Omega ratio statistic.
+
+use std::fmt::{self, Display};
+
+use nautilus_model::position::Position;
+
+use crate::{Returns, statistic::PortfolioStatistic};
+
+/// Calculates the Omega ratio for portfolio returns.
+///
+/// The Omega ratio is the probability weighted ratio of gains to losses relative to a
+/// threshold return, capturing all moments of the return distribution.
+///
+/// Formula: `sum(max(r - threshold, 0)) / sum(max(threshold - r, 0))` over daily returns.
+///
+/// # References
+///
+/// - Keating, C., & Shadwick, W. F. (2002). "A Universal Performance Measure".
+///   *Journal of Performance Measurement*, 6(3), 59-84.
+#[repr(C)]
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.analysis")
+)]
+pub struct OmegaRatio {
+    threshold: f64,
+}
+
+impl OmegaRatio {
+    /// Creates a new [`OmegaRatio`] instance.
+    ///
+    /// The `threshold` is the daily return separating gains from losses (defaults to zero).
+    #[must_use]
+    pub fn new(threshold: Option<f64>) -> Self {
+        Self {
+            threshold: threshold.unwrap_or(0.0),
+        }
+    }
+}
+
+impl Display for OmegaRatio {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.threshold == 0.0 {
+            write!(f, "Omega Ratio")
+        } else {
+            write!(f, "Omega Ratio ({})", self.threshold)
+        }
+    }
+}
+
+impl PortfolioStatistic for OmegaRatio {
+    type Item = f64;
+
+    fn name(&self) -> String {
+        self.to_string()
+    }
+
+    fn calculate_from_returns(&self, raw_returns: &Returns) -> Option<Self::Item> {
+        if !self.check_valid_returns(raw_returns) {
+            return Some(f64::NAN);
+        }
+
+        let returns = self.downsample_to_daily_bins(raw_returns);
+        let (gains, losses) = returns.values().fold((0.0, 0.0), |(gains, losses), &r| {
+            let excess = r - self.threshold;
+            if excess > 0.0 {
+                (gains + excess, losses)
+            } else {
+                (gains, losses - excess)
+            }
+        });
+
+        if losses < f64::EPSILON {
+            return Some(f64::NAN);
+        }
+
+        Some(gains / losses)
+    }
+
+    fn calculate_from_realized_pnls(&self, _realized_pnls: &[f64]) -> Option<Self::Item> {
+        None
+    }
+
+    fn calculate_from_positions(&self, _positions: &[Position]) -> Option<Self::Item> {
+        None
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use nautilus_core::{UnixNanos, approx_eq};
+    use rstest::rstest;
+
+    use super::*;
+
+    fn create_returns(values: Vec<f64>) -> BTreeMap<UnixNanos, f64> {
+        let one_day_in_nanos = 86_400_000_000_000;
+        let start_time = 1_600_000_000_000_000_000;
+
+        values
+            .into_iter()
+            .enumerate()
+            .map(|(i, v)| (UnixNanos::from(start_time + i as u64 * one_day_in_nanos), v))
+            .collect()
+    }
+
+    #[rstest]
+    fn test_name() {
+        assert_eq!(OmegaRatio::new(None).name(), "Omega Ratio");
+        assert_eq!(OmegaRatio::new(Some(0.01)).name(), "Omega Ratio (0.01)");
+    }
+
+    #[rstest]
+    fn test_empty_returns() {
+        let ratio = OmegaRatio::new(None);
+        let result = ratio.calculate_from_returns(&BTreeMap::new());
+        assert!(result.unwrap().is_nan());
+    }
+
+    #[rstest]
+    fn test_no_losses() {
+        let ratio = OmegaRatio::new(None);
+        let result = ratio.calculate_from_returns(&create_returns(vec![0.01, 0.02]));
+        assert!(result.unwrap().is_nan());
+    }
+
+    #[rstest]
+    fn test_valid_omega_ratio() {
+        let ratio = OmegaRatio::new(None);
+        // Gains: 0.02 + 0.03 = 0.05, losses: 0.01 + 0.015 = 0.025
+        let returns = create_returns(vec![0.02, -0.01, 0.03, -0.015]);
+        let result = ratio.calculate_from_returns(&returns).unwrap();
+        assert!(approx_eq!(f64, result, 2.0, epsilon = 1e-9));
+    }
+
+    #[rstest]
+    fn test_omega_ratio_with_threshold() {
+        let ratio = OmegaRatio::new(Some(0.01));
+        // Excess: 0.01, -0.02, 0.02, -0.025 -> gains 0.03, losses 0.045
+        let returns = create_returns(vec![0.02, -0.01, 0.03, -0.015]);
+        let result = ratio.calculate_from_returns(&returns).unwrap();
+        assert!(approx_eq!(f64, result, 0.03 / 0.045, epsilon = 1e-9));
+    }
+}
@@ -0,0 +1,134 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Tail ratio statistic.
+
+use std::fmt::{self, Display};
+
+use nautilus_model::position::Position;
+
+use crate::{Returns, statistic::PortfolioStatistic};
+
+/// Calculates the tail ratio for portfolio returns.
+///
+/// The tail ratio compares the size of the right tail (large gains) to the left tail
+/// (large losses) of the daily return distribution. Values above 1 indicate gains in the
+/// tails are larger than losses.
+///
+/// Formula: `abs(95th percentile) / abs(5th percentile)`
+#[repr(C)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.analysis")
+)]
+pub struct TailRatio {}
+
+impl TailRatio {
+    /// Creates a new [`TailRatio`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Display for TailRatio {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Tail Ratio")
+    }
+}
+
+impl PortfolioStatistic for TailRatio {
+    type Item = f64;
+
+    fn name(&self) -> String {
+        self.to_string()
+    }
+
+    fn calculate_from_returns(&self, raw_returns: &Returns) -> Option<Self::Item> {
+        if !self.check_valid_returns(raw_returns) {
+            return Some(f64::NAN);
+        }
+
+        let returns = self.downsample_to_daily_bins(raw_returns);
+        let right_tail = self.calculate_quantile(&returns, 0.95).abs();
+        let left_tail = self.calculate_quantile(&returns, 0.05).abs();
+
+        if left_tail < f64::EPSILON {
+            return Some(f64::NAN);
+        }
+
+        Some(right_tail / left_tail)
+    }
+
+    fn calculate_from_realized_pnls(&self, _realized_pnls: &[f64]) -> Option<Self::Item> {
+        None
+    }
+
+    fn calculate_from_positions(&self, _positions: &[Position]) -> Option<Self::Item> {
+        None
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use nautilus_core::{UnixNanos, approx_eq};
+    use rstest::rstest;
+
+    use super::*;
+
+    fn create_returns(values: Vec<f64>) -> BTreeMap<UnixNanos, f64> {
+        let one_day_in_nanos = 86_400_000_000_000;
+        let start_time = 1_600_000_000_000_000_000;
+
+        values
+            .into_iter()
+            .enumerate()
+            .map(|(i, v)| (UnixNanos::from(start_time + i as u64 * one_day_in_nanos), v))
+            .collect()
+    }
+
+    #[rstest]
+    fn test_name() {
+        assert_eq!(TailRatio::new().name(), "Tail Ratio");
+    }
+
+    #[rstest]
+    fn test_empty_returns() {
+        let result = TailRatio::new().calculate_from_returns(&BTreeMap::new());
+        assert!(result.unwrap().is_nan());
+    }
+
+    #[rstest]
+    fn test_symmetric_returns() {
+        let returns = create_returns(vec![-0.02, -0.01, 0.0, 0.01, 0.02]);
+        let result = TailRatio::new().calculate_from_returns(&returns).unwrap();
+        assert!(approx_eq!(f64, result, 1.0, epsilon = 1e-9));
+    }
+
+    #[rstest]
+    fn test_right_skewed_returns() {
+        // 5th percentile: -0.01 + 0.2 * 0.01 = -0.008, 95th percentile: 0.02 + 0.8 * 0.02 = 0.036
+        let returns = create_returns(vec![-0.01, 0.0, 0.01, 0.02, 0.04]);
+        let result = TailRatio::new().calculate_from_returns(&returns).unwrap();
+        assert!(approx_eq!(f64, result, 4.5, epsilon = 1e-9));
+    }
+}
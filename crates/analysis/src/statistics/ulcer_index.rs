@@ -0,0 +1,151 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Ulcer Index statistic.
+
+use std::fmt::{self, Display};
+
+use nautilus_model::position::Position;
+
+use crate::{Returns, statistic::PortfolioStatistic};
+
+/// Calculates the Ulcer Index for returns.
+///
+/// The Ulcer Index measures downside risk as the root mean square of the drawdowns from
+/// the running peak of the cumulative returns, penalizing both the depth and duration of
+/// drawdowns.
+///
+/// Formula: `sqrt(sum(drawdown^2) / n)` where each drawdown is `(Peak - Value) / Peak`
+///
+/// # References
+///
+/// - Martin, P. G., & McCann, B. B. (1989). *The Investor's Guide to Fidelity Funds*. Wiley.
+#[repr(C)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.analysis")
+)]
+pub struct UlcerIndex {}
+
+impl UlcerIndex {
+    /// Creates a new [`UlcerIndex`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Display for UlcerIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Ulcer Index")
+    }
+}
+
+impl PortfolioStatistic for UlcerIndex {
+    type Item = f64;
+
+    fn name(&self) -> String {
+        self.to_string()
+    }
+
+    fn calculate_from_returns(&self, raw_returns: &Returns) -> Option<Self::Item> {
+        if !self.check_valid_returns(raw_returns) {
+            return Some(f64::NAN);
+        }
+
+        let returns = self.downsample_to_daily_bins(raw_returns);
+
+        let mut cumulative = 1.0;
+        let mut running_max = 1.0_f64;
+        let mut sum_squares = 0.0;
+
+        for &ret in returns.values() {
+            cumulative *= 1.0 + ret;
+            running_max = running_max.max(cumulative);
+
+            let drawdown = (running_max - cumulative) / running_max;
+            sum_squares += drawdown * drawdown;
+        }
+
+        Some((sum_squares / returns.len() as f64).sqrt())
+    }
+
+    fn calculate_from_realized_pnls(&self, _realized_pnls: &[f64]) -> Option<Self::Item> {
+        None
+    }
+
+    fn calculate_from_positions(&self, _positions: &[Position]) -> Option<Self::Item> {
+        None
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use nautilus_core::{UnixNanos, approx_eq};
+    use rstest::rstest;
+
+    use super::*;
+
+    fn create_returns(values: Vec<f64>) -> BTreeMap<UnixNanos, f64> {
+        let one_day_in_nanos = 86_400_000_000_000;
+
+        values
+            .into_iter()
+            .enumerate()
+            .map(|(i, v)| (UnixNanos::from(i as u64 * one_day_in_nanos), v))
+            .collect()
+    }
+
+    #[rstest]
+    fn test_name() {
+        assert_eq!(UlcerIndex::new().name(), "Ulcer Index");
+    }
+
+    #[rstest]
+    fn test_empty_returns() {
+        let result = UlcerIndex::new().calculate_from_returns(&BTreeMap::new());
+        assert!(result.unwrap().is_nan());
+    }
+
+    #[rstest]
+    fn test_intraday_returns_are_downsampled() {
+        let returns = BTreeMap::from([(UnixNanos::from(1), -0.1), (UnixNanos::from(2), 0.1)]);
+        let result = UlcerIndex::new().calculate_from_returns(&returns);
+        assert_eq!(result, Some(0.0));
+    }
+
+    #[rstest]
+    fn test_no_drawdown() {
+        let returns = create_returns(vec![0.01, 0.02, 0.01]);
+        let result = UlcerIndex::new().calculate_from_returns(&returns);
+        assert_eq!(result, Some(0.0));
+    }
+
+    #[rstest]
+    fn test_ulcer_index() {
+        // Cumulative: 1.0 -> 0.9 -> 0.81 -> 0.9 -> 1.0 (drawdowns 0.1, 0.19, 0.1, 0.0)
+        let returns = create_returns(vec![-0.1, -0.1, 0.9 / 0.81 - 1.0, 1.0 / 0.9 - 1.0]);
+        let result = UlcerIndex::new().calculate_from_returns(&returns).unwrap();
+        let expected = ((0.01 + 0.0361 + 0.01) / 4.0_f64).sqrt();
+        assert!(approx_eq!(f64, result, expected, epsilon = 1e-9));
+    }
+}
@@ -0,0 +1,269 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Value at Risk (VaR) statistic.
+
+use std::fmt::{self, Display};
+
+use nautilus_model::position::Position;
+
+use crate::{Returns, statistic::PortfolioStatistic};
+
+/// Calculates the Value at Risk (VaR) of portfolio returns.
+///
+/// VaR is the daily return which losses are not expected to exceed at the given confidence
+/// level, reported as a return (negative values are losses).
+///
+/// Two methods are supported:
+/// - Historical: the `1 - confidence` quantile of the daily returns.
+/// - Parametric: `mean + z * std` assuming normally distributed daily returns, where `z` is
+///   the standard normal quantile at `1 - confidence`.
+///
+/// # References
+///
+/// - Jorion, P. (2006). *Value at Risk: The New Benchmark for Managing Financial Risk*
+///   (3rd ed.). McGraw-Hill.
+#[repr(C)]
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.analysis")
+)]
+pub struct ValueAtRisk {
+    confidence: f64,
+    parametric: bool,
+}
+
+impl ValueAtRisk {
+    /// Creates a new [`ValueAtRisk`] instance.
+    ///
+    /// The `confidence` defaults to 0.95, and the historical method is used unless
+    /// `parametric` is true.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `confidence` is not in the range (0, 1).
+    #[must_use]
+    pub fn new(confidence: Option<f64>, parametric: Option<bool>) -> Self {
+        let confidence = confidence.unwrap_or(0.95);
+        check_confidence(confidence);
+        Self {
+            confidence,
+            parametric: parametric.unwrap_or(false),
+        }
+    }
+}
+
+impl Display for ValueAtRisk {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Value at Risk ({}%, {})",
+            confidence_pct(self.confidence),
+            method_name(self.parametric),
+        )
+    }
+}
+
+impl PortfolioStatistic for ValueAtRisk {
+    type Item = f64;
+
+    fn name(&self) -> String {
+        self.to_string()
+    }
+
+    fn calculate_from_returns(&self, raw_returns: &Returns) -> Option<Self::Item> {
+        if !self.check_valid_returns(raw_returns) {
+            return Some(f64::NAN);
+        }
+
+        let returns = self.downsample_to_daily_bins(raw_returns);
+        if self.parametric {
+            let mean = returns.values().sum::<f64>() / returns.len() as f64;
+            let std = self.calculate_std(&returns);
+            Some(std.mul_add(normal_ppf(1.0 - self.confidence), mean))
+        } else {
+            Some(self.calculate_quantile(&returns, 1.0 - self.confidence))
+        }
+    }
+
+    fn calculate_from_realized_pnls(&self, _realized_pnls: &[f64]) -> Option<Self::Item> {
+        None
+    }
+
+    fn calculate_from_positions(&self, _positions: &[Position]) -> Option<Self::Item> {
+        None
+    }
+}
+
+pub(crate) fn check_confidence(confidence: f64) {
+    assert!(
+        confidence > 0.0 && confidence < 1.0,
+        "confidence must be in the range (0, 1), was {confidence}"
+    );
+}
+
+pub(crate) fn confidence_pct(confidence: f64) -> f64 {
+    (confidence * 10_000.0).round() / 100.0
+}
+
+pub(crate) const fn method_name(parametric: bool) -> &'static str {
+    if parametric {
+        "parametric"
+    } else {
+        "historical"
+    }
+}
+
+/// Returns the standard normal probability density at `x`.
+pub(crate) fn normal_pdf(x: f64) -> f64 {
+    (-0.5 * x * x).exp() / (2.0 * std::f64::consts::PI).sqrt()
+}
+
+/// Returns the standard normal quantile for the probability `p` in (0, 1).
+///
+/// Uses the rational approximation by Peter Acklam (relative error below 1.15e-9).
+pub(crate) fn normal_ppf(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969_683_028_665_376e1,
+        2.209_460_984_245_205e2,
+        -2.759_285_104_469_687e2,
+        1.383_577_518_672_69e2,
+        -3.066_479_806_614_716e1,
+        2.506_628_277_459_239,
+    ];
+    const B: [f64; 5] = [
+        -5.447_609_879_822_406e1,
+        1.615_858_368_580_409e2,
+        -1.556_989_798_598_866e2,
+        6.680_131_188_771_972e1,
+        -1.328_068_155_288_572e1,
+    ];
+    const C: [f64; 6] = [
+        -7.784_894_002_430_293e-3,
+        -3.223_964_580_411_365e-1,
+        -2.400_758_277_161_838,
+        -2.549_732_539_343_734,
+        4.374_664_141_464_968,
+        2.938_163_982_698_783,
+    ];
+    const D: [f64; 4] = [
+        7.784_695_709_041_462e-3,
+        3.224_671_290_700_398e-1,
+        2.445_134_137_142_996,
+        3.754_408_661_907_416,
+    ];
+    const P_LOW: f64 = 0.024_25;
+
+    let tail = |q: f64| {
+        let num = C.iter().fold(0.0, |acc, c| acc * q + c);
+        let den = D.iter().fold(0.0, |acc, d| acc * q + d) * q + 1.0;
+        num / den
+    };
+
+    if p < P_LOW {
+        tail((-2.0 * p.ln()).sqrt())
+    } else if p > 1.0 - P_LOW {
+        -tail((-2.0 * (1.0 - p).ln()).sqrt())
+    } else {
+        let q = p - 0.5;
+        let r = q * q;
+        let num = A.iter().fold(0.0, |acc, a| acc * r + a);
+        let den = B.iter().fold(0.0, |acc, b| acc * r + b) * r + 1.0;
+        num * q / den
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use nautilus_core::{UnixNanos, approx_eq};
+    use rstest::rstest;
+
+    use super::*;
+
+    fn create_returns(values: Vec<f64>) -> BTreeMap<UnixNanos, f64> {
+        let one_day_in_nanos = 86_400_000_000_000;
+        let start_time = 1_600_000_000_000_000_000;
+
+        values
+            .into_iter()
+            .enumerate()
+            .map(|(i, v)| (UnixNanos::from(start_time + i as u64 * one_day_in_nanos), v))
+            .collect()
+    }
+
+    #[rstest]
+    fn test_name() {
+        assert_eq!(
+            ValueAtRisk::new(None, None).name(),
+            "Value at Risk (95%, historical)"
+        );
+        assert_eq!(
+            ValueAtRisk::new(Some(0.99), Some(true)).name(),
+            "Value at Risk (99%, parametric)"
+        );
+    }
+
+    #[rstest]
+    #[should_panic(expected = "confidence must be in the range")]
+    fn test_invalid_confidence() {
+        let _ = ValueAtRisk::new(Some(1.0), None);
+    }
+
+    #[rstest]
+    fn test_empty_returns() {
+        let result = ValueAtRisk::new(None, None).calculate_from_returns(&BTreeMap::new());
+        assert!(result.unwrap().is_nan());
+    }
+
+    #[rstest]
+    #[case(0.5, 0.0)]
+    #[case(0.975, 1.959_963_984_540_054)]
+    #[case(0.01, -2.326_347_874_040_841)]
+    #[case(0.999, 3.090_232_306_167_813)]
+    fn test_normal_ppf(#[case] p: f64, #[case] expected: f64) {
+        assert!(approx_eq!(f64, normal_ppf(p), expected, epsilon = 1e-8));
+    }
+
+    #[rstest]
+    fn test_historical_var() {
+        let var = ValueAtRisk::new(Some(0.9), None);
+        // 10th percentile of -0.05..=0.04 (step 0.01) is -0.05 + 0.9 * 0.01
+        let returns = create_returns((0..10).map(|i| f64::from(i - 5) / 100.0).collect());
+        let result = var.calculate_from_returns(&returns).unwrap();
+        assert!(approx_eq!(f64, result, -0.041, epsilon = 1e-9));
+    }
+
+    #[rstest]
+    fn test_parametric_var() {
+        let var = ValueAtRisk::new(Some(0.95), Some(true));
+        // Mean 0.0, sample std 0.01
+        let returns = create_returns(vec![0.01, -0.01, 0.01, -0.01, 0.0]);
+        let std = (0.0004_f64 / 4.0).sqrt();
+        let result = var.calculate_from_returns(&returns).unwrap();
+        assert!(approx_eq!(
+            f64,
+            result,
+            -1.644_853_626_951_472 * std,
+            epsilon = 1e-9
+        ));
+    }
+}
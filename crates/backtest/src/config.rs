@@ -25,7 +25,10 @@ use nautilus_common::{
 };
 use nautilus_core::{UUID4, UnixNanos};
 use nautilus_data::engine::config::DataEngineConfig;
-use nautilus_execution::engine::config::ExecutionEngineConfig;
+use nautilus_execution::{
+    engine::config::ExecutionEngineConfig,
+    models::{fee::FeeModelAny, fill::FillModelAny, latency::LatencyModelAny},
+};
 use nautilus_model::{
     data::BarSpecification,
     enums::{AccountType, BookType, OmsType},
@@ -247,49 +250,59 @@ impl Default for BacktestEngineConfig {
 #[derive(Debug, Clone)]
 pub struct BacktestVenueConfig {
     /// The name of the venue.
-    name: Ustr,
+    pub(crate) name: Ustr,
     /// The order management system type for the exchange. If ``HEDGING`` will generate new position IDs.
-    oms_type: OmsType,
+    pub(crate) oms_type: OmsType,
     /// The account type for the exchange.
-    account_type: AccountType,
+    pub(crate) account_type: AccountType,
     /// The default order book type.
-    book_type: BookType,
+    pub(crate) book_type: BookType,
     /// The starting account balances (specify one for a single asset account).
-    starting_balances: Vec<String>,
+    pub(crate) starting_balances: Vec<String>,
     /// If multi-venue routing should be enabled for the execution client.
-    routing: bool,
+    pub(crate) routing: bool,
     /// If the account for this exchange is frozen (balances will not change).
-    frozen_account: bool,
+    pub(crate) frozen_account: bool,
     /// If stop orders are rejected on submission if trigger price is in the market.
-    reject_stop_orders: bool,
+    pub(crate) reject_stop_orders: bool,
     /// If orders with GTD time in force will be supported by the venue.
-    support_gtd_orders: bool,
+    pub(crate) support_gtd_orders: bool,
     /// If contingent orders will be supported/respected by the venue.
     /// If False, then it's expected the strategy will be managing any contingent orders.
-    support_contingent_orders: bool,
+    pub(crate) support_contingent_orders: bool,
     /// If venue position IDs will be generated on order fills.
-    use_position_ids: bool,
+    pub(crate) use_position_ids: bool,
     /// If all venue generated identifiers will be random UUID4's.
-    use_random_ids: bool,
+    pub(crate) use_random_ids: bool,
     /// If the `reduce_only` execution instruction on orders will be honored.
-    use_reduce_only: bool,
+    pub(crate) use_reduce_only: bool,
     /// If bars should be processed by the matching engine(s) (and move the market).
-    bar_execution: bool,
+    pub(crate) bar_execution: bool,
     /// Determines whether the processing order of bar prices is adaptive based on a heuristic.
     /// This setting is only relevant when `bar_execution` is True.
     /// If False, bar prices are always processed in the fixed order: Open, High, Low, Close.
     /// If True, the processing order adapts with the heuristic:
     /// - If High is closer to Open than Low then the processing order is Open, High, Low, Close.
     /// - If Low is closer to Open than High then the processing order is Open, Low, High, Close.
-    bar_adaptive_high_low_ordering: bool,
+    pub(crate) bar_adaptive_high_low_ordering: bool,
     /// If trades should be processed by the matching engine(s) (and move the market).
-    trade_execution: bool,
+    pub(crate) trade_execution: bool,
+    /// If commands are processed through the exchange message queue (otherwise immediately).
+    pub(crate) use_message_queue: bool,
+    /// If cash accounts may borrow (allowing negative balances).
+    pub(crate) allow_cash_borrowing: bool,
     /// The account base currency for the exchange. Use `None` for multi-currency accounts.
-    base_currency: Option<Currency>,
+    pub(crate) base_currency: Option<Currency>,
     /// The account default leverage (for margin accounts).
-    default_leverage: Option<f64>,
+    pub(crate) default_leverage: Option<f64>,
     /// The instrument specific leverage configuration (for margin accounts).
-    leverages: Option<HashMap<Currency, f64>>,
+    pub(crate) leverages: Option<HashMap<Currency, f64>>,
+    /// The fill model for the venue's matching engines.
+    pub(crate) fill_model: FillModelAny,
+    /// The fee model for the venue's matching engines.
+    pub(crate) fee_model: FeeModelAny,
+    /// The latency model for commands sent to the venue (if any).
+    pub(crate) latency_model: Option<LatencyModelAny>,
 }
 
 impl BacktestVenueConfig {
@@ -311,10 +324,15 @@ impl BacktestVenueConfig {
         bar_execution: Option<bool>,
         bar_adaptive_high_low_ordering: Option<bool>,
         trade_execution: Option<bool>,
+        use_message_queue: Option<bool>,
+        allow_cash_borrowing: Option<bool>,
         starting_balances: Vec<String>,
         base_currency: Option<Currency>,
         default_leverage: Option<f64>,
         leverages: Option<HashMap<Currency, f64>>,
+        fill_model: Option<FillModelAny>,
+        fee_model: Option<FeeModelAny>,
+        latency_model: Option<LatencyModelAny>,
     ) -> Self {
         Self {
             name,
@@ -332,12 +350,35 @@ impl BacktestVenueConfig {
             bar_execution: bar_execution.unwrap_or(true),
             bar_adaptive_high_low_ordering: bar_adaptive_high_low_ordering.unwrap_or(false),
             trade_execution: trade_execution.unwrap_or(false),
+            use_message_queue: use_message_queue.unwrap_or(true),
+            allow_cash_borrowing: allow_cash_borrowing.unwrap_or(false),
             starting_balances,
             base_currency,
             default_leverage,
             leverages,
+            fill_model: fill_model.unwrap_or_default(),
+            fee_model: fee_model.unwrap_or_default(),
+            latency_model,
         }
     }
+
+    /// Returns the fill model for the venue.
+    #[must_use]
+    pub const fn fill_model(&self) -> &FillModelAny {
+        &self.fill_model
+    }
+
+    /// Returns the fee model for the venue.
+    #[must_use]
+    pub const fn fee_model(&self) -> &FeeModelAny {
+        &self.fee_model
+    }
+
+    /// Returns the latency model for the venue (if any).
+    #[must_use]
+    pub const fn latency_model(&self) -> Option<&LatencyModelAny> {
+        self.latency_model.as_ref()
+    }
 }
 
 #[derive(Debug, Clone)]
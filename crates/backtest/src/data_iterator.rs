@@ -123,6 +123,23 @@ impl BacktestDataIterator {
         }
     }
 
+    /// Returns the data of the stream `name` (if added).
+    #[must_use]
+    pub fn data(&self, name: &str) -> Option<&[Data]> {
+        self.priorities
+            .get(name)
+            .and_then(|priority| self.streams.get(priority))
+            .map(Vec::as_slice)
+    }
+
+    /// Removes the stream `name`, returning its data (if added).
+    pub fn take_data(&mut self, name: &str) -> Option<Vec<Data>> {
+        let priority = *self.priorities.get(name)?;
+        let data = self.streams.remove(&priority);
+        self.remove_data(name, true);
+        data
+    }
+
     /// Move cursor of stream to `index` (0-based).
     pub fn set_index(&mut self, name: &str, index: usize) {
         if let Some(priority) = self.priorities.get(name) {
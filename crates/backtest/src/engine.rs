@@ -22,31 +22,56 @@
 use std::{
     any::Any,
     cell::RefCell,
-    collections::{HashMap, HashSet, VecDeque},
-    fmt::Debug,
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt::{Debug, Display},
     rc::Rc,
+    str::FromStr,
+    time::Duration,
 };
 
-use nautilus_common::timer::TimeEventHandlerV2;
-use nautilus_core::{UUID4, UnixNanos};
+use nautilus_common::{
+    actor::DataActor,
+    clock::Clock,
+    component::Component,
+    logging::{
+        headers::log_sysinfo, logging_clock_set_realtime_mode, logging_clock_set_static_mode,
+        logging_clock_set_static_time, logging_is_colored,
+    },
+    timer::TimeEventHandlerV2,
+};
+use nautilus_core::{
+    UUID4, UnixNanos, datetime::unix_nanos_to_iso8601, time::get_atomic_clock_realtime,
+};
 use nautilus_data::client::DataClientAdapter;
-use nautilus_execution::models::{fee::FeeModelAny, fill::FillModel, latency::LatencyModel};
+use nautilus_execution::models::{fee::FeeModelAny, fill::FillModelAny, latency::LatencyModelAny};
 use nautilus_model::{
-    data::Data,
+    accounts::Account,
+    data::{Data, HasTsInit},
     enums::{AccountType, BookType, OmsType},
     identifiers::{AccountId, ClientId, InstrumentId, Venue},
     instruments::{Instrument, InstrumentAny},
+    position::Position,
     types::{Currency, Money},
 };
 use nautilus_system::{config::NautilusKernelConfig, kernel::NautilusKernel};
 use rust_decimal::Decimal;
+use thousands::Separable;
+use ustr::Ustr;
 
 use crate::{
-    accumulator::TimeEventAccumulator, config::BacktestEngineConfig,
-    data_client::BacktestDataClient, exchange::SimulatedExchange,
-    execution_client::BacktestExecutionClient, modules::SimulationModule,
+    accumulator::TimeEventAccumulator,
+    config::{BacktestEngineConfig, BacktestVenueConfig},
+    data_client::BacktestDataClient,
+    data_iterator::BacktestDataIterator,
+    exchange::SimulatedExchange,
+    execution_client::BacktestExecutionClient,
+    modules::SimulationModule,
+    result::BacktestResult,
 };
 
+/// The name of the engines internal data stream within the data iterator.
+const BACKTEST_DATA_STREAM: &str = "backtest_data";
+
 /// Core backtesting engine for running event-driven strategy backtests on historical data.
 ///
 /// The `BacktestEngine` provides a high-fidelity simulation environment that processes
@@ -69,13 +94,18 @@ pub struct BacktestEngine {
     venues: HashMap<Venue, Rc<RefCell<SimulatedExchange>>>,
     has_data: HashSet<InstrumentId>,
     has_book_data: HashSet<InstrumentId>,
-    data: VecDeque<Data>,
-    index: usize,
+    data: Vec<Data>,
+    data_iterator: BacktestDataIterator,
+    sorted: bool,
     iteration: usize,
+    last_ns: UnixNanos,
+    end_ns: UnixNanos,
     run_started: Option<UnixNanos>,
     run_finished: Option<UnixNanos>,
     backtest_start: Option<UnixNanos>,
     backtest_end: Option<UnixNanos>,
+    stats_pnls: BTreeMap<String, BTreeMap<String, BTreeMap<String, f64>>>,
+    stats_returns: BTreeMap<String, BTreeMap<String, f64>>,
 }
 
 impl Debug for BacktestEngine {
@@ -107,13 +137,18 @@ impl BacktestEngine {
             venues: HashMap::new(),
             has_data: HashSet::new(),
             has_book_data: HashSet::new(),
-            data: VecDeque::new(),
-            index: 0,
+            data: Vec::new(),
+            data_iterator: BacktestDataIterator::new(),
+            sorted: true,
             iteration: 0,
+            last_ns: UnixNanos::default(),
+            end_ns: UnixNanos::default(),
             run_started: None,
             run_finished: None,
             backtest_start: None,
             backtest_end: None,
+            stats_pnls: BTreeMap::new(),
+            stats_returns: BTreeMap::new(),
         })
     }
 
@@ -132,9 +167,9 @@ impl BacktestEngine {
         default_leverage: Option<Decimal>,
         leverages: HashMap<InstrumentId, Decimal>,
         modules: Vec<Box<dyn SimulationModule>>,
-        fill_model: FillModel,
+        fill_model: FillModelAny,
         fee_model: FeeModelAny,
-        latency_model: Option<LatencyModel>,
+        latency_model: Option<LatencyModelAny>,
         routing: Option<bool>,
         reject_stop_orders: Option<bool>,
         support_gtd_orders: Option<bool>,
@@ -206,7 +241,68 @@ impl BacktestEngine {
         Ok(())
     }
 
-    pub fn change_fill_model(&mut self, venue: Venue, fill_model: FillModel) {
+    /// Adds a venue to the backtest engine from the given `config`.
+    ///
+    /// The config has no simulation modules, venues requiring them should be added with
+    /// [`Self::add_venue`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - A starting balance cannot be parsed.
+    /// - The default leverage is not a valid decimal.
+    /// - Instrument specific leverages are configured, as the config keys them by currency
+    ///   rather than instrument.
+    /// - Initializing the simulated exchange for the venue fails.
+    pub fn add_venue_from_config(&mut self, config: &BacktestVenueConfig) -> anyhow::Result<()> {
+        let starting_balances = config
+            .starting_balances
+            .iter()
+            .map(|balance| Money::from_str(balance).map_err(anyhow::Error::msg))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let default_leverage = config.default_leverage.map(Decimal::try_from).transpose()?;
+
+        if config
+            .leverages
+            .as_ref()
+            .is_some_and(|leverages| !leverages.is_empty())
+        {
+            anyhow::bail!(
+                "Cannot apply currency keyed `leverages` for venue {}, add the venue with instrument leverages instead",
+                config.name
+            );
+        }
+
+        self.add_venue(
+            Venue::from(config.name.as_str()),
+            config.oms_type,
+            config.account_type,
+            config.book_type,
+            starting_balances,
+            config.base_currency,
+            default_leverage,
+            HashMap::new(),
+            vec![],
+            config.fill_model.clone(),
+            config.fee_model.clone(),
+            config.latency_model.clone(),
+            Some(config.routing),
+            Some(config.reject_stop_orders),
+            Some(config.support_gtd_orders),
+            Some(config.support_contingent_orders),
+            Some(config.use_position_ids),
+            Some(config.use_random_ids),
+            Some(config.use_reduce_only),
+            Some(config.use_message_queue),
+            Some(config.bar_execution),
+            Some(config.bar_adaptive_high_low_ordering),
+            Some(config.trade_execution),
+            Some(config.allow_cash_borrowing),
+            Some(config.frozen_account),
+        )
+    }
+
+    pub fn change_fill_model(&mut self, venue: Venue, fill_model: FillModelAny) {
         if let Some(exchange) = self.venues.get_mut(&venue) {
             exchange.borrow_mut().set_fill_model(fill_model);
         } else {
@@ -299,90 +395,499 @@ impl BacktestEngine {
             }
         }
 
-        // Extend master data vector and ensure internal iterator (index) remains valid.
-        for item in to_add {
-            self.data.push_back(item);
-        }
+        // Hold the data until it is merged into the sorted stream
+        self.data.extend(to_add);
 
         if sort {
-            // VecDeque cannot be sorted directly; convert to Vec for sorting, then back.
-            let mut vec: Vec<Data> = self.data.drain(..).collect();
-            vec.sort_by_key(nautilus_model::data::HasTsInit::ts_init);
-            self.data = vec.into();
+            // Re-sort the full data stream and sync it to the internal iterator
+            self.sort_data();
+        } else {
+            self.sorted = false;
         }
 
+        let count = self.stream_data().len() + self.data.len();
         log::info!(
-            "Added {} data element{} to BacktestEngine",
-            self.data.len(),
-            if self.data.len() == 1 { "" } else { "s" }
+            "Added {count} data element{} to BacktestEngine",
+            if count == 1 { "" } else { "s" }
         );
     }
 
-    pub fn add_actor(&mut self) {
-        todo!("implement add_actor")
+    /// Sorts the engines internal data stream by `ts_init`.
+    ///
+    /// Data added since the last sort is merged into the stream held by the data iterator.
+    pub fn sort_data(&mut self) {
+        let mut data = self
+            .data_iterator
+            .take_data(BACKTEST_DATA_STREAM)
+            .unwrap_or_default();
+        data.append(&mut self.data);
+        self.data_iterator
+            .add_data(BACKTEST_DATA_STREAM, data, true);
+        self.sorted = true;
     }
 
-    pub fn add_actors(&mut self) {
-        todo!("implement add_actors")
+    /// Adds the given actor to the backtest engine.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the actor cannot be registered with the trader.
+    pub fn add_actor<T>(&mut self, actor: T) -> anyhow::Result<()>
+    where
+        T: DataActor + Component + Debug + 'static,
+    {
+        // Checked inside trader
+        self.kernel.trader.add_actor(actor)
     }
 
-    pub fn add_strategy(&mut self) {
-        todo!("implement add_strategy")
+    /// Adds the given actors to the backtest engine.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any actor cannot be registered with the trader.
+    pub fn add_actors<T>(&mut self, actors: Vec<T>) -> anyhow::Result<()>
+    where
+        T: DataActor + Component + Debug + 'static,
+    {
+        for actor in actors {
+            self.add_actor(actor)?;
+        }
+        Ok(())
     }
 
-    pub fn add_strategies(&mut self) {
-        todo!("implement add_strategies")
+    /// Adds the given strategy to the backtest engine.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the strategy cannot be registered with the trader.
+    pub fn add_strategy(&mut self, strategy: Box<dyn Component>) -> anyhow::Result<()> {
+        // Checked inside trader
+        self.kernel.trader.add_strategy(strategy)
     }
 
-    pub fn add_exec_algorithm(&mut self) {
-        todo!("implement add_exec_algorithm")
+    /// Adds the given strategies to the backtest engine.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any strategy cannot be registered with the trader.
+    pub fn add_strategies(&mut self, strategies: Vec<Box<dyn Component>>) -> anyhow::Result<()> {
+        for strategy in strategies {
+            self.add_strategy(strategy)?;
+        }
+        Ok(())
+    }
+
+    /// Adds the given execution algorithm to the backtest engine.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the execution algorithm cannot be registered with the trader.
+    pub fn add_exec_algorithm(&mut self, exec_algorithm: Box<dyn Component>) -> anyhow::Result<()> {
+        // Checked inside trader
+        self.kernel.trader.add_exec_algorithm(exec_algorithm)
     }
 
-    pub fn add_exec_algorithms(&mut self) {
-        todo!("implement add_exec_algorithms")
+    /// Adds the given execution algorithms to the backtest engine.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any execution algorithm cannot be registered with the trader.
+    pub fn add_exec_algorithms(
+        &mut self,
+        exec_algorithms: Vec<Box<dyn Component>>,
+    ) -> anyhow::Result<()> {
+        for exec_algorithm in exec_algorithms {
+            self.add_exec_algorithm(exec_algorithm)?;
+        }
+        Ok(())
     }
 
+    /// Resets the backtest engine.
+    ///
+    /// All stateful fields are reset to their initial value, except for data and
+    /// instruments which persist to enable repeated runs against the same dataset.
     pub fn reset(&mut self) {
-        todo!("implement reset")
+        log::debug!("Resetting");
+
+        if self.kernel.trader.is_running() {
+            // End current backtest run
+            self.end();
+        }
+
+        self.kernel.reset();
+
+        for exchange in self.venues.values() {
+            exchange.borrow_mut().reset();
+        }
+
+        // Reset run IDs
+        self.run_config_id = None;
+        self.run_id = None;
+
+        // Reset timing
+        self.iteration = 0;
+        self.data_iterator.set_index(BACKTEST_DATA_STREAM, 0);
+
+        self.run_started = None;
+        self.run_finished = None;
+        self.backtest_start = None;
+        self.backtest_end = None;
+        self.stats_pnls.clear();
+        self.stats_returns.clear();
+
+        log::info!("Reset");
     }
 
+    /// Clears the engines internal data stream.
+    ///
+    /// Does not clear added instruments.
     pub fn clear_data(&mut self) {
-        todo!("implement clear_data")
+        self.has_data.clear();
+        self.has_book_data.clear();
+        self.data.clear();
+        self.data_iterator = BacktestDataIterator::new();
+        self.sorted = true;
     }
 
-    pub fn clear_strategies(&mut self) {
-        todo!("implement clear_strategies")
+    /// Clears all trading strategies from the engines internal trader.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the trader is not in a valid state for removing components.
+    pub fn clear_strategies(&mut self) -> anyhow::Result<()> {
+        self.kernel.trader.clear_strategies()
     }
 
-    pub fn clear_exec_algorithms(&mut self) {
-        todo!("implement clear_exec_algorithms")
+    /// Clears all execution algorithms from the engines internal trader.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the trader is not in a valid state for removing components.
+    pub fn clear_exec_algorithms(&mut self) -> anyhow::Result<()> {
+        self.kernel.trader.clear_exec_algorithms()
     }
 
+    /// Disposes of the backtest engine by disposing the trader and releasing system resources.
+    ///
+    /// Once called, no other methods should be called on this instance.
     pub fn dispose(&mut self) {
-        todo!("implement dispose")
+        self.clear_data();
+        self.kernel.dispose();
     }
 
-    pub fn run(&mut self) {
-        todo!("implement run")
+    /// Runs a backtest.
+    ///
+    /// At the end of the run the trader and strategies will be stopped, then
+    /// post-run analysis performed. When `streaming` is `true` the run is left
+    /// open so that further data batches can be added, and [`Self::end`] must
+    /// be called after the final batch.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Data has been added with `sort = false` but `sort_data` has not been called.
+    /// - A venue with a depth `book_type` has data for an instrument but no order book data.
+    /// - The `start` is after the `end`.
+    pub fn run(
+        &mut self,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+        run_config_id: Option<UUID4>,
+        streaming: bool,
+    ) -> anyhow::Result<()> {
+        if !self.run_impl(start, end, run_config_id)? {
+            return Ok(());
+        }
+
+        if !streaming {
+            self.end();
+        }
+
+        Ok(())
     }
 
+    /// Manually ends the backtest.
+    ///
+    /// Only required if you have previously been running with streaming.
     pub fn end(&mut self) {
-        todo!("implement end")
+        self.kernel.stop();
+
+        // Process remaining messages
+        let ts_now = self.kernel.clock.borrow().timestamp_ns();
+        for exchange in self.venues.values() {
+            exchange.borrow_mut().process(ts_now);
+        }
+
+        self.run_finished = Some(get_atomic_clock_realtime().get_time_ns());
+        self.backtest_end = Some(ts_now);
+
+        // Change logger clock back to real-time for consistent time stamping
+        logging_clock_set_realtime_mode();
+
+        self.log_post_run();
+
+        log::logger().flush();
+    }
+
+    /// Returns the result of the last backtest run.
+    ///
+    /// Performance statistics are keyed by venue and only populated when `run_analysis`
+    /// is enabled in the engine config, as they are calculated at the end of each run.
+    #[must_use]
+    pub fn get_result(&self) -> BacktestResult {
+        let elapsed_time = match (self.backtest_start, self.backtest_end) {
+            (Some(start), Some(end)) => {
+                Duration::from_nanos(end.saturating_sub(start.as_u64())).as_secs_f64()
+            }
+            _ => 0.0,
+        };
+
+        let (total_orders, total_positions) = {
+            let cache = self.kernel.cache.borrow();
+            (
+                cache.orders_total_count(None, None, None, None),
+                cache.positions_total_count(None, None, None, None),
+            )
+        };
+
+        BacktestResult {
+            trader_id: self.kernel.trader_id(),
+            machine_id: self.kernel.machine_id().to_string(),
+            instance_id: self.instance_id,
+            run_config_id: self.run_config_id,
+            run_id: self.run_id,
+            run_started: self.run_started,
+            run_finished: self.run_finished,
+            backtest_start: self.backtest_start,
+            backtest_end: self.backtest_end,
+            elapsed_time,
+            iterations: self.iteration,
+            total_events: self.kernel.exec_engine.event_count(),
+            total_orders,
+            total_positions,
+            stats_pnls: self.stats_pnls.clone(),
+            stats_returns: self.stats_returns.clone(),
+        }
     }
 
-    pub fn get_result(&self) {
-        // TODO: implement full BacktestResult aggregation once portfolio analysis
-        // components are available in Rust. For now we simply log and return.
-        log::info!("BacktestEngine::get_result called – not yet implemented");
+    /// Returns `false` if there was no data to run from `start`.
+    fn run_impl(
+        &mut self,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+        run_config_id: Option<UUID4>,
+    ) -> anyhow::Result<bool> {
+        // Validate data has been sorted and synced to iterator
+        if !self.sorted {
+            anyhow::bail!(
+                "Data has been added but not sorted, call `engine.sort_data()` or use `engine.add_data(..., sort=true)` before running"
+            );
+        }
+
+        // Validate data
+        for exchange in self.venues.values() {
+            let exchange = exchange.borrow();
+            let book_type_has_depth = exchange.book_type() > BookType::L1_MBP;
+
+            for instrument_id in exchange.get_matching_engines().keys() {
+                let has_data = self.has_data.contains(instrument_id);
+                let missing_book_data = !self.has_book_data.contains(instrument_id);
+
+                if book_type_has_depth && has_data && missing_book_data {
+                    anyhow::bail!(
+                        "No order book data found for instrument '{instrument_id}' when `book_type` is '{}'. \
+                        Set the venue `book_type` to 'L1_MBP' (for top-of-book data like quotes, trades, and bars) \
+                        or provide order book data for this instrument",
+                        exchange.book_type()
+                    );
+                }
+            }
+        }
+
+        // Time range check and set
+        let start_ns = start.unwrap_or_else(|| {
+            self.stream_data()
+                .first()
+                .map_or_else(UnixNanos::default, HasTsInit::ts_init)
+        });
+        let end_ns = end.unwrap_or_else(|| {
+            self.stream_data().last().map_or_else(
+                || UnixNanos::from(4_102_444_800_000_000_000), // Year 2100-01-01 00:00:00 UTC
+                HasTsInit::ts_init,
+            )
+        });
+
+        if let Some(last) = self.stream_data().last()
+            && start_ns > last.ts_init()
+        {
+            log::warn!(
+                "`start` {start_ns} is after the last data at {}, nothing to run",
+                last.ts_init()
+            );
+            return Ok(false);
+        }
+
+        if start_ns > end_ns {
+            anyhow::bail!("`start` {start_ns} was > `end` {end_ns}");
+        }
+        self.end_ns = end_ns;
+
+        // Set clocks
+        self.last_ns = start_ns;
+        self.set_clocks_time(start_ns);
+
+        if self.iteration == 0 {
+            // Initialize run
+            self.run_config_id = run_config_id;
+            self.run_id = Some(UUID4::new());
+            self.run_started = Some(get_atomic_clock_realtime().get_time_ns());
+            self.backtest_start = Some(start_ns);
+
+            for exchange in self.venues.values() {
+                exchange.borrow_mut().initialize_account();
+            }
+
+            // Set start time of all components including logging
+            logging_clock_set_static_mode();
+            logging_clock_set_static_time(start_ns.as_u64());
+
+            // Common kernel start-up sequence
+            self.kernel.start();
+
+            self.log_pre_run();
+        }
+
+        self.log_run(start_ns, end_ns);
+
+        // Set starting index
+        if let Some(index) = self
+            .stream_data()
+            .iter()
+            .position(|d| start_ns <= d.ts_init())
+        {
+            self.data_iterator.set_index(BACKTEST_DATA_STREAM, index);
+        }
+
+        // -- MAIN BACKTEST LOOP -----------------------------------------------
+        self.last_ns = UnixNanos::default();
+        let mut raw_handlers: Vec<TimeEventHandlerV2> = Vec::new();
+        let mut data = self.data_iterator.next();
+
+        while let Some(item) = data {
+            let ts_init = item.ts_init();
+            if ts_init > end_ns {
+                // End of backtest
+                break;
+            }
+
+            if ts_init > self.last_ns {
+                // Advance clocks to the next data time
+                self.last_ns = ts_init;
+                raw_handlers = self.advance_time(ts_init);
+            }
+
+            // Process data through exchange
+            self.process_exchange_data(&item);
+            self.kernel.data_engine.borrow_mut().process_data(item);
+
+            // Process all exchange messages
+            for exchange in self.venues.values() {
+                exchange.borrow_mut().process(ts_init);
+            }
+
+            data = self.data_iterator.next();
+
+            if data.as_ref().is_none_or(|d| d.ts_init() > self.last_ns) {
+                // Finally process the time events
+                let handlers = std::mem::take(&mut raw_handlers);
+                self.process_raw_time_event_handlers(handlers, self.last_ns, true, false);
+            }
+
+            self.iteration += 1;
+        }
+        // ---------------------------------------------------------------------
+
+        // Process remaining messages
+        let ts_now = self.kernel.clock.borrow().timestamp_ns();
+        for exchange in self.venues.values() {
+            exchange.borrow_mut().process(ts_now);
+        }
+
+        // Process remaining time events
+        if !raw_handlers.is_empty() {
+            self.process_raw_time_event_handlers(raw_handlers, self.last_ns, true, true);
+        }
+
+        Ok(true)
+    }
+
+    /// Returns the sorted data stream held by the data iterator.
+    fn stream_data(&self) -> &[Data] {
+        self.data_iterator
+            .data(BACKTEST_DATA_STREAM)
+            .unwrap_or_default()
+    }
+
+    fn process_exchange_data(&self, data: &Data) {
+        let Some(exchange) = self.venues.get(&data.instrument_id().venue) else {
+            return;
+        };
+        let mut exchange = exchange.borrow_mut();
+
+        match data {
+            Data::Delta(delta) => exchange.process_order_book_delta(*delta),
+            Data::Deltas(deltas) => exchange.process_order_book_deltas((**deltas).clone()),
+            Data::Quote(quote) => exchange.process_quote_tick(quote),
+            Data::Trade(trade) => exchange.process_trade_tick(trade),
+            Data::Bar(bar) => exchange.process_bar(*bar),
+            Data::Depth10(depth) => exchange.process_order_book_depth10(depth),
+            Data::MarkPriceUpdate(_) | Data::IndexPriceUpdate(_) | Data::InstrumentClose(_) => {
+                exchange.process_reference_data(data);
+            }
+        }
     }
 
-    pub fn next(&mut self) {
-        self.data.pop_front();
+    /// Returns the kernel clock followed by all registered component clocks.
+    fn get_clocks(&self) -> Vec<Rc<RefCell<dyn Clock>>> {
+        let mut clocks = vec![self.kernel.clock.clone()];
+        clocks.extend(self.kernel.trader.get_component_clocks());
+        clocks
     }
 
-    pub fn advance_time(&mut self, _ts_now: UnixNanos) -> Vec<TimeEventHandlerV2> {
-        // TODO: integrate TestClock advancement when kernel clocks are exposed.
-        self.accumulator.drain()
+    fn set_clocks_time(&self, ts_now: UnixNanos) {
+        for clock in self.get_clocks() {
+            if let Some(test_clock) = clock.borrow_mut().as_test_clock_mut() {
+                test_clock.set_time(ts_now);
+            }
+        }
+    }
+
+    /// Advances all clocks to `ts_now`, processing any time events prior to `ts_now`.
+    ///
+    /// Returns the remaining time event handlers to be run at `ts_now`.
+    pub fn advance_time(&mut self, ts_now: UnixNanos) -> Vec<TimeEventHandlerV2> {
+        let clocks = self.get_clocks();
+
+        for clock in &clocks {
+            if let Some(test_clock) = clock.borrow_mut().as_test_clock_mut() {
+                self.accumulator.advance_clock(test_clock, ts_now, false);
+            }
+        }
+
+        let (prior, remaining): (Vec<_>, Vec<_>) = self
+            .accumulator
+            .drain()
+            .into_iter()
+            .partition(|handler| handler.event.ts_event < ts_now);
+
+        // Handle all events prior to the `ts_now`
+        self.process_raw_time_event_handlers(prior, ts_now, false, false);
+
+        // Set all clocks to now
+        logging_clock_set_static_time(ts_now.as_u64());
+        self.set_clocks_time(ts_now);
+
+        // Return all remaining events to be handled (at `ts_now`)
+        remaining
     }
 
     pub fn process_raw_time_event_handlers(
@@ -401,6 +906,10 @@ impl BacktestEngine {
                 continue;
             }
 
+            // Set all clocks to event timestamp
+            logging_clock_set_static_time(ts_event_init.as_u64());
+            self.set_clocks_time(ts_event_init);
+
             if last_ts_init != Some(ts_event_init) {
                 // First handler for this timestamp – process exchange queues beforehand.
                 for exchange in self.venues.values() {
@@ -413,16 +922,217 @@ impl BacktestEngine {
         }
     }
 
-    pub fn log_pre_run(&self) {
-        todo!("implement log_pre_run_diagnostics")
+    fn log_pre_run(&self) {
+        log_sysinfo(Ustr::from(stringify!(BacktestEngine)));
+
+        let color = Self::get_log_color_code();
+
+        for exchange in self.venues.values() {
+            let exchange = exchange.borrow();
+            log::info!("{color}=================================================================");
+            log::info!("{color} SimulatedVenue {}", exchange.id);
+            log::info!("{color}=================================================================");
+
+            let Some(account) = exchange.get_account() else {
+                continue;
+            };
+
+            log::info!("{account:?}");
+            log::info!("{color}-----------------------------------------------------------------");
+            log::info!("Balances starting:");
+
+            if exchange.is_frozen_account() {
+                log::warn!("ACCOUNT FROZEN");
+            } else {
+                for balance in account.starting_balances().values() {
+                    log::info!("{}", balance.to_formatted_string());
+                }
+            }
+        }
     }
 
-    pub fn log_run(&self) {
-        todo!("implement log_run")
+    fn log_run(&self, start: UnixNanos, end: UnixNanos) {
+        let color = Self::get_log_color_code();
+
+        log::info!("{color}=================================================================");
+        log::info!("{color} BACKTEST RUN");
+        log::info!("{color}=================================================================");
+        log::info!("Run config ID:  {}", format_optional(self.run_config_id));
+        log::info!("Run ID:         {}", format_optional(self.run_id));
+        log::info!(
+            "Run started:    {}",
+            format_optional_iso8601(self.run_started)
+        );
+        log::info!(
+            "Backtest start: {}",
+            format_optional_iso8601(self.backtest_start)
+        );
+        log::info!("Batch start:    {}", unix_nanos_to_iso8601(start));
+        log::info!("Batch end:      {}", unix_nanos_to_iso8601(end));
+        log::info!("{color}-----------------------------------------------------------------");
     }
 
-    pub fn log_post_run(&self) {
-        todo!("implement log_post_run")
+    fn log_post_run(&mut self) {
+        let elapsed_time = match (self.run_started, self.run_finished) {
+            (Some(started), Some(finished)) => Some(Duration::from_nanos(
+                finished.saturating_sub(started.as_u64()),
+            )),
+            _ => None,
+        };
+        let backtest_range = match (self.backtest_start, self.backtest_end) {
+            (Some(start), Some(end)) => {
+                Some(Duration::from_nanos(end.saturating_sub(start.as_u64())))
+            }
+            _ => None,
+        };
+
+        let color = Self::get_log_color_code();
+
+        log::info!("{color}=================================================================");
+        log::info!("{color} BACKTEST POST-RUN");
+        log::info!("{color}=================================================================");
+        log::info!("Run config ID:  {}", format_optional(self.run_config_id));
+        log::info!("Run ID:         {}", format_optional(self.run_id));
+        log::info!(
+            "Run started:    {}",
+            format_optional_iso8601(self.run_started)
+        );
+        log::info!(
+            "Run finished:   {}",
+            format_optional_iso8601(self.run_finished)
+        );
+        log::info!("Elapsed time:   {}", format_optional_duration(elapsed_time));
+        log::info!(
+            "Backtest start: {}",
+            format_optional_iso8601(self.backtest_start)
+        );
+        log::info!(
+            "Backtest end:   {}",
+            format_optional_iso8601(self.backtest_end)
+        );
+        log::info!(
+            "Backtest range: {}",
+            format_optional_duration(backtest_range)
+        );
+        log::info!("Iterations: {}", self.iteration.separate_with_underscores());
+
+        let (total_orders, total_positions) = {
+            let cache = self.kernel.cache.borrow();
+            (
+                cache.orders_total_count(None, None, None, None),
+                cache.positions_total_count(None, None, None, None),
+            )
+        };
+        log::info!("Total orders: {}", total_orders.separate_with_underscores());
+        log::info!(
+            "Total positions: {}",
+            total_positions.separate_with_underscores()
+        );
+
+        self.stats_pnls.clear();
+        self.stats_returns.clear();
+
+        if !self.config.run_analysis {
+            return;
+        }
+
+        for exchange in self.venues.values() {
+            let exchange = exchange.borrow();
+            log::info!("{color}=================================================================");
+            log::info!("{color} SimulatedVenue {}", exchange.id);
+            log::info!("{color}=================================================================");
+
+            let Some(account) = exchange.get_account() else {
+                continue;
+            };
+
+            log::info!("{account:?}");
+            log::info!("{color}-----------------------------------------------------------------");
+
+            if exchange.is_frozen_account() {
+                log::warn!("ACCOUNT FROZEN");
+                continue;
+            }
+
+            log::info!("Balances starting:");
+            for balance in account.starting_balances().values() {
+                log::info!("{}", balance.to_formatted_string());
+            }
+
+            log::info!("{color}-----------------------------------------------------------------");
+            log::info!("Balances ending:");
+            for balance in account.balances_total().values() {
+                log::info!("{}", balance.to_formatted_string());
+            }
+
+            exchange.log_diagnostics();
+
+            log::info!("{color}-----------------------------------------------------------------");
+
+            let positions: Vec<Position> = self
+                .kernel
+                .cache
+                .borrow()
+                .positions(Some(&exchange.id), None, None, None)
+                .into_iter()
+                .cloned()
+                .collect();
+
+            let mut analyzer = self.kernel.portfolio.analyzer_mut();
+            analyzer.calculate_statistics(&account, &positions);
+
+            // The analyzer holds the statistics of one account at a time
+            let mut stats_pnls = BTreeMap::new();
+            for currency in account.currencies() {
+                if let Ok(stats) = analyzer.get_performance_stats_pnls(Some(&currency), None) {
+                    stats_pnls.insert(currency.code.to_string(), stats.into_iter().collect());
+                }
+            }
+            self.stats_pnls.insert(exchange.id.to_string(), stats_pnls);
+            self.stats_returns.insert(
+                exchange.id.to_string(),
+                analyzer
+                    .get_performance_stats_returns()
+                    .into_iter()
+                    .collect(),
+            );
+
+            for currency in account.currencies() {
+                log::info!("PnL Statistics ({currency})");
+                log::info!(
+                    "{color}-----------------------------------------------------------------"
+                );
+                match analyzer.get_stats_pnls_formatted(Some(&currency), None) {
+                    Ok(stats) => {
+                        for stat in stats {
+                            log::info!("{stat}");
+                        }
+                    }
+                    Err(e) => log::error!("Failed to calculate PnL statistics for {currency}: {e}"),
+                }
+                log::info!(
+                    "{color}-----------------------------------------------------------------"
+                );
+            }
+
+            log::info!("Returns Statistics");
+            log::info!("{color}-----------------------------------------------------------------");
+            for stat in analyzer.get_stats_returns_formatted() {
+                log::info!("{stat}");
+            }
+            log::info!("{color}-----------------------------------------------------------------");
+
+            log::info!("General Statistics");
+            log::info!("{color}-----------------------------------------------------------------");
+            for stat in analyzer.get_stats_general_formatted() {
+                log::info!("{stat}");
+            }
+            log::info!("{color}-----------------------------------------------------------------");
+        }
+    }
+
+    fn get_log_color_code() -> &'static str {
+        if logging_is_colored() { "\x1b[36m" } else { "" }
     }
 
     pub fn add_data_client_if_not_exists(&mut self, client_id: ClientId) {
@@ -498,25 +1208,51 @@ impl BacktestEngine {
     }
 }
 
+fn format_optional<T: Display>(value: Option<T>) -> String {
+    value.map_or_else(|| "None".to_string(), |v| v.to_string())
+}
+
+fn format_optional_iso8601(unix_nanos: Option<UnixNanos>) -> String {
+    unix_nanos.map_or_else(|| "None".to_string(), unix_nanos_to_iso8601)
+}
+
+fn format_optional_duration(duration: Option<Duration>) -> String {
+    duration.map_or_else(|| "None".to_string(), |d| format!("{d:?}"))
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
 
-    use nautilus_execution::models::{fee::FeeModelAny, fill::FillModel};
+    use nautilus_core::UnixNanos;
+    use nautilus_execution::models::{
+        fee::{FeeModelAny, FixedFeeModel},
+        fill::{FillModelAny, VolumeCappedFillModel},
+        latency::{LatencyModelAny, StaticLatencyModel},
+    };
     use nautilus_model::{
+        data::{Data, HasTsInit, QuoteTick, stubs::stub_depth10},
         enums::{AccountType, BookType, OmsType},
         identifiers::{ClientId, Venue},
         instruments::{
             CryptoPerpetual, Instrument, InstrumentAny, stubs::crypto_perpetual_ethusdt,
         },
-        types::Money,
+        types::{Currency, Money, Price, Quantity},
     };
     use rstest::rstest;
 
-    use crate::{config::BacktestEngineConfig, engine::BacktestEngine};
+    use ustr::Ustr;
+
+    use crate::{
+        config::{BacktestEngineConfig, BacktestVenueConfig},
+        engine::BacktestEngine,
+    };
 
     #[allow(clippy::missing_panics_doc, reason = "OK for testing")]
-    fn get_backtest_engine(config: Option<BacktestEngineConfig>) -> BacktestEngine {
+    fn get_backtest_engine(
+        config: Option<BacktestEngineConfig>,
+        book_type: BookType,
+    ) -> BacktestEngine {
         let config = config.unwrap_or_default();
         let mut engine = BacktestEngine::new(config).unwrap();
         engine
@@ -524,13 +1260,13 @@ mod tests {
                 Venue::from("BINANCE"),
                 OmsType::Netting,
                 AccountType::Margin,
-                BookType::L2_MBP,
+                book_type,
                 vec![Money::from("1_000_000 USD")],
                 None,
                 None,
                 HashMap::new(),
                 vec![],
-                FillModel::default(),
+                FillModelAny::default(),
                 FeeModelAny::default(),
                 None,
                 None,
@@ -551,13 +1287,100 @@ mod tests {
         engine
     }
 
+    #[rstest]
+    fn test_add_venue_from_config_applies_models() {
+        let config = BacktestVenueConfig::new(
+            Ustr::from("BINANCE"),
+            OmsType::Netting,
+            AccountType::Margin,
+            BookType::L2_MBP,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            vec!["1_000_000 USD".to_string()],
+            None,
+            Some(5.0),
+            None,
+            Some(FillModelAny::VolumeCapped(
+                VolumeCappedFillModel::new(0.1).unwrap(),
+            )),
+            Some(FeeModelAny::Fixed(
+                FixedFeeModel::new(Money::from("1 USD"), None).unwrap(),
+            )),
+            Some(LatencyModelAny::Static(StaticLatencyModel::new(
+                UnixNanos::from(1_000),
+                UnixNanos::from(2_000),
+                UnixNanos::from(3_000),
+                UnixNanos::from(4_000),
+            ))),
+        );
+        let mut engine = BacktestEngine::new(BacktestEngineConfig::default()).unwrap();
+
+        engine.add_venue_from_config(&config).unwrap();
+
+        let exchange = engine.venues.get(&Venue::from("BINANCE")).unwrap().borrow();
+        assert!(matches!(
+            exchange.fill_model(),
+            FillModelAny::VolumeCapped(_)
+        ));
+        assert!(matches!(exchange.fee_model(), FeeModelAny::Fixed(_)));
+        assert!(matches!(
+            exchange.latency_model(),
+            Some(LatencyModelAny::Static(_))
+        ));
+    }
+
+    #[rstest]
+    fn test_add_venue_from_config_with_currency_leverages_errors() {
+        let config = BacktestVenueConfig::new(
+            Ustr::from("BINANCE"),
+            OmsType::Netting,
+            AccountType::Margin,
+            BookType::L2_MBP,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            vec!["1_000_000 USD".to_string()],
+            None,
+            None,
+            Some(HashMap::from([(Currency::USD(), 10.0)])),
+            None,
+            None,
+            None,
+        );
+        let mut engine = BacktestEngine::new(BacktestEngineConfig::default()).unwrap();
+
+        assert!(engine.add_venue_from_config(&config).is_err());
+        assert!(!engine.venues.contains_key(&Venue::from("BINANCE")));
+    }
+
     #[rstest]
     fn test_engine_venue_and_instrument_initialization(crypto_perpetual_ethusdt: CryptoPerpetual) {
         let venue = Venue::from("BINANCE");
         let client_id = ClientId::from(venue.as_str());
         let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
         let instrument_id = instrument.id();
-        let mut engine = get_backtest_engine(None);
+        let mut engine = get_backtest_engine(None, BookType::L2_MBP);
         engine.add_instrument(instrument).unwrap();
 
         // Check the venue and exec client has been added
@@ -590,4 +1413,277 @@ mod tests {
                 .contains(&client_id)
         );
     }
+
+    fn quotes(instrument: &CryptoPerpetual, timestamps: &[u64]) -> Vec<Data> {
+        timestamps
+            .iter()
+            .map(|ts| {
+                Data::Quote(QuoteTick::new(
+                    instrument.id(),
+                    Price::from("1000.00"),
+                    Price::from("1000.01"),
+                    Quantity::from("1.000"),
+                    Quantity::from("1.000"),
+                    UnixNanos::from(*ts),
+                    UnixNanos::from(*ts),
+                ))
+            })
+            .collect()
+    }
+
+    #[rstest]
+    fn test_run_processes_all_data(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let mut engine = get_backtest_engine(None, BookType::L1_MBP);
+        engine
+            .add_instrument(InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt))
+            .unwrap();
+        engine.add_data(
+            quotes(&crypto_perpetual_ethusdt, &[3, 1, 2]),
+            None,
+            true,
+            true,
+        );
+
+        engine.run(None, None, None, false).unwrap();
+
+        assert_eq!(engine.iteration, 3);
+        assert!(engine.run_id.is_some());
+        assert_eq!(engine.backtest_start, Some(UnixNanos::from(1)));
+        assert_eq!(engine.backtest_end, Some(UnixNanos::from(3)));
+        assert_eq!(
+            engine.kernel.clock.borrow().timestamp_ns(),
+            UnixNanos::from(3)
+        );
+        assert!(
+            engine
+                .kernel
+                .cache
+                .borrow()
+                .quote(&crypto_perpetual_ethusdt.id())
+                .is_some()
+        );
+    }
+
+    #[rstest]
+    fn test_get_result_after_run(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let mut engine = get_backtest_engine(None, BookType::L1_MBP);
+        engine
+            .add_instrument(InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt))
+            .unwrap();
+        engine.add_data(
+            quotes(&crypto_perpetual_ethusdt, &[1_000_000_000, 3_000_000_000]),
+            None,
+            true,
+            true,
+        );
+
+        engine.run(None, None, None, false).unwrap();
+        let result = engine.get_result();
+
+        assert_eq!(result.trader_id, engine.kernel.trader_id());
+        assert_eq!(result.instance_id, engine.instance_id);
+        assert_eq!(result.run_id, engine.run_id);
+        assert_eq!(result.backtest_start, Some(UnixNanos::from(1_000_000_000)));
+        assert_eq!(result.backtest_end, Some(UnixNanos::from(3_000_000_000)));
+        assert_eq!(result.elapsed_time, 2.0);
+        assert_eq!(result.iterations, 2);
+        assert_eq!(result.total_events, 0);
+        assert_eq!(result.total_orders, 0);
+        assert_eq!(result.total_positions, 0);
+        assert!(result.stats_pnls["BINANCE"].contains_key("USD"));
+        assert!(result.stats_returns.contains_key("BINANCE"));
+    }
+
+    #[rstest]
+    fn test_get_result_keeps_statistics_per_venue(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let mut engine = get_backtest_engine(None, BookType::L1_MBP);
+        engine
+            .add_venue(
+                Venue::from("SIM"),
+                OmsType::Netting,
+                AccountType::Margin,
+                BookType::L1_MBP,
+                vec![Money::from("1_000 EUR")],
+                None,
+                None,
+                HashMap::new(),
+                vec![],
+                FillModelAny::default(),
+                FeeModelAny::default(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        engine
+            .add_instrument(InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt))
+            .unwrap();
+        engine.add_data(
+            quotes(&crypto_perpetual_ethusdt, &[1_000_000_000, 3_000_000_000]),
+            None,
+            true,
+            true,
+        );
+
+        engine.run(None, None, None, false).unwrap();
+        let result = engine.get_result();
+
+        assert_eq!(result.stats_pnls.len(), 2);
+        assert!(result.stats_pnls["BINANCE"].contains_key("USD"));
+        assert!(result.stats_pnls["SIM"].contains_key("EUR"));
+        assert_eq!(result.stats_returns.len(), 2);
+    }
+
+    #[rstest]
+    fn test_run_with_start_and_end_bounds(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let mut engine = get_backtest_engine(None, BookType::L1_MBP);
+        engine
+            .add_instrument(InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt))
+            .unwrap();
+        engine.add_data(
+            quotes(&crypto_perpetual_ethusdt, &[1, 2, 3, 4, 5]),
+            None,
+            true,
+            true,
+        );
+
+        engine
+            .run(
+                Some(UnixNanos::from(2)),
+                Some(UnixNanos::from(4)),
+                None,
+                false,
+            )
+            .unwrap();
+
+        assert_eq!(engine.iteration, 3);
+        assert_eq!(engine.backtest_start, Some(UnixNanos::from(2)));
+    }
+
+    #[rstest]
+    fn test_run_with_start_after_data_returns_early(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let mut engine = get_backtest_engine(None, BookType::L1_MBP);
+        engine
+            .add_instrument(InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt))
+            .unwrap();
+        engine.add_data(
+            quotes(&crypto_perpetual_ethusdt, &[1, 2, 3]),
+            None,
+            true,
+            true,
+        );
+
+        engine
+            .run(Some(UnixNanos::from(10)), None, None, false)
+            .unwrap();
+
+        assert_eq!(engine.iteration, 0);
+        assert_eq!(engine.backtest_start, None);
+    }
+
+    #[rstest]
+    fn test_run_with_unsorted_data_returns_error(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let mut engine = get_backtest_engine(None, BookType::L1_MBP);
+        engine
+            .add_instrument(InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt))
+            .unwrap();
+        engine.add_data(
+            quotes(&crypto_perpetual_ethusdt, &[2, 1]),
+            None,
+            true,
+            false,
+        );
+
+        assert!(engine.run(None, None, None, false).is_err());
+
+        engine.sort_data();
+        assert!(engine.run(None, None, None, false).is_ok());
+    }
+
+    #[rstest]
+    fn test_run_without_book_data_for_depth_venue_returns_error(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+    ) {
+        let mut engine = get_backtest_engine(None, BookType::L2_MBP);
+        engine
+            .add_instrument(InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt))
+            .unwrap();
+        engine.add_data(quotes(&crypto_perpetual_ethusdt, &[1]), None, true, true);
+
+        assert!(engine.run(None, None, None, false).is_err());
+    }
+
+    #[rstest]
+    fn test_reset_retains_data_for_repeated_runs(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let mut engine = get_backtest_engine(None, BookType::L1_MBP);
+        engine
+            .add_instrument(InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt))
+            .unwrap();
+        engine.add_data(quotes(&crypto_perpetual_ethusdt, &[1, 2]), None, true, true);
+
+        engine.run(None, None, None, false).unwrap();
+        engine.reset();
+
+        assert_eq!(engine.iteration, 0);
+        assert!(engine.run_id.is_none());
+        assert_eq!(engine.stream_data().len(), 2);
+        assert!(engine.data.is_empty());
+
+        engine.run(None, None, None, false).unwrap();
+        assert_eq!(engine.iteration, 2);
+
+        engine.clear_data();
+        assert!(engine.stream_data().is_empty());
+    }
+
+    #[rstest]
+    fn test_sort_data_merges_added_data_into_stream(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let mut engine = get_backtest_engine(None, BookType::L1_MBP);
+        engine
+            .add_instrument(InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt))
+            .unwrap();
+        engine.add_data(quotes(&crypto_perpetual_ethusdt, &[1, 3]), None, true, true);
+        engine.add_data(quotes(&crypto_perpetual_ethusdt, &[2]), None, true, false);
+
+        assert_eq!(engine.data.len(), 1);
+
+        engine.sort_data();
+        let timestamps: Vec<u64> = engine
+            .stream_data()
+            .iter()
+            .map(|data| data.ts_init().as_u64())
+            .collect();
+
+        assert!(engine.data.is_empty());
+        assert_eq!(timestamps, vec![1, 2, 3]);
+    }
+
+    #[rstest]
+    fn test_run_processes_depth10_through_exchange(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let instrument_id = crypto_perpetual_ethusdt.id();
+        let mut engine = get_backtest_engine(None, BookType::L2_MBP);
+        engine
+            .add_instrument(InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt))
+            .unwrap();
+        let mut depth = stub_depth10();
+        depth.instrument_id = instrument_id;
+        engine.add_data(vec![Data::Depth10(Box::new(depth))], None, true, true);
+
+        engine.run(None, None, None, false).unwrap();
+
+        let venue = engine.venues.get(&instrument_id.venue).unwrap().borrow();
+        let matching_engine = venue.get_matching_engine(&instrument_id).unwrap();
+        assert_eq!(matching_engine.best_bid_price(), Some(Price::from("99.00")));
+    }
 }
@@ -34,22 +34,27 @@ use nautilus_core::{
 use nautilus_execution::{
     client::ExecutionClient,
     matching_engine::{config::OrderMatchingEngineConfig, engine::OrderMatchingEngine},
-    models::{fee::FeeModelAny, fill::FillModel, latency::LatencyModel},
+    models::{
+        fee::FeeModelAny,
+        fill::FillModelAny,
+        latency::{LatencyModelAny, OrderLatencyModel},
+    },
 };
 use nautilus_model::{
     accounts::AccountAny,
     data::{
-        Bar, Data, InstrumentStatus, OrderBookDelta, OrderBookDeltas, OrderBookDeltas_API,
-        QuoteTick, TradeTick,
+        Bar, Data, FundingRateUpdate, InstrumentStatus, OrderBookDelta, OrderBookDeltas,
+        OrderBookDeltas_API, OrderBookDepth10, QuoteTick, TradeTick,
     },
-    enums::{AccountType, BookType, OmsType},
+    enums::{AccountType, BookType, OmsType, PositionSide},
     identifiers::{InstrumentId, Venue},
     instruments::{Instrument, InstrumentAny},
     orderbook::OrderBook,
     orders::PassiveOrderAny,
+    position::Position,
     types::{AccountBalance, Currency, Money, Price},
 };
-use rust_decimal::Decimal;
+use rust_decimal::{Decimal, prelude::ToPrimitive};
 
 use crate::modules::SimulationModule;
 
@@ -114,8 +119,8 @@ pub struct SimulatedExchange {
     exec_client: Option<Rc<dyn ExecutionClient>>,
     pub base_currency: Option<Currency>,
     fee_model: FeeModelAny,
-    fill_model: FillModel,
-    latency_model: Option<LatencyModel>,
+    fill_model: FillModelAny,
+    latency_model: Option<LatencyModelAny>,
     instruments: HashMap<InstrumentId, InstrumentAny>,
     matching_engines: HashMap<InstrumentId, OrderMatchingEngine>,
     leverages: HashMap<InstrumentId, Decimal>,
@@ -125,6 +130,7 @@ pub struct SimulatedExchange {
     message_queue: VecDeque<TradingCommand>,
     inflight_queue: BinaryHeap<InflightCommand>,
     inflight_counter: HashMap<UnixNanos, u32>,
+    last_inflight_ts: UnixNanos,
     bar_execution: bool,
     reject_stop_orders: bool,
     support_gtd_orders: bool,
@@ -166,10 +172,10 @@ impl SimulatedExchange {
         modules: Vec<Box<dyn SimulationModule>>,
         cache: Rc<RefCell<Cache>>,
         clock: Rc<RefCell<dyn Clock>>,
-        fill_model: FillModel,
+        fill_model: FillModelAny,
         fee_model: FeeModelAny,
         book_type: BookType,
-        latency_model: Option<LatencyModel>,
+        latency_model: Option<LatencyModelAny>,
         bar_execution: Option<bool>,
         reject_stop_orders: Option<bool>,
         support_gtd_orders: Option<bool>,
@@ -187,8 +193,7 @@ impl SimulatedExchange {
         if base_currency.is_some() && starting_balances.len() > 1 {
             anyhow::bail!("single-currency account has multiple starting currencies")
         }
-        // TODO register and load modules
-        Ok(Self {
+        let mut exchange = Self {
             id: venue,
             oms_type,
             account_type,
@@ -209,6 +214,7 @@ impl SimulatedExchange {
             message_queue: VecDeque::new(),
             inflight_queue: BinaryHeap::new(),
             inflight_counter: HashMap::new(),
+            last_inflight_ts: UnixNanos::default(),
             bar_execution: bar_execution.unwrap_or(true),
             reject_stop_orders: reject_stop_orders.unwrap_or(true),
             support_gtd_orders: support_gtd_orders.unwrap_or(true),
@@ -219,14 +225,58 @@ impl SimulatedExchange {
             use_message_queue: use_message_queue.unwrap_or(true),
             allow_cash_borrowing: allow_cash_borrowing.unwrap_or(false),
             frozen_account: frozen_account.unwrap_or(false),
-        })
+        };
+
+        let mut modules = std::mem::take(&mut exchange.modules);
+        for module in &mut modules {
+            module.register_venue(&exchange);
+        }
+        exchange.modules = modules;
+
+        Ok(exchange)
+    }
+
+    /// Returns the order book type for the venue.
+    #[must_use]
+    pub const fn book_type(&self) -> BookType {
+        self.book_type
+    }
+
+    /// Returns whether the venue account is frozen.
+    #[must_use]
+    pub const fn is_frozen_account(&self) -> bool {
+        self.frozen_account
+    }
+
+    /// Returns the shared cache for the venue.
+    #[must_use]
+    pub const fn cache(&self) -> &Rc<RefCell<Cache>> {
+        &self.cache
+    }
+
+    /// Returns the fill model for the venue.
+    #[must_use]
+    pub const fn fill_model(&self) -> &FillModelAny {
+        &self.fill_model
+    }
+
+    /// Returns the fee model for the venue.
+    #[must_use]
+    pub const fn fee_model(&self) -> &FeeModelAny {
+        &self.fee_model
+    }
+
+    /// Returns the latency model for the venue (if any).
+    #[must_use]
+    pub const fn latency_model(&self) -> Option<&LatencyModelAny> {
+        self.latency_model.as_ref()
     }
 
     pub fn register_client(&mut self, client: Rc<dyn ExecutionClient>) {
         self.exec_client = Some(client);
     }
 
-    pub fn set_fill_model(&mut self, fill_model: FillModel) {
+    pub fn set_fill_model(&mut self, fill_model: FillModelAny) {
         for matching_engine in self.matching_engines.values_mut() {
             matching_engine.set_fill_model(fill_model.clone());
             log::info!(
@@ -238,7 +288,7 @@ impl SimulatedExchange {
         self.fill_model = fill_model;
     }
 
-    pub const fn set_latency_model(&mut self, latency_model: LatencyModel) {
+    pub fn set_latency_model(&mut self, latency_model: LatencyModelAny) {
         self.latency_model = Some(latency_model);
     }
 
@@ -461,25 +511,32 @@ impl SimulatedExchange {
         }
     }
 
+    /// Returns the arrival time and sequence counter for the given `command` at the venue.
+    ///
+    /// Commands arrive in the order they were sent, so a command never arrives before the
+    /// previously sent command, whatever latency was sampled for it.
+    ///
     /// # Panics
     ///
     /// Panics if the command is invalid when generating inflight command.
     pub fn generate_inflight_command(&mut self, command: &TradingCommand) -> (UnixNanos, u32) {
-        if let Some(latency_model) = &self.latency_model {
+        if let Some(latency_model) = &mut self.latency_model {
             let ts = match command {
                 TradingCommand::SubmitOrder(_) | TradingCommand::SubmitOrderList(_) => {
-                    command.ts_init() + latency_model.insert_latency_nanos
+                    command.ts_init() + latency_model.insert_latency(command.ts_init())
                 }
                 TradingCommand::ModifyOrder(_) => {
-                    command.ts_init() + latency_model.update_latency_nanos
+                    command.ts_init() + latency_model.update_latency(command.ts_init())
                 }
                 TradingCommand::CancelOrder(_)
                 | TradingCommand::CancelAllOrders(_)
                 | TradingCommand::BatchCancelOrders(_) => {
-                    command.ts_init() + latency_model.delete_latency_nanos
+                    command.ts_init() + latency_model.delete_latency(command.ts_init())
                 }
                 _ => panic!("Cannot handle command: {command:?}"),
             };
+            let ts = ts.max(self.last_inflight_ts);
+            self.last_inflight_ts = ts;
 
             let counter = self
                 .inflight_counter
@@ -497,8 +554,8 @@ impl SimulatedExchange {
     ///
     /// Panics if adding a missing instrument during delta processing fails.
     pub fn process_order_book_delta(&mut self, delta: OrderBookDelta) {
-        for module in &self.modules {
-            module.pre_process(Data::Delta(delta));
+        for module in &mut self.modules {
+            module.pre_process(&Data::Delta(delta));
         }
 
         if !self.matching_engines.contains_key(&delta.instrument_id) {
@@ -528,8 +585,11 @@ impl SimulatedExchange {
     ///
     /// Panics if adding a missing instrument during deltas processing fails.
     pub fn process_order_book_deltas(&mut self, deltas: OrderBookDeltas) {
-        for module in &self.modules {
-            module.pre_process(Data::Deltas(OrderBookDeltas_API::new(deltas.clone())));
+        if !self.modules.is_empty() {
+            let data = Data::Deltas(OrderBookDeltas_API::new(deltas.clone()));
+            for module in &mut self.modules {
+                module.pre_process(&data);
+            }
         }
 
         if !self.matching_engines.contains_key(&deltas.instrument_id) {
@@ -555,12 +615,54 @@ impl SimulatedExchange {
         }
     }
 
+    /// # Panics
+    ///
+    /// Panics if adding a missing instrument during depth processing fails.
+    pub fn process_order_book_depth10(&mut self, depth: &OrderBookDepth10) {
+        if !self.modules.is_empty() {
+            let data = Data::Depth10(Box::new(*depth));
+            for module in &mut self.modules {
+                module.pre_process(&data);
+            }
+        }
+
+        if !self.matching_engines.contains_key(&depth.instrument_id) {
+            let instrument = {
+                let cache = self.cache.as_ref().borrow();
+                cache.instrument(&depth.instrument_id).cloned()
+            };
+
+            if let Some(instrument) = instrument {
+                self.add_instrument(instrument).unwrap();
+            } else {
+                panic!(
+                    "No matching engine found for instrument {}",
+                    depth.instrument_id
+                );
+            }
+        }
+
+        if let Some(matching_engine) = self.matching_engines.get_mut(&depth.instrument_id) {
+            matching_engine.process_order_book_depth10(depth);
+        } else {
+            panic!("Matching engine should be initialized");
+        }
+    }
+
+    /// Processes data which is not simulated by the matching engines (mark and index prices,
+    /// and instrument closes), passing it to the simulation modules.
+    pub fn process_reference_data(&mut self, data: &Data) {
+        for module in &mut self.modules {
+            module.pre_process(data);
+        }
+    }
+
     /// # Panics
     ///
     /// Panics if adding a missing instrument during quote tick processing fails.
     pub fn process_quote_tick(&mut self, quote: &QuoteTick) {
-        for module in &self.modules {
-            module.pre_process(Data::Quote(quote.to_owned()));
+        for module in &mut self.modules {
+            module.pre_process(&Data::Quote(*quote));
         }
 
         if !self.matching_engines.contains_key(&quote.instrument_id) {
@@ -590,8 +692,8 @@ impl SimulatedExchange {
     ///
     /// Panics if adding a missing instrument during trade tick processing fails.
     pub fn process_trade_tick(&mut self, trade: &TradeTick) {
-        for module in &self.modules {
-            module.pre_process(Data::Trade(trade.to_owned()));
+        for module in &mut self.modules {
+            module.pre_process(&Data::Trade(*trade));
         }
 
         if !self.matching_engines.contains_key(&trade.instrument_id) {
@@ -621,8 +723,8 @@ impl SimulatedExchange {
     ///
     /// Panics if adding a missing instrument during bar processing fails.
     pub fn process_bar(&mut self, bar: Bar) {
-        for module in &self.modules {
-            module.pre_process(Data::Bar(bar));
+        for module in &mut self.modules {
+            module.pre_process(&Data::Bar(bar));
         }
 
         if !self.matching_engines.contains_key(&bar.instrument_id()) {
@@ -652,7 +754,9 @@ impl SimulatedExchange {
     ///
     /// Panics if adding a missing instrument during instrument status processing fails.
     pub fn process_instrument_status(&mut self, status: InstrumentStatus) {
-        // TODO add module preprocessing
+        for module in &mut self.modules {
+            module.pre_process_status(&status);
+        }
 
         if !self.matching_engines.contains_key(&status.instrument_id) {
             let instrument = {
@@ -677,6 +781,89 @@ impl SimulatedExchange {
         }
     }
 
+    /// Applies a funding rate to all open perpetual positions for the instrument at this venue.
+    ///
+    /// Long positions pay `notional * rate` and short positions receive it (a negative rate
+    /// reverses the flow), with the notional valued at the latest mark price, falling back to
+    /// the venue mid price. Each payment adjusts the account balance and is recorded on the
+    /// position so that it is included in the realized PnL, positions which cannot record the
+    /// payment are skipped.
+    ///
+    /// Funding rates are not part of the engine data stream, so this is only called when a
+    /// [`FundingRateModule`](crate::modules::FundingRateModule) is added to the venue.
+    ///
+    /// Returns the payments applied (positive values were received).
+    pub fn process_funding_rate(&mut self, funding_rate: &FundingRateUpdate) -> Vec<Money> {
+        for module in &mut self.modules {
+            module.pre_process_funding_rate(funding_rate);
+        }
+
+        let instrument_id = funding_rate.instrument_id;
+        let Some(instrument @ InstrumentAny::CryptoPerpetual(_)) =
+            self.cache.borrow().instrument(&instrument_id).cloned()
+        else {
+            log::debug!("Ignoring funding rate for non-perpetual instrument {instrument_id}");
+            return Vec::new();
+        };
+
+        let positions: Vec<Position> = self
+            .cache
+            .borrow()
+            .positions_open(Some(&self.id), Some(&instrument_id), None, None)
+            .into_iter()
+            .cloned()
+            .collect();
+        if positions.is_empty() {
+            return Vec::new();
+        }
+
+        let mark_price = self
+            .cache
+            .borrow()
+            .mark_price(&instrument_id)
+            .map(|mark| mark.value);
+        let Some(price) = mark_price.or_else(|| self.mid_price(&instrument)) else {
+            log::warn!("Cannot apply funding for {instrument_id}: no price available");
+            return Vec::new();
+        };
+        let Some(rate) = funding_rate.rate.to_f64() else {
+            return Vec::new();
+        };
+
+        let mut payments = Vec::with_capacity(positions.len());
+        for mut position in positions {
+            let notional = instrument.calculate_notional_value(position.quantity, price, None);
+            let amount = match position.side {
+                PositionSide::Long => -notional.as_f64() * rate,
+                PositionSide::Short => notional.as_f64() * rate,
+                _ => continue,
+            };
+            let payment = Money::new(amount, notional.currency);
+
+            if let Err(e) = position.apply_funding(payment) {
+                log::warn!("Cannot apply funding for position {}: {e}", position.id);
+                continue;
+            }
+            if let Err(e) = self.cache.borrow_mut().update_position(&position) {
+                log::error!("Cannot record funding for position {}: {e}", position.id);
+            }
+
+            log::debug!("Applying funding {payment} for position {}", position.id);
+            self.adjust_account(payment);
+            payments.push(payment);
+        }
+
+        payments
+    }
+
+    /// Returns the mid price from the top of the book for the instrument (if both sides exist).
+    #[must_use]
+    pub fn mid_price(&self, instrument: &InstrumentAny) -> Option<Price> {
+        let bid = self.best_bid_price(instrument.id())?;
+        let ask = self.best_ask_price(instrument.id())?;
+        Some(instrument.make_price(f64::midpoint(bid.as_f64(), ask.as_f64())))
+    }
+
     /// # Panics
     ///
     /// Panics if popping an inflight command fails during processing.
@@ -698,10 +885,24 @@ impl SimulatedExchange {
         while let Some(command) = self.message_queue.pop_front() {
             self.process_trading_command(command);
         }
+
+        // Modules may act on the exchange, so they are detached while processing
+        let mut modules = std::mem::take(&mut self.modules);
+        for module in &mut modules {
+            module.process(ts_now, self);
+        }
+        self.modules = modules;
     }
 
-    pub fn reset(&mut self) {
+    /// Logs diagnostic information for all registered simulation modules.
+    pub fn log_diagnostics(&self) {
         for module in &self.modules {
+            module.log_diagnostics();
+        }
+    }
+
+    pub fn reset(&mut self) {
+        for module in &mut self.modules {
             module.reset();
         }
 
@@ -711,7 +912,11 @@ impl SimulatedExchange {
             matching_engine.reset();
         }
 
-        // TODO Clear the inflight and message queues
+        self.message_queue.clear();
+        self.inflight_queue.clear();
+        self.inflight_counter.clear();
+        self.last_inflight_ts = UnixNanos::default();
+
         log::info!("Resetting exchange state");
     }
 
@@ -809,14 +1014,16 @@ mod tests {
     use nautilus_core::{AtomicTime, UUID4, UnixNanos};
     use nautilus_execution::models::{
         fee::{FeeModelAny, MakerTakerFeeModel},
-        fill::FillModel,
-        latency::LatencyModel,
+        fill::FillModelAny,
+        latency::{
+            LatencyDistribution, LatencyModelAny, StaticLatencyModel, StochasticLatencyModel,
+        },
     };
     use nautilus_model::{
         accounts::{AccountAny, MarginAccount},
         data::{
-            Bar, BarType, BookOrder, InstrumentStatus, OrderBookDelta, OrderBookDeltas, QuoteTick,
-            TradeTick,
+            Bar, BarType, BookOrder, Data, FundingRateUpdate, InstrumentStatus, MarkPriceUpdate,
+            OrderBookDelta, OrderBookDeltas, QuoteTick, TradeTick,
         },
         enums::{
             AccountType, AggressorSide, BookAction, BookType, MarketStatus, MarketStatusAction,
@@ -827,17 +1034,58 @@ mod tests {
             AccountId, ClientId, ClientOrderId, InstrumentId, StrategyId, TradeId, TraderId, Venue,
             VenueOrderId,
         },
-        instruments::{CryptoPerpetual, InstrumentAny, stubs::crypto_perpetual_ethusdt},
-        orders::OrderTestBuilder,
+        instruments::{
+            CryptoPerpetual, Instrument, InstrumentAny,
+            stubs::{audusd_sim, crypto_perpetual_ethusdt},
+        },
+        orders::{OrderTestBuilder, stubs::TestOrderEventStubs},
+        position::Position,
         types::{AccountBalance, Currency, Money, Price, Quantity},
     };
     use rstest::rstest;
+    use rust_decimal::Decimal;
 
     use crate::{
         exchange::{InflightCommand, SimulatedExchange},
         execution_client::BacktestExecutionClient,
+        modules::{FundingRateModule, SimulationModule},
     };
 
+    #[derive(Default)]
+    struct RecordingModule {
+        calls: Rc<RefCell<Vec<String>>>,
+    }
+
+    impl SimulationModule for RecordingModule {
+        fn register_venue(&mut self, exchange: &SimulatedExchange) {
+            self.calls
+                .borrow_mut()
+                .push(format!("register_venue {}", exchange.id));
+        }
+
+        fn pre_process(&mut self, data: &Data) {
+            self.calls
+                .borrow_mut()
+                .push(format!("pre_process {}", data.instrument_id()));
+        }
+
+        fn pre_process_status(&mut self, status: &InstrumentStatus) {
+            self.calls
+                .borrow_mut()
+                .push(format!("pre_process_status {}", status.instrument_id));
+        }
+
+        fn process(&mut self, ts_now: UnixNanos, _exchange: &mut SimulatedExchange) {
+            self.calls.borrow_mut().push(format!("process {ts_now}"));
+        }
+
+        fn log_diagnostics(&self) {}
+
+        fn reset(&mut self) {
+            self.calls.borrow_mut().push("reset".to_string());
+        }
+    }
+
     static ATOMIC_TIME: LazyLock<AtomicTime> =
         LazyLock::new(|| AtomicTime::new(true, UnixNanos::default()));
 
@@ -846,6 +1094,16 @@ mod tests {
         account_type: AccountType,
         book_type: BookType,
         cache: Option<Rc<RefCell<Cache>>>,
+    ) -> Rc<RefCell<SimulatedExchange>> {
+        get_exchange_with_modules(venue, account_type, book_type, cache, vec![])
+    }
+
+    fn get_exchange_with_modules(
+        venue: Venue,
+        account_type: AccountType,
+        book_type: BookType,
+        cache: Option<Rc<RefCell<Cache>>>,
+        modules: Vec<Box<dyn SimulationModule>>,
     ) -> Rc<RefCell<SimulatedExchange>> {
         let cache = cache.unwrap_or(Rc::new(RefCell::new(Cache::default())));
         let clock = Rc::new(RefCell::new(TestClock::new()));
@@ -858,10 +1116,10 @@ mod tests {
                 None,
                 1.into(),
                 HashMap::new(),
-                vec![],
+                modules,
                 cache.clone(),
                 clock,
-                FillModel::default(),
+                FillModelAny::default(),
                 FeeModelAny::MakerTaker(MakerTakerFeeModel),
                 book_type,
                 None,
@@ -1408,12 +1666,12 @@ mod tests {
 
     #[rstest]
     fn test_process_with_latency_model(crypto_perpetual_ethusdt: CryptoPerpetual) {
-        let latency_model = LatencyModel::new(
+        let latency_model = LatencyModelAny::Static(StaticLatencyModel::new(
             UnixNanos::from(100),
             UnixNanos::from(200),
             UnixNanos::from(300),
             UnixNanos::from(100),
-        );
+        ));
         let exchange = get_exchange(
             Venue::new("BINANCE"),
             AccountType::Margin,
@@ -1433,24 +1691,301 @@ mod tests {
         // Verify that inflight queue has 2 commands and message queue is empty
         assert_eq!(exchange.borrow().message_queue.len(), 0);
         assert_eq!(exchange.borrow().inflight_queue.len(), 2);
-        // First inflight command should have timestamp at 100 and 200 insert latency
+        // First inflight command should have timestamp at 100 with 100 base and 200 insert latency
         assert_eq!(
             exchange.borrow().inflight_queue.iter().next().unwrap().ts,
-            UnixNanos::from(300)
+            UnixNanos::from(400)
         );
-        // Second inflight command should have timestamp at 150 and 200 insert latency
+        // Second inflight command should have timestamp at 150 with 100 base and 200 insert latency
         assert_eq!(
             exchange.borrow().inflight_queue.iter().nth(1).unwrap().ts,
-            UnixNanos::from(350)
+            UnixNanos::from(450)
         );
 
-        // Process at timestamp 350, and test that only first command is processed
-        exchange.borrow_mut().process(UnixNanos::from(320));
+        // Process at timestamp 420, and test that only first command is processed
+        exchange.borrow_mut().process(UnixNanos::from(420));
         assert_eq!(exchange.borrow().message_queue.len(), 0);
         assert_eq!(exchange.borrow().inflight_queue.len(), 1);
         assert_eq!(
             exchange.borrow().inflight_queue.iter().next().unwrap().ts,
-            UnixNanos::from(350)
+            UnixNanos::from(450)
+        );
+    }
+
+    #[rstest]
+    fn test_inflight_commands_arrive_in_send_order() {
+        let latency_model = LatencyModelAny::Stochastic(StochasticLatencyModel::new(
+            LatencyDistribution::Fixed(0),
+            LatencyDistribution::normal(1_000.0, 500.0).unwrap(),
+            LatencyDistribution::Fixed(0),
+            LatencyDistribution::Fixed(0),
+            Some(42),
+        ));
+        let exchange = get_exchange(
+            Venue::new("BINANCE"),
+            AccountType::Margin,
+            BookType::L2_MBP,
+            None,
+        );
+        exchange.borrow_mut().set_latency_model(latency_model);
+
+        let arrivals: Vec<UnixNanos> = (0..50)
+            .map(|i| {
+                let command = create_submit_order_command(UnixNanos::from(100 + i));
+                exchange.borrow_mut().generate_inflight_command(&command).0
+            })
+            .collect();
+
+        assert!(arrivals.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+
+    #[rstest]
+    fn test_simulation_module_lifecycle(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let module = RecordingModule {
+            calls: calls.clone(),
+        };
+        let exchange = get_exchange_with_modules(
+            Venue::new("BINANCE"),
+            AccountType::Margin,
+            BookType::L1_MBP,
+            None,
+            vec![Box::new(module)],
+        );
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        exchange.borrow_mut().add_instrument(instrument).unwrap();
+
+        let quote = QuoteTick::new(
+            crypto_perpetual_ethusdt.id,
+            Price::from("1000.00"),
+            Price::from("1001.00"),
+            Quantity::from("1.000"),
+            Quantity::from("1.000"),
+            UnixNanos::from(1),
+            UnixNanos::from(1),
+        );
+        let status = InstrumentStatus::new(
+            crypto_perpetual_ethusdt.id,
+            MarketStatusAction::Trading,
+            UnixNanos::from(2),
+            UnixNanos::from(2),
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        exchange.borrow_mut().process_quote_tick(&quote);
+        exchange.borrow_mut().process_instrument_status(status);
+        exchange.borrow_mut().process(UnixNanos::from(3));
+
+        assert_eq!(
+            *calls.borrow(),
+            vec![
+                "register_venue BINANCE".to_string(),
+                "pre_process ETHUSDT-PERP.BINANCE".to_string(),
+                "pre_process_status ETHUSDT-PERP.BINANCE".to_string(),
+                "process 3".to_string(),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_funding_rate_module_settles_open_position(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let mut cache = Cache::default();
+        let handler = get_message_saving_handler::<AccountState>(None);
+        msgbus::register("Portfolio.update_account".into(), handler.clone());
+        let margin_account = MarginAccount::new(
+            AccountState::new(
+                AccountId::from("BINANCE-001"),
+                AccountType::Margin,
+                vec![AccountBalance::new(
+                    Money::from("1000 USDT"),
+                    Money::from("0 USDT"),
+                    Money::from("1000 USDT"),
+                )],
+                vec![],
+                false,
+                UUID4::default(),
+                UnixNanos::default(),
+                UnixNanos::default(),
+                None,
+            ),
+            false,
+        );
+        cache
+            .add_account(AccountAny::Margin(margin_account))
+            .unwrap();
+        cache.add_instrument(instrument.clone()).unwrap();
+
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("2.000"))
+            .build();
+        let filled = TestOrderEventStubs::filled(
+            &order,
+            &instrument,
+            None,
+            None,
+            Some(Price::from("1500.00")),
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let position = Position::new(&instrument, filled.into());
+        let position_id = position.id;
+        let realized_pnl = position.realized_pnl.unwrap();
+        cache.add_position(position, OmsType::Netting).unwrap();
+        cache
+            .add_mark_price(MarkPriceUpdate::new(
+                instrument.id(),
+                Price::from("2000.00"),
+                UnixNanos::from(1),
+                UnixNanos::from(1),
+            ))
+            .unwrap();
+        cache.build_index();
+
+        let funding_rate = FundingRateUpdate::new(
+            instrument.id(),
+            Decimal::new(1, 4),
+            Some(UnixNanos::from(100)),
+            UnixNanos::from(1),
+            UnixNanos::from(1),
+        );
+        let cache = Rc::new(RefCell::new(cache));
+        let exchange = get_exchange_with_modules(
+            Venue::new("BINANCE"),
+            AccountType::Margin,
+            BookType::L1_MBP,
+            Some(cache.clone()),
+            vec![Box::new(FundingRateModule::new(vec![funding_rate]))],
+        );
+
+        // Not yet funding time
+        exchange.borrow_mut().process(UnixNanos::from(50));
+        assert!(get_saved_messages::<AccountState>(handler.clone()).is_empty());
+
+        // Settles once: long 2 ETH at mark 2000 pays 0.0001 * 4000 USDT
+        exchange.borrow_mut().process(UnixNanos::from(100));
+        exchange.borrow_mut().process(UnixNanos::from(200));
+
+        let messages = get_saved_messages::<AccountState>(handler);
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].balances[0].total, Money::from("999.6 USDT"));
+
+        // Funding is recorded on the position and included in its realized PnL
+        let cache = cache.borrow();
+        let position = cache.position(&position_id).unwrap();
+        assert_eq!(position.funding_pnl, Some(Money::from("-0.4 USDT")));
+        assert_eq!(
+            position.realized_pnl,
+            Some(realized_pnl - Money::from("0.4 USDT"))
+        );
+    }
+
+    #[rstest]
+    fn test_process_funding_rate_skips_position_with_other_settlement_currency(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+    ) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let mut cache = Cache::default();
+        let handler = get_message_saving_handler::<AccountState>(None);
+        msgbus::register("Portfolio.update_account".into(), handler.clone());
+        cache.add_instrument(instrument.clone()).unwrap();
+
+        // Position opened while the instrument settled in USDC
+        let mut usdc_perpetual = crypto_perpetual_ethusdt;
+        usdc_perpetual.quote_currency = Currency::USDC();
+        usdc_perpetual.settlement_currency = Currency::USDC();
+        let usdc_instrument = InstrumentAny::CryptoPerpetual(usdc_perpetual);
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("2.000"))
+            .build();
+        let filled = TestOrderEventStubs::filled(
+            &order,
+            &usdc_instrument,
+            None,
+            None,
+            Some(Price::from("1500.00")),
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let position = Position::new(&usdc_instrument, filled.into());
+        let position_id = position.id;
+        cache.add_position(position, OmsType::Netting).unwrap();
+        cache
+            .add_mark_price(MarkPriceUpdate::new(
+                instrument.id(),
+                Price::from("2000.00"),
+                UnixNanos::from(1),
+                UnixNanos::from(1),
+            ))
+            .unwrap();
+        cache.build_index();
+
+        let cache = Rc::new(RefCell::new(cache));
+        let exchange = get_exchange(
+            Venue::new("BINANCE"),
+            AccountType::Margin,
+            BookType::L1_MBP,
+            Some(cache.clone()),
+        );
+        let funding_rate = FundingRateUpdate::new(
+            instrument.id(),
+            Decimal::new(1, 4),
+            None,
+            UnixNanos::from(1),
+            UnixNanos::from(1),
+        );
+
+        let payments = exchange.borrow_mut().process_funding_rate(&funding_rate);
+
+        assert!(payments.is_empty());
+        assert!(get_saved_messages::<AccountState>(handler).is_empty());
+        assert_eq!(
+            cache.borrow().position(&position_id).unwrap().funding_pnl,
+            None
+        );
+    }
+
+    #[rstest]
+    fn test_process_funding_rate_ignores_non_perpetual() {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim());
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        cache
+            .borrow_mut()
+            .add_instrument(instrument.clone())
+            .unwrap();
+        let exchange = get_exchange(
+            Venue::new("SIM"),
+            AccountType::Margin,
+            BookType::L1_MBP,
+            Some(cache),
+        );
+
+        let funding_rate = FundingRateUpdate::new(
+            instrument.id(),
+            Decimal::new(1, 4),
+            None,
+            UnixNanos::from(1),
+            UnixNanos::from(1),
+        );
+
+        assert!(
+            exchange
+                .borrow_mut()
+                .process_funding_rate(&funding_rate)
+                .is_empty()
         );
     }
 }
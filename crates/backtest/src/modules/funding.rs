@@ -0,0 +1,102 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Funding rate settlement for perpetual swap positions.
+
+use std::collections::HashMap;
+
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    data::{Data, FundingRateUpdate},
+    types::{Currency, Money},
+};
+
+use super::SimulationModule;
+use crate::exchange::SimulatedExchange;
+
+/// Settles funding payments on open perpetual positions from a schedule of funding rates.
+///
+/// Each rate is applied through [`SimulatedExchange::process_funding_rate`] once the simulation
+/// reaches its funding time (`next_funding_ns`, or `ts_event` if not set).
+///
+/// Funding rates cannot be added to the engine as data, this module is required for a venue
+/// to settle funding at all.
+#[derive(Debug)]
+pub struct FundingRateModule {
+    schedule: Vec<FundingRateUpdate>,
+    next_index: usize,
+    totals: HashMap<Currency, f64>,
+}
+
+impl FundingRateModule {
+    /// Creates a new [`FundingRateModule`] instance.
+    #[must_use]
+    pub fn new(mut funding_rates: Vec<FundingRateUpdate>) -> Self {
+        funding_rates.sort_by_key(Self::funding_time);
+        Self {
+            schedule: funding_rates,
+            next_index: 0,
+            totals: HashMap::new(),
+        }
+    }
+
+    /// Returns the total funding settled per currency (positive values were received).
+    #[must_use]
+    pub const fn totals(&self) -> &HashMap<Currency, f64> {
+        &self.totals
+    }
+
+    fn funding_time(funding_rate: &FundingRateUpdate) -> UnixNanos {
+        funding_rate
+            .next_funding_ns
+            .unwrap_or(funding_rate.ts_event)
+    }
+}
+
+impl SimulationModule for FundingRateModule {
+    fn register_venue(&mut self, exchange: &SimulatedExchange) {
+        self.schedule
+            .retain(|funding_rate| funding_rate.instrument_id.venue == exchange.id);
+    }
+
+    fn pre_process(&mut self, _data: &Data) {}
+
+    fn process(&mut self, ts_now: UnixNanos, exchange: &mut SimulatedExchange) {
+        while let Some(funding_rate) = self.schedule.get(self.next_index).copied() {
+            if Self::funding_time(&funding_rate) > ts_now {
+                break;
+            }
+            self.next_index += 1;
+
+            for payment in exchange.process_funding_rate(&funding_rate) {
+                *self.totals.entry(payment.currency).or_default() += payment.as_f64();
+            }
+        }
+    }
+
+    fn log_diagnostics(&self) {
+        for (currency, total) in &self.totals {
+            log::info!(
+                "Funding total: {}",
+                Money::new(*total, *currency).to_formatted_string()
+            );
+        }
+    }
+
+    fn reset(&mut self) {
+        self.next_index = 0;
+        self.totals.clear();
+    }
+}
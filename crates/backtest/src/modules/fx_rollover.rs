@@ -0,0 +1,391 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Overnight rollover interest for spot FX positions.
+
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::{DateTime, Datelike, NaiveDate, NaiveTime, Weekday};
+use chrono_tz::America::New_York;
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    data::Data,
+    enums::{PositionSide, PriceType},
+    identifiers::Venue,
+    instruments::{Instrument, InstrumentAny},
+    types::{Currency, Money},
+};
+
+use super::SimulationModule;
+use crate::exchange::SimulatedExchange;
+
+/// Applies overnight rollover interest to open spot FX positions.
+///
+/// Rollover is booked once per trading day at 17:00 New York time. A position earns the
+/// interest rate of the currency it is long and pays the rate of the currency it is short,
+/// on its notional value at the venue mid price over a 365 day year. Wednesday rollovers are
+/// booked three times to cover the weekend, and no rollover is booked on weekends.
+///
+/// Interest rates are annual rates as decimal fractions (e.g. 0.05 for 5%), each effective from
+/// its timestamp until the next rate for the same currency.
+#[derive(Debug, Default)]
+pub struct FXRolloverInterestModule {
+    rates: HashMap<Currency, BTreeMap<UnixNanos, f64>>,
+    venue: Option<Venue>,
+    base_currency: Option<Currency>,
+    last_rollover_date: Option<NaiveDate>,
+    totals: HashMap<Currency, f64>,
+}
+
+impl FXRolloverInterestModule {
+    /// Creates a new [`FXRolloverInterestModule`] instance with no interest rates.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an annual interest rate for the `currency`, effective from `ts_effective`.
+    pub fn add_rate(&mut self, currency: Currency, ts_effective: UnixNanos, rate: f64) {
+        self.rates
+            .entry(currency)
+            .or_default()
+            .insert(ts_effective, rate);
+    }
+
+    /// Returns the annual interest rate for the `currency` in effect at `ts` (if any).
+    #[must_use]
+    pub fn rate(&self, currency: &Currency, ts: UnixNanos) -> Option<f64> {
+        self.rates
+            .get(currency)?
+            .range(..=ts)
+            .next_back()
+            .map(|(_, rate)| *rate)
+    }
+
+    /// Returns the total rollover interest applied per currency (positive values were received).
+    #[must_use]
+    pub const fn totals(&self) -> &HashMap<Currency, f64> {
+        &self.totals
+    }
+
+    fn apply_rollover(&mut self, ts_now: UnixNanos, days: f64, exchange: &mut SimulatedExchange) {
+        let positions: Vec<(InstrumentAny, PositionSide, f64)> = {
+            let cache = exchange.cache().borrow();
+            cache
+                .positions_open(self.venue.as_ref(), None, None, None)
+                .into_iter()
+                .filter_map(|position| {
+                    let instrument = cache.instrument(&position.instrument_id)?;
+                    matches!(instrument, InstrumentAny::CurrencyPair(_)).then(|| {
+                        (
+                            instrument.clone(),
+                            position.side,
+                            position.quantity.as_f64(),
+                        )
+                    })
+                })
+                .collect()
+        };
+
+        for (instrument, side, quantity) in positions {
+            let Some(base) = instrument.base_currency() else {
+                continue;
+            };
+            let quote = instrument.quote_currency();
+
+            let (Some(base_rate), Some(quote_rate)) =
+                (self.rate(&base, ts_now), self.rate(&quote, ts_now))
+            else {
+                log::warn!(
+                    "Cannot apply rollover for {}: no interest rate data",
+                    instrument.id()
+                );
+                continue;
+            };
+            let Some(mid) = exchange.mid_price(&instrument) else {
+                continue;
+            };
+
+            let direction = match side {
+                PositionSide::Long => 1.0,
+                PositionSide::Short => -1.0,
+                _ => continue,
+            };
+            let mut rollover =
+                direction * quantity * mid.as_f64() * (base_rate - quote_rate) / 365.0 * days;
+            let mut currency = quote;
+
+            if let Some(base_currency) = self.base_currency
+                && base_currency != quote
+            {
+                let venue = instrument.id().venue;
+                let xrate = exchange.cache().borrow().get_xrate(
+                    venue,
+                    quote,
+                    base_currency,
+                    PriceType::Mid,
+                );
+                let Some(xrate) = xrate else {
+                    log::warn!("Cannot apply rollover: no xrate for {quote}/{base_currency}");
+                    continue;
+                };
+                rollover *= xrate;
+                currency = base_currency;
+            }
+
+            *self.totals.entry(currency).or_default() += rollover;
+            exchange.adjust_account(Money::new(rollover, currency));
+        }
+    }
+}
+
+impl SimulationModule for FXRolloverInterestModule {
+    fn register_venue(&mut self, exchange: &SimulatedExchange) {
+        self.venue = Some(exchange.id);
+        self.base_currency = exchange.base_currency;
+    }
+
+    fn pre_process(&mut self, _data: &Data) {}
+
+    fn process(&mut self, ts_now: UnixNanos, exchange: &mut SimulatedExchange) {
+        let local = DateTime::from_timestamp_nanos(ts_now.as_i64()).with_timezone(&New_York);
+        let date = local.date_naive();
+
+        if self.last_rollover_date == Some(date) || local.time() < rollover_time() {
+            return;
+        }
+        self.last_rollover_date = Some(date);
+
+        let days = match local.weekday() {
+            Weekday::Sat | Weekday::Sun => return,
+            Weekday::Wed => 3.0,
+            _ => 1.0,
+        };
+        self.apply_rollover(ts_now, days, exchange);
+    }
+
+    fn log_diagnostics(&self) {
+        for (currency, total) in &self.totals {
+            log::info!(
+                "Rollover interest (total): {}",
+                Money::new(*total, *currency).to_formatted_string()
+            );
+        }
+    }
+
+    fn reset(&mut self) {
+        self.last_rollover_date = None;
+        self.totals.clear();
+    }
+}
+
+fn rollover_time() -> NaiveTime {
+    NaiveTime::from_hms_opt(17, 0, 0).expect("valid time")
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use nautilus_common::{cache::Cache, clock::TestClock};
+    use nautilus_execution::models::{fee::FeeModelAny, fill::FillModelAny};
+    use nautilus_model::{
+        data::QuoteTick,
+        enums::{AccountType, BookType, OmsType, OrderSide, OrderType},
+        instruments::stubs::audusd_sim,
+        orders::{OrderTestBuilder, stubs::TestOrderEventStubs},
+        position::Position,
+        types::{Price, Quantity},
+    };
+    use rstest::rstest;
+    use rust_decimal::Decimal;
+
+    use super::*;
+
+    // 2024-01-02 is a Tuesday, rollover is at 17:00 New York time (22:00 UTC)
+    const TUESDAY_ROLLOVER_NS: u64 = 1_704_232_800_000_000_000;
+    const ONE_DAY_NS: u64 = 86_400_000_000_000;
+
+    /// Returns an exchange holding a 365,000 AUD/USD position at a mid price of 0.80.
+    fn exchange_with_position(
+        side: OrderSide,
+        base_currency: Option<Currency>,
+    ) -> SimulatedExchange {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim());
+        let quote = QuoteTick::new(
+            instrument.id(),
+            Price::from("0.79999"),
+            Price::from("0.80001"),
+            Quantity::from(1_000_000),
+            Quantity::from(1_000_000),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        );
+
+        let mut cache = Cache::default();
+        cache.add_instrument(instrument.clone()).unwrap();
+        cache.add_quote(quote).unwrap();
+
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(side)
+            .quantity(Quantity::from(365_000))
+            .build();
+        let filled = TestOrderEventStubs::filled(
+            &order,
+            &instrument,
+            None,
+            None,
+            Some(Price::from("0.80000")),
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let position = Position::new(&instrument, filled.into());
+        cache.add_position(position, OmsType::Netting).unwrap();
+        cache.build_index();
+
+        let starting_balance = Money::new(1_000_000.0, base_currency.unwrap_or(Currency::USD()));
+        let mut exchange = SimulatedExchange::new(
+            Venue::new("SIM"),
+            OmsType::Netting,
+            AccountType::Margin,
+            vec![starting_balance],
+            base_currency,
+            Decimal::ONE,
+            HashMap::new(),
+            vec![],
+            Rc::new(RefCell::new(cache)),
+            Rc::new(RefCell::new(TestClock::new())),
+            FillModelAny::default(),
+            FeeModelAny::default(),
+            BookType::L1_MBP,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        exchange.add_instrument(instrument).unwrap();
+        exchange.process_quote_tick(&quote);
+        exchange
+    }
+
+    fn rollover_module(exchange: &SimulatedExchange) -> FXRolloverInterestModule {
+        let mut module = FXRolloverInterestModule::new();
+        module.add_rate(Currency::AUD(), UnixNanos::default(), 0.06);
+        module.add_rate(Currency::USD(), UnixNanos::default(), 0.02);
+        module.register_venue(exchange);
+        module
+    }
+
+    fn total(module: &FXRolloverInterestModule, currency: Currency) -> f64 {
+        module.totals().get(&currency).copied().unwrap_or_default()
+    }
+
+    #[rstest]
+    #[case(50, None)]
+    #[case(100, Some(0.05))]
+    #[case(150, Some(0.05))]
+    #[case(200, Some(0.0525))]
+    fn test_rate_in_effect(#[case] ts: u64, #[case] expected: Option<f64>) {
+        let mut module = FXRolloverInterestModule::new();
+        module.add_rate(Currency::USD(), UnixNanos::from(100), 0.05);
+        module.add_rate(Currency::USD(), UnixNanos::from(200), 0.0525);
+
+        assert_eq!(module.rate(&Currency::USD(), UnixNanos::from(ts)), expected);
+        assert_eq!(module.rate(&Currency::AUD(), UnixNanos::from(ts)), None);
+    }
+
+    #[rstest]
+    fn test_rollover_booked_once_per_day_after_rollover_time() {
+        let mut exchange = exchange_with_position(OrderSide::Buy, None);
+        let mut module = rollover_module(&exchange);
+
+        // Before 17:00 New York time
+        module.process(UnixNanos::from(TUESDAY_ROLLOVER_NS - 1), &mut exchange);
+        assert!(module.totals().is_empty());
+
+        module.process(UnixNanos::from(TUESDAY_ROLLOVER_NS), &mut exchange);
+        module.process(UnixNanos::from(TUESDAY_ROLLOVER_NS + 1), &mut exchange);
+
+        // 365,000 * 0.80 * (0.06 - 0.02) / 365
+        assert!((total(&module, Currency::USD()) - 32.0).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_rollover_tripled_on_wednesday() {
+        let mut exchange = exchange_with_position(OrderSide::Buy, None);
+        let mut module = rollover_module(&exchange);
+
+        module.process(
+            UnixNanos::from(TUESDAY_ROLLOVER_NS + ONE_DAY_NS),
+            &mut exchange,
+        );
+
+        assert!((total(&module, Currency::USD()) - 96.0).abs() < 1e-9);
+    }
+
+    #[rstest]
+    #[case(4)] // Saturday
+    #[case(5)] // Sunday
+    fn test_rollover_skipped_on_weekends(#[case] days_after_tuesday: u64) {
+        let mut exchange = exchange_with_position(OrderSide::Buy, None);
+        let mut module = rollover_module(&exchange);
+
+        module.process(
+            UnixNanos::from(TUESDAY_ROLLOVER_NS + days_after_tuesday * ONE_DAY_NS),
+            &mut exchange,
+        );
+
+        assert!(module.totals().is_empty());
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, 32.0)]
+    #[case(OrderSide::Sell, -32.0)]
+    fn test_rollover_sign_follows_position_side(#[case] side: OrderSide, #[case] expected: f64) {
+        let mut exchange = exchange_with_position(side, None);
+        let mut module = rollover_module(&exchange);
+
+        module.process(UnixNanos::from(TUESDAY_ROLLOVER_NS), &mut exchange);
+
+        assert!((total(&module, Currency::USD()) - expected).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_rollover_converted_to_account_base_currency() {
+        let mut exchange = exchange_with_position(OrderSide::Buy, Some(Currency::AUD()));
+        let mut module = rollover_module(&exchange);
+
+        module.process(UnixNanos::from(TUESDAY_ROLLOVER_NS), &mut exchange);
+
+        // 32 USD at 1.25 AUD per USD
+        assert!((total(&module, Currency::AUD()) - 40.0).abs() < 1e-9);
+        assert_eq!(total(&module, Currency::USD()), 0.0);
+    }
+}
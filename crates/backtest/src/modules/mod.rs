@@ -13,32 +13,41 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use nautilus_common::logging::logger::Logger;
+pub mod funding;
+pub mod fx_rollover;
+
 use nautilus_core::UnixNanos;
-use nautilus_model::data::Data;
+use nautilus_model::data::{Data, FundingRateUpdate, InstrumentStatus};
 
 use crate::exchange::SimulatedExchange;
 
+pub use self::{funding::FundingRateModule, fx_rollover::FXRolloverInterestModule};
+
 /// Trait for custom simulation modules that extend backtesting functionality.
 ///
 /// The `SimulationModule` trait allows for custom extensions to the backtesting
 /// simulation environment. Implementations can add specialized behavior such as
 /// market makers, price impact models, or other venue-specific simulation logic
 /// that runs alongside the core backtesting engine.
-#[warn(dead_code)]
 pub trait SimulationModule {
     /// Registers a simulated exchange venue with this module.
-    fn register_venue(&self, exchange: SimulatedExchange);
+    fn register_venue(&mut self, exchange: &SimulatedExchange);
 
     /// Pre-processes market data before main simulation processing.
-    fn pre_process(&self, data: Data);
+    fn pre_process(&mut self, data: &Data);
+
+    /// Pre-processes an instrument status update before main simulation processing.
+    fn pre_process_status(&mut self, _status: &InstrumentStatus) {}
+
+    /// Pre-processes a funding rate update before it is applied to open positions.
+    fn pre_process_funding_rate(&mut self, _funding_rate: &FundingRateUpdate) {}
 
     /// Processes simulation logic at the given timestamp.
-    fn process(&self, ts_now: UnixNanos);
+    fn process(&mut self, ts_now: UnixNanos, exchange: &mut SimulatedExchange);
 
     /// Logs diagnostic information about the module's state.
-    fn log_diagnostics(&self, logger: Logger);
+    fn log_diagnostics(&self);
 
     /// Resets the module to its initial state.
-    fn reset(&self);
+    fn reset(&mut self);
 }
@@ -0,0 +1,108 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Results produced by a backtest run.
+
+use std::collections::BTreeMap;
+
+use nautilus_core::{UUID4, UnixNanos};
+use nautilus_model::identifiers::TraderId;
+use serde::{Deserialize, Serialize};
+
+/// Represents the results of a single complete backtest run.
+///
+/// Statistics maps are ordered by key so that serialized results are stable and can be
+/// compared between runs.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BacktestResult {
+    /// The trader ID for the backtest.
+    pub trader_id: TraderId,
+    /// The machine ID the backtest ran on.
+    pub machine_id: String,
+    /// The instance ID of the backtest engine.
+    pub instance_id: UUID4,
+    /// The run configuration ID (if any).
+    pub run_config_id: Option<UUID4>,
+    /// The ID of the last run (if any).
+    pub run_id: Option<UUID4>,
+    /// UNIX timestamp (nanoseconds) when the run started.
+    pub run_started: Option<UnixNanos>,
+    /// UNIX timestamp (nanoseconds) when the run finished.
+    pub run_finished: Option<UnixNanos>,
+    /// UNIX timestamp (nanoseconds) of the backtest start.
+    pub backtest_start: Option<UnixNanos>,
+    /// UNIX timestamp (nanoseconds) of the backtest end.
+    pub backtest_end: Option<UnixNanos>,
+    /// The simulated time covered by the backtest (seconds).
+    pub elapsed_time: f64,
+    /// The number of data iterations processed.
+    pub iterations: usize,
+    /// The total number of order events processed by the execution engine.
+    pub total_events: usize,
+    /// The total number of orders in the cache.
+    pub total_orders: usize,
+    /// The total number of positions in the cache.
+    pub total_positions: usize,
+    /// The PnL-based performance statistics per venue, then per currency code.
+    pub stats_pnls: BTreeMap<String, BTreeMap<String, BTreeMap<String, f64>>>,
+    /// The returns-based performance statistics per venue.
+    pub stats_returns: BTreeMap<String, BTreeMap<String, f64>>,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_backtest_result_json_round_trip() {
+        let result = BacktestResult {
+            trader_id: TraderId::from("TRADER-001"),
+            machine_id: "machine".to_string(),
+            instance_id: UUID4::new(),
+            run_config_id: None,
+            run_id: Some(UUID4::new()),
+            run_started: Some(UnixNanos::from(1)),
+            run_finished: Some(UnixNanos::from(2)),
+            backtest_start: Some(UnixNanos::from(1_000_000_000)),
+            backtest_end: Some(UnixNanos::from(3_000_000_000)),
+            elapsed_time: 2.0,
+            iterations: 10,
+            total_events: 4,
+            total_orders: 2,
+            total_positions: 1,
+            stats_pnls: BTreeMap::from([(
+                "BINANCE".to_string(),
+                BTreeMap::from([(
+                    "USDT".to_string(),
+                    BTreeMap::from([("PnL (total)".to_string(), 10.5)]),
+                )]),
+            )]),
+            stats_returns: BTreeMap::from([(
+                "BINANCE".to_string(),
+                BTreeMap::from([("Returns Volatility (252 days)".to_string(), 0.1)]),
+            )]),
+        };
+
+        let json = serde_json::to_string(&result).unwrap();
+        let deserialized: BacktestResult = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized, result);
+    }
+}
@@ -1954,6 +1954,22 @@ impl Cache {
         Ok(())
     }
 
+    /// Updates the price excursions of all open positions for the `instrument_id` with the
+    /// market `price`.
+    ///
+    /// The positions are updated in memory only, the next `update_position` persists them.
+    pub fn update_position_excursions(&mut self, instrument_id: &InstrumentId, price: Price) {
+        let Some(position_ids) = self.index.instrument_positions.get(instrument_id) else {
+            return;
+        };
+
+        for position_id in position_ids.intersection(&self.index.positions_open) {
+            if let Some(position) = self.positions.get_mut(position_id) {
+                position.update_excursions(price);
+            }
+        }
+    }
+
     /// Creates a snapshot of the `position` by cloning it, assigning a new ID,
     /// serializing it, and storing it in the position snapshots.
     ///
@@ -638,6 +638,39 @@ fn test_position_when_some(mut cache: Cache, audusd_sim: CurrencyPair) {
     assert_eq!(cache.positions_closed_count(None, None, None, None), 0);
 }
 
+#[rstest]
+fn test_update_position_excursions(mut cache: Cache, audusd_sim: CurrencyPair) {
+    let audusd_sim = InstrumentAny::CurrencyPair(audusd_sim);
+    let order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(audusd_sim.id())
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from(100_000))
+        .build();
+    let filled = TestOrderEventStubs::filled(
+        &order,
+        &audusd_sim,
+        None,
+        Some(PositionId::new("P-123456")),
+        Some(Price::from("1.00000")),
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    let position = Position::new(&audusd_sim, filled.into());
+    cache
+        .add_position(position.clone(), OmsType::Netting)
+        .unwrap();
+
+    cache.update_position_excursions(&audusd_sim.id(), Price::from("1.00100"));
+    cache.update_position_excursions(&audusd_sim.id(), Price::from("0.99800"));
+
+    let position = cache.position(&position.id).unwrap();
+    assert_eq!(position.max_px, Some(1.001));
+    assert_eq!(position.min_px, Some(0.998));
+}
+
 // -- DATA ------------------------------------------------------------------------------------
 
 #[rstest]
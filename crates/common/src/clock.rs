@@ -227,6 +227,11 @@ pub trait Clock: Debug {
 
     /// Resets the clock by clearing it's internal state.
     fn reset(&mut self);
+
+    /// Returns the clock as a mutable [`TestClock`] (if it is one).
+    fn as_test_clock_mut(&mut self) -> Option<&mut TestClock> {
+        None
+    }
 }
 
 /// A static test clock.
@@ -651,6 +656,10 @@ impl Clock for TestClock {
         self.heap = BinaryHeap::new();
         self.callbacks = HashMap::new();
     }
+
+    fn as_test_clock_mut(&mut self) -> Option<&mut TestClock> {
+        Some(self)
+    }
 }
 
 /// A real-time clock which uses system time.
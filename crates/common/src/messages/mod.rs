@@ -19,7 +19,10 @@
 //! parts of the NautilusTrader system, including data requests, execution commands,
 //! and system control messages.
 
-use nautilus_model::{data::Data, events::OrderEventAny};
+use nautilus_model::{
+    data::{Data, FundingRateUpdate},
+    events::OrderEventAny,
+};
 use strum::Display;
 
 pub mod data;
@@ -39,6 +42,7 @@ pub use execution::ExecutionReport;
 pub enum DataEvent {
     Response(DataResponse),
     Data(Data),
+    FundingRate(FundingRateUpdate),
     #[cfg(feature = "defi")]
     DeFi(nautilus_model::defi::data::DefiData),
 }
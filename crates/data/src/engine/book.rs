@@ -15,20 +15,24 @@
 
 use std::{
     any::Any,
-    cell::{Ref, RefCell},
+    cell::{Cell, Ref, RefCell},
     num::NonZeroUsize,
     rc::Rc,
+    time::Duration,
 };
 
 use nautilus_common::{
     cache::Cache,
-    msgbus::{self, MStr, Topic, handler::MessageHandler},
+    messages::data::{DataCommand, RequestBookSnapshot, RequestCommand},
+    msgbus::{self, MStr, Topic, handler::MessageHandler, switchboard::MessagingSwitchboard},
     timer::TimeEvent,
 };
+use nautilus_core::{UUID4, UnixNanos};
 use nautilus_model::{
-    data::Data,
+    data::{Data, OrderBookDeltas, OrderBookDepth10},
     identifiers::{InstrumentId, Venue},
     instruments::Instrument,
+    orderbook::{BookIntegrityError, OrderBook},
 };
 use ustr::Ustr;
 
@@ -48,20 +52,56 @@ pub struct BookSnapshotInfo {
 /// The `BookUpdater` processes incoming order book deltas and maintains
 /// the current state of an order book. It can handle both incremental
 /// updates and full snapshots for the instrument it's assigned to.
+///
+/// When the book detects a sequence gap a snapshot is requested, and requested again if
+/// deltas are still being dropped once `resync_timeout` has elapsed since the last request.
 #[derive(Debug)]
 pub struct BookUpdater {
     pub id: Ustr,
     pub instrument_id: InstrumentId,
     pub cache: Rc<RefCell<Cache>>,
+    pub resync_timeout: Duration,
+    snapshot_requested: Cell<UnixNanos>,
 }
 
 impl BookUpdater {
     /// Creates a new [`BookUpdater`] instance.
-    pub fn new(instrument_id: &InstrumentId, cache: Rc<RefCell<Cache>>) -> Self {
+    pub fn new(
+        instrument_id: &InstrumentId,
+        cache: Rc<RefCell<Cache>>,
+        resync_timeout: Duration,
+    ) -> Self {
         Self {
             id: Ustr::from(&format!("{}-{}", stringify!(BookUpdater), instrument_id)),
             instrument_id: *instrument_id,
             cache,
+            resync_timeout,
+            snapshot_requested: Cell::new(UnixNanos::default()),
+        }
+    }
+}
+
+impl BookUpdater {
+    /// Requests an order book snapshot to resync the book after a sequence gap.
+    fn request_snapshot(&self, ts_init: UnixNanos) {
+        self.snapshot_requested.set(ts_init);
+        let request =
+            RequestBookSnapshot::new(self.instrument_id, None, None, UUID4::new(), ts_init, None);
+        let command = DataCommand::Request(RequestCommand::BookSnapshot(request));
+
+        // Queued as the data engine is processing the data which triggered the request
+        let endpoint = MessagingSwitchboard::data_engine_queue_execute();
+        msgbus::send_any(endpoint, command.as_any());
+    }
+
+    fn update_book(
+        &self,
+        instrument_id: &InstrumentId,
+        update: impl FnOnce(&mut OrderBook) -> Result<(), BookIntegrityError>,
+    ) -> Result<(), BookIntegrityError> {
+        match self.cache.borrow_mut().order_book_mut(instrument_id) {
+            Some(book) => update(book),
+            None => Ok(()),
         }
     }
 }
@@ -73,26 +113,61 @@ impl MessageHandler for BookUpdater {
 
     fn handle(&self, message: &dyn Any) {
         // TODO: Temporary handler implementation (this will be removed soon)
-        if let Some(data) = message.downcast_ref::<Data>()
-            && let Some(book) = self
-                .cache
-                .borrow_mut()
-                .order_book_mut(&data.instrument_id())
-        {
+        let result = if let Some(deltas) = message.downcast_ref::<OrderBookDeltas>() {
+            self.update_book(&deltas.instrument_id, |book| book.apply_deltas(deltas))
+                .map_err(|e| (e, deltas.ts_init))
+        } else if let Some(depth) = message.downcast_ref::<OrderBookDepth10>() {
+            self.update_book(&depth.instrument_id, |book| {
+                book.apply_depth(depth);
+                Ok(())
+            })
+            .map_err(|e| (e, depth.ts_init))
+        } else if let Some(data) = message.downcast_ref::<Data>() {
             match data {
-                Data::Delta(delta) => {
-                    if let Err(e) = book.apply_delta(delta) {
-                        log::error!("Failed to apply delta: {e}");
-                    }
+                Data::Delta(delta) => self
+                    .update_book(&delta.instrument_id, |book| book.apply_delta(delta))
+                    .map_err(|e| (e, delta.ts_init)),
+                Data::Deltas(deltas) => self
+                    .update_book(&deltas.instrument_id, |book| book.apply_deltas(deltas))
+                    .map_err(|e| (e, deltas.ts_init)),
+                Data::Depth10(depth) => self
+                    .update_book(&depth.instrument_id, |book| {
+                        book.apply_depth(depth);
+                        Ok(())
+                    })
+                    .map_err(|e| (e, depth.ts_init)),
+                _ => {
+                    log::error!("Invalid data type for book update, was {data:?}");
+                    return;
                 }
-                Data::Deltas(deltas) => {
-                    if let Err(e) = book.apply_deltas(deltas) {
-                        log::error!("Failed to apply deltas: {e}");
-                    }
+            }
+        } else {
+            return;
+        };
+
+        match result {
+            Ok(()) => {}
+            Err((e @ BookIntegrityError::SequenceGap(..), ts_init)) => {
+                log::warn!("{e}, requesting snapshot for {}", self.instrument_id);
+                self.request_snapshot(ts_init);
+            }
+            Err((BookIntegrityError::ResyncPending(sequence), ts_init)) => {
+                log::debug!(
+                    "Dropped delta sequence={sequence} for {} awaiting snapshot",
+                    self.instrument_id
+                );
+
+                let timeout_ns = self.resync_timeout.as_nanos() as u64;
+                if ts_init.as_u64() >= self.snapshot_requested.get().as_u64() + timeout_ns {
+                    log::warn!(
+                        "No snapshot received within {:?}, requesting again for {}",
+                        self.resync_timeout,
+                        self.instrument_id
+                    );
+                    self.request_snapshot(ts_init);
                 }
-                Data::Depth10(depth) => book.apply_depth(depth),
-                _ => log::error!("Invalid data type for book update, was {data:?}"),
             }
+            Err((e, _)) => log::error!("Failed to apply delta: {e}"),
         }
     }
 
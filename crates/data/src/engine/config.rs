@@ -17,7 +17,7 @@ use std::{collections::HashMap, time::Duration};
 
 use nautilus_model::{
     enums::{BarAggregation, BarIntervalType},
-    identifiers::ClientId,
+    identifiers::{ClientId, Venue},
 };
 
 /// Configuration for `DataEngine` instances.
@@ -40,6 +40,11 @@ pub struct DataEngineConfig {
     pub validate_data_sequence: bool,
     /// If order book deltas should be buffered until the `F_LAST` flag is set for a delta.
     pub buffer_deltas: bool,
+    /// The venues whose managed order books check deltas for sequence gaps, requesting a
+    /// snapshot to resync (only for venues with contiguous delta sequence numbers).
+    pub book_gap_detection_venues: Vec<Venue>,
+    /// The time to wait for a resync snapshot before requesting it again.
+    pub book_resync_timeout: Duration,
     /// The client IDs declared for external stream processing.
     /// The data engine will not attempt to send data commands to these client IDs.
     pub external_clients: Option<Vec<ClientId>>,
@@ -58,6 +63,8 @@ impl DataEngineConfig {
         time_bars_origins: HashMap<BarAggregation, Duration>,
         validate_data_sequence: bool,
         buffer_deltas: bool,
+        book_gap_detection_venues: Vec<Venue>,
+        book_resync_timeout: Duration,
         external_clients: Option<Vec<ClientId>>,
         debug: bool,
     ) -> Self {
@@ -69,6 +76,8 @@ impl DataEngineConfig {
             time_bars_origins,
             validate_data_sequence,
             buffer_deltas,
+            book_gap_detection_venues,
+            book_resync_timeout,
             external_clients,
             debug,
         }
@@ -83,6 +92,8 @@ impl Default for DataEngineConfig {
             time_bars_interval_type: BarIntervalType::LeftOpen,
             validate_data_sequence: false,
             buffer_deltas: false,
+            book_gap_detection_venues: Vec::new(),
+            book_resync_timeout: Duration::from_secs(5),
             external_clients: None,
             debug: false,
             time_bars_skip_first_non_full_bar: false,
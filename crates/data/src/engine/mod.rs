@@ -674,6 +674,7 @@ impl DataEngine {
             DataResponse::Quotes(resp) => self.handle_quotes(&resp.data),
             DataResponse::Trades(resp) => self.handle_trades(&resp.data),
             DataResponse::Bars(resp) => self.handle_bars(&resp.data),
+            DataResponse::Book(resp) => self.handle_book_response(&resp.data),
             _ => todo!(),
         }
 
@@ -1131,6 +1132,16 @@ impl DataEngine {
         }
     }
 
+    fn handle_book_response(&self, snapshot: &OrderBook) {
+        let mut cache = self.cache.borrow_mut();
+        if let Some(book) = cache.order_book_mut(&snapshot.instrument_id)
+            && book.needs_resync()
+        {
+            book.apply_snapshot(snapshot);
+            log::info!("Resynced {book} from snapshot sequence={}", book.sequence);
+        }
+    }
+
     fn handle_bars(&self, bars: &[Bar]) {
         if let Err(e) = self.cache.as_ref().borrow_mut().add_bars(bars) {
             log_error_on_cache_insert(&e);
@@ -1149,13 +1160,22 @@ impl DataEngine {
     ) -> anyhow::Result<()> {
         let mut cache = self.cache.borrow_mut();
         if managed && !cache.has_order_book(instrument_id) {
-            let book = OrderBook::new(*instrument_id, book_type);
+            let mut book = OrderBook::new(*instrument_id, book_type);
+            book.set_gap_detection(
+                self.config
+                    .book_gap_detection_venues
+                    .contains(&instrument_id.venue),
+            );
             log::debug!("Created {book}");
             cache.add_order_book(book)?;
         }
 
         // Set up subscriptions
-        let updater = Rc::new(BookUpdater::new(instrument_id, self.cache.clone()));
+        let updater = Rc::new(BookUpdater::new(
+            instrument_id,
+            self.cache.clone(),
+            self.config.book_resync_timeout,
+        ));
         self.book_updaters.insert(*instrument_id, updater.clone());
 
         let handler = ShareableMessageHandler(updater);
@@ -15,7 +15,9 @@
 
 mod common;
 
-use std::{any::Any, cell::RefCell, num::NonZeroUsize, rc::Rc, str::FromStr, sync::Arc};
+use std::{
+    any::Any, cell::RefCell, num::NonZeroUsize, rc::Rc, str::FromStr, sync::Arc, time::Duration,
+};
 
 use alloy_primitives::{Address, I256, U160, U256};
 use common::mocks::MockDataClient;
@@ -33,14 +35,15 @@ use nautilus_common::{
     cache::Cache,
     clock::{Clock, TestClock},
     messages::data::{
-        DataCommand, RequestBars, RequestBookDepth, RequestBookSnapshot, RequestCommand,
-        RequestCustomData, RequestInstrument, RequestInstruments, RequestQuotes, RequestTrades,
-        SubscribeBars, SubscribeBookDeltas, SubscribeBookDepth10, SubscribeBookSnapshots,
-        SubscribeCommand, SubscribeCustomData, SubscribeFundingRates, SubscribeIndexPrices,
-        SubscribeInstrument, SubscribeMarkPrices, SubscribeQuotes, SubscribeTrades,
-        UnsubscribeBars, UnsubscribeBookDeltas, UnsubscribeBookSnapshots, UnsubscribeCommand,
-        UnsubscribeCustomData, UnsubscribeFundingRates, UnsubscribeIndexPrices,
-        UnsubscribeInstrument, UnsubscribeMarkPrices, UnsubscribeQuotes, UnsubscribeTrades,
+        BookResponse, DataCommand, DataResponse, RequestBars, RequestBookDepth,
+        RequestBookSnapshot, RequestCommand, RequestCustomData, RequestInstrument,
+        RequestInstruments, RequestQuotes, RequestTrades, SubscribeBars, SubscribeBookDeltas,
+        SubscribeBookDepth10, SubscribeBookSnapshots, SubscribeCommand, SubscribeCustomData,
+        SubscribeFundingRates, SubscribeIndexPrices, SubscribeInstrument, SubscribeMarkPrices,
+        SubscribeQuotes, SubscribeTrades, UnsubscribeBars, UnsubscribeBookDeltas,
+        UnsubscribeBookSnapshots, UnsubscribeCommand, UnsubscribeCustomData,
+        UnsubscribeFundingRates, UnsubscribeIndexPrices, UnsubscribeInstrument,
+        UnsubscribeMarkPrices, UnsubscribeQuotes, UnsubscribeTrades,
     },
     msgbus::{
         self, MessageBus,
@@ -50,27 +53,28 @@ use nautilus_common::{
     },
 };
 use nautilus_core::{UUID4, UnixNanos};
-use nautilus_data::{client::DataClientAdapter, engine::DataEngine};
+use nautilus_data::{
+    client::DataClientAdapter,
+    engine::{DataEngine, config::DataEngineConfig},
+};
+#[cfg(feature = "defi")]
+use nautilus_model::defi::{
+    Block, Blockchain, DefiData, Pool, PoolLiquidityUpdate, PoolLiquidityUpdateType, PoolProfiler,
+    PoolSwap, Token, data::PoolFeeCollect, data::PoolFlash,
+};
 use nautilus_model::{
     data::{
-        Bar, BarType, Data, DataType, FundingRateUpdate, IndexPriceUpdate, MarkPriceUpdate,
-        OrderBookDeltas, OrderBookDeltas_API, OrderBookDepth10, QuoteTick, TradeTick,
+        Bar, BarType, BookOrder, Data, DataType, FundingRateUpdate, IndexPriceUpdate,
+        MarkPriceUpdate, OrderBookDelta, OrderBookDeltas, OrderBookDeltas_API, OrderBookDepth10,
+        QuoteTick, TradeTick,
         stubs::{stub_delta, stub_deltas, stub_depth10},
     },
     defi::{AmmType, Dex, DexType, chain::chains},
-    enums::{BookType, PriceType},
+    enums::{BookAction, BookType, OrderSide, PriceType, RecordFlag},
     identifiers::{ClientId, InstrumentId, TraderId, Venue},
     instruments::{CurrencyPair, Instrument, InstrumentAny, stubs::audusd_sim},
-    types::Price,
-};
-#[cfg(feature = "defi")]
-use nautilus_model::{
-    defi::{
-        Block, Blockchain, DefiData, Pool, PoolLiquidityUpdate, PoolLiquidityUpdateType,
-        PoolProfiler, PoolSwap, Token, data::PoolFeeCollect, data::PoolFlash,
-    },
-    enums::OrderSide,
-    types::Quantity,
+    orderbook::OrderBook,
+    types::{Price, Quantity},
 };
 use rstest::*;
 
@@ -1324,6 +1328,93 @@ fn test_process_book_deltas(
     assert!(messages.contains(&deltas));
 }
 
+#[rstest]
+fn test_book_gap_resyncs_from_snapshot_response(
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    audusd_sim: CurrencyPair,
+    data_client: DataClientAdapter,
+) {
+    let config = DataEngineConfig {
+        book_gap_detection_venues: vec![audusd_sim.id.venue],
+        book_resync_timeout: Duration::from_secs(1),
+        ..Default::default()
+    };
+    let mut data_engine = DataEngine::new(clock, cache.clone(), Some(config));
+    let client_id = data_client.client_id;
+    let venue = data_client.venue;
+    data_engine.register_client(data_client, None);
+
+    let requests = get_message_saving_handler::<DataCommand>(None);
+    msgbus::register(
+        MessagingSwitchboard::data_engine_queue_execute(),
+        requests.clone(),
+    );
+
+    let sub = SubscribeBookDeltas::new(
+        audusd_sim.id,
+        BookType::L2_MBP,
+        Some(client_id),
+        venue,
+        UUID4::new(),
+        UnixNanos::default(),
+        None,
+        true,
+        None,
+    );
+    data_engine.execute(&DataCommand::Subscribe(SubscribeCommand::BookDeltas(sub)));
+
+    let delta = |price: &str, sequence: u64, ts_init: u64| {
+        let order = BookOrder::new(OrderSide::Buy, Price::from(price), Quantity::from(1), 0);
+        OrderBookDelta::new(
+            audusd_sim.id,
+            BookAction::Add,
+            order,
+            RecordFlag::F_LAST as u8,
+            sequence,
+            ts_init.into(),
+            ts_init.into(),
+        )
+    };
+    data_engine.process_data(Data::Delta(delta("1.00000", 1, 1)));
+    data_engine.process_data(Data::Delta(delta("0.99000", 3, 2)));
+    assert!(
+        cache
+            .borrow()
+            .order_book(&audusd_sim.id)
+            .unwrap()
+            .needs_resync()
+    );
+    assert_eq!(get_saved_messages::<DataCommand>(requests.clone()).len(), 1);
+
+    // Requested again only once the resync timeout has elapsed
+    data_engine.process_data(Data::Delta(delta("0.98000", 4, 1_000_000_000)));
+    assert_eq!(get_saved_messages::<DataCommand>(requests.clone()).len(), 1);
+    data_engine.process_data(Data::Delta(delta("0.97000", 5, 1_000_000_002)));
+    assert_eq!(get_saved_messages::<DataCommand>(requests).len(), 2);
+
+    let mut snapshot = OrderBook::new(audusd_sim.id, BookType::L2_MBP);
+    snapshot.apply_delta(&delta("1.01000", 10, 3)).unwrap();
+    data_engine.response(DataResponse::Book(BookResponse::new(
+        UUID4::new(),
+        client_id,
+        audusd_sim.id,
+        snapshot,
+        None,
+        None,
+        UnixNanos::default(),
+        None,
+    )));
+    data_engine.process_data(Data::Delta(delta("1.00500", 11, 1_000_000_003)));
+
+    let cache = cache.borrow();
+    let book = cache.order_book(&audusd_sim.id).unwrap();
+    assert!(!book.needs_resync());
+    assert_eq!(book.sequence, 11);
+    assert_eq!(book.best_bid_price(), Some(Price::from("1.01000")));
+    assert_eq!(book.bids(None).count(), 2);
+}
+
 #[rstest]
 fn test_process_book_depth10(
     audusd_sim: CurrencyPair,
@@ -79,6 +79,7 @@ pub struct ExecutionEngine {
     external_clients: HashSet<ClientId>,
     pos_id_generator: PositionIdGenerator,
     config: ExecutionEngineConfig,
+    event_count: usize,
 }
 
 impl Debug for ExecutionEngine {
@@ -113,6 +114,7 @@ impl ExecutionEngine {
                 .collect(),
             pos_id_generator: PositionIdGenerator::new(trader_id, clock),
             config: config.unwrap_or_default(),
+            event_count: 0,
         }
     }
 
@@ -122,6 +124,12 @@ impl ExecutionEngine {
         self.pos_id_generator.count(strategy_id)
     }
 
+    #[must_use]
+    /// Returns the total count of order events processed by the engine.
+    pub const fn event_count(&self) -> usize {
+        self.event_count
+    }
+
     #[must_use]
     /// Checks the integrity of cached execution data.
     pub fn check_integrity(&self) -> bool {
@@ -271,8 +279,15 @@ impl ExecutionEngine {
         self.cache.borrow_mut().flush_db();
     }
 
+    /// Resets the engine to its initial state, clearing event counts and position ID state.
+    pub fn reset(&mut self) {
+        self.pos_id_generator.reset();
+        self.event_count = 0;
+    }
+
     /// Processes an order event, updating internal state and routing as needed.
     pub fn process(&mut self, event: &OrderEventAny) {
+        self.event_count += 1;
         self.handle_event(event);
     }
 
@@ -23,6 +23,9 @@ pub struct OrderMatchingEngineConfig {
     pub use_position_ids: bool,
     pub use_random_ids: bool,
     pub use_reduce_only: bool,
+    /// If own orders resting in the matching engine are subtracted from the public book when
+    /// simulating fills (prevents self-trading when the book includes own liquidity).
+    pub exclude_own_liquidity: bool,
 }
 
 impl OrderMatchingEngineConfig {
@@ -45,6 +48,7 @@ impl OrderMatchingEngineConfig {
             use_position_ids,
             use_random_ids,
             use_reduce_only,
+            exclude_own_liquidity: false,
         }
     }
 }
@@ -61,6 +65,7 @@ impl Default for OrderMatchingEngineConfig {
             use_position_ids: false,
             use_random_ids: false,
             use_reduce_only: false,
+            exclude_own_liquidity: false,
         }
     }
 }
@@ -36,7 +36,10 @@ use nautilus_common::{
 };
 use nautilus_core::{UUID4, UnixNanos};
 use nautilus_model::{
-    data::{Bar, BarType, OrderBookDelta, OrderBookDeltas, QuoteTick, TradeTick, order::BookOrder},
+    data::{
+        Bar, BarType, OrderBookDelta, OrderBookDeltas, OrderBookDepth10, QuoteTick, TradeTick,
+        order::BookOrder,
+    },
     enums::{
         AccountType, AggregationSource, AggressorSide, BarAggregation, BookType, ContingencyType,
         LiquiditySide, MarketStatus, MarketStatusAction, OmsType, OrderSide, OrderSideSpecified,
@@ -51,7 +54,7 @@ use nautilus_model::{
         VenueOrderId,
     },
     instruments::{EXPIRING_INSTRUMENT_TYPES, Instrument, InstrumentAny},
-    orderbook::OrderBook,
+    orderbook::{NetOrderBook, OrderBook, own::OwnOrderBook},
     orders::{Order, OrderAny, PassiveOrderAny, StopOrderAny},
     position::Position,
     types::{Currency, Money, Price, Quantity, fixed::FIXED_PRECISION},
@@ -63,7 +66,8 @@ use crate::{
     matching_engine::{config::OrderMatchingEngineConfig, ids_generator::IdsGenerator},
     models::{
         fee::{FeeModel, FeeModelAny},
-        fill::FillModel,
+        fill::{FillModelAny, OrderFillModel},
+        queue::QueuePositionTracker,
     },
     trailing::trailing_stop_calculate,
 };
@@ -90,7 +94,7 @@ pub struct OrderMatchingEngine {
     cache: Rc<RefCell<Cache>>,
     book: OrderBook,
     pub core: OrderMatchingCore,
-    fill_model: FillModel,
+    fill_model: FillModelAny,
     fee_model: FeeModelAny,
     target_bid: Option<Price>,
     target_ask: Option<Price>,
@@ -101,6 +105,8 @@ pub struct OrderMatchingEngine {
     execution_bar_deltas: HashMap<BarType, TimeDelta>,
     account_ids: HashMap<TraderId, AccountId>,
     cached_filled_qty: HashMap<ClientOrderId, Quantity>,
+    queue_tracker: QueuePositionTracker,
+    net_book: Option<NetOrderBook>,
     ids_generator: IdsGenerator,
 }
 
@@ -119,7 +125,7 @@ impl OrderMatchingEngine {
     pub fn new(
         instrument: InstrumentAny,
         raw_id: u32,
-        fill_model: FillModel,
+        fill_model: FillModelAny,
         fee_model: FeeModelAny,
         book_type: BookType,
         oms_type: OmsType,
@@ -129,6 +135,9 @@ impl OrderMatchingEngine {
         config: OrderMatchingEngineConfig,
     ) -> Self {
         let book = OrderBook::new(instrument.id(), book_type);
+        let net_book = config.exclude_own_liquidity.then(|| {
+            NetOrderBook::new(&book, &OwnOrderBook::new(instrument.id()), None, None, None)
+        });
         let core = OrderMatchingCore::new(
             instrument.id(),
             instrument.price_increment(),
@@ -169,6 +178,8 @@ impl OrderMatchingEngine {
             execution_bar_deltas: HashMap::new(),
             account_ids: HashMap::new(),
             cached_filled_qty: HashMap::new(),
+            queue_tracker: QueuePositionTracker::new(),
+            net_book,
             ids_generator,
         }
     }
@@ -179,11 +190,15 @@ impl OrderMatchingEngine {
     /// internal components. This is typically used for backtesting scenarios
     /// where the engine needs to be reset between test runs.
     pub fn reset(&mut self) {
-        self.book.clear(0, UnixNanos::default());
+        self.book.reset();
         self.execution_bar_types.clear();
         self.execution_bar_deltas.clear();
         self.account_ids.clear();
         self.cached_filled_qty.clear();
+        self.queue_tracker.clear();
+        if let Some(net_book) = &mut self.net_book {
+            net_book.reset();
+        }
         self.core.reset();
         self.target_bid = None;
         self.target_ask = None;
@@ -194,7 +209,7 @@ impl OrderMatchingEngine {
     }
 
     /// Sets the fill model for the matching engine.
-    pub const fn set_fill_model(&mut self, fill_model: FillModel) {
+    pub const fn set_fill_model(&mut self, fill_model: FillModelAny) {
         self.fill_model = fill_model;
     }
 
@@ -238,6 +253,12 @@ impl OrderMatchingEngine {
         orders
     }
 
+    #[must_use]
+    /// Returns the queue tracker for passive limit orders.
+    pub const fn get_queue_tracker(&self) -> &QueuePositionTracker {
+        &self.queue_tracker
+    }
+
     #[must_use]
     /// Returns true if an order with the given client order ID exists in the matching engine.
     pub fn order_exists(&self, client_order_id: ClientOrderId) -> bool {
@@ -255,9 +276,13 @@ impl OrderMatchingEngine {
         log::debug!("Processing {delta}");
 
         if self.book_type == BookType::L2_MBP || self.book_type == BookType::L3_MBO {
-            self.book.apply_delta(delta)?;
+            match &mut self.net_book {
+                Some(net_book) => net_book.apply_delta(&mut self.book, delta)?,
+                None => self.book.apply_delta(delta)?,
+            }
         }
 
+        self.update_queue_positions();
         self.iterate(delta.ts_init);
         Ok(())
     }
@@ -271,13 +296,32 @@ impl OrderMatchingEngine {
         log::debug!("Processing {deltas}");
 
         if self.book_type == BookType::L2_MBP || self.book_type == BookType::L3_MBO {
-            self.book.apply_deltas(deltas)?;
+            match &mut self.net_book {
+                Some(net_book) => net_book.apply_deltas(&mut self.book, deltas)?,
+                None => self.book.apply_deltas(deltas)?,
+            }
         }
 
+        self.update_queue_positions();
         self.iterate(deltas.ts_init);
         Ok(())
     }
 
+    /// Process the venues market for the given order book depth snapshot.
+    pub fn process_order_book_depth10(&mut self, depth: &OrderBookDepth10) {
+        log::debug!("Processing {depth}");
+
+        if self.book_type == BookType::L2_MBP || self.book_type == BookType::L3_MBO {
+            self.book.apply_depth(depth);
+            if let Some(net_book) = &mut self.net_book {
+                net_book.rebuild(&self.book);
+            }
+        }
+
+        self.update_queue_positions();
+        self.iterate(depth.ts_init);
+    }
+
     /// # Panics
     ///
     /// Panics if updating the order book with the quote tick fails.
@@ -341,6 +385,8 @@ impl OrderMatchingEngine {
             }
         }
 
+        self.fill_model.record_volume(bar.volume);
+
         match bar_type.spec().price_type {
             PriceType::Last | PriceType::Mid => self.process_trade_ticks_from_bar(bar),
             PriceType::Bid => {
@@ -492,8 +538,20 @@ impl OrderMatchingEngine {
             self.book.update_trade_tick(trade).unwrap();
         }
         self.core.set_last_raw(trade.price);
+        self.fill_model.record_volume(trade.size);
+
+        let queue_fillable = if self.is_queue_position_enabled() {
+            self.queue_tracker
+                .trade(trade.price, trade.size, trade.aggressor_side)
+        } else {
+            Vec::new()
+        };
 
         self.iterate(trade.ts_init);
+
+        for client_order_id in queue_fillable {
+            self.fill_queued_order(client_order_id);
+        }
     }
 
     pub fn process_status(&mut self, action: MarketStatusAction) {
@@ -1099,6 +1157,13 @@ impl OrderMatchingEngine {
     pub fn iterate(&mut self, timestamp_ns: UnixNanos) {
         // TODO implement correct clock fixed time setting self.clock.set_time(ts_now);
 
+        // Top-of-book updates replace the single level per side, so rebuild the net book
+        if self.book_type == BookType::L1_MBP
+            && let Some(net_book) = &mut self.net_book
+        {
+            net_book.rebuild(&self.book);
+        }
+
         // Check for updates in orderbook and set bid and ask in order matching core and iterate
         if self.book.has_bid() {
             self.core.set_bid_raw(self.book.best_bid_price().unwrap());
@@ -1214,6 +1279,8 @@ impl OrderMatchingEngine {
             {
                 let _ = self.core.delete_order(order);
                 self.cached_filled_qty.remove(&order.client_order_id());
+                self.queue_tracker.remove(&order.client_order_id());
+                self.update_own_liquidity(&order.to_any());
                 self.expire_order(order);
                 continue;
             }
@@ -1265,7 +1332,7 @@ impl OrderMatchingEngine {
                 let book_order =
                     BookOrder::new(order.order_side(), order_price, order.quantity(), 1);
 
-                let mut fills = self.book.simulate_fills(&book_order);
+                let mut fills = self.simulate_book_fills(&book_order);
 
                 // return immediately if no fills
                 if fills.is_empty() {
@@ -1362,6 +1429,40 @@ impl OrderMatchingEngine {
         }
     }
 
+    fn simulate_book_fills(&self, order: &BookOrder) -> Vec<(Price, Quantity)> {
+        match &self.net_book {
+            Some(net_book) => net_book.simulate_fills(order),
+            None => self.book.simulate_fills(order),
+        }
+    }
+
+    /// Keeps the own liquidity subtracted by the net book in line with the `order`, which
+    /// counts while it rests in the matching core at a limit price.
+    fn update_own_liquidity(&mut self, order: &OrderAny) {
+        let client_order_id = order.client_order_id();
+        let is_resting = order.is_open()
+            && self.core.order_exists(client_order_id)
+            && (matches!(
+                order.order_type(),
+                OrderType::Limit | OrderType::MarketToLimit
+            ) || order.is_triggered().is_some_and(|t| t));
+
+        let Some(net_book) = &mut self.net_book else {
+            return;
+        };
+
+        match order.price() {
+            Some(price) if is_resting => net_book.update_own_order(
+                &self.book,
+                client_order_id,
+                order.order_side_specified(),
+                price,
+                order.leaves_qty(),
+            ),
+            _ => net_book.remove_own_order(&self.book, &client_order_id),
+        }
+    }
+
     fn determine_market_price_and_volume(&self, order: &OrderAny) -> Vec<(Price, Quantity)> {
         // construct price
         let price = match order.order_side().as_specified() {
@@ -1371,7 +1472,7 @@ impl OrderMatchingEngine {
 
         // Construct BookOrder from order
         let book_order = BookOrder::new(order.order_side(), price, order.quantity(), 0);
-        self.book.simulate_fills(&book_order)
+        self.simulate_book_fills(&book_order)
     }
 
     pub fn fill_market_order(&mut self, order: &mut OrderAny) {
@@ -1428,10 +1529,16 @@ impl OrderMatchingEngine {
                     return;
                 }
 
-                if order
+                let is_maker = order
                     .liquidity_side()
-                    .is_some_and(|liquidity_side| liquidity_side == LiquiditySide::Maker)
-                {
+                    .is_some_and(|liquidity_side| liquidity_side == LiquiditySide::Maker);
+
+                if is_maker && self.is_queue_position_enabled() {
+                    if !self.queue_tracker.is_front(&order.client_order_id()) {
+                        // Volume still ahead in the queue
+                        return;
+                    }
+                } else if is_maker {
                     if order.order_side() == OrderSide::Buy
                         && self.core.bid.is_some_and(|bid| bid == order_price)
                         && !self.fill_model.is_limit_filled()
@@ -1465,7 +1572,18 @@ impl OrderMatchingEngine {
                     return;
                 }
 
-                let fills = self.determine_limit_price_and_volume(order);
+                let mut fills = self.determine_limit_price_and_volume(order);
+
+                if is_maker && self.queue_tracker.contains(&order.client_order_id()) {
+                    // Cap at the traded volume which has reached the order in the queue
+                    let fill_qty = self
+                        .queue_tracker
+                        .take_fillable(&order.client_order_id(), order.leaves_qty());
+                    if fill_qty.is_zero() {
+                        return;
+                    }
+                    fills = cap_fills(fills, fill_qty);
+                }
 
                 self.apply_fills(
                     order,
@@ -1479,6 +1597,104 @@ impl OrderMatchingEngine {
         }
     }
 
+    fn is_queue_position_enabled(&self) -> bool {
+        self.fill_model.queue_position()
+            && matches!(self.book_type, BookType::L2_MBP | BookType::L3_MBO)
+    }
+
+    fn level_size(&self, side: OrderSideSpecified, price: Price) -> Quantity {
+        let level = match side {
+            OrderSideSpecified::Buy => self.book.bids(None).find(|l| l.price.value == price),
+            OrderSideSpecified::Sell => self.book.asks(None).find(|l| l.price.value == price),
+        };
+        let size_precision = self.instrument.size_precision();
+
+        level.map_or_else(
+            || Quantity::zero(size_precision),
+            |level| Quantity::from_raw(level.size_raw(), size_precision),
+        )
+    }
+
+    fn join_queue(
+        &mut self,
+        client_order_id: ClientOrderId,
+        side: OrderSideSpecified,
+        price: Price,
+    ) {
+        let ahead = self.level_size(side, price);
+        self.queue_tracker.add(client_order_id, side, price, ahead);
+    }
+
+    fn update_queue_positions(&mut self) {
+        if !self.is_queue_position_enabled() {
+            return;
+        }
+
+        let levels: Vec<(OrderSideSpecified, Price)> = self.queue_tracker.levels().collect();
+        for (side, price) in levels {
+            let size = self.level_size(side, price);
+            self.queue_tracker.update_level(side, price, size);
+        }
+    }
+
+    fn fill_queued_order(&mut self, client_order_id: ClientOrderId) {
+        let Some(passive_order) = self.core.get_order(client_order_id).cloned() else {
+            self.queue_tracker.remove(&client_order_id);
+            return;
+        };
+
+        let mut order = OrderAny::from(passive_order);
+        let Some(price) = order.price() else {
+            return;
+        };
+
+        let filled_qty = self
+            .cached_filled_qty
+            .get(&client_order_id)
+            .copied()
+            .unwrap_or_else(|| order.filled_qty());
+        let leaves_qty = order.quantity().saturating_sub(filled_qty);
+        let fill_qty = self
+            .queue_tracker
+            .take_fillable(&client_order_id, leaves_qty);
+        if fill_qty.is_zero() {
+            return;
+        }
+
+        let venue_position_id = self.ids_generator.get_position_id(&order, None);
+        let position = if let Some(venue_position_id) = venue_position_id {
+            let cache = self.cache.as_ref().borrow();
+            cache.position(&venue_position_id).cloned()
+        } else {
+            None
+        };
+
+        if self.config.use_reduce_only && order.is_reduce_only() && position.is_none() {
+            log::warn!(
+                "Canceling REDUCE_ONLY {} as would increase position",
+                order.order_type()
+            );
+            self.cancel_order(&order, None);
+            return;
+        }
+
+        order.set_liquidity_side(LiquiditySide::Maker);
+        self.apply_fills(
+            &mut order,
+            vec![(price, fill_qty)],
+            LiquiditySide::Maker,
+            venue_position_id,
+            position,
+        );
+
+        // Keep the resting order in sync with any partial fill
+        if order.is_open() && self.core.order_exists(client_order_id) {
+            let passive_order = PassiveOrderAny::try_from(order).expect("passive order conversion");
+            let _ = self.core.delete_order(&passive_order);
+            let _ = self.core.add_order(passive_order);
+        }
+    }
+
     fn apply_fills(
         &mut self,
         order: &mut OrderAny,
@@ -1487,6 +1703,27 @@ impl OrderMatchingEngine {
         venue_position_id: Option<PositionId>,
         position: Option<Position>,
     ) {
+        let requested_qty = fills.iter().fold(
+            Quantity::zero(order.quantity().precision),
+            |acc, (_, qty)| acc.add(*qty),
+        );
+        let fills = self
+            .fill_model
+            .adjust_fills(order, liquidity_side, fills, &self.instrument);
+        let adjusted_qty = fills.iter().fold(
+            Quantity::zero(order.quantity().precision),
+            |acc, (_, qty)| acc.add(*qty),
+        );
+        let is_capped = adjusted_qty < requested_qty;
+
+        if is_capped && fills.is_empty() {
+            // No simulated volume available from the fill model
+            if is_market_type(order.order_type()) {
+                self.cancel_order(order, None);
+            }
+            return;
+        }
+
         if order.time_in_force() == TimeInForce::Fok {
             let mut total_size = Quantity::zero(order.quantity().precision);
             for (fill_px, fill_qty) in &fills {
@@ -1612,15 +1849,15 @@ impl OrderMatchingEngine {
             return;
         }
 
+        if is_capped && order.is_open() && is_market_type(order.order_type()) {
+            // Fill model capped the available volume, cancel the remaining quantity
+            self.cancel_order(order, None);
+            return;
+        }
+
         if order.is_open()
             && self.book_type == BookType::L1_MBP
-            && matches!(
-                order.order_type(),
-                OrderType::Market
-                    | OrderType::MarketIfTouched
-                    | OrderType::StopMarket
-                    | OrderType::TrailingStopMarket
-            )
+            && is_market_type(order.order_type())
         {
             // Exhausted simulated book volume (continue aggressive filling into next level)
             // This is a very basic implementation of slipping by a single tick, in the future
@@ -1679,7 +1916,9 @@ impl OrderMatchingEngine {
                 );
             }
             self.cached_filled_qty.remove(&order.client_order_id());
+            self.queue_tracker.remove(&order.client_order_id());
         }
+        self.update_own_liquidity(order);
 
         if !self.config.support_contingent_orders {
             return;
@@ -1832,6 +2071,12 @@ impl OrderMatchingEngine {
             self.fill_limit_order(order);
             return;
         }
+
+        // Amending the price loses queue priority
+        if self.queue_tracker.contains(&order.client_order_id()) && order.price() != Some(price) {
+            self.join_queue(order.client_order_id(), order.order_side_specified(), price);
+        }
+
         self.generate_order_updated(order, quantity, Some(price), None);
     }
 
@@ -2100,9 +2345,21 @@ impl OrderMatchingEngine {
             }
         }
 
-        let _ = self.core.add_order(
-            PassiveOrderAny::try_from(order.to_owned()).expect("passive order conversion"),
-        );
+        let passive_order =
+            PassiveOrderAny::try_from(order.to_owned()).expect("passive order conversion");
+
+        if self.is_queue_position_enabled()
+            && let PassiveOrderAny::Limit(limit_order) = &passive_order
+        {
+            self.join_queue(
+                order.client_order_id(),
+                order.order_side_specified(),
+                limit_order.limit_px(),
+            );
+        }
+
+        let _ = self.core.add_order(passive_order);
+        self.update_own_liquidity(order);
     }
 
     fn expire_order(&mut self, order: &PassiveOrderAny) {
@@ -2134,6 +2391,8 @@ impl OrderMatchingEngine {
             );
         }
         self.cached_filled_qty.remove(&order.client_order_id());
+        self.queue_tracker.remove(&order.client_order_id());
+        self.update_own_liquidity(order);
 
         let venue_order_id = self.ids_generator.get_venue_order_id(order).unwrap();
         self.generate_order_canceled(order, venue_order_id);
@@ -2198,6 +2457,7 @@ impl OrderMatchingEngine {
                 );
             }
         }
+        self.update_own_liquidity(order);
 
         if self.config.support_contingent_orders
             && order
@@ -2490,3 +2750,28 @@ impl OrderMatchingEngine {
         order.apply(event).expect("Failed to apply order event");
     }
 }
+
+const fn is_market_type(order_type: OrderType) -> bool {
+    matches!(
+        order_type,
+        OrderType::Market
+            | OrderType::MarketIfTouched
+            | OrderType::StopMarket
+            | OrderType::TrailingStopMarket
+    )
+}
+
+/// Truncates `fills` so their total quantity does not exceed `max_qty`.
+fn cap_fills(fills: Vec<(Price, Quantity)>, max_qty: Quantity) -> Vec<(Price, Quantity)> {
+    let mut remaining = max_qty;
+    let mut capped = Vec::with_capacity(fills.len());
+    for (price, qty) in fills {
+        if remaining.is_zero() {
+            break;
+        }
+        let fill_qty = qty.min(remaining);
+        remaining -= fill_qty;
+        capped.push((price, fill_qty));
+    }
+    capped
+}
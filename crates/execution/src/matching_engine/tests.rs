@@ -58,7 +58,10 @@ use ustr::Ustr;
 
 use crate::{
     matching_engine::{config::OrderMatchingEngineConfig, engine::OrderMatchingEngine},
-    models::{fee::FeeModelAny, fill::FillModel},
+    models::{
+        fee::FeeModelAny,
+        fill::{FillModelAny, ProbabilisticFillModel, VolumeCappedFillModel},
+    },
 };
 
 #[fixture]
@@ -157,6 +160,7 @@ fn engine_config() -> OrderMatchingEngineConfig {
         use_position_ids: false,
         use_random_ids: false,
         use_reduce_only: true,
+        exclude_own_liquidity: false,
     }
 }
 // -- HELPERS ---------------------------------------------------------------------------
@@ -174,7 +178,7 @@ fn get_order_matching_engine(
     OrderMatchingEngine::new(
         instrument,
         1,
-        FillModel::default(),
+        FillModelAny::default(),
         FeeModelAny::default(),
         BookType::L1_MBP,
         OmsType::Netting,
@@ -198,7 +202,7 @@ fn get_order_matching_engine_l2(
     OrderMatchingEngine::new(
         instrument,
         1,
-        FillModel::default(),
+        FillModelAny::default(),
         FeeModelAny::default(),
         BookType::L2_MBP,
         OmsType::Netting,
@@ -2472,6 +2476,71 @@ fn test_process_market_to_limit_orders_not_fully_filled(
     assert_eq!(first_order.client_order_id(), client_order_id);
 }
 
+#[rstest]
+fn test_process_market_order_excludes_own_liquidity(
+    instrument_eth_usdt: InstrumentAny,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus::register(
+        MessagingSwitchboard::exec_engine_process(),
+        order_event_handler.clone(),
+    );
+
+    let engine_config = OrderMatchingEngineConfig {
+        exclude_own_liquidity: true,
+        ..Default::default()
+    };
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        None,
+        None,
+        Some(engine_config),
+        None,
+    );
+
+    for (price, order_id) in [("1500.00", 1), ("1501.00", 2)] {
+        let delta = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+            .book_action(BookAction::Add)
+            .book_order(BookOrder::new(
+                OrderSide::Sell,
+                Price::from(price),
+                Quantity::from("1.000"),
+                order_id,
+            ))
+            .build();
+        engine_l2.process_order_book_delta(&delta).unwrap();
+    }
+
+    // Our own resting ask makes up the whole of the public best ask
+    let mut own_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Sell)
+        .price(Price::from("1500.00"))
+        .quantity(Quantity::from("1.000"))
+        .client_order_id(ClientOrderId::from("O-OWN-ASK"))
+        .submit(true)
+        .build();
+    engine_l2.process_order(&mut own_order, account_id);
+
+    let mut market_order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from("1.000"))
+        .build();
+    let submitted = TestOrderEventStubs::submitted(&market_order, account_id);
+    market_order.apply(submitted).unwrap();
+    engine_l2.process_order(&mut market_order, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    let fill = match saved_messages.last().unwrap() {
+        OrderEventAny::Filled(fill) => fill,
+        _ => panic!("Expected OrderFilled event"),
+    };
+    assert_eq!(fill.last_px, Price::from("1501.00"));
+    assert_eq!(fill.last_qty, Quantity::from("1.000"));
+}
+
 #[rstest]
 fn test_process_trailing_stop_orders_rejeceted_and_valid(
     instrument_eth_usdt: InstrumentAny,
@@ -2808,3 +2877,279 @@ fn test_reduce_only_order_exceeding_position_quantity(
         "Order should be processed without panic"
     );
 }
+
+#[rstest]
+fn test_limit_order_fills_after_queue_ahead_traded(
+    instrument_eth_usdt: InstrumentAny,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus::register(
+        MessagingSwitchboard::exec_engine_process(),
+        order_event_handler.clone(),
+    );
+
+    let mut engine_l2 = OrderMatchingEngine::new(
+        instrument_eth_usdt.clone(),
+        1,
+        FillModelAny::Probabilistic(ProbabilisticFillModel::default().with_queue_position(true)),
+        FeeModelAny::default(),
+        BookType::L2_MBP,
+        OmsType::Netting,
+        AccountType::Cash,
+        Rc::new(RefCell::new(TestClock::new())),
+        Rc::new(RefCell::new(Cache::default())),
+        OrderMatchingEngineConfig::default(),
+    );
+
+    let orderbook_delta_buy = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Buy,
+            Price::from("1500.00"),
+            Quantity::from("5.000"),
+            1,
+        ))
+        .build();
+    let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1500.01"),
+            Quantity::from("1.000"),
+            2,
+        ))
+        .build();
+    engine_l2
+        .process_order_book_delta(&orderbook_delta_buy)
+        .unwrap();
+    engine_l2
+        .process_order_book_delta(&orderbook_delta_sell)
+        .unwrap();
+
+    // Join the back of the queue at the best bid
+    let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .price(Price::from("1500.00"))
+        .quantity(Quantity::from("2.000"))
+        .client_order_id(client_order_id)
+        .submit(true)
+        .build();
+    engine_l2.process_order(&mut limit_order, account_id);
+    assert_eq!(
+        engine_l2
+            .get_queue_tracker()
+            .get(&client_order_id)
+            .unwrap()
+            .ahead,
+        Quantity::from("5.000")
+    );
+
+    // Trades at the order price consume the queue ahead before reaching the order
+    for (trade_id, size) in [("1", "4.000"), ("2", "2.000")] {
+        let tick = TradeTick::new(
+            instrument_eth_usdt.id(),
+            Price::from("1500.00"),
+            Quantity::from(size),
+            AggressorSide::Seller,
+            TradeId::new(trade_id),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        );
+        engine_l2.process_trade_tick(&tick);
+    }
+
+    // Check we have received OrderAccepted and a single partial OrderFilled event
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 2);
+    assert_eq!(saved_messages[0].event_type(), OrderEventType::Accepted);
+    let OrderEventAny::Filled(fill) = &saved_messages[1] else {
+        panic!("Expected OrderFilled event in second message");
+    };
+    assert_eq!(fill.last_qty, Quantity::from("1.000"));
+    assert_eq!(fill.last_px, Price::from("1500.00"));
+    assert_eq!(fill.liquidity_side, LiquiditySide::Maker);
+    assert!(engine_l2.order_exists(client_order_id));
+}
+
+#[rstest]
+fn test_limit_order_at_queue_front_capped_by_traded_volume(
+    instrument_eth_usdt: InstrumentAny,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus::register(
+        MessagingSwitchboard::exec_engine_process(),
+        order_event_handler.clone(),
+    );
+
+    let mut engine_l2 = OrderMatchingEngine::new(
+        instrument_eth_usdt.clone(),
+        1,
+        FillModelAny::Probabilistic(ProbabilisticFillModel::default().with_queue_position(true)),
+        FeeModelAny::default(),
+        BookType::L2_MBP,
+        OmsType::Netting,
+        AccountType::Cash,
+        Rc::new(RefCell::new(TestClock::new())),
+        Rc::new(RefCell::new(Cache::default())),
+        OrderMatchingEngineConfig::default(),
+    );
+
+    let bid_order = BookOrder::new(
+        OrderSide::Buy,
+        Price::from("1500.00"),
+        Quantity::from("5.000"),
+        1,
+    );
+    let orderbook_delta_buy = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(bid_order)
+        .build();
+    let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1500.01"),
+            Quantity::from("1.000"),
+            2,
+        ))
+        .build();
+    engine_l2
+        .process_order_book_delta(&orderbook_delta_buy)
+        .unwrap();
+    engine_l2
+        .process_order_book_delta(&orderbook_delta_sell)
+        .unwrap();
+
+    let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .price(Price::from("1500.00"))
+        .quantity(Quantity::from("2.000"))
+        .client_order_id(client_order_id)
+        .submit(true)
+        .build();
+    engine_l2.process_order(&mut limit_order, account_id);
+
+    // The level ahead is canceled, moving the order to the front of the queue
+    let orderbook_delta_delete = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Delete)
+        .book_order(bid_order)
+        .build();
+    engine_l2
+        .process_order_book_delta(&orderbook_delta_delete)
+        .unwrap();
+    assert!(engine_l2.get_queue_tracker().is_front(&client_order_id));
+
+    // Offers moving down to the order price do not fill it before any volume trades
+    let orderbook_delta_ask = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1500.00"),
+            Quantity::from("10.000"),
+            3,
+        ))
+        .build();
+    engine_l2
+        .process_order_book_delta(&orderbook_delta_ask)
+        .unwrap();
+
+    let tick = TradeTick::new(
+        instrument_eth_usdt.id(),
+        Price::from("1500.00"),
+        Quantity::from("1.500"),
+        AggressorSide::Seller,
+        TradeId::new("1"),
+        UnixNanos::default(),
+        UnixNanos::default(),
+    );
+    engine_l2.process_trade_tick(&tick);
+
+    // Check the fill is capped at the traded volume rather than the order leaves
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 2);
+    assert_eq!(saved_messages[0].event_type(), OrderEventType::Accepted);
+    let OrderEventAny::Filled(fill) = &saved_messages[1] else {
+        panic!("Expected OrderFilled event in second message");
+    };
+    assert_eq!(fill.last_qty, Quantity::from("1.500"));
+    assert_eq!(fill.liquidity_side, LiquiditySide::Maker);
+}
+
+#[rstest]
+fn test_market_order_capped_by_traded_volume(
+    instrument_eth_usdt: InstrumentAny,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus::register(
+        MessagingSwitchboard::exec_engine_process(),
+        order_event_handler.clone(),
+    );
+
+    let mut engine_l2 = OrderMatchingEngine::new(
+        instrument_eth_usdt.clone(),
+        1,
+        FillModelAny::VolumeCapped(VolumeCappedFillModel::new(0.1).unwrap()),
+        FeeModelAny::default(),
+        BookType::L2_MBP,
+        OmsType::Netting,
+        AccountType::Cash,
+        Rc::new(RefCell::new(TestClock::new())),
+        Rc::new(RefCell::new(Cache::default())),
+        OrderMatchingEngineConfig::default(),
+    );
+
+    let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1500.00"),
+            Quantity::from("5.000"),
+            1,
+        ))
+        .build();
+    engine_l2
+        .process_order_book_delta(&orderbook_delta_sell)
+        .unwrap();
+
+    // 10% of the last traded volume is available to fill
+    let tick = TradeTick::new(
+        instrument_eth_usdt.id(),
+        Price::from("1500.00"),
+        Quantity::from("10.000"),
+        AggressorSide::Buyer,
+        TradeId::new("1"),
+        UnixNanos::default(),
+        UnixNanos::default(),
+    );
+    engine_l2.process_trade_tick(&tick);
+
+    let mut market_order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from("2.000"))
+        .client_order_id(ClientOrderId::from("O-19700101-000000-001-001-1"))
+        .submit(true)
+        .build();
+    engine_l2.process_order(&mut market_order, account_id);
+
+    // Check we have received a partial OrderFilled and OrderCanceled for the remainder
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 2);
+    let order_filled = match saved_messages.first().unwrap() {
+        OrderEventAny::Filled(filled) => filled,
+        _ => panic!("Expected OrderFilled event in first message"),
+    };
+    assert_eq!(order_filled.last_qty, Quantity::from("1.000"));
+    assert_eq!(order_filled.last_px, Price::from("1500.00"));
+    assert_eq!(
+        saved_messages.get(1).unwrap().event_type(),
+        OrderEventType::Canceled
+    );
+}
@@ -15,23 +15,173 @@
 
 use std::fmt::Display;
 
-use nautilus_core::correctness::{FAILED, check_in_range_inclusive_f64};
+use nautilus_core::correctness::{FAILED, check_in_range_inclusive_f64, check_non_negative_f64};
+use nautilus_model::{
+    enums::{LiquiditySide, OrderSideSpecified},
+    instruments::{Instrument, InstrumentAny},
+    orders::{Order, OrderAny},
+    types::{Price, Quantity},
+};
 use rand::{Rng, SeedableRng, rngs::StdRng};
 
+/// Models how simulated orders are filled by the matching engine.
+///
+/// The default method implementations describe a deterministic model which always fills
+/// at the simulated book prices and quantities.
+pub trait OrderFillModel {
+    /// Returns `true` if a limit order should be filled when the market rests on its price.
+    fn is_limit_filled(&mut self) -> bool {
+        true
+    }
+
+    /// Returns `true` if a stop order should be filled when the market rests on its price.
+    fn is_stop_filled(&mut self) -> bool {
+        true
+    }
+
+    /// Returns `true` if a fill should slip by one tick (L1 books only).
+    fn is_slipped(&mut self) -> bool {
+        false
+    }
+
+    /// Returns `true` if passive limit orders fill according to their simulated queue position.
+    fn queue_position(&self) -> bool {
+        false
+    }
+
+    /// Records traded market volume from a trade or bar.
+    fn record_volume(&mut self, _volume: Quantity) {}
+
+    /// Adjusts the simulated fills for an order before they are applied.
+    ///
+    /// Implementations may change fill prices (e.g. slippage) or reduce fill quantities
+    /// (e.g. volume caps), returning the fills to apply in the same level order.
+    fn adjust_fills(
+        &mut self,
+        _order: &OrderAny,
+        _liquidity_side: LiquiditySide,
+        fills: Vec<(Price, Quantity)>,
+        _instrument: &InstrumentAny,
+    ) -> Vec<(Price, Quantity)> {
+        fills
+    }
+}
+
+/// Dispatches to one of the available [`OrderFillModel`] implementations.
+///
+/// Only the probabilistic model applies fill probabilities (and queue position simulation) when
+/// the market rests on a limit or stop order price. The other variants use the deterministic
+/// defaults, so every limit order fills as soon as the market touches its price, and only adjust
+/// the resulting fills.
+#[derive(Clone, Debug)]
+#[allow(clippy::large_enum_variant)]
+pub enum FillModelAny {
+    Probabilistic(ProbabilisticFillModel),
+    SqrtImpact(SqrtImpactFillModel),
+    FixedBps(FixedBpsSlippageFillModel),
+    VolumeCapped(VolumeCappedFillModel),
+}
+
+impl OrderFillModel for FillModelAny {
+    fn is_limit_filled(&mut self) -> bool {
+        match self {
+            Self::Probabilistic(model) => model.is_limit_filled(),
+            Self::SqrtImpact(model) => model.is_limit_filled(),
+            Self::FixedBps(model) => model.is_limit_filled(),
+            Self::VolumeCapped(model) => model.is_limit_filled(),
+        }
+    }
+
+    fn is_stop_filled(&mut self) -> bool {
+        match self {
+            Self::Probabilistic(model) => model.is_stop_filled(),
+            Self::SqrtImpact(model) => model.is_stop_filled(),
+            Self::FixedBps(model) => model.is_stop_filled(),
+            Self::VolumeCapped(model) => model.is_stop_filled(),
+        }
+    }
+
+    fn is_slipped(&mut self) -> bool {
+        match self {
+            Self::Probabilistic(model) => model.is_slipped(),
+            Self::SqrtImpact(model) => model.is_slipped(),
+            Self::FixedBps(model) => model.is_slipped(),
+            Self::VolumeCapped(model) => model.is_slipped(),
+        }
+    }
+
+    fn queue_position(&self) -> bool {
+        match self {
+            Self::Probabilistic(model) => model.queue_position(),
+            Self::SqrtImpact(model) => model.queue_position(),
+            Self::FixedBps(model) => model.queue_position(),
+            Self::VolumeCapped(model) => model.queue_position(),
+        }
+    }
+
+    fn record_volume(&mut self, volume: Quantity) {
+        match self {
+            Self::Probabilistic(model) => model.record_volume(volume),
+            Self::SqrtImpact(model) => model.record_volume(volume),
+            Self::FixedBps(model) => model.record_volume(volume),
+            Self::VolumeCapped(model) => model.record_volume(volume),
+        }
+    }
+
+    fn adjust_fills(
+        &mut self,
+        order: &OrderAny,
+        liquidity_side: LiquiditySide,
+        fills: Vec<(Price, Quantity)>,
+        instrument: &InstrumentAny,
+    ) -> Vec<(Price, Quantity)> {
+        match self {
+            Self::Probabilistic(model) => {
+                model.adjust_fills(order, liquidity_side, fills, instrument)
+            }
+            Self::SqrtImpact(model) => model.adjust_fills(order, liquidity_side, fills, instrument),
+            Self::FixedBps(model) => model.adjust_fills(order, liquidity_side, fills, instrument),
+            Self::VolumeCapped(model) => {
+                model.adjust_fills(order, liquidity_side, fills, instrument)
+            }
+        }
+    }
+}
+
+impl Display for FillModelAny {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Probabilistic(model) => model.fmt(f),
+            Self::SqrtImpact(model) => model.fmt(f),
+            Self::FixedBps(model) => model.fmt(f),
+            Self::VolumeCapped(model) => model.fmt(f),
+        }
+    }
+}
+
+impl Default for FillModelAny {
+    fn default() -> Self {
+        Self::Probabilistic(ProbabilisticFillModel::default())
+    }
+}
+
+/// Simulates queue position and slippage with simple probabilities.
 #[derive(Debug, Clone)]
-pub struct FillModel {
+pub struct ProbabilisticFillModel {
     /// The probability of limit order filling if the market rests on its price.
     prob_fill_on_limit: f64,
     /// The probability of stop orders filling if the market rests on its price.
     prob_fill_on_stop: f64,
     /// The probability of order fill prices slipping by one tick.
     prob_slippage: f64,
+    /// If passive limit orders fill according to their simulated queue position (L2/L3 books).
+    queue_position: bool,
     /// Random number generator
     rng: StdRng,
 }
 
-impl FillModel {
-    /// Creates a new [`FillModel`] instance.
+impl ProbabilisticFillModel {
+    /// Creates a new [`ProbabilisticFillModel`] instance.
     ///
     /// # Errors
     ///
@@ -59,83 +209,301 @@ impl FillModel {
             prob_fill_on_limit,
             prob_fill_on_stop,
             prob_slippage,
+            queue_position: false,
             rng,
         })
     }
 
-    /// Returns `true` if a limit order should be filled based on the configured probability.
-    pub fn is_limit_filled(&mut self) -> bool {
+    /// Sets whether passive limit orders fill according to their simulated queue position.
+    ///
+    /// When enabled with L2 or L3 book data, a resting limit order only fills once the volume
+    /// ahead of it at its price level has traded (or been canceled), replacing the
+    /// `prob_fill_on_limit` probability for maker fills.
+    #[must_use]
+    pub const fn with_queue_position(mut self, queue_position: bool) -> Self {
+        self.queue_position = queue_position;
+        self
+    }
+
+    fn event_success(&mut self, probability: f64) -> bool {
+        match probability {
+            0.0 => false,
+            1.0 => true,
+            _ => self.rng.random_bool(probability),
+        }
+    }
+}
+
+/// The previous name of [`ProbabilisticFillModel`].
+#[deprecated(note = "Use `ProbabilisticFillModel` instead")]
+pub type FillModel = ProbabilisticFillModel;
+
+impl OrderFillModel for ProbabilisticFillModel {
+    fn is_limit_filled(&mut self) -> bool {
         self.event_success(self.prob_fill_on_limit)
     }
 
-    /// Returns `true` if a stop order should be filled based on the configured probability.
-    pub fn is_stop_filled(&mut self) -> bool {
+    fn is_stop_filled(&mut self) -> bool {
         self.event_success(self.prob_fill_on_stop)
     }
 
-    /// Returns `true` if an order should slip by one tick based on the configured probability.
-    pub fn is_slipped(&mut self) -> bool {
+    fn is_slipped(&mut self) -> bool {
         self.event_success(self.prob_slippage)
     }
 
-    /// Returns a simulated `OrderBook` for fill simulation.
+    fn queue_position(&self) -> bool {
+        self.queue_position
+    }
+}
+
+impl Display for ProbabilisticFillModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ProbabilisticFillModel(prob_fill_on_limit: {}, prob_fill_on_stop: {}, prob_slippage: {}, queue_position: {})",
+            self.prob_fill_on_limit,
+            self.prob_fill_on_stop,
+            self.prob_slippage,
+            self.queue_position
+        )
+    }
+}
+
+impl Default for ProbabilisticFillModel {
+    /// Creates a new default [`ProbabilisticFillModel`] instance.
+    fn default() -> Self {
+        Self::new(0.5, 0.5, 0.1, None).unwrap()
+    }
+}
+
+/// Slips taker fills by a square-root market impact of the filled size.
+///
+/// The impact in basis points is `impact_coefficient_bps * sqrt(fill_qty / reference_volume)`,
+/// applied against the order side to every fill price (rounded to the instrument tick).
+#[derive(Debug, Clone)]
+pub struct SqrtImpactFillModel {
+    impact_coefficient_bps: f64,
+    reference_volume: f64,
+}
+
+impl SqrtImpactFillModel {
+    /// Creates a new [`SqrtImpactFillModel`] instance.
     ///
-    /// This method allows custom fill models to provide their own liquidity
-    /// simulation by returning a custom `OrderBook` that represents the expected
-    /// market liquidity. The matching engine will use this simulated `OrderBook`
-    /// to determine fills.
+    /// # Errors
     ///
-    /// The default implementation returns None, which means the matching engine
-    /// will use its standard fill logic (maintaining backward compatibility).
-    pub fn get_orderbook_for_fill_simulation(
-        &self,
-        _instrument: &dyn std::any::Any, // Placeholder for instrument type
-        _order: &dyn std::any::Any,      // Placeholder for order type
-        _best_bid: f64,
-        _best_ask: f64,
-    ) -> Option<Box<dyn std::any::Any>> {
-        None // Default implementation - use existing fill logic
+    /// Returns an error if `impact_coefficient_bps` is negative or `reference_volume` is not positive.
+    pub fn new(impact_coefficient_bps: f64, reference_volume: f64) -> anyhow::Result<Self> {
+        check_non_negative_f64(impact_coefficient_bps, "impact_coefficient_bps")?;
+        if reference_volume <= 0.0 || reference_volume.is_nan() {
+            anyhow::bail!("Reference volume must be positive, was {reference_volume}")
+        }
+        Ok(Self {
+            impact_coefficient_bps,
+            reference_volume,
+        })
     }
+}
 
-    fn event_success(&mut self, probability: f64) -> bool {
-        match probability {
-            0.0 => false,
-            1.0 => true,
-            _ => self.rng.random_bool(probability),
+impl OrderFillModel for SqrtImpactFillModel {
+    fn adjust_fills(
+        &mut self,
+        order: &OrderAny,
+        liquidity_side: LiquiditySide,
+        fills: Vec<(Price, Quantity)>,
+        instrument: &InstrumentAny,
+    ) -> Vec<(Price, Quantity)> {
+        if liquidity_side != LiquiditySide::Taker {
+            return fills;
+        }
+
+        let fill_qty: f64 = fills.iter().map(|(_, qty)| qty.as_f64()).sum();
+        let impact_bps = self.impact_coefficient_bps * (fill_qty / self.reference_volume).sqrt();
+        let side = order.order_side_specified();
+
+        fills
+            .into_iter()
+            .map(|(px, qty)| (slip_price(px, side, impact_bps, instrument), qty))
+            .collect()
+    }
+}
+
+impl Display for SqrtImpactFillModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "SqrtImpactFillModel(impact_coefficient_bps: {}, reference_volume: {})",
+            self.impact_coefficient_bps, self.reference_volume
+        )
+    }
+}
+
+/// Slips taker fills by a fixed number of basis points.
+#[derive(Debug, Clone)]
+pub struct FixedBpsSlippageFillModel {
+    slippage_bps: f64,
+}
+
+impl FixedBpsSlippageFillModel {
+    /// Creates a new [`FixedBpsSlippageFillModel`] instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `slippage_bps` is negative.
+    pub fn new(slippage_bps: f64) -> anyhow::Result<Self> {
+        check_non_negative_f64(slippage_bps, "slippage_bps")?;
+        Ok(Self { slippage_bps })
+    }
+}
+
+impl OrderFillModel for FixedBpsSlippageFillModel {
+    fn adjust_fills(
+        &mut self,
+        order: &OrderAny,
+        liquidity_side: LiquiditySide,
+        fills: Vec<(Price, Quantity)>,
+        instrument: &InstrumentAny,
+    ) -> Vec<(Price, Quantity)> {
+        if liquidity_side != LiquiditySide::Taker {
+            return fills;
         }
+
+        let side = order.order_side_specified();
+        fills
+            .into_iter()
+            .map(|(px, qty)| (slip_price(px, side, self.slippage_bps, instrument), qty))
+            .collect()
     }
 }
 
-impl Display for FillModel {
+impl Display for FixedBpsSlippageFillModel {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "FillModel(prob_fill_on_limit: {}, prob_fill_on_stop: {}, prob_slippage: {})",
-            self.prob_fill_on_limit, self.prob_fill_on_stop, self.prob_slippage
+            "FixedBpsSlippageFillModel(slippage_bps: {})",
+            self.slippage_bps
         )
     }
 }
 
-impl Default for FillModel {
-    /// Creates a new default [`FillModel`] instance.
-    fn default() -> Self {
-        Self::new(0.5, 0.5, 0.1, None).unwrap()
+/// Caps fills at a percentage of the most recently traded volume.
+///
+/// Each trade or bar resets the available volume to `max_volume_pct` of its volume, which is
+/// then consumed by subsequent fills. No fills are possible until volume has been recorded.
+#[derive(Debug, Clone)]
+pub struct VolumeCappedFillModel {
+    max_volume_pct: f64,
+    available: f64,
+}
+
+impl VolumeCappedFillModel {
+    /// Creates a new [`VolumeCappedFillModel`] instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `max_volume_pct` is not in the range (0.0, 1.0].
+    pub fn new(max_volume_pct: f64) -> anyhow::Result<Self> {
+        if !(max_volume_pct > 0.0 && max_volume_pct <= 1.0) {
+            anyhow::bail!("Max volume percentage must be in range (0, 1], was {max_volume_pct}")
+        }
+        Ok(Self {
+            max_volume_pct,
+            available: 0.0,
+        })
+    }
+
+    /// Returns the volume currently available to fill.
+    #[must_use]
+    pub const fn available(&self) -> f64 {
+        self.available
+    }
+}
+
+impl OrderFillModel for VolumeCappedFillModel {
+    fn record_volume(&mut self, volume: Quantity) {
+        self.available = volume.as_f64() * self.max_volume_pct;
+    }
+
+    fn adjust_fills(
+        &mut self,
+        _order: &OrderAny,
+        _liquidity_side: LiquiditySide,
+        fills: Vec<(Price, Quantity)>,
+        _instrument: &InstrumentAny,
+    ) -> Vec<(Price, Quantity)> {
+        let mut capped = Vec::with_capacity(fills.len());
+
+        for (px, qty) in fills {
+            let precision = qty.precision;
+            let factor = 10f64.powi(i32::from(precision));
+            let available = (self.available * factor).floor() / factor;
+            let fill_qty = Quantity::new(qty.as_f64().min(available), precision);
+            if fill_qty.is_zero() {
+                break;
+            }
+            self.available = (self.available - fill_qty.as_f64()).max(0.0);
+            capped.push((px, fill_qty));
+        }
+
+        capped
     }
 }
 
+impl Display for VolumeCappedFillModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "VolumeCappedFillModel(max_volume_pct: {})",
+            self.max_volume_pct
+        )
+    }
+}
+
+/// Moves `price` against the order `side` by `bps` basis points, rounded to the instrument tick.
+fn slip_price(
+    price: Price,
+    side: OrderSideSpecified,
+    bps: f64,
+    instrument: &InstrumentAny,
+) -> Price {
+    let factor = bps / 10_000.0;
+    let value = match side {
+        OrderSideSpecified::Buy => price.as_f64() * (1.0 + factor),
+        OrderSideSpecified::Sell => price.as_f64() * (1.0 - factor),
+    };
+    instrument.make_price(value)
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    use nautilus_model::{
+        enums::{OrderSide, OrderType},
+        instruments::stubs::crypto_perpetual_ethusdt,
+        orders::OrderTestBuilder,
+    };
     use rstest::{fixture, rstest};
 
     use super::*;
 
     #[fixture]
-    fn fill_model() -> FillModel {
+    fn instrument() -> InstrumentAny {
+        InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt())
+    }
+
+    fn market_order(instrument: &InstrumentAny, side: OrderSide) -> OrderAny {
+        OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(side)
+            .quantity(Quantity::from("4.000"))
+            .build()
+    }
+
+    #[fixture]
+    fn fill_model() -> ProbabilisticFillModel {
         let seed = 42;
-        FillModel::new(0.5, 0.5, 0.1, Some(seed)).unwrap()
+        ProbabilisticFillModel::new(0.5, 0.5, 0.1, Some(seed)).unwrap()
     }
 
     #[rstest]
@@ -143,7 +511,7 @@ mod tests {
         expected = "Condition failed: invalid f64 for 'prob_fill_on_limit' not in range [0, 1], was 1.1"
     )]
     fn test_fill_model_param_prob_fill_on_limit_error() {
-        let _ = super::FillModel::new(1.1, 0.5, 0.1, None).unwrap();
+        let _ = super::ProbabilisticFillModel::new(1.1, 0.5, 0.1, None).unwrap();
     }
 
     #[rstest]
@@ -151,7 +519,7 @@ mod tests {
         expected = "Condition failed: invalid f64 for 'prob_fill_on_stop' not in range [0, 1], was 1.1"
     )]
     fn test_fill_model_param_prob_fill_on_stop_error() {
-        let _ = super::FillModel::new(0.5, 1.1, 0.1, None).unwrap();
+        let _ = super::ProbabilisticFillModel::new(0.5, 1.1, 0.1, None).unwrap();
     }
 
     #[rstest]
@@ -159,27 +527,119 @@ mod tests {
         expected = "Condition failed: invalid f64 for 'prob_slippage' not in range [0, 1], was 1.1"
     )]
     fn test_fill_model_param_prob_slippage_error() {
-        let _ = super::FillModel::new(0.5, 0.5, 1.1, None).unwrap();
+        let _ = super::ProbabilisticFillModel::new(0.5, 0.5, 1.1, None).unwrap();
     }
 
     #[rstest]
-    fn test_fill_model_is_limit_filled(mut fill_model: FillModel) {
+    fn test_fill_model_is_limit_filled(mut fill_model: ProbabilisticFillModel) {
         // because of fixed seed this is deterministic
         let result = fill_model.is_limit_filled();
         assert!(!result);
     }
 
     #[rstest]
-    fn test_fill_model_is_stop_filled(mut fill_model: FillModel) {
+    fn test_fill_model_is_stop_filled(mut fill_model: ProbabilisticFillModel) {
         // because of fixed seed this is deterministic
         let result = fill_model.is_stop_filled();
         assert!(!result);
     }
 
     #[rstest]
-    fn test_fill_model_is_slipped(mut fill_model: FillModel) {
+    fn test_fill_model_is_slipped(mut fill_model: ProbabilisticFillModel) {
         // because of fixed seed this is deterministic
         let result = fill_model.is_slipped();
         assert!(!result);
     }
+
+    #[rstest]
+    #[case(OrderSide::Buy, "1501.50")]
+    #[case(OrderSide::Sell, "1498.50")]
+    fn test_fixed_bps_slippage_moves_taker_fills_against_side(
+        instrument: InstrumentAny,
+        #[case] side: OrderSide,
+        #[case] expected_px: &str,
+    ) {
+        let mut model = FixedBpsSlippageFillModel::new(10.0).unwrap();
+        let order = market_order(&instrument, side);
+        let fills = vec![(Price::from("1500.00"), Quantity::from("1.000"))];
+
+        let adjusted = model.adjust_fills(&order, LiquiditySide::Taker, fills, &instrument);
+
+        assert_eq!(
+            adjusted,
+            vec![(Price::from(expected_px), Quantity::from("1.000"))]
+        );
+    }
+
+    #[rstest]
+    fn test_fixed_bps_slippage_ignores_maker_fills(instrument: InstrumentAny) {
+        let mut model = FixedBpsSlippageFillModel::new(10.0).unwrap();
+        let order = market_order(&instrument, OrderSide::Buy);
+        let fills = vec![(Price::from("1500.00"), Quantity::from("1.000"))];
+
+        let adjusted = model.adjust_fills(&order, LiquiditySide::Maker, fills.clone(), &instrument);
+
+        assert_eq!(adjusted, fills);
+    }
+
+    #[rstest]
+    fn test_sqrt_impact_scales_with_fill_size(instrument: InstrumentAny) {
+        // 20 bps * sqrt(4 / 100) = 4 bps
+        let mut model = SqrtImpactFillModel::new(20.0, 100.0).unwrap();
+        let order = market_order(&instrument, OrderSide::Buy);
+        let fills = vec![
+            (Price::from("1000.00"), Quantity::from("1.000")),
+            (Price::from("1001.00"), Quantity::from("3.000")),
+        ];
+
+        let adjusted = model.adjust_fills(&order, LiquiditySide::Taker, fills, &instrument);
+
+        assert_eq!(
+            adjusted,
+            vec![
+                (Price::from("1000.40"), Quantity::from("1.000")),
+                (Price::from("1001.40"), Quantity::from("3.000")),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_volume_capped_no_volume_recorded_fills_nothing(instrument: InstrumentAny) {
+        let mut model = VolumeCappedFillModel::new(0.1).unwrap();
+        let order = market_order(&instrument, OrderSide::Buy);
+        let fills = vec![(Price::from("1500.00"), Quantity::from("1.000"))];
+
+        let adjusted = model.adjust_fills(&order, LiquiditySide::Taker, fills, &instrument);
+
+        assert!(adjusted.is_empty());
+    }
+
+    #[rstest]
+    fn test_volume_capped_consumes_available_volume(instrument: InstrumentAny) {
+        let mut model = VolumeCappedFillModel::new(0.1).unwrap();
+        let order = market_order(&instrument, OrderSide::Buy);
+        model.record_volume(Quantity::from("25.000"));
+
+        let fills = vec![
+            (Price::from("1500.00"), Quantity::from("2.000")),
+            (Price::from("1500.50"), Quantity::from("2.000")),
+        ];
+        let adjusted = model.adjust_fills(&order, LiquiditySide::Taker, fills, &instrument);
+
+        assert_eq!(
+            adjusted,
+            vec![
+                (Price::from("1500.00"), Quantity::from("2.000")),
+                (Price::from("1500.50"), Quantity::from("0.500")),
+            ]
+        );
+        assert_eq!(model.available(), 0.0);
+    }
+
+    #[rstest]
+    #[case(0.0)]
+    #[case(1.5)]
+    fn test_volume_capped_invalid_pct(#[case] max_volume_pct: f64) {
+        assert!(VolumeCappedFillModel::new(max_volume_pct).is_err());
+    }
 }
@@ -13,24 +13,81 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::fmt::Display;
+use std::{fmt::Display, path::Path};
 
-use nautilus_core::UnixNanos;
+use nautilus_core::{UnixNanos, correctness::check_non_negative_f64};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+const NANOSECONDS_IN_DAY: u64 = 86_400_000_000_000;
 
 /// Provides latency modeling for order processing operations.
 ///
+/// Each method returns the latency to apply to a command initialized at `ts`.
+pub trait OrderLatencyModel {
+    /// Returns the latency for an order insert (submit) operation.
+    fn insert_latency(&mut self, ts: UnixNanos) -> UnixNanos;
+
+    /// Returns the latency for an order update (modify) operation.
+    fn update_latency(&mut self, ts: UnixNanos) -> UnixNanos;
+
+    /// Returns the latency for an order delete (cancel) operation.
+    fn delete_latency(&mut self, ts: UnixNanos) -> UnixNanos;
+}
+
+#[derive(Clone, Debug)]
+#[allow(clippy::large_enum_variant)]
+pub enum LatencyModelAny {
+    Static(StaticLatencyModel),
+    Stochastic(StochasticLatencyModel),
+}
+
+impl OrderLatencyModel for LatencyModelAny {
+    fn insert_latency(&mut self, ts: UnixNanos) -> UnixNanos {
+        match self {
+            Self::Static(model) => model.insert_latency(ts),
+            Self::Stochastic(model) => model.insert_latency(ts),
+        }
+    }
+
+    fn update_latency(&mut self, ts: UnixNanos) -> UnixNanos {
+        match self {
+            Self::Static(model) => model.update_latency(ts),
+            Self::Stochastic(model) => model.update_latency(ts),
+        }
+    }
+
+    fn delete_latency(&mut self, ts: UnixNanos) -> UnixNanos {
+        match self {
+            Self::Static(model) => model.delete_latency(ts),
+            Self::Stochastic(model) => model.delete_latency(ts),
+        }
+    }
+}
+
+impl Display for LatencyModelAny {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Static(model) => model.fmt(f),
+            Self::Stochastic(model) => model.fmt(f),
+        }
+    }
+}
+
+/// Provides fixed latencies for order processing operations.
+///
 /// Models the latency for different order operations including base network latency
 /// and specific operation latencies for insert, update, and delete operations.
-#[derive(Debug)]
-pub struct LatencyModel {
+/// Each operation latency is the sum of the base latency and the operation latency.
+#[derive(Clone, Debug)]
+pub struct StaticLatencyModel {
     pub base_latency_nanos: UnixNanos,
     pub insert_latency_nanos: UnixNanos,
     pub update_latency_nanos: UnixNanos,
     pub delete_latency_nanos: UnixNanos,
 }
 
-impl LatencyModel {
-    /// Creates a new [`LatencyModel`] instance.
+impl StaticLatencyModel {
+    /// Creates a new [`StaticLatencyModel`] instance.
     #[must_use]
     pub const fn new(
         base_latency_nanos: UnixNanos,
@@ -47,8 +104,485 @@ impl LatencyModel {
     }
 }
 
-impl Display for LatencyModel {
+impl OrderLatencyModel for StaticLatencyModel {
+    fn insert_latency(&mut self, _ts: UnixNanos) -> UnixNanos {
+        self.base_latency_nanos + self.insert_latency_nanos
+    }
+
+    fn update_latency(&mut self, _ts: UnixNanos) -> UnixNanos {
+        self.base_latency_nanos + self.update_latency_nanos
+    }
+
+    fn delete_latency(&mut self, _ts: UnixNanos) -> UnixNanos {
+        self.base_latency_nanos + self.delete_latency_nanos
+    }
+}
+
+/// The previous name of [`StaticLatencyModel`].
+#[deprecated(note = "Use `StaticLatencyModel` instead")]
+pub type LatencyModel = StaticLatencyModel;
+
+impl Display for StaticLatencyModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "StaticLatencyModel()")
+    }
+}
+
+/// A distribution of latencies (nanoseconds) to sample from.
+///
+/// Negative samples are clamped to zero.
+#[derive(Clone, Debug)]
+pub enum LatencyDistribution {
+    /// A constant latency.
+    Fixed(u64),
+    /// A normal distribution with the given mean and standard deviation.
+    Normal { mean_nanos: f64, std_nanos: f64 },
+    /// A lognormal distribution, where `mu` and `sigma` parameterize the natural log of the latency.
+    LogNormal { mu: f64, sigma: f64 },
+    /// An empirical histogram of observed latencies.
+    Empirical(EmpiricalLatencyHistogram),
+}
+
+impl LatencyDistribution {
+    /// Creates a new normal [`LatencyDistribution`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `mean_nanos` or `std_nanos` is negative.
+    pub fn normal(mean_nanos: f64, std_nanos: f64) -> anyhow::Result<Self> {
+        check_non_negative_f64(mean_nanos, "mean_nanos")?;
+        check_non_negative_f64(std_nanos, "std_nanos")?;
+        Ok(Self::Normal {
+            mean_nanos,
+            std_nanos,
+        })
+    }
+
+    /// Creates a new lognormal [`LatencyDistribution`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `mu` is not finite or `sigma` is negative.
+    pub fn lognormal(mu: f64, sigma: f64) -> anyhow::Result<Self> {
+        if !mu.is_finite() {
+            anyhow::bail!("Lognormal `mu` must be finite, was {mu}")
+        }
+        check_non_negative_f64(sigma, "sigma")?;
+        Ok(Self::LogNormal { mu, sigma })
+    }
+
+    /// Samples a latency (nanoseconds) from the distribution.
+    pub fn sample(&self, rng: &mut StdRng) -> u64 {
+        let value = match self {
+            Self::Fixed(nanos) => return *nanos,
+            Self::Normal {
+                mean_nanos,
+                std_nanos,
+            } => std_normal(rng).mul_add(*std_nanos, *mean_nanos),
+            Self::LogNormal { mu, sigma } => std_normal(rng).mul_add(*sigma, *mu).exp(),
+            Self::Empirical(histogram) => return histogram.sample(rng),
+        };
+        value.max(0.0).round() as u64
+    }
+}
+
+/// An empirical latency histogram of `(lower_nanos, upper_nanos, weight)` bins.
+///
+/// Sampling picks a bin in proportion to its weight, then a uniform latency within the bin.
+#[derive(Clone, Debug)]
+pub struct EmpiricalLatencyHistogram {
+    bins: Vec<(u64, u64)>,
+    cumulative_weights: Vec<f64>,
+}
+
+impl EmpiricalLatencyHistogram {
+    /// Creates a new [`EmpiricalLatencyHistogram`] instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `bins` is empty.
+    /// - Any bin has an upper bound below its lower bound.
+    /// - Any weight is negative, or all weights are zero.
+    pub fn new(bins: Vec<(u64, u64, f64)>) -> anyhow::Result<Self> {
+        if bins.is_empty() {
+            anyhow::bail!("Latency histogram must contain at least one bin")
+        }
+
+        let mut total = 0.0;
+        let mut cumulative_weights = Vec::with_capacity(bins.len());
+        for (lower, upper, weight) in &bins {
+            if upper < lower {
+                anyhow::bail!("Invalid latency histogram bin: upper {upper} < lower {lower}")
+            }
+            check_non_negative_f64(*weight, "weight")?;
+            total += weight;
+            cumulative_weights.push(total);
+        }
+
+        if total <= 0.0 {
+            anyhow::bail!("Latency histogram weights must sum to a positive value")
+        }
+
+        Ok(Self {
+            bins: bins
+                .into_iter()
+                .map(|(lower, upper, _)| (lower, upper))
+                .collect(),
+            cumulative_weights,
+        })
+    }
+
+    /// Loads a histogram from a CSV file with `lower_nanos,upper_nanos,weight` rows.
+    ///
+    /// Blank lines, lines starting with `#` and a non-numeric header row are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read, a row cannot be parsed, or the bins are invalid.
+    pub fn from_csv<P: AsRef<Path>>(path: P) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| anyhow::anyhow!("Failed to read latency histogram {path:?}: {e}"))?;
+        Self::parse_csv(&content)
+    }
+
+    fn parse_csv(content: &str) -> anyhow::Result<Self> {
+        let mut bins = Vec::new();
+
+        for (i, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            if fields.len() != 3 {
+                anyhow::bail!("Invalid latency histogram row {}: '{line}'", i + 1)
+            }
+
+            match (
+                fields[0].parse::<u64>(),
+                fields[1].parse::<u64>(),
+                fields[2].parse::<f64>(),
+            ) {
+                (Ok(lower), Ok(upper), Ok(weight)) => bins.push((lower, upper, weight)),
+                _ if i == 0 => continue, // Header
+                _ => anyhow::bail!("Invalid latency histogram row {}: '{line}'", i + 1),
+            }
+        }
+
+        Self::new(bins)
+    }
+
+    /// Samples a latency (nanoseconds) from the histogram.
+    pub fn sample(&self, rng: &mut StdRng) -> u64 {
+        let total = self.cumulative_weights[self.cumulative_weights.len() - 1];
+        let target = rng.random::<f64>() * total;
+        let index = self
+            .cumulative_weights
+            .partition_point(|weight| *weight <= target)
+            .min(self.bins.len() - 1);
+
+        let (lower, upper) = self.bins[index];
+        if lower == upper {
+            lower
+        } else {
+            rng.random_range(lower..=upper)
+        }
+    }
+}
+
+/// A time-of-day window (UTC) during which sampled latencies are scaled by `multiplier`.
+///
+/// Windows where `start_nanos` is after `end_nanos` wrap around midnight.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LatencySpike {
+    /// The window start as nanoseconds since midnight UTC (inclusive).
+    pub start_nanos: u64,
+    /// The window end as nanoseconds since midnight UTC (exclusive).
+    pub end_nanos: u64,
+    /// The factor applied to latencies sampled within the window.
+    pub multiplier: f64,
+}
+
+impl LatencySpike {
+    /// Creates a new [`LatencySpike`] instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either bound is not within a day, or `multiplier` is negative.
+    pub fn new(start_nanos: u64, end_nanos: u64, multiplier: f64) -> anyhow::Result<Self> {
+        if start_nanos >= NANOSECONDS_IN_DAY || end_nanos > NANOSECONDS_IN_DAY {
+            anyhow::bail!(
+                "Latency spike window must be within a day, was {start_nanos}..{end_nanos}"
+            )
+        }
+        check_non_negative_f64(multiplier, "multiplier")?;
+        Ok(Self {
+            start_nanos,
+            end_nanos,
+            multiplier,
+        })
+    }
+
+    /// Returns `true` if the given timestamp falls within the window.
+    #[must_use]
+    pub fn contains(&self, ts: UnixNanos) -> bool {
+        let time_of_day = ts.as_u64() % NANOSECONDS_IN_DAY;
+        if self.start_nanos <= self.end_nanos {
+            time_of_day >= self.start_nanos && time_of_day < self.end_nanos
+        } else {
+            time_of_day >= self.start_nanos || time_of_day < self.end_nanos
+        }
+    }
+}
+
+/// Provides latencies sampled from per-operation distributions with a seeded RNG.
+///
+/// Each operation latency is the sum of a base (network) sample and the operation sample,
+/// scaled by the multiplier of every [`LatencySpike`] window containing the command timestamp.
+#[derive(Clone, Debug)]
+pub struct StochasticLatencyModel {
+    base: LatencyDistribution,
+    insert: LatencyDistribution,
+    update: LatencyDistribution,
+    delete: LatencyDistribution,
+    spikes: Vec<LatencySpike>,
+    rng: StdRng,
+}
+
+impl StochasticLatencyModel {
+    /// Creates a new [`StochasticLatencyModel`] instance.
+    #[must_use]
+    pub fn new(
+        base: LatencyDistribution,
+        insert: LatencyDistribution,
+        update: LatencyDistribution,
+        delete: LatencyDistribution,
+        random_seed: Option<u64>,
+    ) -> Self {
+        let rng = match random_seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_os_rng(),
+        };
+        Self {
+            base,
+            insert,
+            update,
+            delete,
+            spikes: Vec::new(),
+            rng,
+        }
+    }
+
+    /// Adds time-of-day latency spike windows to the model.
+    #[must_use]
+    pub fn with_spikes(mut self, spikes: Vec<LatencySpike>) -> Self {
+        self.spikes.extend(spikes);
+        self
+    }
+
+    fn sample(&mut self, ts: UnixNanos, operation: Operation) -> UnixNanos {
+        let distribution = match operation {
+            Operation::Insert => &self.insert,
+            Operation::Update => &self.update,
+            Operation::Delete => &self.delete,
+        };
+        let latency = self.base.sample(&mut self.rng) + distribution.sample(&mut self.rng);
+
+        let multiplier: f64 = self
+            .spikes
+            .iter()
+            .filter(|spike| spike.contains(ts))
+            .map(|spike| spike.multiplier)
+            .product();
+
+        if multiplier == 1.0 {
+            UnixNanos::from(latency)
+        } else {
+            UnixNanos::from((latency as f64 * multiplier).round() as u64)
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Operation {
+    Insert,
+    Update,
+    Delete,
+}
+
+impl OrderLatencyModel for StochasticLatencyModel {
+    fn insert_latency(&mut self, ts: UnixNanos) -> UnixNanos {
+        self.sample(ts, Operation::Insert)
+    }
+
+    fn update_latency(&mut self, ts: UnixNanos) -> UnixNanos {
+        self.sample(ts, Operation::Update)
+    }
+
+    fn delete_latency(&mut self, ts: UnixNanos) -> UnixNanos {
+        self.sample(ts, Operation::Delete)
+    }
+}
+
+impl Display for StochasticLatencyModel {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "LatencyModel()")
+        write!(f, "StochasticLatencyModel(spikes: {})", self.spikes.len())
+    }
+}
+
+/// Samples a standard normal variate using the Box-Muller transform.
+fn std_normal(rng: &mut StdRng) -> f64 {
+    let u1 = 1.0 - rng.random::<f64>(); // (0, 1] to avoid ln(0)
+    let u2 = rng.random::<f64>();
+    (-2.0 * u1.ln()).sqrt() * (std::f64::consts::TAU * u2).cos()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn sample_mean(distribution: &LatencyDistribution, n: usize) -> f64 {
+        let mut rng = StdRng::seed_from_u64(42);
+        (0..n)
+            .map(|_| distribution.sample(&mut rng) as f64)
+            .sum::<f64>()
+            / n as f64
+    }
+
+    #[rstest]
+    fn test_static_latency_model() {
+        let mut model = StaticLatencyModel::new(
+            UnixNanos::from(100),
+            UnixNanos::from(200),
+            UnixNanos::from(300),
+            UnixNanos::from(400),
+        );
+
+        assert_eq!(model.insert_latency(UnixNanos::default()), 300);
+        assert_eq!(model.update_latency(UnixNanos::default()), 400);
+        assert_eq!(model.delete_latency(UnixNanos::default()), 500);
+    }
+
+    #[rstest]
+    fn test_normal_distribution_mean() {
+        let distribution = LatencyDistribution::normal(1_000_000.0, 100_000.0).unwrap();
+
+        let mean = sample_mean(&distribution, 10_000);
+
+        assert!((mean - 1_000_000.0).abs() < 5_000.0, "mean was {mean}");
+    }
+
+    #[rstest]
+    fn test_lognormal_distribution_median() {
+        let distribution = LatencyDistribution::lognormal(1_000_000f64.ln(), 0.5).unwrap();
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let mut samples: Vec<u64> = (0..10_001).map(|_| distribution.sample(&mut rng)).collect();
+        samples.sort_unstable();
+        let median = samples[5_000] as f64;
+
+        assert!(
+            (median - 1_000_000.0).abs() < 30_000.0,
+            "median was {median}"
+        );
+    }
+
+    #[rstest]
+    fn test_normal_distribution_clamps_to_zero() {
+        let distribution = LatencyDistribution::normal(0.0, 1_000.0).unwrap();
+        let mut rng = StdRng::seed_from_u64(1);
+
+        // Roughly half the samples would be negative without clamping
+        assert!((0..100).any(|_| distribution.sample(&mut rng) == 0));
+    }
+
+    #[rstest]
+    fn test_empirical_histogram_from_csv() {
+        let content = "lower_nanos,upper_nanos,weight\n# fast path\n100,200,3\n\n1000,1000,1\n";
+        let histogram = EmpiricalLatencyHistogram::parse_csv(content).unwrap();
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let samples: Vec<u64> = (0..1_000).map(|_| histogram.sample(&mut rng)).collect();
+        let slow = samples.iter().filter(|s| **s == 1_000).count();
+
+        assert!(
+            samples
+                .iter()
+                .all(|s| (100..=200).contains(s) || *s == 1_000)
+        );
+        assert!((200..300).contains(&slow), "slow count was {slow}");
+    }
+
+    #[rstest]
+    #[case("100,50,1\n")]
+    #[case("100,200\n")]
+    #[case("100,200,0\n")]
+    #[case("a,b,c\n100,x,1\n")]
+    fn test_empirical_histogram_invalid(#[case] content: &str) {
+        assert!(EmpiricalLatencyHistogram::parse_csv(content).is_err());
+    }
+
+    #[rstest]
+    #[case(3_600_000_000_000, true)] // 01:00
+    #[case(82_800_000_000_000, true)] // 23:00
+    #[case(43_200_000_000_000, false)] // 12:00
+    fn test_latency_spike_wraps_midnight(#[case] ts: u64, #[case] expected: bool) {
+        // 22:00 to 02:00 UTC
+        let spike = LatencySpike::new(79_200_000_000_000, 7_200_000_000_000, 2.0).unwrap();
+
+        assert_eq!(
+            spike.contains(UnixNanos::from(NANOSECONDS_IN_DAY + ts)),
+            expected
+        );
+    }
+
+    #[rstest]
+    fn test_stochastic_model_applies_spikes() {
+        let mut model = StochasticLatencyModel::new(
+            LatencyDistribution::Fixed(1_000),
+            LatencyDistribution::Fixed(500),
+            LatencyDistribution::Fixed(200),
+            LatencyDistribution::Fixed(100),
+            Some(42),
+        )
+        .with_spikes(vec![LatencySpike::new(0, 3_600_000_000_000, 3.0).unwrap()]);
+
+        assert_eq!(model.insert_latency(UnixNanos::from(1)), 4_500);
+        assert_eq!(
+            model.update_latency(UnixNanos::from(7_200_000_000_000)),
+            1_200
+        );
+        assert_eq!(
+            model.delete_latency(UnixNanos::from(7_200_000_000_000)),
+            1_100
+        );
+    }
+
+    #[rstest]
+    fn test_stochastic_model_seeded_is_deterministic() {
+        let new_model = || {
+            StochasticLatencyModel::new(
+                LatencyDistribution::normal(1_000_000.0, 200_000.0).unwrap(),
+                LatencyDistribution::lognormal(10.0, 1.0).unwrap(),
+                LatencyDistribution::Fixed(0),
+                LatencyDistribution::Fixed(0),
+                Some(7),
+            )
+        };
+        let mut model1 = new_model();
+        let mut model2 = new_model();
+
+        for i in 0..10 {
+            let ts = UnixNanos::from(i);
+            assert_eq!(model1.insert_latency(ts), model2.insert_latency(ts));
+        }
     }
 }
@@ -0,0 +1,380 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Queue position tracking for simulated passive limit orders.
+
+use std::collections::{HashMap, HashSet};
+
+use nautilus_model::{
+    enums::{AggressorSide, OrderSideSpecified},
+    identifiers::ClientOrderId,
+    types::{Price, Quantity},
+};
+
+/// The queue position of a single simulated order at its price level.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct QueuePosition {
+    /// The side of the resting order.
+    pub side: OrderSideSpecified,
+    /// The limit price of the resting order.
+    pub price: Price,
+    /// The volume resting ahead of the order at its price level.
+    pub ahead: Quantity,
+    /// The traded volume which has reached the order after the queue ahead was exhausted.
+    pub fillable: Quantity,
+}
+
+impl QueuePosition {
+    /// Returns `true` if there is no volume remaining ahead of the order.
+    #[must_use]
+    pub fn is_front(&self) -> bool {
+        self.ahead.is_zero()
+    }
+}
+
+/// Tracks the queue position of simulated resting limit orders.
+///
+/// An order joins the back of the queue at its price level, so the volume ahead of it starts as
+/// the size of that level. Trade prints at the order price consume the volume ahead, with any
+/// excess becoming fillable for the order. Level size updates can only move an order forward:
+/// when a level shrinks below the volume ahead, the cancellations are assumed to have come from
+/// ahead of the order (a level shrinking by less is attributed to orders behind it). A trade
+/// through the order price means the whole level was consumed.
+///
+/// Tracked orders are indexed by price level, so level updates only touch the orders resting
+/// at that level.
+#[derive(Clone, Debug, Default)]
+pub struct QueuePositionTracker {
+    positions: HashMap<ClientOrderId, QueuePosition>,
+    levels: HashMap<(OrderSideSpecified, Price), HashSet<ClientOrderId>>,
+}
+
+impl QueuePositionTracker {
+    /// Creates a new empty [`QueuePositionTracker`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the queue position for the given order (if tracked).
+    #[must_use]
+    pub fn get(&self, client_order_id: &ClientOrderId) -> Option<&QueuePosition> {
+        self.positions.get(client_order_id)
+    }
+
+    /// Returns `true` if the given order is tracked.
+    #[must_use]
+    pub fn contains(&self, client_order_id: &ClientOrderId) -> bool {
+        self.positions.contains_key(client_order_id)
+    }
+
+    /// Returns `true` if the given order is untracked or has no volume remaining ahead of it.
+    #[must_use]
+    pub fn is_front(&self, client_order_id: &ClientOrderId) -> bool {
+        self.positions
+            .get(client_order_id)
+            .is_none_or(QueuePosition::is_front)
+    }
+
+    /// Returns the price levels which have at least one tracked order.
+    pub fn levels(&self) -> impl Iterator<Item = (OrderSideSpecified, Price)> + '_ {
+        self.levels.keys().copied()
+    }
+
+    /// Adds an order to the back of the queue at its price level, replacing any existing entry.
+    pub fn add(
+        &mut self,
+        client_order_id: ClientOrderId,
+        side: OrderSideSpecified,
+        price: Price,
+        ahead: Quantity,
+    ) {
+        let position = QueuePosition {
+            side,
+            price,
+            ahead,
+            fillable: Quantity::zero(ahead.precision),
+        };
+        if let Some(previous) = self.positions.insert(client_order_id, position) {
+            self.unindex(&client_order_id, &previous);
+        }
+        self.levels
+            .entry((side, price))
+            .or_default()
+            .insert(client_order_id);
+    }
+
+    /// Removes the given order from tracking.
+    pub fn remove(&mut self, client_order_id: &ClientOrderId) -> Option<QueuePosition> {
+        let position = self.positions.remove(client_order_id)?;
+        self.unindex(client_order_id, &position);
+        Some(position)
+    }
+
+    /// Clears all tracked orders.
+    pub fn clear(&mut self) {
+        self.positions.clear();
+        self.levels.clear();
+    }
+
+    /// Updates the volume ahead of orders resting at the given level with its new `size`.
+    pub fn update_level(&mut self, side: OrderSideSpecified, price: Price, size: Quantity) {
+        let Some(client_order_ids) = self.levels.get(&(side, price)) else {
+            return;
+        };
+
+        for client_order_id in client_order_ids {
+            if let Some(position) = self.positions.get_mut(client_order_id)
+                && size < position.ahead
+            {
+                position.ahead = size;
+            }
+        }
+    }
+
+    /// Applies a trade print to all tracked orders on the passive side of the trade.
+    ///
+    /// Returns the IDs of orders which now have fillable volume.
+    pub fn trade(
+        &mut self,
+        price: Price,
+        size: Quantity,
+        aggressor_side: AggressorSide,
+    ) -> Vec<ClientOrderId> {
+        let mut fillable = Vec::new();
+
+        for (client_order_id, position) in &mut self.positions {
+            let affected = match aggressor_side {
+                AggressorSide::Buyer => position.side == OrderSideSpecified::Sell,
+                AggressorSide::Seller => position.side == OrderSideSpecified::Buy,
+                AggressorSide::NoAggressor => true,
+            };
+            if !affected {
+                continue;
+            }
+
+            let traded_through = match position.side {
+                OrderSideSpecified::Buy => price < position.price,
+                OrderSideSpecified::Sell => price > position.price,
+            };
+
+            if traded_through {
+                position.ahead = Quantity::zero(position.ahead.precision);
+                position.fillable += size;
+            } else if price == position.price {
+                let consumed = size.min(position.ahead);
+                position.ahead -= consumed;
+                position.fillable += size - consumed;
+            } else {
+                continue;
+            }
+
+            if position.fillable.is_positive() {
+                fillable.push(*client_order_id);
+            }
+        }
+
+        fillable
+    }
+
+    /// Consumes up to `max_qty` of the fillable volume for the given order.
+    ///
+    /// Returns the quantity consumed, which is zero if the order is not tracked.
+    pub fn take_fillable(
+        &mut self,
+        client_order_id: &ClientOrderId,
+        max_qty: Quantity,
+    ) -> Quantity {
+        match self.positions.get_mut(client_order_id) {
+            Some(position) => {
+                let qty = position.fillable.min(max_qty);
+                position.fillable -= qty;
+                qty
+            }
+            None => Quantity::zero(max_qty.precision),
+        }
+    }
+
+    fn unindex(&mut self, client_order_id: &ClientOrderId, position: &QueuePosition) {
+        let key = (position.side, position.price);
+        if let Some(client_order_ids) = self.levels.get_mut(&key) {
+            client_order_ids.remove(client_order_id);
+            if client_order_ids.is_empty() {
+                self.levels.remove(&key);
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    #[fixture]
+    fn tracker() -> QueuePositionTracker {
+        let mut tracker = QueuePositionTracker::new();
+        tracker.add(
+            ClientOrderId::from("O-1"),
+            OrderSideSpecified::Buy,
+            Price::from("100.00"),
+            Quantity::from("10.000"),
+        );
+        tracker
+    }
+
+    #[rstest]
+    fn test_trade_at_price_consumes_volume_ahead(mut tracker: QueuePositionTracker) {
+        let id = ClientOrderId::from("O-1");
+
+        let fillable = tracker.trade(
+            Price::from("100.00"),
+            Quantity::from("4.000"),
+            AggressorSide::Seller,
+        );
+
+        assert!(fillable.is_empty());
+        assert_eq!(tracker.get(&id).unwrap().ahead, Quantity::from("6.000"));
+        assert!(!tracker.is_front(&id));
+    }
+
+    #[rstest]
+    fn test_trade_beyond_queue_becomes_fillable(mut tracker: QueuePositionTracker) {
+        let id = ClientOrderId::from("O-1");
+
+        let fillable = tracker.trade(
+            Price::from("100.00"),
+            Quantity::from("12.000"),
+            AggressorSide::Seller,
+        );
+
+        assert_eq!(fillable, vec![id]);
+        assert!(tracker.is_front(&id));
+        assert_eq!(
+            tracker.take_fillable(&id, Quantity::from("1.000")),
+            Quantity::from("1.000")
+        );
+        assert_eq!(tracker.get(&id).unwrap().fillable, Quantity::from("1.000"));
+    }
+
+    #[rstest]
+    fn test_trade_on_same_side_is_ignored(mut tracker: QueuePositionTracker) {
+        let fillable = tracker.trade(
+            Price::from("100.00"),
+            Quantity::from("12.000"),
+            AggressorSide::Buyer,
+        );
+
+        assert!(fillable.is_empty());
+        assert_eq!(
+            tracker.get(&ClientOrderId::from("O-1")).unwrap().ahead,
+            Quantity::from("10.000")
+        );
+    }
+
+    #[rstest]
+    fn test_trade_through_price_clears_queue(mut tracker: QueuePositionTracker) {
+        let id = ClientOrderId::from("O-1");
+
+        let fillable = tracker.trade(
+            Price::from("99.99"),
+            Quantity::from("2.000"),
+            AggressorSide::Seller,
+        );
+
+        assert_eq!(fillable, vec![id]);
+        assert!(tracker.is_front(&id));
+        assert_eq!(tracker.get(&id).unwrap().fillable, Quantity::from("2.000"));
+    }
+
+    #[rstest]
+    #[case("12.000", "10.000")]
+    #[case("8.000", "8.000")]
+    #[case("0.000", "0.000")]
+    fn test_update_level_only_moves_forward(
+        mut tracker: QueuePositionTracker,
+        #[case] level_size: &str,
+        #[case] expected_ahead: &str,
+    ) {
+        tracker.update_level(
+            OrderSideSpecified::Buy,
+            Price::from("100.00"),
+            Quantity::from(level_size),
+        );
+
+        assert_eq!(
+            tracker.get(&ClientOrderId::from("O-1")).unwrap().ahead,
+            Quantity::from(expected_ahead)
+        );
+    }
+
+    #[rstest]
+    fn test_update_level_only_touches_orders_at_level(mut tracker: QueuePositionTracker) {
+        let other = ClientOrderId::from("O-2");
+        tracker.add(
+            other,
+            OrderSideSpecified::Buy,
+            Price::from("99.99"),
+            Quantity::from("10.000"),
+        );
+
+        tracker.update_level(
+            OrderSideSpecified::Buy,
+            Price::from("99.99"),
+            Quantity::from("3.000"),
+        );
+
+        assert_eq!(tracker.get(&other).unwrap().ahead, Quantity::from("3.000"));
+        assert_eq!(
+            tracker.get(&ClientOrderId::from("O-1")).unwrap().ahead,
+            Quantity::from("10.000")
+        );
+
+        // Re-adding at a new price moves the order to the new level
+        tracker.add(
+            other,
+            OrderSideSpecified::Buy,
+            Price::from("100.00"),
+            Quantity::from("20.000"),
+        );
+        let mut levels: Vec<_> = tracker.levels().collect();
+        levels.sort();
+        assert_eq!(
+            levels,
+            vec![(OrderSideSpecified::Buy, Price::from("100.00"))]
+        );
+
+        tracker.clear();
+        assert_eq!(tracker.levels().count(), 0);
+    }
+
+    #[rstest]
+    fn test_untracked_order_is_front(mut tracker: QueuePositionTracker) {
+        let id = ClientOrderId::from("O-1");
+        tracker.remove(&id);
+
+        assert!(!tracker.contains(&id));
+        assert!(tracker.is_front(&id));
+        assert!(
+            tracker
+                .take_fillable(&id, Quantity::from("1.000"))
+                .is_zero()
+        );
+    }
+}
@@ -0,0 +1,227 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use nautilus_model::{data::OrderBookDepth10, orderbook::OrderBook};
+
+use crate::{
+    book::{BookLevelTuple, book_levels, depth10_levels},
+    indicator::Indicator,
+};
+
+/// An indicator which calculates the slope of the cumulative depth of each side of the book.
+///
+/// The slope of each side is the least squares fit of the cumulative size against the distance
+/// of each level from the mid price, i.e. the size available per unit of price moved. Steeper
+/// slopes indicate a more liquid book. The value is the average of the bid and ask slopes.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
+)]
+pub struct DepthSlope {
+    pub depth: usize,
+    pub bid_slope: f64,
+    pub ask_slope: f64,
+    pub value: f64,
+    pub count: usize,
+    pub initialized: bool,
+    has_inputs: bool,
+}
+
+impl Display for DepthSlope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.depth)
+    }
+}
+
+impl Indicator for DepthSlope {
+    fn name(&self) -> String {
+        stringify!(DepthSlope).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_book(&mut self, book: &OrderBook) {
+        let (bids, asks) = book_levels(book, self.depth);
+        self.update(&bids, &asks);
+    }
+
+    fn handle_depth(&mut self, depth: &OrderBookDepth10) {
+        let (bids, asks) = depth10_levels(depth, self.depth);
+        self.update(&bids, &asks);
+    }
+
+    fn reset(&mut self) {
+        self.bid_slope = 0.0;
+        self.ask_slope = 0.0;
+        self.value = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl DepthSlope {
+    /// Creates a new [`DepthSlope`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `depth` is less than 2 (a slope requires at least two levels).
+    #[must_use]
+    pub fn new(depth: usize) -> Self {
+        assert!(depth >= 2, "DepthSlope: depth must be >= 2");
+        Self {
+            depth,
+            bid_slope: 0.0,
+            ask_slope: 0.0,
+            value: 0.0,
+            count: 0,
+            initialized: false,
+            has_inputs: false,
+        }
+    }
+
+    /// Updates the indicator with the `(price, size)` levels of each side, best price first.
+    pub fn update(&mut self, bids: &[BookLevelTuple], asks: &[BookLevelTuple]) {
+        self.has_inputs = true;
+        self.count += 1;
+
+        let (Some((best_bid, _)), Some((best_ask, _))) = (bids.first(), asks.first()) else {
+            return; // No market yet
+        };
+        let mid = (best_bid + best_ask) / 2.0;
+
+        if let (Some(bid_slope), Some(ask_slope)) =
+            (self.side_slope(bids, mid), self.side_slope(asks, mid))
+        {
+            self.bid_slope = bid_slope;
+            self.ask_slope = ask_slope;
+            self.value = (bid_slope + ask_slope) / 2.0;
+            self.initialized = true;
+        }
+    }
+
+    fn side_slope(&self, levels: &[BookLevelTuple], mid: f64) -> Option<f64> {
+        let mut cumulative = 0.0;
+        let points: Vec<(f64, f64)> = levels
+            .iter()
+            .take(self.depth)
+            .map(|(price, size)| {
+                cumulative += size;
+                ((price - mid).abs(), cumulative)
+            })
+            .collect();
+
+        if points.len() < 2 {
+            return None;
+        }
+
+        let n = points.len() as f64;
+        let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+        let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+        let (cov, var) = points.iter().fold((0.0, 0.0), |(cov, var), (x, y)| {
+            let dx = x - mean_x;
+            (dx.mul_add(y - mean_y, cov), dx.mul_add(dx, var))
+        });
+
+        (var > 0.0).then(|| cov / var)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{data::stubs::stub_depth10, stubs::stub_order_book_mbp_appl_xnas};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_initialized() {
+        let slope = DepthSlope::new(5);
+        assert_eq!(format!("{slope}"), "DepthSlope(5)");
+        assert_eq!(slope.value, 0.0);
+        assert!(!slope.has_inputs());
+        assert!(!slope.initialized());
+    }
+
+    #[rstest]
+    #[should_panic(expected = "depth must be >= 2")]
+    fn test_new_with_single_level() {
+        let _ = DepthSlope::new(1);
+    }
+
+    #[rstest]
+    fn test_update_with_linear_depth() {
+        let mut slope = DepthSlope::new(5);
+        // Cumulative bids 10, 20, 30 and asks 20, 40, 60 at distances 0.5, 1.5, 2.5
+        slope.update(
+            &[(100.0, 10.0), (99.0, 10.0), (98.0, 10.0)],
+            &[(101.0, 20.0), (102.0, 20.0), (103.0, 20.0)],
+        );
+
+        assert!((slope.bid_slope - 10.0).abs() < 1e-9);
+        assert!((slope.ask_slope - 20.0).abs() < 1e-9);
+        assert!((slope.value - 15.0).abs() < 1e-9);
+        assert!(slope.initialized());
+    }
+
+    #[rstest]
+    fn test_update_with_single_level_side() {
+        let mut slope = DepthSlope::new(5);
+        slope.update(&[(100.0, 10.0), (99.0, 10.0)], &[(101.0, 20.0)]);
+
+        assert!(slope.has_inputs());
+        assert!(!slope.initialized());
+    }
+
+    #[rstest]
+    fn test_handle_book_and_depth() {
+        let mut slope = DepthSlope::new(10);
+        slope.handle_book(&stub_order_book_mbp_appl_xnas());
+        assert!(slope.initialized());
+        assert!((slope.bid_slope - slope.ask_slope).abs() < 1e-6);
+
+        // Sizes increase by 100 per level, 1.0 apart
+        slope.handle_depth(&stub_depth10());
+        assert!(slope.bid_slope > 0.0);
+        assert!((slope.bid_slope - slope.ask_slope).abs() < 1e-9);
+        assert_eq!(slope.count, 2);
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let mut slope = DepthSlope::new(2);
+        slope.update(&[(100.0, 1.0), (99.0, 1.0)], &[(101.0, 1.0), (102.0, 1.0)]);
+        slope.reset();
+
+        assert_eq!(slope.value, 0.0);
+        assert_eq!(slope.bid_slope, 0.0);
+        assert_eq!(slope.count, 0);
+        assert!(!slope.has_inputs());
+        assert!(!slope.initialized());
+    }
+}
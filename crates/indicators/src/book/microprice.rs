@@ -0,0 +1,219 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use nautilus_model::{
+    data::{OrderBookDepth10, QuoteTick},
+    orderbook::OrderBook,
+};
+
+use crate::{
+    book::{BookLevelTuple, book_levels, depth10_levels},
+    indicator::Indicator,
+};
+
+/// An indicator which calculates the size weighted mid price of the top of book.
+///
+/// The microprice `(ask_price * bid_size + bid_price * ask_size) / (bid_size + ask_size)`
+/// leans towards the side with less size, which is the side more likely to be traded through.
+#[repr(C)]
+#[derive(Debug, Default)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
+)]
+pub struct Microprice {
+    pub value: f64,
+    pub count: usize,
+    pub initialized: bool,
+    has_inputs: bool,
+}
+
+impl Display for Microprice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}()", self.name())
+    }
+}
+
+impl Indicator for Microprice {
+    fn name(&self) -> String {
+        stringify!(Microprice).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_book(&mut self, book: &OrderBook) {
+        let (bids, asks) = book_levels(book, 1);
+        self.update_levels(bids.first().copied(), asks.first().copied());
+    }
+
+    fn handle_depth(&mut self, depth: &OrderBookDepth10) {
+        let (bids, asks) = depth10_levels(depth, 1);
+        self.update_levels(bids.first().copied(), asks.first().copied());
+    }
+
+    fn handle_quote(&mut self, quote: &QuoteTick) {
+        self.update(
+            quote.bid_price.as_f64(),
+            quote.bid_size.as_f64(),
+            quote.ask_price.as_f64(),
+            quote.ask_size.as_f64(),
+        );
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl Microprice {
+    /// Creates a new [`Microprice`] instance.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            value: 0.0,
+            count: 0,
+            initialized: false,
+            has_inputs: false,
+        }
+    }
+
+    pub fn update(&mut self, bid_price: f64, bid_size: f64, ask_price: f64, ask_size: f64) {
+        self.has_inputs = true;
+        self.count += 1;
+
+        let total_size = bid_size + ask_size;
+        if total_size > 0.0 {
+            self.value = ask_price.mul_add(bid_size, bid_price * ask_size) / total_size;
+            self.initialized = true;
+        }
+    }
+
+    fn update_levels(
+        &mut self,
+        best_bid: Option<BookLevelTuple>,
+        best_ask: Option<BookLevelTuple>,
+    ) {
+        if let (Some((bid_price, bid_size)), Some((ask_price, ask_size))) = (best_bid, best_ask) {
+            self.update(bid_price, bid_size, ask_price, ask_size);
+        } else {
+            // No market yet
+            self.has_inputs = true;
+            self.count += 1;
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::stubs::{quote_ethusdt_binance, stub_depth10},
+        enums::BookType,
+        identifiers::InstrumentId,
+        stubs::stub_order_book_mbp,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_initialized() {
+        let microprice = Microprice::new();
+        assert_eq!(format!("{microprice}"), "Microprice()");
+        assert_eq!(microprice.value, 0.0);
+        assert!(!microprice.has_inputs());
+        assert!(!microprice.initialized());
+    }
+
+    #[rstest]
+    #[case(100.0, 100.0, 100.5)]
+    #[case(300.0, 100.0, 100.75)]
+    #[case(100.0, 300.0, 100.25)]
+    fn test_update(#[case] bid_size: f64, #[case] ask_size: f64, #[case] expected: f64) {
+        let mut microprice = Microprice::new();
+        microprice.update(100.0, bid_size, 101.0, ask_size);
+
+        assert_eq!(microprice.value, expected);
+        assert!(microprice.initialized());
+    }
+
+    #[rstest]
+    fn test_handle_book_with_bid_heavy_book() {
+        let mut microprice = Microprice::new();
+        let book = stub_order_book_mbp(
+            InstrumentId::from("AAPL.XNAS"),
+            101.0,
+            100.0,
+            100.0,
+            300.0,
+            2,
+            0.01,
+            0,
+            100.0,
+            10,
+        );
+        microprice.handle_book(&book);
+
+        assert_eq!(microprice.value, 100.75);
+        assert_eq!(microprice.count, 1);
+    }
+
+    #[rstest]
+    fn test_handle_empty_book() {
+        let mut microprice = Microprice::new();
+        let book = OrderBook::new(InstrumentId::from("AAPL.XNAS"), BookType::L2_MBP);
+        microprice.handle_book(&book);
+
+        assert!(microprice.has_inputs());
+        assert!(!microprice.initialized());
+    }
+
+    #[rstest]
+    fn test_handle_depth_and_quote(quote_ethusdt_binance: QuoteTick) {
+        let mut microprice = Microprice::new();
+        microprice.handle_depth(&stub_depth10());
+        assert_eq!(microprice.value, 99.5);
+
+        microprice.handle_quote(&quote_ethusdt_binance);
+        assert!(microprice.value > quote_ethusdt_binance.bid_price.as_f64());
+        assert!(microprice.value < quote_ethusdt_binance.ask_price.as_f64());
+        assert_eq!(microprice.count, 2);
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let mut microprice = Microprice::new();
+        microprice.update(100.0, 1.0, 101.0, 1.0);
+        microprice.reset();
+
+        assert_eq!(microprice.value, 0.0);
+        assert_eq!(microprice.count, 0);
+        assert!(!microprice.has_inputs());
+        assert!(!microprice.initialized());
+    }
+}
@@ -15,4 +15,46 @@
 
 //! Order book specific indicators.
 
+pub mod depth_slope;
 pub mod imbalance;
+pub mod microprice;
+pub mod ofi;
+pub mod pressure;
+pub mod weighted_imbalance;
+
+use nautilus_model::{
+    data::{BookOrder, OrderBookDepth10},
+    enums::OrderSide,
+    orderbook::{BookLevel, OrderBook},
+};
+
+/// A book level as `(price, size)`.
+pub type BookLevelTuple = (f64, f64);
+
+/// Returns the top `depth` bid and ask levels of the `book`, best price first.
+pub(crate) fn book_levels(
+    book: &OrderBook,
+    depth: usize,
+) -> (Vec<BookLevelTuple>, Vec<BookLevelTuple>) {
+    let to_tuple = |level: &BookLevel| (level.price.value.as_f64(), level.size());
+    (
+        book.bids(Some(depth)).map(to_tuple).collect(),
+        book.asks(Some(depth)).map(to_tuple).collect(),
+    )
+}
+
+/// Returns the top `depth` bid and ask levels of the `depth10` snapshot (skipping padding).
+pub(crate) fn depth10_levels(
+    depth10: &OrderBookDepth10,
+    depth: usize,
+) -> (Vec<BookLevelTuple>, Vec<BookLevelTuple>) {
+    let to_tuples = |orders: &[BookOrder]| {
+        orders
+            .iter()
+            .filter(|order| order.side != OrderSide::NoOrderSide && order.size.is_positive())
+            .take(depth)
+            .map(|order| (order.price.as_f64(), order.size.as_f64()))
+            .collect()
+    };
+    (to_tuples(&depth10.bids), to_tuples(&depth10.asks))
+}
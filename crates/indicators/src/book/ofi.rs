@@ -0,0 +1,261 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use arraydeque::{ArrayDeque, Wrapping};
+use nautilus_model::{
+    data::{OrderBookDepth10, QuoteTick},
+    orderbook::OrderBook,
+};
+
+use crate::{
+    book::{BookLevelTuple, book_levels, depth10_levels},
+    indicator::Indicator,
+};
+
+const MAX_PERIOD: usize = 1_024;
+
+/// An indicator which calculates the order flow imbalance (OFI) of the top of book.
+///
+/// Following Cont, Kukanov and Stoikov (2014), each change in the best bid and ask between
+/// successive book states contributes the size added to the bid (or removed from the ask) as
+/// positive flow, and the size removed from the bid (or added to the ask) as negative flow.
+/// The value is the sum of the flows over the last `period` book updates.
+///
+/// Feed the book after each batch of deltas is applied, as intermediate states are otherwise
+/// not observed.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
+)]
+pub struct OrderFlowImbalance {
+    pub period: usize,
+    pub value: f64,
+    pub count: usize,
+    pub initialized: bool,
+    has_inputs: bool,
+    prev_bid: Option<BookLevelTuple>,
+    prev_ask: Option<BookLevelTuple>,
+    flows: ArrayDeque<f64, MAX_PERIOD, Wrapping>,
+}
+
+impl Display for OrderFlowImbalance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.period)
+    }
+}
+
+impl Indicator for OrderFlowImbalance {
+    fn name(&self) -> String {
+        stringify!(OrderFlowImbalance).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_book(&mut self, book: &OrderBook) {
+        let (bids, asks) = book_levels(book, 1);
+        self.update_levels(bids.first().copied(), asks.first().copied());
+    }
+
+    fn handle_depth(&mut self, depth: &OrderBookDepth10) {
+        let (bids, asks) = depth10_levels(depth, 1);
+        self.update_levels(bids.first().copied(), asks.first().copied());
+    }
+
+    fn handle_quote(&mut self, quote: &QuoteTick) {
+        self.update(
+            quote.bid_price.as_f64(),
+            quote.bid_size.as_f64(),
+            quote.ask_price.as_f64(),
+            quote.ask_size.as_f64(),
+        );
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+        self.prev_bid = None;
+        self.prev_ask = None;
+        self.flows.clear();
+    }
+}
+
+impl OrderFlowImbalance {
+    /// Creates a new [`OrderFlowImbalance`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is not positive (> 0) or exceeds `MAX_PERIOD`.
+    #[must_use]
+    pub fn new(period: usize) -> Self {
+        assert!(period > 0, "OrderFlowImbalance: period must be > 0");
+        assert!(
+            period <= MAX_PERIOD,
+            "OrderFlowImbalance: period {period} exceeds MAX_PERIOD ({MAX_PERIOD})"
+        );
+        Self {
+            period,
+            value: 0.0,
+            count: 0,
+            initialized: false,
+            has_inputs: false,
+            prev_bid: None,
+            prev_ask: None,
+            flows: ArrayDeque::new(),
+        }
+    }
+
+    pub fn update(&mut self, bid_price: f64, bid_size: f64, ask_price: f64, ask_size: f64) {
+        self.update_levels(Some((bid_price, bid_size)), Some((ask_price, ask_size)));
+    }
+
+    fn update_levels(&mut self, bid: Option<BookLevelTuple>, ask: Option<BookLevelTuple>) {
+        self.has_inputs = true;
+
+        let (Some(bid), Some(ask)) = (bid, ask) else {
+            return; // No market yet
+        };
+
+        if let (Some(prev_bid), Some(prev_ask)) = (self.prev_bid, self.prev_ask) {
+            let flow = bid_flow(prev_bid, bid) - ask_flow(prev_ask, ask);
+            if self.flows.len() == self.period {
+                self.flows.pop_front();
+            }
+            let _ = self.flows.push_back(flow);
+            self.count += 1;
+
+            self.value = self.flows.iter().sum();
+            if self.count >= self.period {
+                self.initialized = true;
+            }
+        }
+
+        self.prev_bid = Some(bid);
+        self.prev_ask = Some(ask);
+    }
+}
+
+fn bid_flow((prev_price, prev_size): BookLevelTuple, (price, size): BookLevelTuple) -> f64 {
+    let mut flow = 0.0;
+    if price >= prev_price {
+        flow += size;
+    }
+    if price <= prev_price {
+        flow -= prev_size;
+    }
+    flow
+}
+
+fn ask_flow((prev_price, prev_size): BookLevelTuple, (price, size): BookLevelTuple) -> f64 {
+    let mut flow = 0.0;
+    if price <= prev_price {
+        flow += size;
+    }
+    if price >= prev_price {
+        flow -= prev_size;
+    }
+    flow
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::stubs::stub_order_book_mbp_appl_xnas;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_initialized() {
+        let ofi = OrderFlowImbalance::new(3);
+        assert_eq!(format!("{ofi}"), "OrderFlowImbalance(3)");
+        assert_eq!(ofi.value, 0.0);
+        assert!(!ofi.has_inputs());
+        assert!(!ofi.initialized());
+    }
+
+    #[rstest]
+    #[should_panic(expected = "period must be > 0")]
+    fn test_new_with_zero_period() {
+        let _ = OrderFlowImbalance::new(0);
+    }
+
+    #[rstest]
+    #[case((100.0, 10.0), (100.0, 15.0), 5.0)] // Size added at same price
+    #[case((100.0, 10.0), (100.1, 4.0), 4.0)] // Price improved
+    #[case((100.0, 10.0), (99.9, 8.0), -10.0)] // Level depleted
+    fn test_bid_flow(
+        #[case] prev: BookLevelTuple,
+        #[case] current: BookLevelTuple,
+        #[case] expected: f64,
+    ) {
+        assert_eq!(bid_flow(prev, current), expected);
+    }
+
+    #[rstest]
+    fn test_update_sums_flows_over_period() {
+        let mut ofi = OrderFlowImbalance::new(2);
+        ofi.update(100.0, 10.0, 101.0, 10.0);
+        assert_eq!(ofi.count, 0);
+
+        ofi.update(100.0, 15.0, 101.0, 10.0); // +5 bid size
+        assert_eq!(ofi.value, 5.0);
+        assert!(!ofi.initialized());
+
+        ofi.update(100.0, 15.0, 101.0, 12.0); // +2 ask size
+        assert_eq!(ofi.value, 3.0);
+        assert!(ofi.initialized());
+
+        ofi.update(100.0, 15.0, 100.5, 4.0); // Ask improved with 4
+        assert_eq!(ofi.value, -6.0);
+    }
+
+    #[rstest]
+    fn test_handle_unchanged_book_has_zero_flow() {
+        let mut ofi = OrderFlowImbalance::new(1);
+        let book = stub_order_book_mbp_appl_xnas();
+        ofi.handle_book(&book);
+        ofi.handle_book(&book);
+
+        assert_eq!(ofi.value, 0.0);
+        assert!(ofi.initialized());
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let mut ofi = OrderFlowImbalance::new(1);
+        ofi.update(100.0, 10.0, 101.0, 10.0);
+        ofi.update(100.0, 15.0, 101.0, 10.0);
+        ofi.reset();
+
+        assert_eq!(ofi.value, 0.0);
+        assert_eq!(ofi.count, 0);
+        assert!(!ofi.has_inputs());
+        assert!(!ofi.initialized());
+    }
+}
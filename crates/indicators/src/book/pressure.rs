@@ -0,0 +1,203 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use nautilus_model::{data::OrderBookDepth10, orderbook::OrderBook};
+
+use crate::{
+    book::{BookLevelTuple, book_levels, depth10_levels},
+    indicator::Indicator,
+};
+
+/// An indicator which calculates the pressure of resting size towards the mid price.
+///
+/// The pressure of each side is the sum of the level sizes divided by their distance from the
+/// mid price, so size close to the mid dominates. The value is
+/// `(bid_pressure - ask_pressure) / (bid_pressure + ask_pressure)`, ranging from -1 to 1.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
+)]
+pub struct BookPressure {
+    pub depth: usize,
+    pub bid_pressure: f64,
+    pub ask_pressure: f64,
+    pub value: f64,
+    pub count: usize,
+    pub initialized: bool,
+    has_inputs: bool,
+}
+
+impl Display for BookPressure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.depth)
+    }
+}
+
+impl Indicator for BookPressure {
+    fn name(&self) -> String {
+        stringify!(BookPressure).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_book(&mut self, book: &OrderBook) {
+        let (bids, asks) = book_levels(book, self.depth);
+        self.update(&bids, &asks);
+    }
+
+    fn handle_depth(&mut self, depth: &OrderBookDepth10) {
+        let (bids, asks) = depth10_levels(depth, self.depth);
+        self.update(&bids, &asks);
+    }
+
+    fn reset(&mut self) {
+        self.bid_pressure = 0.0;
+        self.ask_pressure = 0.0;
+        self.value = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl BookPressure {
+    /// Creates a new [`BookPressure`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `depth` is not positive (> 0).
+    #[must_use]
+    pub fn new(depth: usize) -> Self {
+        assert!(depth > 0, "BookPressure: depth must be > 0");
+        Self {
+            depth,
+            bid_pressure: 0.0,
+            ask_pressure: 0.0,
+            value: 0.0,
+            count: 0,
+            initialized: false,
+            has_inputs: false,
+        }
+    }
+
+    /// Updates the indicator with the `(price, size)` levels of each side, best price first.
+    pub fn update(&mut self, bids: &[BookLevelTuple], asks: &[BookLevelTuple]) {
+        self.has_inputs = true;
+        self.count += 1;
+
+        let (Some((best_bid, _)), Some((best_ask, _))) = (bids.first(), asks.first()) else {
+            return; // No market yet
+        };
+        let mid = (best_bid + best_ask) / 2.0;
+
+        self.bid_pressure = self.side_pressure(bids, mid);
+        self.ask_pressure = self.side_pressure(asks, mid);
+
+        let total = self.bid_pressure + self.ask_pressure;
+        if total > 0.0 {
+            self.value = (self.bid_pressure - self.ask_pressure) / total;
+            self.initialized = true;
+        }
+    }
+
+    fn side_pressure(&self, levels: &[BookLevelTuple], mid: f64) -> f64 {
+        levels
+            .iter()
+            .take(self.depth)
+            .filter_map(|(price, size)| {
+                let distance = (price - mid).abs();
+                // Locked or crossed levels have no meaningful distance
+                (distance > 0.0).then(|| size / distance)
+            })
+            .sum()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::stubs::stub_depth10;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_initialized() {
+        let pressure = BookPressure::new(5);
+        assert_eq!(format!("{pressure}"), "BookPressure(5)");
+        assert_eq!(pressure.value, 0.0);
+        assert!(!pressure.has_inputs());
+        assert!(!pressure.initialized());
+    }
+
+    #[rstest]
+    fn test_update_weights_size_by_distance() {
+        let mut pressure = BookPressure::new(5);
+        // Mid 100.5: bids 10 / 0.5 + 30 / 1.5 = 40, asks 10 / 0.5 + 10 / 1.5 = 26.67
+        pressure.update(
+            &[(100.0, 10.0), (99.0, 30.0)],
+            &[(101.0, 10.0), (102.0, 10.0)],
+        );
+
+        assert_eq!(pressure.bid_pressure, 40.0);
+        assert!((pressure.ask_pressure - 80.0 / 3.0).abs() < 1e-9);
+        assert!((pressure.value - 0.2).abs() < 1e-9);
+        assert!(pressure.initialized());
+    }
+
+    #[rstest]
+    fn test_update_with_one_sided_book() {
+        let mut pressure = BookPressure::new(5);
+        pressure.update(&[(100.0, 10.0)], &[]);
+
+        assert!(pressure.has_inputs());
+        assert!(!pressure.initialized());
+    }
+
+    #[rstest]
+    fn test_handle_depth_with_symmetric_book() {
+        let mut pressure = BookPressure::new(10);
+        pressure.handle_depth(&stub_depth10());
+
+        // Symmetric around the mid of 99.5
+        assert!(pressure.value.abs() < 1e-9);
+        assert!(pressure.initialized());
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let mut pressure = BookPressure::new(5);
+        pressure.update(&[(100.0, 10.0)], &[(101.0, 10.0)]);
+        pressure.reset();
+
+        assert_eq!(pressure.value, 0.0);
+        assert_eq!(pressure.bid_pressure, 0.0);
+        assert_eq!(pressure.count, 0);
+        assert!(!pressure.has_inputs());
+        assert!(!pressure.initialized());
+    }
+}
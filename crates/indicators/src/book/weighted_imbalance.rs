@@ -0,0 +1,213 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use nautilus_model::{data::OrderBookDepth10, orderbook::OrderBook};
+
+use crate::{
+    book::{BookLevelTuple, book_levels, depth10_levels},
+    indicator::Indicator,
+};
+
+/// An indicator which calculates the size imbalance over multiple book levels.
+///
+/// Level `i` (zero based from the best price) is weighted by `decay^i`, and the value is
+/// `(bid - ask) / (bid + ask)` of the weighted sizes, ranging from -1 (all asks) to 1 (all bids).
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
+)]
+pub struct WeightedBookImbalance {
+    pub depth: usize,
+    pub decay: f64,
+    pub value: f64,
+    pub count: usize,
+    pub initialized: bool,
+    has_inputs: bool,
+}
+
+impl Display for WeightedBookImbalance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({},{})", self.name(), self.depth, self.decay)
+    }
+}
+
+impl Indicator for WeightedBookImbalance {
+    fn name(&self) -> String {
+        stringify!(WeightedBookImbalance).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_book(&mut self, book: &OrderBook) {
+        let (bids, asks) = book_levels(book, self.depth);
+        self.update(&bids, &asks);
+    }
+
+    fn handle_depth(&mut self, depth: &OrderBookDepth10) {
+        let (bids, asks) = depth10_levels(depth, self.depth);
+        self.update(&bids, &asks);
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl WeightedBookImbalance {
+    /// Creates a new [`WeightedBookImbalance`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// - `depth` is not positive (> 0).
+    /// - `decay` is not in the range (0, 1].
+    #[must_use]
+    pub fn new(depth: usize, decay: Option<f64>) -> Self {
+        let decay = decay.unwrap_or(0.5);
+        assert!(depth > 0, "WeightedBookImbalance: depth must be > 0");
+        assert!(
+            decay > 0.0 && decay <= 1.0,
+            "WeightedBookImbalance: decay must be in the range (0, 1], was {decay}"
+        );
+        Self {
+            depth,
+            decay,
+            value: 0.0,
+            count: 0,
+            initialized: false,
+            has_inputs: false,
+        }
+    }
+
+    /// Updates the indicator with the `(price, size)` levels of each side, best price first.
+    pub fn update(&mut self, bids: &[BookLevelTuple], asks: &[BookLevelTuple]) {
+        self.has_inputs = true;
+        self.count += 1;
+
+        let bid = self.weighted_size(bids);
+        let ask = self.weighted_size(asks);
+        let total = bid + ask;
+
+        if total > 0.0 {
+            self.value = (bid - ask) / total;
+            self.initialized = true;
+        }
+        // No market yet
+    }
+
+    fn weighted_size(&self, levels: &[BookLevelTuple]) -> f64 {
+        let mut weight = 1.0;
+        let mut total = 0.0;
+        for (_, size) in levels.iter().take(self.depth) {
+            total += weight * size;
+            weight *= self.decay;
+        }
+        total
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{data::stubs::stub_depth10, stubs::stub_order_book_mbp_appl_xnas};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_initialized() {
+        let imbalance = WeightedBookImbalance::new(5, None);
+        assert_eq!(format!("{imbalance}"), "WeightedBookImbalance(5,0.5)");
+        assert_eq!(imbalance.value, 0.0);
+        assert!(!imbalance.has_inputs());
+        assert!(!imbalance.initialized());
+    }
+
+    #[rstest]
+    #[should_panic(expected = "decay must be in the range")]
+    fn test_new_with_invalid_decay() {
+        let _ = WeightedBookImbalance::new(5, Some(1.5));
+    }
+
+    #[rstest]
+    fn test_update_weights_levels_by_decay() {
+        let mut imbalance = WeightedBookImbalance::new(3, Some(0.5));
+        // Bids: 100 + 0.5 * 200 = 200
+        // Asks: 100 + 0.5 * 100 + 0.25 * 200 = 200 (levels beyond the depth are ignored)
+        imbalance.update(
+            &[(100.0, 100.0), (99.0, 200.0)],
+            &[
+                (101.0, 100.0),
+                (102.0, 100.0),
+                (103.0, 200.0),
+                (104.0, 1000.0),
+            ],
+        );
+
+        assert_eq!(imbalance.value, 0.0);
+        assert!(imbalance.initialized());
+
+        imbalance.update(&[(100.0, 300.0)], &[(101.0, 100.0)]);
+        assert_eq!(imbalance.value, 0.5);
+        assert_eq!(imbalance.count, 2);
+    }
+
+    #[rstest]
+    fn test_update_with_empty_book() {
+        let mut imbalance = WeightedBookImbalance::new(3, None);
+        imbalance.update(&[], &[]);
+
+        assert!(imbalance.has_inputs());
+        assert!(!imbalance.initialized());
+    }
+
+    #[rstest]
+    fn test_handle_book_and_depth() {
+        let mut imbalance = WeightedBookImbalance::new(10, Some(1.0));
+        imbalance.handle_book(&stub_order_book_mbp_appl_xnas());
+        assert_eq!(imbalance.value, 0.0);
+
+        imbalance.handle_depth(&stub_depth10());
+        assert_eq!(imbalance.value, 0.0);
+        assert_eq!(imbalance.count, 2);
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let mut imbalance = WeightedBookImbalance::new(3, None);
+        imbalance.update(&[(100.0, 300.0)], &[(101.0, 100.0)]);
+        imbalance.reset();
+
+        assert_eq!(imbalance.value, 0.0);
+        assert_eq!(imbalance.count, 0);
+        assert!(!imbalance.has_inputs());
+        assert!(!imbalance.initialized());
+    }
+}
@@ -0,0 +1,103 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_model::{data::OrderBookDepth10, orderbook::OrderBook};
+use pyo3::prelude::*;
+
+use crate::{
+    book::{BookLevelTuple, depth_slope::DepthSlope},
+    indicator::Indicator,
+};
+
+#[pymethods]
+impl DepthSlope {
+    #[new]
+    #[pyo3(signature = (depth))]
+    fn py_new(depth: usize) -> Self {
+        Self::new(depth)
+    }
+
+    fn __repr__(&self) -> String {
+        self.to_string()
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    fn py_name(&self) -> String {
+        self.name()
+    }
+
+    #[getter]
+    #[pyo3(name = "depth")]
+    const fn py_depth(&self) -> usize {
+        self.depth
+    }
+
+    #[getter]
+    #[pyo3(name = "bid_slope")]
+    const fn py_bid_slope(&self) -> f64 {
+        self.bid_slope
+    }
+
+    #[getter]
+    #[pyo3(name = "ask_slope")]
+    const fn py_ask_slope(&self) -> f64 {
+        self.ask_slope
+    }
+
+    #[getter]
+    #[pyo3(name = "count")]
+    const fn py_count(&self) -> usize {
+        self.count
+    }
+
+    #[getter]
+    #[pyo3(name = "value")]
+    const fn py_value(&self) -> f64 {
+        self.value
+    }
+
+    #[getter]
+    #[pyo3(name = "has_inputs")]
+    fn py_has_inputs(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[getter]
+    #[pyo3(name = "initialized")]
+    const fn py_initialized(&self) -> bool {
+        self.initialized
+    }
+
+    #[pyo3(name = "handle_book")]
+    fn py_handle_book(&mut self, book: &OrderBook) {
+        self.handle_book(book);
+    }
+
+    #[pyo3(name = "handle_depth")]
+    fn py_handle_depth(&mut self, depth: &OrderBookDepth10) {
+        self.handle_depth(depth);
+    }
+
+    #[pyo3(name = "update")]
+    fn py_update(&mut self, bids: Vec<BookLevelTuple>, asks: Vec<BookLevelTuple>) {
+        self.update(&bids, &asks);
+    }
+
+    #[pyo3(name = "reset")]
+    fn py_reset(&mut self) {
+        self.reset();
+    }
+}
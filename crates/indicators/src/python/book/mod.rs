@@ -13,4 +13,9 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+pub mod depth_slope;
 pub mod imbalance;
+pub mod microprice;
+pub mod ofi;
+pub mod pressure;
+pub mod weighted_imbalance;
@@ -45,6 +45,11 @@ pub fn indicators(_: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
 
     // Book
     m.add_class::<crate::book::imbalance::BookImbalanceRatio>()?;
+    m.add_class::<crate::book::weighted_imbalance::WeightedBookImbalance>()?;
+    m.add_class::<crate::book::microprice::Microprice>()?;
+    m.add_class::<crate::book::ofi::OrderFlowImbalance>()?;
+    m.add_class::<crate::book::pressure::BookPressure>()?;
+    m.add_class::<crate::book::depth_slope::DepthSlope>()?;
 
     // Ratio
     m.add_class::<crate::ratio::efficiency_ratio::EfficiencyRatio>()?;
@@ -106,6 +106,10 @@ pub struct LiveExecEngineConfig {
     pub open_check_missing_retries: u32,
     /// If the `check_open_orders` requests only currently open orders from the venue.
     pub open_check_open_only: bool,
+    /// The interval (seconds) between requesting a mass status from each client to reconcile against.
+    pub mass_status_interval_secs: Option<f64>,
+    /// The lookback minutes for periodic mass status reconciliation.
+    pub mass_status_lookback_mins: Option<u32>,
     /// The interval (minutes) between purging closed orders from the in-memory cache.
     pub purge_closed_orders_interval_mins: Option<u32>,
     /// The time buffer (minutes) before closed orders can be purged.
@@ -146,6 +150,8 @@ impl Default for LiveExecEngineConfig {
             open_check_threshold_ms: 5_000,
             open_check_missing_retries: 5,
             open_check_open_only: true,
+            mass_status_interval_secs: None,
+            mass_status_lookback_mins: Some(60),
             purge_closed_orders_interval_mins: None,
             purge_closed_orders_buffer_mins: None,
             purge_closed_positions_interval_mins: None,
@@ -425,6 +431,8 @@ mod tests {
         assert_eq!(config.open_check_lookback_mins, Some(60));
         assert_eq!(config.open_check_missing_retries, 5);
         assert!(config.open_check_open_only);
+        assert_eq!(config.mass_status_interval_secs, None);
+        assert_eq!(config.mass_status_lookback_mins, Some(60));
         assert!(!config.purge_from_database);
         assert!(!config.graceful_shutdown_on_exception);
         assert_eq!(config.qsize, 100_000);
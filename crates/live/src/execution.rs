@@ -20,6 +20,7 @@
 //! while managing state reconciliation.
 
 use std::{
+    any::Any,
     cell::{Ref, RefCell},
     collections::HashSet,
     fmt::{Debug, Display},
@@ -32,14 +33,15 @@ use nautilus_common::{
     clock::Clock,
     logging::{CMD, EVT, RECV},
     messages::{ExecutionEvent, ExecutionReport as ExecReportEnum, execution::TradingCommand},
-    msgbus::{self, MessageBus, switchboard},
+    msgbus::{self, MessageBus, switchboard::MessagingSwitchboard},
 };
-use nautilus_execution::client::ExecutionClient;
+use nautilus_execution::client::{ExecutionClient, LiveExecutionClient};
 use nautilus_model::{
     events::OrderEventAny,
     identifiers::{ClientId, ClientOrderId, InstrumentId},
     reports::{ExecutionMassStatus, FillReport, OrderStatusReport, PositionStatusReport},
 };
+use tokio::task::LocalSet;
 
 use crate::{
     config::LiveExecEngineConfig,
@@ -52,18 +54,21 @@ use crate::{
 /// - Startup reconciliation with all venues.
 /// - Continuous reconciliation of execution reports.
 /// - Inflight order checking and resolution.
+/// - Periodic mass status reconciliation with each registered client.
 /// - Message routing between venues and the core execution engine.
 #[allow(dead_code)]
 pub struct LiveExecutionEngine {
     clock: Rc<RefCell<dyn Clock>>,
     cache: Rc<RefCell<Cache>>,
     msgbus: Rc<RefCell<MessageBus>>,
-    reconciliation: ReconciliationManager,
+    reconciliation: Rc<RefCell<ReconciliationManager>>,
+    clients: Vec<Rc<dyn LiveExecutionClient>>,
     config: LiveExecEngineConfig,
     cmd_tx: tokio::sync::mpsc::UnboundedSender<TradingCommand>,
     cmd_rx: Option<tokio::sync::mpsc::UnboundedReceiver<TradingCommand>>,
     evt_tx: tokio::sync::mpsc::UnboundedSender<OrderEventAny>,
     evt_rx: Option<tokio::sync::mpsc::UnboundedReceiver<OrderEventAny>>,
+    local_tasks: Rc<LocalSet>,
     reconciliation_task: Option<tokio::task::JoinHandle<()>>,
     inflight_check_task: Option<tokio::task::JoinHandle<()>>,
     open_check_task: Option<tokio::task::JoinHandle<()>>,
@@ -75,6 +80,7 @@ impl Debug for LiveExecutionEngine {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct(stringify!(LiveExecutionEngine))
             .field("config", &self.config)
+            .field("clients", &self.clients.len())
             .field("is_running", &self.is_running)
             .field("shutdown_initiated", &self.shutdown_initiated)
             .finish()
@@ -106,6 +112,7 @@ impl LiveExecutionEngine {
             .collect();
 
         let reconciliation_config = ReconciliationConfig {
+            trader_id: msgbus.borrow().trader_id,
             lookback_mins: config.reconciliation_lookback_mins.map(|m| m as u64),
             inflight_threshold_ms: config.inflight_check_threshold_ms as u64,
             inflight_max_retries: config.inflight_check_retries,
@@ -120,8 +127,11 @@ impl LiveExecutionEngine {
             reconciliation_instrument_ids,
         };
 
-        let reconciliation =
-            ReconciliationManager::new(clock.clone(), cache.clone(), reconciliation_config);
+        let reconciliation = Rc::new(RefCell::new(ReconciliationManager::new(
+            clock.clone(),
+            cache.clone(),
+            reconciliation_config,
+        )));
 
         let (cmd_tx, cmd_rx) = tokio::sync::mpsc::unbounded_channel();
         let (evt_tx, evt_rx) = tokio::sync::mpsc::unbounded_channel();
@@ -131,11 +141,13 @@ impl LiveExecutionEngine {
             cache,
             msgbus,
             reconciliation,
+            clients: Vec::new(),
             config,
             cmd_tx,
             cmd_rx: Some(cmd_rx),
             evt_tx,
             evt_rx: Some(evt_rx),
+            local_tasks: Rc::new(LocalSet::new()),
             reconciliation_task: None,
             inflight_check_task: None,
             open_check_task: None,
@@ -151,6 +163,9 @@ impl LiveExecutionEngine {
     /// - Continuous reconciliation tasks.
     /// - Message processing loops.
     ///
+    /// Periodic tasks are spawned on the engine's [`LocalSet`] (see [`Self::local_tasks`]),
+    /// as the clients and cache are not `Send`.
+    ///
     /// # Errors
     ///
     /// Returns an error if startup reconciliation fails.
@@ -215,52 +230,32 @@ impl LiveExecutionEngine {
             tokio::time::sleep(Duration::from_secs_f64(delay_secs)).await;
         }
 
-        // TODO: Get all registered clients from ExecutionEngine
-        // For now, this is a placeholder
-        // let clients = self.get_execution_clients();
-        // for client in clients {
-        //     let lookback_mins = self.config.reconciliation_lookback_mins;
-        //     let mass_status = client.generate_mass_status(lookback_mins).await?;
-        //     if let Some(mass_status) = mass_status {
-        //         self.reconcile_execution_mass_status(mass_status).await?;
-        //     }
-        // }
+        let lookback_mins = self.config.reconciliation_lookback_mins.map(u64::from);
+        reconcile_clients_mass_status(&self.clients, &self.reconciliation, lookback_mins).await;
 
         log::info!("Startup reconciliation complete");
         Ok(())
     }
 
-    /// Reconciles execution mass status report.
-    #[allow(dead_code)]
-    async fn reconcile_execution_mass_status(
-        &mut self,
-        mass_status: ExecutionMassStatus,
-    ) -> anyhow::Result<()> {
-        log::info!(
-            "Processing mass status for {}: {} orders, {} fills",
-            mass_status.venue,
-            mass_status.order_reports().len(),
-            mass_status.fill_reports().len()
-        );
-
-        let events = self
-            .reconciliation
-            .reconcile_execution_mass_status(mass_status)
-            .await;
-
-        // Publish events to execution engine
-        for event in events {
-            self.publish_event(event);
-        }
+    /// Registers a live execution client for mass status reconciliation.
+    pub fn register_client(&mut self, client: Rc<dyn LiveExecutionClient>) {
+        log::info!("Registered {} for reconciliation", client.client_id());
+        self.clients.push(client);
+    }
 
-        Ok(())
+    /// Requests a mass status from each registered client and reconciles it with the cache.
+    ///
+    /// This is the unit of work run periodically every `mass_status_interval_secs`.
+    pub async fn reconcile_mass_status(&self) {
+        let lookback_mins = self.config.mass_status_lookback_mins.map(u64::from);
+        reconcile_clients_mass_status(&self.clients, &self.reconciliation, lookback_mins).await;
     }
 
     /// Reconciles an execution report.
     pub fn reconcile_execution_report(&mut self, report: ExecutionReport) {
         log::debug!("{RECV} {report:?}");
 
-        let events = match self.reconciliation.reconcile_report(report) {
+        let events = match self.reconciliation.borrow_mut().reconcile_report(report) {
             Ok(events) => events,
             Err(e) => {
                 log::error!("Failed to reconcile execution report: {e}");
@@ -270,7 +265,7 @@ impl LiveExecutionEngine {
 
         // Publish events to execution engine
         for event in events {
-            self.publish_event(event);
+            publish_event(event);
         }
     }
 
@@ -281,14 +276,6 @@ impl LiveExecutionEngine {
         // Commands would be forwarded to appropriate execution client
     }
 
-    /// Publishes an event to the execution engine.
-    fn publish_event(&mut self, event: OrderEventAny) {
-        log::debug!("{EVT} {event:?}");
-
-        let topic = switchboard::get_event_orders_topic(event.strategy_id());
-        msgbus::publish(topic, &event);
-    }
-
     /// Records local order activity for reconciliation tracking.
     pub fn record_local_activity(&mut self, event: &OrderEventAny) {
         let client_order_id = event.client_order_id();
@@ -297,6 +284,7 @@ impl LiveExecutionEngine {
             ts_event = self.clock.borrow().timestamp_ns();
         }
         self.reconciliation
+            .borrow_mut()
             .record_local_activity(client_order_id, ts_event);
     }
 
@@ -307,9 +295,19 @@ impl LiveExecutionEngine {
         drop_last_query: bool,
     ) {
         self.reconciliation
+            .borrow_mut()
             .clear_recon_tracking(client_order_id, drop_last_query);
     }
 
+    /// Returns the local task set running the engine's periodic tasks.
+    ///
+    /// These tasks only make progress while the set is driven (e.g. with
+    /// [`LocalSet::run_until`]), which the live node does while running.
+    #[must_use]
+    pub fn local_tasks(&self) -> Rc<LocalSet> {
+        self.local_tasks.clone()
+    }
+
     /// Starts continuous reconciliation tasks.
     fn start_continuous_tasks(&mut self) {
         if self.config.inflight_check_interval_ms > 0 {
@@ -322,6 +320,12 @@ impl LiveExecutionEngine {
         {
             self.start_open_check_task(interval_secs);
         }
+
+        if let Some(interval_secs) = self.config.mass_status_interval_secs
+            && interval_secs > 0.0
+        {
+            self.start_mass_status_task(interval_secs);
+        }
     }
 
     fn start_inflight_check_task(&mut self, _interval_ms: u64) {
@@ -332,6 +336,28 @@ impl LiveExecutionEngine {
         log::warn!("Open check task not yet implemented due to Send constraints");
     }
 
+    fn start_mass_status_task(&mut self, interval_secs: f64) {
+        let clients = self.clients.clone();
+        let reconciliation = self.reconciliation.clone();
+        let lookback_mins = self.config.mass_status_lookback_mins.map(u64::from);
+        let period = Duration::from_secs_f64(interval_secs);
+
+        log::info!("Starting mass status reconciliation every {interval_secs}s");
+
+        let task = self.local_tasks.spawn_local(async move {
+            let mut interval =
+                tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+            loop {
+                interval.tick().await;
+                reconcile_clients_mass_status(&clients, &reconciliation, lookback_mins).await;
+            }
+        });
+
+        self.reconciliation_task = Some(task);
+    }
+
     // TODO: Implement when LiveExecutionClient is available
     // fn get_execution_clients(&self) -> Vec<Rc<dyn LiveExecutionClient>> {
     //     Vec::new()
@@ -343,6 +369,53 @@ impl LiveExecutionEngine {
     }
 }
 
+/// Requests a mass status from each client and publishes the events which reconcile it.
+async fn reconcile_clients_mass_status(
+    clients: &[Rc<dyn LiveExecutionClient>],
+    reconciliation: &RefCell<ReconciliationManager>,
+    lookback_mins: Option<u64>,
+) {
+    for client in clients {
+        let client_id = client.client_id();
+        let mass_status = match client.generate_mass_status(lookback_mins).await {
+            Ok(Some(mass_status)) => mass_status,
+            Ok(None) => continue,
+            Err(e) => {
+                log::error!("Failed to generate mass status for {client_id}: {e}");
+                continue;
+            }
+        };
+
+        log::info!(
+            "Reconciling mass status for {}: {} orders, {} fills, {} positions",
+            mass_status.venue,
+            mass_status.order_reports().len(),
+            mass_status.fill_reports().len(),
+            mass_status.position_reports().len(),
+        );
+
+        let events = reconciliation
+            .borrow_mut()
+            .reconcile_mass_status(&mass_status, lookback_mins);
+
+        if !events.is_empty() {
+            log::info!("Reconciled {} events for {client_id}", events.len());
+        }
+
+        for event in events {
+            publish_event(event);
+        }
+    }
+}
+
+/// Sends an event to the execution engine for processing.
+fn publish_event(event: OrderEventAny) {
+    log::debug!("{EVT} {event:?}");
+
+    let endpoint = MessagingSwitchboard::exec_engine_process();
+    msgbus::send_any(endpoint, &event as &dyn Any);
+}
+
 /// Extension trait for live execution clients with message channel support.
 pub trait LiveExecutionClientExt: ExecutionClient {
     /// Gets the message channel for sending execution events.
@@ -410,3 +483,43 @@ pub trait LiveExecutionClientExt: ExecutionClient {
 fn log_send_error<E: Display>(client_id: &ClientId, e: &E) {
     log::error!("ExecutionClient-{client_id} failed to send message: {e}");
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_common::clock::TestClock;
+    use nautilus_core::UUID4;
+    use nautilus_model::identifiers::TraderId;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_start_with_mass_status_interval_outside_local_set() {
+        let config = LiveExecEngineConfig {
+            reconciliation: false,
+            mass_status_interval_secs: Some(1.0),
+            ..Default::default()
+        };
+        let msgbus = Rc::new(RefCell::new(MessageBus::new(
+            TraderId::from("TRADER-001"),
+            UUID4::new(),
+            None,
+            None,
+        )));
+        let mut engine = LiveExecutionEngine::new(
+            Rc::new(RefCell::new(TestClock::new())),
+            Rc::new(RefCell::new(Cache::default())),
+            msgbus,
+            config,
+        );
+
+        engine.start().await.unwrap();
+        assert!(engine.is_running());
+        assert!(engine.reconciliation_task.is_some());
+
+        engine.stop().await.unwrap();
+        assert!(!engine.is_running());
+    }
+}
@@ -36,6 +36,7 @@ use nautilus_common::{
 };
 use nautilus_core::UUID4;
 use nautilus_data::client::DataClientAdapter;
+use nautilus_execution::client::LiveExecutionClient;
 use nautilus_model::identifiers::TraderId;
 use nautilus_system::{
     config::NautilusKernelConfig,
@@ -43,7 +44,7 @@ use nautilus_system::{
     kernel::NautilusKernel,
 };
 
-use crate::{config::LiveNodeConfig, runner::AsyncRunner};
+use crate::{config::LiveNodeConfig, execution::LiveExecutionEngine, runner::AsyncRunner};
 
 /// A thread-safe handle to control a `LiveNode` from other threads.
 /// This allows starting, stopping, and querying the node's state
@@ -111,6 +112,7 @@ impl LiveNodeHandle {
 pub struct LiveNode {
     clock: Rc<RefCell<LiveClock>>,
     kernel: NautilusKernel,
+    exec_engine: LiveExecutionEngine,
     runner: AsyncRunner,
     config: LiveNodeConfig,
     is_running: bool,
@@ -161,12 +163,19 @@ impl LiveNode {
         let runner = AsyncRunner::new();
         let clock = Rc::new(RefCell::new(LiveClock::default()));
         let kernel = NautilusKernel::new(name, config.clone())?;
+        let exec_engine = LiveExecutionEngine::new(
+            kernel.clock(),
+            kernel.cache(),
+            kernel.msgbus(),
+            config.exec_engine.clone(),
+        );
 
         log::info!("LiveNode built successfully with kernel config");
 
         Ok(Self {
             clock,
             kernel,
+            exec_engine,
             runner,
             config,
             is_running: false,
@@ -176,6 +185,10 @@ impl LiveNode {
 
     /// Starts the live node.
     ///
+    /// Periodic reconciliation tasks are spawned on the execution engine's local task set,
+    /// which is driven by [`Self::run`] (or by the caller, see
+    /// [`LiveExecutionEngine::local_tasks`]).
+    ///
     /// # Errors
     ///
     /// Returns an error if startup fails.
@@ -185,6 +198,7 @@ impl LiveNode {
         }
 
         self.kernel.start_async().await;
+        self.exec_engine.start().await?;
         self.is_running = true;
         self.handle.set_running(true);
 
@@ -201,6 +215,7 @@ impl LiveNode {
             anyhow::bail!("Not running");
         }
 
+        self.exec_engine.stop().await?;
         self.kernel.stop_async().await;
         self.is_running = false;
         self.handle.set_running(false);
@@ -217,6 +232,12 @@ impl LiveNode {
     ///
     /// Returns an error if the node fails to start or encounters a runtime error.
     pub async fn run(&mut self) -> anyhow::Result<()> {
+        // Drives the periodic reconciliation tasks of the live execution engine
+        let local_tasks = self.exec_engine.local_tasks();
+        local_tasks.run_until(self.run_until_stopped()).await
+    }
+
+    async fn run_until_stopped(&mut self) -> anyhow::Result<()> {
         self.start().await?;
 
         tokio::select! {
@@ -519,7 +540,8 @@ impl LiveNodeBuilder {
     /// This will:
     /// 1. Build the underlying kernel.
     /// 2. Register all client factories.
-    /// 3. Create and register all clients.
+    /// 3. Create and register all clients, including execution clients with the live
+    ///    execution engine for reconciliation.
     ///
     /// # Errors
     ///
@@ -532,7 +554,13 @@ impl LiveNodeBuilder {
 
         let runner = AsyncRunner::new();
         let clock = Rc::new(RefCell::new(LiveClock::default()));
-        let kernel = NautilusKernel::new("LiveNode".to_string(), self.config.clone())?;
+        let mut kernel = NautilusKernel::new("LiveNode".to_string(), self.config.clone())?;
+        let mut exec_engine = LiveExecutionEngine::new(
+            kernel.clock(),
+            kernel.cache(),
+            kernel.msgbus(),
+            self.config.exec_engine.clone(),
+        );
 
         // Create and register data clients
         for (name, factory) in self.data_client_factories {
@@ -568,13 +596,20 @@ impl LiveNodeBuilder {
             if let Some(config) = self.exec_client_configs.remove(&name) {
                 log::info!("Creating execution client '{name}'");
 
-                let client =
-                    factory.create(&name, config.as_ref(), kernel.cache(), kernel.clock())?;
+                let client: Rc<dyn LiveExecutionClient> = Rc::from(factory.create(
+                    &name,
+                    config.as_ref(),
+                    kernel.cache(),
+                    kernel.clock(),
+                )?);
 
                 log::info!("Registering execution client '{name}' with execution engine");
 
-                // TODO: Implement when ExecutionEngine has a register_client method
-                // kernel.exec_engine().register_client(client);
+                let client_id = client.client_id();
+                kernel.exec_engine.register_client(client.clone())?;
+                exec_engine.register_client(client);
+
+                log::info!("Successfully registered execution client '{name}' ({client_id})");
             } else {
                 log::warn!("No config found for execution client factory '{name}'");
             }
@@ -585,6 +620,7 @@ impl LiveNodeBuilder {
         Ok(LiveNode {
             clock,
             kernel,
+            exec_engine,
             runner,
             config: self.config,
             is_running: false,
@@ -26,24 +26,43 @@ use std::{
 };
 
 use nautilus_common::{cache::Cache, clock::Clock};
-use nautilus_core::{UUID4, UnixNanos};
+use nautilus_core::{UUID4, UnixNanos, datetime::NANOSECONDS_IN_SECOND};
 use nautilus_model::{
-    enums::OrderStatus,
+    enums::{LiquiditySide, OrderSide, OrderStatus, OrderType, PriceType, TimeInForce},
     events::{
         OrderAccepted, OrderCanceled, OrderEventAny, OrderExpired, OrderFilled, OrderRejected,
         OrderTriggered,
     },
-    identifiers::{AccountId, ClientOrderId, InstrumentId, StrategyId, TradeId, VenueOrderId},
+    identifiers::{
+        AccountId, ClientOrderId, InstrumentId, PositionId, StrategyId, TradeId, TraderId,
+        VenueOrderId,
+    },
     instruments::{Instrument, InstrumentAny},
-    orders::{Order, OrderAny},
-    reports::{ExecutionMassStatus, FillReport, OrderStatusReport},
-    types::Quantity,
+    orders::{MarketOrder, Order, OrderAny},
+    reports::{ExecutionMassStatus, FillReport, OrderStatusReport, PositionStatusReport},
+    types::{Price, Quantity},
+};
+use rust_decimal::{
+    Decimal,
+    prelude::{FromPrimitive, ToPrimitive},
 };
 use ustr::Ustr;
 
+use crate::reconciliation::calculations::{
+    FillSnapshot, calculate_reconciliation_price, simulate_position,
+};
+
+/// The client order ID prefix for synthetic orders generated to reconcile positions.
+pub const RECONCILIATION_ORDER_ID_PREFIX: &str = "RECON-";
+
+/// The tag applied to synthetic orders generated to reconcile positions.
+pub const RECONCILIATION_TAG: &str = "RECONCILIATION";
+
 /// Configuration for reconciliation manager.
 #[derive(Debug, Clone)]
 pub struct ReconciliationConfig {
+    /// The trader ID for synthetic orders generated during reconciliation.
+    pub trader_id: TraderId,
     /// Number of minutes to look back during reconciliation.
     pub lookback_mins: Option<u64>,
     /// Threshold in milliseconds for inflight order checks.
@@ -73,6 +92,7 @@ pub struct ReconciliationConfig {
 impl Default for ReconciliationConfig {
     fn default() -> Self {
         Self {
+            trader_id: TraderId::default(),
             lookback_mins: Some(60),
             inflight_threshold_ms: 5000,
             inflight_max_retries: 5,
@@ -143,6 +163,8 @@ pub struct ReconciliationManager {
     recon_check_retries: HashMap<ClientOrderId, u32>,
     ts_last_query: HashMap<ClientOrderId, UnixNanos>,
     order_local_activity_ns: HashMap<ClientOrderId, UnixNanos>,
+    open_check_missing: HashMap<ClientOrderId, u32>,
+    pending_position_orders: HashMap<InstrumentId, ClientOrderId>,
 }
 
 impl Debug for ReconciliationManager {
@@ -153,6 +175,8 @@ impl Debug for ReconciliationManager {
             .field("external_order_claims", &self.external_order_claims)
             .field("processed_fills", &self.processed_fills)
             .field("recon_check_retries", &self.recon_check_retries)
+            .field("open_check_missing", &self.open_check_missing)
+            .field("pending_position_orders", &self.pending_position_orders)
             .finish()
     }
 }
@@ -174,50 +198,128 @@ impl ReconciliationManager {
             recon_check_retries: HashMap::new(),
             ts_last_query: HashMap::new(),
             order_local_activity_ns: HashMap::new(),
+            open_check_missing: HashMap::new(),
+            pending_position_orders: HashMap::new(),
         }
     }
 
-    /// Reconciles orders and fills from a mass status report.
+    /// Reconciles orders, fills and positions from a mass status report.
     pub async fn reconcile_execution_mass_status(
         &mut self,
         mass_status: ExecutionMassStatus,
+    ) -> Vec<OrderEventAny> {
+        let lookback_mins = self.config.lookback_mins;
+        self.reconcile_mass_status(&mass_status, lookback_mins)
+    }
+
+    /// Diffs a mass status report against the cache and returns the events to align local state.
+    ///
+    /// For each order known to the cache this generates any missing status transitions and
+    /// fills (inferring a fill when the reported filled quantity exceeds the fills received).
+    /// Open orders at the venue which are missing from the report are canceled once they have
+    /// been missing for `open_check_missing_retries` consecutive reports, unless they were created
+    /// before the `lookback_mins` window the report was requested for. Any remaining position
+    /// difference (e.g. from trades placed manually on the venue) is closed with a synthetic
+    /// order and fill, tagged `RECONCILIATION`, when `generate_missing_orders` is enabled. No
+    /// further synthetic order is generated for an instrument until the previous one is applied.
+    pub fn reconcile_mass_status(
+        &mut self,
+        mass_status: &ExecutionMassStatus,
+        lookback_mins: Option<u64>,
     ) -> Vec<OrderEventAny> {
         let mut events = Vec::new();
+        let mut reconciled: HashSet<ClientOrderId> = HashSet::new();
 
-        // Process order status reports first
-        for report in mass_status.order_reports().values() {
-            if let Some(client_order_id) = &report.client_order_id {
-                if let Some(order) = self.get_order(client_order_id) {
-                    let mut order = order;
-                    if let Some(event) = self.reconcile_order_report(&mut order, report) {
-                        events.push(event);
-                    }
+        let order_reports = mass_status.order_reports();
+        let fill_reports = mass_status.fill_reports();
+
+        // Process order status reports first, along with the fills for each order
+        for (venue_order_id, report) in &order_reports {
+            if !self.should_reconcile_instrument(&report.instrument_id) {
+                continue;
+            }
+
+            let Some(client_order_id) =
+                self.resolve_client_order_id(report.client_order_id, venue_order_id)
+            else {
+                if !self.config.filter_unclaimed_external
+                    && let Some(event) = self.handle_external_order(report, &mass_status.account_id)
+                {
+                    events.push(event);
                 }
-            } else if !self.config.filter_unclaimed_external
-                && let Some(event) = self.handle_external_order(report, &mass_status.account_id)
+                continue;
+            };
+
+            if self
+                .config
+                .filtered_client_order_ids
+                .contains(&client_order_id)
             {
-                events.push(event);
+                continue;
             }
+
+            let Some(mut order) = self.get_order(&client_order_id) else {
+                continue;
+            };
+
+            reconciled.insert(client_order_id);
+            let fills = fill_reports
+                .get(venue_order_id)
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+            events.extend(self.reconcile_order(&mut order, report, fills));
         }
 
-        // Process fill reports
-        for fills in mass_status.fill_reports().values() {
+        // Process fill reports for orders without a status report (e.g. closed at the venue)
+        for (venue_order_id, fills) in &fill_reports {
+            if order_reports.contains_key(venue_order_id) {
+                continue;
+            }
+
             for fill in fills {
-                if let Some(client_order_id) = &fill.client_order_id
-                    && let Some(order) = self.get_order(client_order_id)
+                if !self.should_reconcile_instrument(&fill.instrument_id) {
+                    continue;
+                }
+
+                let Some(client_order_id) =
+                    self.resolve_client_order_id(fill.client_order_id, venue_order_id)
+                else {
+                    continue;
+                };
+
+                if self
+                    .config
+                    .filtered_client_order_ids
+                    .contains(&client_order_id)
                 {
-                    let mut order = order;
-                    // Get instrument for the order
-                    let instrument_id = order.instrument_id();
-                    if let Some(instrument) = self.get_instrument(&instrument_id)
-                        && let Some(event) = self.create_order_fill(&mut order, fill, &instrument)
-                    {
-                        events.push(event);
-                    }
+                    continue;
+                }
+
+                let Some(mut order) = self.get_order(&client_order_id) else {
+                    continue;
+                };
+
+                reconciled.insert(client_order_id);
+                if order.trade_ids().contains(&&fill.trade_id) {
+                    continue;
+                }
+
+                let instrument_id = order.instrument_id();
+                if let Some(instrument) = self.get_instrument(&instrument_id)
+                    && let Some(event) = self.create_order_fill(&mut order, fill, &instrument)
+                {
+                    events.push(event);
                 }
             }
         }
 
+        events.extend(self.check_missing_open_orders(mass_status, &reconciled, lookback_mins));
+
+        if !self.config.filter_position_reports {
+            let position_events = self.reconcile_position_reports(mass_status, &events);
+            events.extend(position_events);
+        }
+
         events
     }
 
@@ -354,6 +456,7 @@ impl ReconciliationManager {
             self.ts_last_query.remove(client_order_id);
         }
         self.order_local_activity_ns.remove(client_order_id);
+        self.open_check_missing.remove(client_order_id);
     }
 
     /// Claims external orders for a specific strategy and instrument.
@@ -372,13 +475,301 @@ impl ReconciliationManager {
         self.cache.borrow().instrument(instrument_id).cloned()
     }
 
+    fn should_reconcile_instrument(&self, instrument_id: &InstrumentId) -> bool {
+        self.config.reconciliation_instrument_ids.is_empty()
+            || self
+                .config
+                .reconciliation_instrument_ids
+                .contains(instrument_id)
+    }
+
+    fn resolve_client_order_id(
+        &self,
+        client_order_id: Option<ClientOrderId>,
+        venue_order_id: &VenueOrderId,
+    ) -> Option<ClientOrderId> {
+        client_order_id.or_else(|| self.cache.borrow().client_order_id(venue_order_id).copied())
+    }
+
+    fn reconcile_order(
+        &mut self,
+        order: &mut OrderAny,
+        report: &OrderStatusReport,
+        fills: &[FillReport],
+    ) -> Vec<OrderEventAny> {
+        let mut events = Vec::new();
+
+        // The venue is working an order we never saw acknowledged
+        if order.status() == OrderStatus::Submitted
+            && matches!(
+                report.order_status,
+                OrderStatus::Triggered
+                    | OrderStatus::PartiallyFilled
+                    | OrderStatus::PendingUpdate
+                    | OrderStatus::PendingCancel
+            )
+        {
+            events.push(self.create_order_accepted(order, report));
+        }
+
+        let instrument_id = order.instrument_id();
+        if let Some(instrument) = self.get_instrument(&instrument_id) {
+            let mut filled_qty = order.filled_qty();
+            let mut notional = order.avg_px().unwrap_or(0.0) * filled_qty.as_f64();
+
+            for fill in fills {
+                if order.trade_ids().contains(&&fill.trade_id) {
+                    continue;
+                }
+                if let Some(event) = self.create_order_fill(order, fill, &instrument) {
+                    filled_qty += fill.last_qty;
+                    notional += fill.last_px.as_f64() * fill.last_qty.as_f64();
+                    events.push(event);
+                }
+            }
+
+            if report.filled_qty > filled_qty
+                && let Some(event) =
+                    self.create_inferred_fill(order, report, filled_qty, notional, &instrument)
+            {
+                events.push(event);
+            }
+        } else if !fills.is_empty() || report.filled_qty > order.filled_qty() {
+            log::warn!(
+                "Cannot reconcile fills for {}: no instrument {instrument_id} in cache",
+                order.client_order_id()
+            );
+        }
+
+        if let Some(event) = self.reconcile_order_report(order, report) {
+            events.push(event);
+        }
+
+        events
+    }
+
+    fn check_missing_open_orders(
+        &mut self,
+        mass_status: &ExecutionMassStatus,
+        reconciled: &HashSet<ClientOrderId>,
+        lookback_mins: Option<u64>,
+    ) -> Vec<OrderEventAny> {
+        let mut events = Vec::new();
+        let ts_now = self.clock.borrow().timestamp_ns();
+
+        // Orders created before the report window may simply not have been reported
+        let ts_window_start = lookback_mins
+            .map(|mins| ts_now.saturating_sub(mins.saturating_mul(60 * NANOSECONDS_IN_SECOND)));
+
+        for client_order_id in reconciled {
+            self.open_check_missing.remove(client_order_id);
+        }
+
+        let open_orders: Vec<OrderAny> = self
+            .cache
+            .borrow()
+            .orders_open(Some(&mass_status.venue), None, None, None)
+            .into_iter()
+            .cloned()
+            .collect();
+
+        for order in open_orders {
+            let client_order_id = order.client_order_id();
+            if reconciled.contains(&client_order_id)
+                || self
+                    .config
+                    .filtered_client_order_ids
+                    .contains(&client_order_id)
+                || !self.should_reconcile_instrument(&order.instrument_id())
+                || ts_window_start.is_some_and(|ts| order.ts_init().as_u64() < ts)
+            {
+                continue;
+            }
+
+            // Allow time for venue state to catch up with recent local activity
+            let ts_last_activity = self
+                .order_local_activity_ns
+                .get(&client_order_id)
+                .map_or(order.ts_last(), |ts| order.ts_last().max(*ts));
+            if ts_now.saturating_sub(*ts_last_activity) < self.config.open_check_threshold_ns {
+                continue;
+            }
+
+            let missing_count = self.open_check_missing.entry(client_order_id).or_insert(0);
+            *missing_count += 1;
+
+            if *missing_count >= self.config.open_check_missing_retries {
+                log::warn!(
+                    "Open order {client_order_id} not found at {} after {missing_count} checks, canceling",
+                    mass_status.venue
+                );
+                events.push(self.create_order_canceled_simple(&order, ts_now));
+                self.clear_recon_tracking(&client_order_id, true);
+            }
+        }
+
+        events
+    }
+
+    fn reconcile_position_reports(
+        &mut self,
+        mass_status: &ExecutionMassStatus,
+        pending: &[OrderEventAny],
+    ) -> Vec<OrderEventAny> {
+        let mut events = Vec::new();
+
+        for (instrument_id, reports) in mass_status.position_reports() {
+            if !self.should_reconcile_instrument(&instrument_id) {
+                continue;
+            }
+
+            let Some(instrument) = self.get_instrument(&instrument_id) else {
+                log::warn!("Cannot reconcile position for {instrument_id}: no instrument in cache");
+                continue;
+            };
+
+            if self.is_position_reconciliation_pending(&instrument_id) {
+                continue;
+            }
+
+            let size_precision = u32::from(instrument.size_precision());
+            let (current_qty, current_value) =
+                self.simulate_cached_position(&instrument_id, pending);
+            let current_qty = current_qty.round_dp(size_precision);
+            let target_qty: Decimal = reports
+                .iter()
+                .map(|report| report.signed_decimal_qty)
+                .sum::<Decimal>()
+                .round_dp(size_precision);
+
+            if current_qty == target_qty {
+                continue;
+            }
+
+            log::warn!(
+                "Position discrepancy for {instrument_id}: cached {current_qty}, venue {target_qty}"
+            );
+
+            if !self.config.generate_missing_orders {
+                continue;
+            }
+
+            let current_avg_px =
+                (!current_qty.is_zero()).then(|| current_value / current_qty.abs());
+            let target_avg_px = reported_avg_px_open(&reports, target_qty);
+            let Some(last_px) = calculate_reconciliation_price(
+                current_qty,
+                current_avg_px,
+                target_qty,
+                target_avg_px,
+            )
+            .or_else(|| self.market_price(&instrument_id)) else {
+                log::warn!("Cannot reconcile position for {instrument_id}: no price to fill at");
+                continue;
+            };
+
+            let venue_position_id = match reports.as_slice() {
+                [report] => report.venue_position_id,
+                _ => None,
+            };
+
+            match self.create_position_reconciliation(
+                &instrument,
+                target_qty - current_qty,
+                last_px,
+                mass_status.account_id,
+                venue_position_id,
+            ) {
+                Ok(reconciliation_events) => {
+                    if let Some(event) = reconciliation_events.first() {
+                        self.pending_position_orders
+                            .insert(instrument_id, event.client_order_id());
+                    }
+                    events.extend(reconciliation_events);
+                }
+                Err(e) => log::error!("Failed to reconcile position for {instrument_id}: {e}"),
+            }
+        }
+
+        events
+    }
+
+    /// Returns whether a synthetic order generated for a previous position discrepancy on the
+    /// instrument has not yet been applied to the cache.
+    fn is_position_reconciliation_pending(&mut self, instrument_id: &InstrumentId) -> bool {
+        let Some(client_order_id) = self.pending_position_orders.get(instrument_id) else {
+            return false;
+        };
+
+        let is_closed = self
+            .cache
+            .borrow()
+            .order(client_order_id)
+            .is_none_or(|order| order.is_closed());
+        if is_closed {
+            self.pending_position_orders.remove(instrument_id);
+            return false;
+        }
+
+        log::debug!("Position reconciliation for {instrument_id} pending with {client_order_id}");
+        true
+    }
+
+    /// Returns the net (quantity, value) of the cached open positions for the instrument,
+    /// including the fills pending from the current reconciliation.
+    fn simulate_cached_position(
+        &self,
+        instrument_id: &InstrumentId,
+        pending: &[OrderEventAny],
+    ) -> (Decimal, Decimal) {
+        let cache = self.cache.borrow();
+        let mut positions = cache.positions_open(None, Some(instrument_id), None, None);
+        positions.sort_by_key(|position| position.ts_opened);
+
+        let mut fills: Vec<FillSnapshot> = positions
+            .iter()
+            .map(|position| {
+                FillSnapshot::new(
+                    position.ts_opened.as_u64(),
+                    position.entry,
+                    position.quantity.as_decimal(),
+                    Decimal::from_f64(position.avg_px_open).unwrap_or_default(),
+                    VenueOrderId::new(position.id.as_str()),
+                )
+            })
+            .collect();
+
+        fills.extend(pending.iter().filter_map(|event| match event {
+            OrderEventAny::Filled(fill) if fill.instrument_id == *instrument_id => {
+                Some(FillSnapshot::new(
+                    fill.ts_event.as_u64(),
+                    fill.order_side,
+                    fill.last_qty.as_decimal(),
+                    fill.last_px.as_decimal(),
+                    fill.venue_order_id,
+                ))
+            }
+            _ => None,
+        }));
+
+        simulate_position(&fills)
+    }
+
+    fn market_price(&self, instrument_id: &InstrumentId) -> Option<Decimal> {
+        let cache = self.cache.borrow();
+        cache
+            .price(instrument_id, PriceType::Mid)
+            .or_else(|| cache.price(instrument_id, PriceType::Last))
+            .map(|price| price.as_decimal())
+    }
+
     fn reconcile_order_report(
         &mut self,
         order: &mut OrderAny,
         report: &OrderStatusReport,
     ) -> Option<OrderEventAny> {
-        // Check if reconciliation is needed
-        if order.status() == report.order_status && order.filled_qty() == report.filled_qty {
+        // Check if reconciliation is needed (fills are reconciled separately)
+        if order.status() == report.order_status {
             return None; // Already in sync
         }
 
@@ -473,7 +864,6 @@ impl ReconciliationManager {
         ))
     }
 
-    #[allow(dead_code)]
     fn create_order_canceled_simple(&self, order: &OrderAny, ts_event: UnixNanos) -> OrderEventAny {
         OrderEventAny::Canceled(OrderCanceled::new(
             order.trader_id(),
@@ -541,6 +931,176 @@ impl ReconciliationManager {
             Some(fill.commission),
         )))
     }
+
+    fn create_inferred_fill(
+        &self,
+        order: &OrderAny,
+        report: &OrderStatusReport,
+        filled_qty: Quantity,
+        notional: f64,
+        instrument: &InstrumentAny,
+    ) -> Option<OrderEventAny> {
+        let last_qty = report.filled_qty - filled_qty;
+
+        // Back out the price of the missing quantity from the reported average price
+        let last_px = match (report.avg_px.and_then(|px| px.to_f64()), report.price) {
+            (Some(avg_px), _) => {
+                let px = avg_px.mul_add(report.filled_qty.as_f64(), -notional) / last_qty.as_f64();
+                if px > 0.0 { px } else { avg_px }
+            }
+            (None, Some(price)) => price.as_f64(),
+            (None, None) => {
+                log::warn!(
+                    "Cannot infer fill for {}: no average price or price reported",
+                    order.client_order_id()
+                );
+                return None;
+            }
+        };
+
+        log::info!(
+            "Inferred fill of {last_qty} @ {last_px} for {} from order status report",
+            order.client_order_id()
+        );
+
+        Some(OrderEventAny::Filled(OrderFilled::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            report.venue_order_id,
+            order.account_id().unwrap_or(report.account_id),
+            TradeId::new(UUID4::new().to_string()),
+            order.order_side(),
+            order.order_type(),
+            last_qty,
+            Price::new(last_px, instrument.price_precision()),
+            instrument.quote_currency(),
+            LiquiditySide::NoLiquiditySide,
+            UUID4::new(),
+            report.ts_last,
+            self.clock.borrow().timestamp_ns(),
+            true,
+            report.venue_position_id,
+            None,
+        )))
+    }
+
+    fn create_position_reconciliation(
+        &self,
+        instrument: &InstrumentAny,
+        qty_diff: Decimal,
+        last_px: Decimal,
+        account_id: AccountId,
+        venue_position_id: Option<PositionId>,
+    ) -> anyhow::Result<Vec<OrderEventAny>> {
+        let instrument_id = instrument.id();
+        let order_side = if qty_diff > Decimal::ZERO {
+            OrderSide::Buy
+        } else {
+            OrderSide::Sell
+        };
+        let quantity = Quantity::from_decimal(qty_diff.abs(), instrument.size_precision())?;
+        let last_px = Price::from_decimal(
+            last_px.round_dp(u32::from(instrument.price_precision())),
+            instrument.price_precision(),
+        )?;
+
+        let client_order_id =
+            ClientOrderId::new(format!("{RECONCILIATION_ORDER_ID_PREFIX}{}", UUID4::new()));
+        let venue_order_id = VenueOrderId::new(client_order_id.as_str());
+        let strategy_id = self
+            .external_order_claims
+            .get(&instrument_id)
+            .copied()
+            .unwrap_or_else(StrategyId::external);
+        let ts_now = self.clock.borrow().timestamp_ns();
+
+        let order = OrderAny::Market(MarketOrder::new(
+            self.config.trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            order_side,
+            quantity,
+            TimeInForce::Gtc,
+            UUID4::new(),
+            ts_now,
+            false,
+            false,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(vec![Ustr::from(RECONCILIATION_TAG)]),
+        ));
+        self.cache
+            .borrow_mut()
+            .add_order(order.clone(), None, None, false)?;
+
+        log::info!(
+            "Generated {client_order_id} to reconcile position: {order_side} {quantity} {instrument_id} @ {last_px}"
+        );
+
+        let accepted = OrderEventAny::Accepted(OrderAccepted::new(
+            order.trader_id(),
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            venue_order_id,
+            account_id,
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            true,
+        ));
+
+        let filled = OrderEventAny::Filled(OrderFilled::new(
+            order.trader_id(),
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            venue_order_id,
+            account_id,
+            TradeId::new(UUID4::new().to_string()),
+            order_side,
+            OrderType::Market,
+            quantity,
+            last_px,
+            instrument.quote_currency(),
+            LiquiditySide::NoLiquiditySide,
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            true,
+            venue_position_id,
+            None,
+        ));
+
+        Ok(vec![accepted, filled])
+    }
+}
+
+/// Returns the average open price of the reported positions on the side of `target_qty`.
+fn reported_avg_px_open(reports: &[PositionStatusReport], target_qty: Decimal) -> Option<Decimal> {
+    let mut qty = Decimal::ZERO;
+    let mut value = Decimal::ZERO;
+
+    for report in reports {
+        if report.signed_decimal_qty.is_sign_positive() != target_qty.is_sign_positive()
+            || report.signed_decimal_qty.is_zero()
+        {
+            continue;
+        }
+        let avg_px_open = report.avg_px_open?;
+        qty += report.signed_decimal_qty.abs();
+        value += report.signed_decimal_qty.abs() * avg_px_open;
+    }
+
+    (!qty.is_zero()).then(|| value / qty)
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -549,15 +1109,18 @@ impl ReconciliationManager {
 
 #[cfg(test)]
 mod tests {
-    use std::{cell::RefCell, rc::Rc};
+    use std::{cell::RefCell, rc::Rc, str::FromStr};
 
     use nautilus_common::{cache::Cache, clock::TestClock};
-    use nautilus_core::{UUID4, UnixNanos};
+    use nautilus_core::{UUID4, UnixNanos, datetime::NANOSECONDS_IN_SECOND};
     use nautilus_model::{
-        enums::OrderStatus,
-        identifiers::{AccountId, ClientId, ClientOrderId, VenueOrderId},
+        enums::{OmsType, OrderSide, OrderStatus, OrderType, PositionSideSpecified},
+        identifiers::{AccountId, ClientId, ClientOrderId, Venue, VenueOrderId},
+        instruments::stubs::audusd_sim,
+        orders::{builder::OrderTestBuilder, stubs::TestOrderStubs},
+        position::Position,
         reports::ExecutionMassStatus,
-        types::Quantity,
+        types::{Money, Price, Quantity},
     };
     use rstest::rstest;
 
@@ -783,4 +1346,322 @@ mod tests {
         assert!(!config.filter_unclaimed_external);
         assert!(config.generate_missing_orders);
     }
+
+    fn create_manager_with_order(
+        config: ReconciliationConfig,
+    ) -> (ReconciliationManager, OrderAny, ExecutionMassStatus) {
+        let clock = Rc::new(RefCell::new(TestClock::new()));
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim());
+        let order = TestOrderStubs::make_accepted_order(
+            &OrderTestBuilder::new(OrderType::Limit)
+                .instrument_id(instrument.id())
+                .side(OrderSide::Buy)
+                .price(Price::from("1.00000"))
+                .quantity(Quantity::from(100_000))
+                .build(),
+        );
+
+        cache.borrow_mut().add_instrument(instrument).unwrap();
+        cache
+            .borrow_mut()
+            .add_order(order.clone(), None, None, false)
+            .unwrap();
+        cache.borrow_mut().update_order(&order).unwrap();
+
+        let mass_status = ExecutionMassStatus::new(
+            ClientId::from("SIM"),
+            AccountId::from("SIM-001"),
+            Venue::from("SIM"),
+            UnixNanos::default(),
+            Some(UUID4::new()),
+        );
+
+        let manager = ReconciliationManager::new(clock, cache, config);
+        (manager, order, mass_status)
+    }
+
+    fn order_report(order: &OrderAny, status: OrderStatus, filled_qty: u64) -> OrderStatusReport {
+        OrderStatusReport::new(
+            AccountId::from("SIM-001"),
+            order.instrument_id(),
+            Some(order.client_order_id()),
+            VenueOrderId::from("V-001"),
+            order.order_side(),
+            order.order_type(),
+            order.time_in_force(),
+            status,
+            order.quantity(),
+            Quantity::from(filled_qty),
+            UnixNanos::default(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+            Some(UUID4::new()),
+        )
+    }
+
+    #[rstest]
+    fn test_reconcile_mass_status_infers_missing_fill() {
+        let (mut manager, order, mut mass_status) =
+            create_manager_with_order(ReconciliationConfig::default());
+        let report = order_report(&order, OrderStatus::PartiallyFilled, 40_000)
+            .with_avg_px(1.00002)
+            .unwrap();
+        mass_status.add_order_reports(vec![report]);
+
+        let events = manager.reconcile_mass_status(&mass_status, None);
+
+        assert_eq!(events.len(), 1);
+        let OrderEventAny::Filled(fill) = &events[0] else {
+            panic!("expected fill, was {:?}", events[0]);
+        };
+        assert_eq!(fill.last_qty, Quantity::from(40_000));
+        assert_eq!(fill.last_px, Price::from("1.00002"));
+        assert!(fill.reconciliation);
+    }
+
+    #[rstest]
+    fn test_reconcile_mass_status_applies_fills_before_cancel() {
+        let (mut manager, order, mut mass_status) =
+            create_manager_with_order(ReconciliationConfig::default());
+        let fill = FillReport::new(
+            AccountId::from("SIM-001"),
+            order.instrument_id(),
+            VenueOrderId::from("V-001"),
+            TradeId::from("T-001"),
+            OrderSide::Buy,
+            Quantity::from(30_000),
+            Price::from("0.99999"),
+            Money::from("1.50 USD"),
+            LiquiditySide::Maker,
+            Some(order.client_order_id()),
+            None,
+            UnixNanos::default(),
+            UnixNanos::default(),
+            None,
+        );
+        mass_status.add_order_reports(vec![order_report(&order, OrderStatus::Canceled, 30_000)]);
+        mass_status.add_fill_reports(vec![fill.clone()]);
+
+        let events = manager.reconcile_mass_status(&mass_status, None);
+
+        assert_eq!(events.len(), 2);
+        assert!(matches!(&events[0], OrderEventAny::Filled(e) if e.trade_id == fill.trade_id));
+        assert!(matches!(events[1], OrderEventAny::Canceled(_)));
+
+        // Once the events are applied a repeated report is a no-op
+        let mut cached = manager
+            .cache
+            .borrow()
+            .order(&order.client_order_id())
+            .cloned()
+            .unwrap();
+        for event in events {
+            cached.apply(event).unwrap();
+        }
+        manager.cache.borrow_mut().update_order(&cached).unwrap();
+
+        assert!(manager.reconcile_mass_status(&mass_status, None).is_empty());
+    }
+
+    #[rstest]
+    fn test_reconcile_mass_status_cancels_missing_open_order_after_retries() {
+        let config = ReconciliationConfig {
+            open_check_threshold_ns: 0,
+            open_check_missing_retries: 2,
+            ..ReconciliationConfig::default()
+        };
+        let (mut manager, order, mass_status) = create_manager_with_order(config);
+
+        assert!(manager.reconcile_mass_status(&mass_status, None).is_empty());
+
+        let events = manager.reconcile_mass_status(&mass_status, None);
+        assert_eq!(events.len(), 1);
+        assert!(
+            matches!(&events[0], OrderEventAny::Canceled(e) if e.client_order_id == order.client_order_id())
+        );
+        assert!(manager.open_check_missing.is_empty());
+    }
+
+    #[rstest]
+    fn test_reconcile_mass_status_skips_open_orders_older_than_lookback() {
+        let config = ReconciliationConfig {
+            open_check_threshold_ns: 0,
+            open_check_missing_retries: 1,
+            ..ReconciliationConfig::default()
+        };
+        let (mut manager, _, mass_status) = create_manager_with_order(config);
+        let mut clock = TestClock::new();
+        clock.advance_time(UnixNanos::from(2 * 60 * 60 * NANOSECONDS_IN_SECOND), true);
+        manager.clock = Rc::new(RefCell::new(clock));
+
+        // The order was created two hours ago, outside the one hour report window
+        assert!(
+            manager
+                .reconcile_mass_status(&mass_status, Some(60))
+                .is_empty()
+        );
+        assert!(manager.open_check_missing.is_empty());
+
+        assert_eq!(
+            manager.reconcile_mass_status(&mass_status, Some(180)).len(),
+            1
+        );
+    }
+
+    #[rstest]
+    fn test_reconcile_mass_status_resets_missing_count_when_reported() {
+        let config = ReconciliationConfig {
+            open_check_threshold_ns: 0,
+            open_check_missing_retries: 2,
+            ..ReconciliationConfig::default()
+        };
+        let (mut manager, order, mut mass_status) = create_manager_with_order(config);
+
+        assert!(manager.reconcile_mass_status(&mass_status, None).is_empty());
+        assert_eq!(
+            manager.open_check_missing.get(&order.client_order_id()),
+            Some(&1)
+        );
+
+        mass_status.add_order_reports(vec![order_report(&order, OrderStatus::Accepted, 0)]);
+        assert!(manager.reconcile_mass_status(&mass_status, None).is_empty());
+        assert!(manager.open_check_missing.is_empty());
+    }
+
+    fn position_report(
+        side: PositionSideSpecified,
+        qty: u64,
+        avg_px: &str,
+    ) -> PositionStatusReport {
+        PositionStatusReport::new(
+            AccountId::from("SIM-001"),
+            InstrumentId::from("AUD/USD.SIM"),
+            side,
+            Quantity::from(qty),
+            UnixNanos::default(),
+            UnixNanos::default(),
+            Some(UUID4::new()),
+            None,
+            Some(Decimal::from_str(avg_px).unwrap()),
+        )
+    }
+
+    #[rstest]
+    fn test_reconcile_mass_status_generates_synthetic_fill_for_external_position() {
+        let (mut manager, _, mut mass_status) =
+            create_manager_with_order(ReconciliationConfig::default());
+        mass_status.add_position_reports(vec![position_report(
+            PositionSideSpecified::Short,
+            150_000,
+            "1.00010",
+        )]);
+
+        let events = manager.reconcile_mass_status(&mass_status, None);
+
+        assert_eq!(events.len(), 2);
+        let OrderEventAny::Accepted(accepted) = &events[0] else {
+            panic!("expected accepted, was {:?}", events[0]);
+        };
+        let OrderEventAny::Filled(fill) = &events[1] else {
+            panic!("expected fill, was {:?}", events[1]);
+        };
+        assert!(
+            fill.client_order_id
+                .as_str()
+                .starts_with(RECONCILIATION_ORDER_ID_PREFIX)
+        );
+        assert_eq!(fill.client_order_id, accepted.client_order_id);
+        assert_eq!(fill.strategy_id, StrategyId::external());
+        assert_eq!(fill.order_side, OrderSide::Sell);
+        assert_eq!(fill.last_qty, Quantity::from(150_000));
+        assert_eq!(fill.last_px, Price::from("1.00010"));
+        assert!(fill.reconciliation);
+
+        let cache = manager.cache.borrow();
+        let order = cache.order(&fill.client_order_id).unwrap();
+        assert_eq!(order.tags(), Some(&[Ustr::from(RECONCILIATION_TAG)][..]));
+    }
+
+    #[rstest]
+    fn test_reconcile_mass_status_generates_synthetic_order_once_per_discrepancy() {
+        let (mut manager, _, mut mass_status) =
+            create_manager_with_order(ReconciliationConfig::default());
+        mass_status.add_position_reports(vec![position_report(
+            PositionSideSpecified::Short,
+            150_000,
+            "1.00010",
+        )]);
+
+        let events = manager.reconcile_mass_status(&mass_status, None);
+        assert_eq!(events.len(), 2);
+
+        // The synthetic order has not been applied yet, so it is not generated again
+        assert!(manager.reconcile_mass_status(&mass_status, None).is_empty());
+
+        let OrderEventAny::Filled(fill) = events[1].clone() else {
+            panic!("expected fill, was {:?}", events[1]);
+        };
+        let mut order = manager
+            .cache
+            .borrow()
+            .order(&fill.client_order_id)
+            .cloned()
+            .unwrap();
+        for event in events {
+            order.apply(event).unwrap();
+        }
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim());
+        let position = Position::new(
+            &instrument,
+            OrderFilled {
+                position_id: Some(PositionId::from("P-001")),
+                ..fill
+            },
+        );
+        {
+            let mut cache = manager.cache.borrow_mut();
+            cache.update_order(&order).unwrap();
+            cache.add_position(position, OmsType::Netting).unwrap();
+        }
+
+        assert!(manager.reconcile_mass_status(&mass_status, None).is_empty());
+        assert!(manager.pending_position_orders.is_empty());
+    }
+
+    #[rstest]
+    fn test_reconcile_mass_status_accounts_for_pending_fills_in_position() {
+        let (mut manager, order, mut mass_status) =
+            create_manager_with_order(ReconciliationConfig::default());
+        let report = order_report(&order, OrderStatus::PartiallyFilled, 40_000)
+            .with_avg_px(1.00002)
+            .unwrap();
+        mass_status.add_order_reports(vec![report]);
+        mass_status.add_position_reports(vec![position_report(
+            PositionSideSpecified::Long,
+            40_000,
+            "1.00002",
+        )]);
+
+        let events = manager.reconcile_mass_status(&mass_status, None);
+
+        // Only the inferred order fill, the position then matches the venue
+        assert!(matches!(events[..], [OrderEventAny::Filled(_)]));
+    }
+
+    #[rstest]
+    fn test_reconcile_mass_status_position_without_generate_missing_orders() {
+        let config = ReconciliationConfig {
+            generate_missing_orders: false,
+            ..ReconciliationConfig::default()
+        };
+        let (mut manager, _, mut mass_status) = create_manager_with_order(config);
+        mass_status.add_position_reports(vec![position_report(
+            PositionSideSpecified::Long,
+            100_000,
+            "1.00000",
+        )]);
+
+        assert!(manager.reconcile_mass_status(&mass_status, None).is_empty());
+    }
 }
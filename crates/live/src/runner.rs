@@ -181,6 +181,9 @@ impl AsyncRunner {
                 Some(event) = self.data_evt_rx.recv() => {
                     match event {
                         DataEvent::Data(data) => msgbus::send_any(data_engine_process, &data),
+                        DataEvent::FundingRate(rate) => {
+                            msgbus::send_any(data_engine_process, &rate);
+                        }
                         DataEvent::Response(resp) => {
                             msgbus::send_any(data_engine_response, &resp);
                         }
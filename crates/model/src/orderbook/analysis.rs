@@ -15,15 +15,113 @@
 
 //! Functions related to order book analysis.
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
+
+use nautilus_core::UnixNanos;
 
 use super::{BookLevel, BookPrice, OrderBook};
 use crate::{
+    data::order::OrderId,
     enums::{BookType, OrderSide},
     orderbook::BookIntegrityError,
     types::{Price, Quantity, fixed::FIXED_SCALAR, quantity::QuantityRaw},
 };
 
+/// The queue position of an order within its price level.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct QueuePosition {
+    /// The price of the level the order rests at.
+    pub price: Price,
+    /// The number of orders ahead of the order in FIFO priority.
+    pub orders_ahead: usize,
+    /// The total size of the orders ahead of the order.
+    pub volume_ahead: Quantity,
+    /// The total number of orders at the level.
+    pub level_orders: usize,
+    /// The total size of all orders at the level.
+    pub level_volume: Quantity,
+}
+
+/// Counts of the individual order events applied to an L3 (MBO) order book.
+///
+/// Deletes cannot be distinguished from fills in MBO data, so all removals are
+/// counted as cancels.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BookOrderActivity {
+    /// The number of orders added.
+    pub adds: u64,
+    /// The number of size updates at an unchanged price (queue priority retained).
+    pub modifies: u64,
+    /// The number of price updates (the order is re-queued at the new level).
+    pub replaces: u64,
+    /// The number of orders removed.
+    pub cancels: u64,
+}
+
+impl BookOrderActivity {
+    /// Returns the ratio of cancels to adds (zero if no orders were added).
+    #[must_use]
+    pub fn cancel_rate(&self) -> f64 {
+        ratio(self.cancels, self.adds)
+    }
+
+    /// Returns the ratio of replaces to adds (zero if no orders were added).
+    #[must_use]
+    pub fn replace_rate(&self) -> f64 {
+        ratio(self.replaces, self.adds)
+    }
+}
+
+fn ratio(count: u64, adds: u64) -> f64 {
+    if adds == 0 {
+        0.0
+    } else {
+        count as f64 / adds as f64
+    }
+}
+
+/// Calculates the queue position of the order with `order_id` within the `level`.
+///
+/// Returns `None` if the order is not at the level.
+#[must_use]
+pub fn get_queue_position(level: &BookLevel, order_id: OrderId) -> Option<QueuePosition> {
+    let index = level.orders.get_index_of(&order_id)?;
+    let precision = level.orders[index].size.precision;
+
+    let volume_ahead_raw: QuantityRaw = level
+        .orders
+        .values()
+        .take(index)
+        .map(|order| order.size.raw)
+        .sum();
+
+    Some(QueuePosition {
+        price: level.price.value,
+        orders_ahead: index,
+        volume_ahead: Quantity::from_raw(volume_ahead_raw, precision),
+        level_orders: level.len(),
+        level_volume: Quantity::from_raw(level.size_raw(), precision),
+    })
+}
+
+/// Calculates the ages (nanoseconds at `ts_now`) of the orders at the `level` in FIFO order,
+/// from the time each order joined the queue in `order_ts`.
+///
+/// Orders without a recorded queue entry time are skipped.
+#[must_use]
+pub fn get_order_ages(
+    level: &BookLevel,
+    order_ts: &HashMap<OrderId, UnixNanos>,
+    ts_now: UnixNanos,
+) -> Vec<u64> {
+    level
+        .orders
+        .keys()
+        .filter_map(|order_id| order_ts.get(order_id))
+        .map(|ts| ts_now.as_u64().saturating_sub(ts.as_u64()))
+        .collect()
+}
+
 /// Calculates the estimated fill quantity for a specified price from a set of
 /// order book levels and order side.
 ///
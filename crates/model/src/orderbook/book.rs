@@ -15,19 +15,30 @@
 
 //! A performant, generic, multi-purpose order book.
 
-use std::{collections::HashSet, fmt::Display};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
 
 use indexmap::IndexMap;
 use nautilus_core::UnixNanos;
 use rust_decimal::Decimal;
 
 use super::{
-    aggregation::pre_process_order, analysis, display::pprint_book, level::BookLevel,
+    aggregation::pre_process_order,
+    analysis::{self, BookOrderActivity, QueuePosition},
+    checksum::{self, BookChecksumConfig},
+    display::pprint_book,
+    ladder::BookPrice,
+    level::BookLevel,
     own::OwnOrderBook,
 };
 use crate::{
-    data::{BookOrder, OrderBookDelta, OrderBookDeltas, OrderBookDepth10, QuoteTick, TradeTick},
-    enums::{BookAction, BookType, OrderSide, OrderSideSpecified, OrderStatus},
+    data::{
+        BookOrder, OrderBookDelta, OrderBookDeltas, OrderBookDepth10, QuoteTick, TradeTick,
+        order::OrderId,
+    },
+    enums::{BookAction, BookType, OrderSide, OrderSideSpecified, OrderStatus, RecordFlag},
     identifiers::InstrumentId,
     orderbook::{BookIntegrityError, InvalidBookOperation, ladder::BookLadder},
     types::{
@@ -61,6 +72,13 @@ pub struct OrderBook {
     pub update_count: u64,
     pub(crate) bids: BookLadder,
     pub(crate) asks: BookLadder,
+    /// The time each resting order joined its queue (L3_MBO books only).
+    pub(crate) order_ts: HashMap<OrderId, UnixNanos>,
+    pub(crate) order_activity: BookOrderActivity,
+    /// If deltas are checked for gaps in their sequence numbers.
+    pub(crate) detect_gaps: bool,
+    /// If a sequence gap was detected and the book awaits a snapshot.
+    pub(crate) needs_resync: bool,
 }
 
 impl PartialEq for OrderBook {
@@ -96,6 +114,10 @@ impl OrderBook {
             update_count: 0,
             bids: BookLadder::new(OrderSideSpecified::Buy, book_type),
             asks: BookLadder::new(OrderSideSpecified::Sell, book_type),
+            order_ts: HashMap::new(),
+            order_activity: BookOrderActivity::default(),
+            detect_gaps: false,
+            needs_resync: false,
         }
     }
 
@@ -103,6 +125,9 @@ impl OrderBook {
     pub fn reset(&mut self) {
         self.bids.clear();
         self.asks.clear();
+        self.order_ts.clear();
+        self.order_activity = BookOrderActivity::default();
+        self.needs_resync = false;
         self.sequence = 0;
         self.ts_last = UnixNanos::default();
         self.update_count = 0;
@@ -111,6 +136,11 @@ impl OrderBook {
     /// Adds an order to the book after preprocessing based on book type.
     pub fn add(&mut self, order: BookOrder, flags: u8, sequence: u64, ts_event: UnixNanos) {
         let order = pre_process_order(self.book_type, order, flags);
+        if self.book_type == BookType::L3_MBO && order.size.is_positive() {
+            self.order_ts.insert(order.order_id, ts_event);
+            self.order_activity.adds += 1;
+        }
+
         match order.side.as_specified() {
             OrderSideSpecified::Buy => self.bids.add(order),
             OrderSideSpecified::Sell => self.asks.add(order),
@@ -122,6 +152,11 @@ impl OrderBook {
     /// Updates an existing order in the book after preprocessing based on book type.
     pub fn update(&mut self, order: BookOrder, flags: u8, sequence: u64, ts_event: UnixNanos) {
         let order = pre_process_order(self.book_type, order, flags);
+        if self.book_type == BookType::L3_MBO {
+            let previous = self.cached_price(&order);
+            self.track_update(&order, previous, ts_event);
+        }
+
         match order.side.as_specified() {
             OrderSideSpecified::Buy => self.bids.update(order),
             OrderSideSpecified::Sell => self.asks.update(order),
@@ -133,6 +168,11 @@ impl OrderBook {
     /// Deletes an order from the book after preprocessing based on book type.
     pub fn delete(&mut self, order: BookOrder, flags: u8, sequence: u64, ts_event: UnixNanos) {
         let order = pre_process_order(self.book_type, order, flags);
+        if self.book_type == BookType::L3_MBO && self.cached_price(&order).is_some() {
+            self.order_ts.remove(&order.order_id);
+            self.order_activity.cancels += 1;
+        }
+
         match order.side.as_specified() {
             OrderSideSpecified::Buy => self.bids.delete(order, sequence, ts_event),
             OrderSideSpecified::Sell => self.asks.delete(order, sequence, ts_event),
@@ -145,18 +185,21 @@ impl OrderBook {
     pub fn clear(&mut self, sequence: u64, ts_event: UnixNanos) {
         self.bids.clear();
         self.asks.clear();
+        self.order_ts.clear();
         self.increment(sequence, ts_event);
     }
 
     /// Clears all bid orders from the book.
     pub fn clear_bids(&mut self, sequence: u64, ts_event: UnixNanos) {
         self.bids.clear();
+        self.prune_order_ts();
         self.increment(sequence, ts_event);
     }
 
     /// Clears all ask orders from the book.
     pub fn clear_asks(&mut self, sequence: u64, ts_event: UnixNanos) {
         self.asks.clear();
+        self.prune_order_ts();
         self.increment(sequence, ts_event);
     }
 
@@ -240,6 +283,7 @@ impl OrderBook {
             }
         }
 
+        self.prune_order_ts();
         self.increment(self.sequence, self.ts_last);
 
         if removed_levels.is_empty() {
@@ -262,6 +306,70 @@ impl OrderBook {
         }
     }
 
+    /// Sets whether deltas are checked for gaps in their sequence numbers.
+    ///
+    /// When enabled, a delta whose sequence skips past the next expected sequence is
+    /// rejected with [`BookIntegrityError::SequenceGap`], and subsequent deltas are rejected
+    /// with [`BookIntegrityError::ResyncPending`] until a snapshot is applied (a `Clear`
+    /// delta, a delta flagged `F_SNAPSHOT`, or a depth snapshot). Deltas with a zero
+    /// sequence are not checked.
+    pub fn set_gap_detection(&mut self, enabled: bool) {
+        self.detect_gaps = enabled;
+        if !enabled {
+            self.needs_resync = false;
+        }
+    }
+
+    /// Returns whether deltas are checked for gaps in their sequence numbers.
+    #[must_use]
+    pub const fn gap_detection(&self) -> bool {
+        self.detect_gaps
+    }
+
+    /// Returns whether a sequence gap was detected and the book awaits a snapshot.
+    #[must_use]
+    pub const fn needs_resync(&self) -> bool {
+        self.needs_resync
+    }
+
+    /// Returns the CRC32 checksum of the top levels of the book in the configured format.
+    #[must_use]
+    pub fn checksum(&self, config: &BookChecksumConfig) -> u32 {
+        checksum::compute_checksum(self.bids(None), self.asks(None), config)
+    }
+
+    /// Validates the book against a checksum published by the venue.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BookIntegrityError::ChecksumMismatch`] if the book's checksum in the
+    /// configured format differs from `expected`.
+    pub fn validate_checksum(
+        &self,
+        expected: u32,
+        config: &BookChecksumConfig,
+    ) -> Result<(), BookIntegrityError> {
+        let checksum = self.checksum(config);
+        if checksum != expected {
+            return Err(BookIntegrityError::ChecksumMismatch(expected, checksum));
+        }
+        Ok(())
+    }
+
+    /// Replaces the contents of the book with those of the `snapshot` book.
+    ///
+    /// The gap detection setting is kept and any pending resync is cleared.
+    pub fn apply_snapshot(&mut self, snapshot: &Self) {
+        self.bids = snapshot.bids.clone();
+        self.asks = snapshot.asks.clone();
+        self.order_ts = snapshot.order_ts.clone();
+        self.needs_resync = false;
+        // The snapshot defines the new sequence, which may precede the dropped deltas
+        self.sequence = snapshot.sequence;
+        self.ts_last = snapshot.ts_last;
+        self.update_count += 1;
+    }
+
     /// Applies a single order book delta operation.
     ///
     /// # Errors
@@ -269,7 +377,13 @@ impl OrderBook {
     /// Returns an error if:
     /// - An `Add` is given with `NoOrderSide` (either explicitly or because the cache lookup failed).
     /// - After resolution the delta still has `NoOrderSide` but its action is not `Clear`.
+    /// - Gap detection is enabled and the delta skips a sequence number, or the book is
+    ///   awaiting a snapshot after a previous gap.
     pub fn apply_delta(&mut self, delta: &OrderBookDelta) -> Result<(), BookIntegrityError> {
+        if self.detect_gaps {
+            self.check_sequence(delta)?;
+        }
+
         let mut order = delta.order;
 
         if order.side == OrderSide::NoOrderSide && order.order_id != 0 {
@@ -327,6 +441,8 @@ impl OrderBook {
     pub fn apply_depth(&mut self, depth: &OrderBookDepth10) {
         self.bids.clear();
         self.asks.clear();
+        self.order_ts.clear();
+        self.needs_resync = false;
 
         for order in depth.bids {
             // Skip padding entries
@@ -365,6 +481,27 @@ impl OrderBook {
         self.increment(depth.sequence, depth.ts_event);
     }
 
+    fn check_sequence(&mut self, delta: &OrderBookDelta) -> Result<(), BookIntegrityError> {
+        if delta.action == BookAction::Clear || RecordFlag::F_SNAPSHOT.matches(delta.flags) {
+            self.needs_resync = false;
+            return Ok(());
+        }
+
+        if self.needs_resync {
+            return Err(BookIntegrityError::ResyncPending(delta.sequence));
+        }
+
+        if self.sequence != 0 && delta.sequence > self.sequence + 1 {
+            self.needs_resync = true;
+            return Err(BookIntegrityError::SequenceGap(
+                self.sequence + 1,
+                delta.sequence,
+            ));
+        }
+
+        Ok(())
+    }
+
     fn resolve_no_side_order(&self, mut order: BookOrder) -> Result<BookOrder, BookIntegrityError> {
         let resolved_side = self
             .bids
@@ -629,6 +766,45 @@ impl OrderBook {
         analysis::get_quantity_for_price(price, order_side, levels)
     }
 
+    /// Returns the queue position of the resting order with `order_id` (L3_MBO books).
+    #[must_use]
+    pub fn queue_position(&self, order_id: OrderId) -> Option<QueuePosition> {
+        [&self.bids, &self.asks].into_iter().find_map(|ladder| {
+            let price = ladder.cache.get(&order_id)?;
+            analysis::get_queue_position(ladder.levels.get(price)?, order_id)
+        })
+    }
+
+    /// Returns the number of orders at each price level for the `side`, best price first.
+    #[must_use]
+    pub fn level_order_counts(&self, side: OrderSide, depth: Option<usize>) -> Vec<(Price, usize)> {
+        self.side_levels(side, depth)
+            .map(|level| (level.price.value, level.len()))
+            .collect()
+    }
+
+    /// Returns the ages (nanoseconds since joining the queue, as of the last book event) of the
+    /// orders at each price level for the `side`, best price first (L3_MBO books).
+    #[must_use]
+    pub fn level_order_ages(
+        &self,
+        side: OrderSide,
+        depth: Option<usize>,
+    ) -> Vec<(Price, Vec<u64>)> {
+        self.side_levels(side, depth)
+            .map(|level| {
+                let ages = analysis::get_order_ages(level, &self.order_ts, self.ts_last);
+                (level.price.value, ages)
+            })
+            .collect()
+    }
+
+    /// Returns the counts of order events applied to the book (L3_MBO books).
+    #[must_use]
+    pub const fn order_activity(&self) -> BookOrderActivity {
+        self.order_activity
+    }
+
     /// Simulates fills for an order, returning list of (price, quantity) tuples.
     #[must_use]
     pub fn simulate_fills(&self, order: &BookOrder) -> Vec<(Price, Quantity)> {
@@ -644,6 +820,58 @@ impl OrderBook {
         pprint_book(self, num_levels, group_size)
     }
 
+    fn side_levels(
+        &self,
+        side: OrderSide,
+        depth: Option<usize>,
+    ) -> Box<dyn Iterator<Item = &BookLevel> + '_> {
+        match side.as_specified() {
+            OrderSideSpecified::Buy => Box::new(self.bids(depth)),
+            OrderSideSpecified::Sell => Box::new(self.asks(depth)),
+        }
+    }
+
+    fn cached_price(&self, order: &BookOrder) -> Option<BookPrice> {
+        let ladder = match order.side.as_specified() {
+            OrderSideSpecified::Buy => &self.bids,
+            OrderSideSpecified::Sell => &self.asks,
+        };
+        ladder.cache.get(&order.order_id).copied()
+    }
+
+    fn track_update(
+        &mut self,
+        order: &BookOrder,
+        previous: Option<BookPrice>,
+        ts_event: UnixNanos,
+    ) {
+        match previous {
+            None if order.size.is_positive() => {
+                self.order_ts.insert(order.order_id, ts_event);
+                self.order_activity.adds += 1;
+            }
+            None => {}
+            Some(_) if order.size.raw == 0 => {
+                self.order_ts.remove(&order.order_id);
+                self.order_activity.cancels += 1;
+            }
+            Some(price) if price.value != order.price => {
+                self.order_ts.insert(order.order_id, ts_event);
+                self.order_activity.replaces += 1;
+            }
+            Some(_) => self.order_activity.modifies += 1,
+        }
+    }
+
+    fn prune_order_ts(&mut self) {
+        if self.order_ts.is_empty() {
+            return;
+        }
+        let (bids, asks) = (&self.bids.cache, &self.asks.cache);
+        self.order_ts
+            .retain(|order_id, _| bids.contains_key(order_id) || asks.contains_key(order_id));
+    }
+
     fn increment(&mut self, sequence: u64, ts_event: UnixNanos) {
         // Critical invariant checks: panic in debug, warn in release
         if sequence < self.sequence {
@@ -0,0 +1,201 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Venue style CRC32 checksums over the top levels of an order book.
+//!
+//! Venues publishing book checksums build a string from the prices and sizes of the top
+//! levels and take its CRC32. The formats differ in the ordering of the sides, the separator
+//! and how decimals are rendered, which is captured by a [`BookChecksumConfig`].
+//!
+//! Prices and sizes are rendered with the book's `Price` and `Quantity` display format
+//! (optionally with trailing zeros trimmed), so instruments must use the venue's precisions
+//! for checksums to match.
+
+use super::BookLevel;
+use crate::types::Quantity;
+
+/// The ordering of price levels within the checksum string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChecksumLayout {
+    /// Alternates bid and ask levels (`bid1, ask1, bid2, ask2, ...`), continuing with the
+    /// remaining side once the other is exhausted.
+    Interleaved,
+    /// All bid levels followed by all ask levels.
+    BidsThenAsks,
+    /// All ask levels followed by all bid levels.
+    AsksThenBids,
+}
+
+/// The configuration for computing an order book checksum.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BookChecksumConfig {
+    /// The number of levels per side to include.
+    pub depth: usize,
+    /// The ordering of levels within the checksum string.
+    pub layout: ChecksumLayout,
+    /// The separator between each price and size (`None` to concatenate).
+    pub separator: Option<char>,
+    /// If the decimal point and leading zeros are removed from each value.
+    pub strip_decimal: bool,
+    /// If trailing fractional zeros (and a bare decimal point) are removed from each value.
+    pub trim_trailing_zeros: bool,
+}
+
+impl BookChecksumConfig {
+    /// Creates a new [`BookChecksumConfig`] instance.
+    #[must_use]
+    pub const fn new(
+        depth: usize,
+        layout: ChecksumLayout,
+        separator: Option<char>,
+        strip_decimal: bool,
+        trim_trailing_zeros: bool,
+    ) -> Self {
+        Self {
+            depth,
+            layout,
+            separator,
+            strip_decimal,
+            trim_trailing_zeros,
+        }
+    }
+
+    /// Returns the OKX checksum format (top 25 levels interleaved, separated by `:`, trailing
+    /// zeros trimmed).
+    ///
+    /// OKX compares the checksum as a signed 32-bit integer, so cast the result to `i32`.
+    #[must_use]
+    pub const fn okx() -> Self {
+        Self::new(25, ChecksumLayout::Interleaved, Some(':'), false, true)
+    }
+
+    /// Returns the Kraken checksum format (top 10 asks then bids, decimals stripped).
+    #[must_use]
+    pub const fn kraken() -> Self {
+        Self::new(10, ChecksumLayout::AsksThenBids, None, true, false)
+    }
+}
+
+/// Computes the CRC32 checksum of the `bids` and `asks` levels (each best price first).
+pub(crate) fn compute_checksum<'a>(
+    bids: impl Iterator<Item = &'a BookLevel>,
+    asks: impl Iterator<Item = &'a BookLevel>,
+    config: &BookChecksumConfig,
+) -> u32 {
+    let bids: Vec<&BookLevel> = bids.take(config.depth).collect();
+    let asks: Vec<&BookLevel> = asks.take(config.depth).collect();
+
+    let ordered: Vec<&BookLevel> = match config.layout {
+        ChecksumLayout::Interleaved => {
+            let mut levels = Vec::with_capacity(bids.len() + asks.len());
+            for i in 0..bids.len().max(asks.len()) {
+                levels.extend(bids.get(i));
+                levels.extend(asks.get(i));
+            }
+            levels
+        }
+        ChecksumLayout::BidsThenAsks => bids.into_iter().chain(asks).collect(),
+        ChecksumLayout::AsksThenBids => asks.into_iter().chain(bids).collect(),
+    };
+
+    crc32fast::hash(checksum_string(&ordered, config).as_bytes())
+}
+
+fn checksum_string(levels: &[&BookLevel], config: &BookChecksumConfig) -> String {
+    let mut buf = String::new();
+    for level in levels {
+        let price = level.price.value;
+        let size = level_size(level);
+        for value in [price.to_string(), size.to_string()] {
+            if let Some(separator) = config.separator
+                && !buf.is_empty()
+            {
+                buf.push(separator);
+            }
+            let value = if config.trim_trailing_zeros {
+                trim_trailing_zeros(&value)
+            } else {
+                &value
+            };
+            if config.strip_decimal {
+                push_stripped(&mut buf, value);
+            } else {
+                buf.push_str(value);
+            }
+        }
+    }
+    buf
+}
+
+fn level_size(level: &BookLevel) -> Quantity {
+    let precision = level.first().map_or(0, |order| order.size.precision);
+    Quantity::from_raw(level.size_raw(), precision)
+}
+
+fn trim_trailing_zeros(value: &str) -> &str {
+    if value.contains('.') {
+        value.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        value
+    }
+}
+
+fn push_stripped(buf: &mut String, value: &str) {
+    let digits: String = value.chars().filter(|c| *c != '.').collect();
+    let trimmed = digits.trim_start_matches('0');
+    buf.push_str(if trimmed.is_empty() { "0" } else { trimmed });
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("0.05005", "5005")]
+    #[case("5541.30000", "554130000")]
+    #[case("0.00000", "0")]
+    #[case("100", "100")]
+    fn test_push_stripped(#[case] value: &str, #[case] expected: &str) {
+        let mut buf = String::new();
+        push_stripped(&mut buf, value);
+        assert_eq!(buf, expected);
+    }
+
+    #[rstest]
+    #[case("3366.0", "3366")]
+    #[case("3366.10", "3366.1")]
+    #[case("0.00000500", "0.000005")]
+    #[case("100", "100")]
+    fn test_trim_trailing_zeros(#[case] value: &str, #[case] expected: &str) {
+        assert_eq!(trim_trailing_zeros(value), expected);
+    }
+
+    #[rstest]
+    fn test_presets() {
+        assert_eq!(BookChecksumConfig::okx().depth, 25);
+        assert_eq!(BookChecksumConfig::okx().separator, Some(':'));
+        assert!(BookChecksumConfig::okx().trim_trailing_zeros);
+        assert_eq!(
+            BookChecksumConfig::kraken().layout,
+            ChecksumLayout::AsksThenBids
+        );
+        assert!(BookChecksumConfig::kraken().strip_decimal);
+    }
+}
@@ -44,4 +44,10 @@ pub enum BookIntegrityError {
     TooManyOrders(OrderSide, usize),
     #[error("Integrity error: number of {0} levels > 1 for L1_MBP book, was {1}")]
     TooManyLevels(OrderSide, usize),
+    #[error("Integrity error: sequence gap, expected={0}, received={1}")]
+    SequenceGap(u64, u64),
+    #[error("Integrity error: awaiting snapshot to resync, dropped sequence={0}")]
+    ResyncPending(u64),
+    #[error("Integrity error: checksum mismatch, expected={0}, computed={1}")]
+    ChecksumMismatch(u32, u32),
 }
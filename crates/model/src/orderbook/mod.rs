@@ -18,10 +18,12 @@
 pub mod aggregation;
 pub mod analysis;
 pub mod book;
+pub mod checksum;
 pub mod display;
 pub mod error;
 pub mod ladder;
 pub mod level;
+pub mod net;
 pub mod own;
 
 #[cfg(test)]
@@ -30,8 +32,10 @@ mod tests;
 // Re-exports
 pub use crate::orderbook::{
     book::OrderBook,
+    checksum::{BookChecksumConfig, ChecksumLayout},
     error::{BookIntegrityError, InvalidBookOperation},
     ladder::BookPrice,
     level::BookLevel,
+    net::NetOrderBook,
     own::OwnBookOrder,
 };
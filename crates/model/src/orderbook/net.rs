@@ -0,0 +1,340 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! An order book view of the public liquidity net of own orders.
+
+use std::collections::{HashMap, HashSet};
+
+use indexmap::IndexMap;
+use rust_decimal::Decimal;
+
+use super::{
+    BookLevel, BookPrice, OrderBook, aggregation::pre_process_order, ladder::BookLadder,
+    own::OwnOrderBook,
+};
+use crate::{
+    data::{BookOrder, OrderBookDelta, OrderBookDeltas},
+    enums::{BookAction, BookType, OrderSide, OrderSideSpecified, OrderStatus},
+    identifiers::{ClientOrderId, InstrumentId},
+    orderbook::BookIntegrityError,
+    types::{Price, Quantity, quantity::QuantityRaw},
+};
+
+/// An order book of the public liquidity with own orders subtracted from each price level.
+///
+/// The view is an L2 (MBP) book, so strategies and the matching engine can price and simulate
+/// fills against only the liquidity of other participants. Levels where own orders account for
+/// all of the public size are removed.
+///
+/// Once built, the view is kept current incrementally: public book changes are applied through
+/// [`NetOrderBook::apply_delta`] (or [`NetOrderBook::rebuild`] for snapshots), and own order
+/// changes through [`NetOrderBook::update_own_order`] and [`NetOrderBook::remove_own_order`].
+/// Only the affected price levels are recomputed.
+#[derive(Clone, Debug)]
+pub struct NetOrderBook {
+    book: OrderBook,
+    own_orders: HashMap<ClientOrderId, (OrderSideSpecified, Price, QuantityRaw)>,
+    own_sizes: HashMap<(OrderSideSpecified, Price), QuantityRaw>,
+}
+
+impl NetOrderBook {
+    /// Creates a new [`NetOrderBook`] from the public `book` net of the `own_book` orders.
+    ///
+    /// Own orders are filtered by `status` if provided. With `accepted_buffer_ns`, only orders
+    /// accepted at least that many nanoseconds before `now` (defaults to now) are subtracted.
+    #[must_use]
+    pub fn new(
+        book: &OrderBook,
+        own_book: &OwnOrderBook,
+        status: Option<HashSet<OrderStatus>>,
+        accepted_buffer_ns: Option<u64>,
+        now: Option<u64>,
+    ) -> Self {
+        let mut net = Self {
+            book: OrderBook::new(book.instrument_id, BookType::L2_MBP),
+            own_orders: HashMap::new(),
+            own_sizes: HashMap::new(),
+        };
+
+        let own_bids = own_book.bids_as_map(status.clone(), accepted_buffer_ns, now);
+        let own_asks = own_book.asks_as_map(status, accepted_buffer_ns, now);
+        for order in own_bids.values().chain(own_asks.values()).flatten() {
+            net.insert_own_order(
+                order.client_order_id,
+                order.side,
+                order.price,
+                order.size.raw,
+            );
+        }
+
+        net.rebuild(book);
+        net
+    }
+
+    /// Recomputes every net level from the public `book`.
+    ///
+    /// Use after the public book is replaced wholesale (e.g. a depth snapshot or top-of-book
+    /// update) rather than changed by individual deltas.
+    pub fn rebuild(&mut self, book: &OrderBook) {
+        self.book.bids.clear();
+        self.book.asks.clear();
+
+        for level in book.bids(None).chain(book.asks(None)) {
+            let own_size = self.own_size(level.side(), level.price.value);
+            add_net_level(self.ladder_mut(level.side()), level, own_size);
+        }
+
+        self.sync_book_state(book);
+    }
+
+    /// Applies the `delta` to the public `book` and recomputes the affected net levels.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if applying the delta to the public book fails.
+    pub fn apply_delta(
+        &mut self,
+        book: &mut OrderBook,
+        delta: &OrderBookDelta,
+    ) -> Result<(), BookIntegrityError> {
+        // An update or delete by order ID may move or remove the order from another level
+        let prev_price = book
+            .bids
+            .cache
+            .get(&delta.order.order_id)
+            .or_else(|| book.asks.cache.get(&delta.order.order_id))
+            .copied();
+
+        book.apply_delta(delta)?;
+
+        if delta.action == BookAction::Clear {
+            self.rebuild(book);
+            return Ok(());
+        }
+
+        if let Some(prev_price) = prev_price {
+            self.update_level(book, prev_price.side, prev_price.value);
+        }
+        if delta.order.side != OrderSide::NoOrderSide {
+            self.update_level(book, delta.order.side.as_specified(), delta.order.price);
+        }
+
+        self.sync_book_state(book);
+        Ok(())
+    }
+
+    /// Applies the `deltas` to the public `book` and recomputes the affected net levels.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error encountered when applying deltas to the public book.
+    pub fn apply_deltas(
+        &mut self,
+        book: &mut OrderBook,
+        deltas: &OrderBookDeltas,
+    ) -> Result<(), BookIntegrityError> {
+        for delta in &deltas.deltas {
+            self.apply_delta(book, delta)?;
+        }
+        Ok(())
+    }
+
+    /// Sets the resting size of an own order, recomputing the net levels it leaves and joins.
+    pub fn update_own_order(
+        &mut self,
+        book: &OrderBook,
+        client_order_id: ClientOrderId,
+        side: OrderSideSpecified,
+        price: Price,
+        size: Quantity,
+    ) {
+        self.remove_own_order(book, &client_order_id);
+        self.insert_own_order(client_order_id, side, price, size.raw);
+        self.update_level(book, side, price);
+    }
+
+    /// Removes an own order, restoring its size to the net level it rested at.
+    pub fn remove_own_order(&mut self, book: &OrderBook, client_order_id: &ClientOrderId) {
+        let Some((side, price, size)) = self.own_orders.remove(client_order_id) else {
+            return;
+        };
+
+        if let Some(own_size) = self.own_sizes.get_mut(&(side, price)) {
+            *own_size = own_size.saturating_sub(size);
+            if *own_size == 0 {
+                self.own_sizes.remove(&(side, price));
+            }
+        }
+        self.update_level(book, side, price);
+    }
+
+    /// Removes all own orders and net levels.
+    pub fn reset(&mut self) {
+        self.book.reset();
+        self.own_orders.clear();
+        self.own_sizes.clear();
+    }
+
+    fn insert_own_order(
+        &mut self,
+        client_order_id: ClientOrderId,
+        side: OrderSideSpecified,
+        price: Price,
+        size: QuantityRaw,
+    ) {
+        self.own_orders.insert(client_order_id, (side, price, size));
+        *self.own_sizes.entry((side, price)).or_default() += size;
+    }
+
+    fn own_size(&self, side: OrderSideSpecified, price: Price) -> QuantityRaw {
+        self.own_sizes
+            .get(&(side, price))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn update_level(&mut self, book: &OrderBook, side: OrderSideSpecified, price: Price) {
+        let book_price = BookPrice::new(price, side);
+        let own_size = self.own_size(side, price);
+        let public = match side {
+            OrderSideSpecified::Buy => &book.bids,
+            OrderSideSpecified::Sell => &book.asks,
+        };
+
+        let ladder = self.ladder_mut(side);
+        ladder.remove_level(book_price);
+        if let Some(level) = public.levels.get(&book_price) {
+            add_net_level(ladder, level, own_size);
+        }
+    }
+
+    fn ladder_mut(&mut self, side: OrderSideSpecified) -> &mut BookLadder {
+        match side {
+            OrderSideSpecified::Buy => &mut self.book.bids,
+            OrderSideSpecified::Sell => &mut self.book.asks,
+        }
+    }
+
+    fn sync_book_state(&mut self, book: &OrderBook) {
+        self.book.sequence = book.sequence;
+        self.book.ts_last = book.ts_last;
+        self.book.update_count = book.update_count;
+    }
+
+    /// Returns the instrument ID for the book.
+    #[must_use]
+    pub const fn instrument_id(&self) -> InstrumentId {
+        self.book.instrument_id
+    }
+
+    /// Returns the net book as an L2 (MBP) [`OrderBook`].
+    #[must_use]
+    pub const fn book(&self) -> &OrderBook {
+        &self.book
+    }
+
+    /// Returns an iterator over the net bid price levels.
+    pub fn bids(&self, depth: Option<usize>) -> impl Iterator<Item = &BookLevel> {
+        self.book.bids(depth)
+    }
+
+    /// Returns an iterator over the net ask price levels.
+    pub fn asks(&self, depth: Option<usize>) -> impl Iterator<Item = &BookLevel> {
+        self.book.asks(depth)
+    }
+
+    /// Returns the net bid price levels as a map of price to size.
+    #[must_use]
+    pub fn bids_as_map(&self, depth: Option<usize>) -> IndexMap<Decimal, Decimal> {
+        self.book.bids_as_map(depth)
+    }
+
+    /// Returns the net ask price levels as a map of price to size.
+    #[must_use]
+    pub fn asks_as_map(&self, depth: Option<usize>) -> IndexMap<Decimal, Decimal> {
+        self.book.asks_as_map(depth)
+    }
+
+    /// Returns the best bid price not made up entirely of own orders, if available.
+    #[must_use]
+    pub fn best_bid_price(&self) -> Option<Price> {
+        self.book.best_bid_price()
+    }
+
+    /// Returns the best ask price not made up entirely of own orders, if available.
+    #[must_use]
+    pub fn best_ask_price(&self) -> Option<Price> {
+        self.book.best_ask_price()
+    }
+
+    /// Returns the net size at the best bid price, if available.
+    #[must_use]
+    pub fn best_bid_size(&self) -> Option<Quantity> {
+        self.book.best_bid_size()
+    }
+
+    /// Returns the net size at the best ask price, if available.
+    #[must_use]
+    pub fn best_ask_size(&self) -> Option<Quantity> {
+        self.book.best_ask_size()
+    }
+
+    /// Returns the spread between the net best ask and bid prices.
+    #[must_use]
+    pub fn spread(&self) -> Option<f64> {
+        self.book.spread()
+    }
+
+    /// Returns the midpoint between the net best ask and bid prices.
+    #[must_use]
+    pub fn midpoint(&self) -> Option<f64> {
+        self.book.midpoint()
+    }
+
+    /// Calculates the average price to fill the specified quantity against the net liquidity.
+    #[must_use]
+    pub fn get_avg_px_for_quantity(&self, qty: Quantity, order_side: OrderSide) -> f64 {
+        self.book.get_avg_px_for_quantity(qty, order_side)
+    }
+
+    /// Calculates the net quantity available at or better than the specified price.
+    #[must_use]
+    pub fn get_quantity_for_price(&self, price: Price, order_side: OrderSide) -> f64 {
+        self.book.get_quantity_for_price(price, order_side)
+    }
+
+    /// Simulates fills for an order against the net liquidity, returning (price, quantity) tuples.
+    #[must_use]
+    pub fn simulate_fills(&self, order: &BookOrder) -> Vec<(Price, Quantity)> {
+        self.book.simulate_fills(order)
+    }
+}
+
+/// Adds the public `level` to the net `ladder` with `own_size` subtracted, unless nothing remains.
+fn add_net_level(ladder: &mut BookLadder, level: &BookLevel, own_size: QuantityRaw) {
+    let net_size = level.size_raw().saturating_sub(own_size);
+    if net_size == 0 {
+        return;
+    }
+
+    let precision = level.first().map_or(0, |order| order.size.precision);
+    let order = BookOrder::new(
+        level.side().as_order_side(),
+        level.price.value,
+        Quantity::from_raw(net_size, precision),
+        0,
+    );
+    ladder.add(pre_process_order(BookType::L2_MBP, order, 0));
+}
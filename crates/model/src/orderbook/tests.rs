@@ -30,7 +30,7 @@ use crate::{
     },
     identifiers::{ClientOrderId, InstrumentId, TradeId, TraderId, VenueOrderId},
     orderbook::{
-        BookIntegrityError, BookPrice, OrderBook, OwnBookOrder,
+        BookChecksumConfig, BookIntegrityError, BookPrice, NetOrderBook, OrderBook, OwnBookOrder,
         analysis::book_check_integrity,
         own::{OwnBookLadder, OwnBookLevel, OwnOrderBook},
     },
@@ -2881,6 +2881,460 @@ fn test_book_clear_stale_levels_l1_mbp() {
     assert_eq!(book.update_count, initial_update_count);
 }
 
+#[rstest]
+fn test_book_queue_position_l3() {
+    let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+    let mut book = OrderBook::new(instrument_id, BookType::L3_MBO);
+    for (order_id, size) in [(1, "1.0"), (2, "2.0"), (3, "3.0")] {
+        let order = BookOrder::new(
+            OrderSide::Buy,
+            Price::from("100.00"),
+            Quantity::from(size),
+            order_id,
+        );
+        book.add(order, 0, order_id, order_id.into());
+    }
+
+    let position = book.queue_position(3).unwrap();
+    assert_eq!(position.price, Price::from("100.00"));
+    assert_eq!(position.orders_ahead, 2);
+    assert_eq!(position.volume_ahead, Quantity::from("3.0"));
+    assert_eq!(position.level_orders, 3);
+    assert_eq!(position.level_volume, Quantity::from("6.0"));
+
+    // Size update keeps priority, price update re-queues at the new level
+    let modified = BookOrder::new(
+        OrderSide::Buy,
+        Price::from("100.00"),
+        Quantity::from("0.5"),
+        1,
+    );
+    book.update(modified, 0, 4, 4.into());
+    let replaced = BookOrder::new(
+        OrderSide::Buy,
+        Price::from("99.00"),
+        Quantity::from("2.0"),
+        2,
+    );
+    book.update(replaced, 0, 5, 5.into());
+
+    let position = book.queue_position(3).unwrap();
+    assert_eq!(position.orders_ahead, 1);
+    assert_eq!(position.volume_ahead, Quantity::from("0.5"));
+    assert_eq!(book.queue_position(2).unwrap().orders_ahead, 0);
+    assert_eq!(book.queue_position(99), None);
+}
+
+#[rstest]
+fn test_book_level_order_counts_and_ages_l3() {
+    let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+    let mut book = OrderBook::new(instrument_id, BookType::L3_MBO);
+    let orders = [(1, "101.00", 100), (2, "101.00", 250), (3, "102.00", 400)];
+    for (order_id, price, ts) in orders {
+        let order = BookOrder::new(
+            OrderSide::Sell,
+            Price::from(price),
+            Quantity::from("1.0"),
+            order_id,
+        );
+        book.add(order, 0, order_id, ts.into());
+    }
+
+    assert_eq!(
+        book.level_order_counts(OrderSide::Sell, None),
+        vec![(Price::from("101.00"), 2), (Price::from("102.00"), 1)]
+    );
+    assert_eq!(
+        book.level_order_ages(OrderSide::Sell, Some(1)),
+        vec![(Price::from("101.00"), vec![300, 150])]
+    );
+    assert!(book.level_order_counts(OrderSide::Buy, None).is_empty());
+}
+
+#[rstest]
+fn test_book_order_activity_l3() {
+    let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+    let mut book = OrderBook::new(instrument_id, BookType::L3_MBO);
+    for order_id in 1..=4 {
+        let order = BookOrder::new(
+            OrderSide::Buy,
+            Price::from("100.00"),
+            Quantity::from("1.0"),
+            order_id,
+        );
+        book.add(order, 0, order_id, order_id.into());
+    }
+
+    let modified = BookOrder::new(
+        OrderSide::Buy,
+        Price::from("100.00"),
+        Quantity::from("2.0"),
+        1,
+    );
+    book.update(modified, 0, 5, 5.into());
+    let replaced = BookOrder::new(
+        OrderSide::Buy,
+        Price::from("99.00"),
+        Quantity::from("1.0"),
+        2,
+    );
+    book.update(replaced, 0, 6, 6.into());
+    let zero_size = BookOrder::new(
+        OrderSide::Buy,
+        Price::from("100.00"),
+        Quantity::from("0.0"),
+        3,
+    );
+    book.update(zero_size, 0, 7, 7.into());
+    let deleted = BookOrder::new(
+        OrderSide::Buy,
+        Price::from("100.00"),
+        Quantity::from("1.0"),
+        4,
+    );
+    book.delete(deleted, 0, 8, 8.into());
+    book.delete(deleted, 0, 9, 9.into()); // Unknown order is not counted
+
+    let activity = book.order_activity();
+    assert_eq!(activity.adds, 4);
+    assert_eq!(activity.modifies, 1);
+    assert_eq!(activity.replaces, 1);
+    assert_eq!(activity.cancels, 2);
+    assert_eq!(activity.cancel_rate(), 0.5);
+    assert_eq!(activity.replace_rate(), 0.25);
+    assert_eq!(
+        book.level_order_ages(OrderSide::Buy, None),
+        vec![
+            (Price::from("100.00"), vec![8]),
+            (Price::from("99.00"), vec![3])
+        ]
+    );
+
+    book.reset();
+    assert_eq!(book.order_activity(), Default::default());
+}
+
+#[rstest]
+fn test_book_checksum_okx_documented_example() {
+    // Example from the OKX order book checksum documentation
+    let instrument_id = InstrumentId::from("BTC-USDT.OKX");
+    let mut book = OrderBook::new(instrument_id, BookType::L2_MBP);
+    for (side, price, size) in [
+        (OrderSide::Buy, "3366.1", "7"),
+        (OrderSide::Buy, "3366.0", "6"),
+        (OrderSide::Sell, "3366.8", "9"),
+        (OrderSide::Sell, "3368.0", "8"),
+    ] {
+        let order = BookOrder::new(side, Price::from(price), Quantity::from(size), 0);
+        book.add(order, 0, 1, 1.into());
+    }
+
+    // Checksum string "3366.1:7:3366.8:9:3366:6:3368:8"
+    let checksum = book.checksum(&BookChecksumConfig::okx());
+    assert_eq!(checksum as i32, -1_881_014_294);
+}
+
+#[rstest]
+fn test_book_checksum_kraken_documented_example() {
+    // Example from the Kraken spot websocket book checksum documentation
+    let instrument_id = InstrumentId::from("ETH/XBT.KRAKEN");
+    let mut book = OrderBook::new(instrument_id, BookType::L2_MBP);
+    let asks = [
+        "0.05005", "0.05010", "0.05015", "0.05020", "0.05025", "0.05030", "0.05035", "0.05040",
+        "0.05045", "0.05050",
+    ];
+    let bids = [
+        "0.05000", "0.04995", "0.04990", "0.04980", "0.04975", "0.04970", "0.04965", "0.04960",
+        "0.04955", "0.04950",
+    ];
+    for (side, prices) in [(OrderSide::Sell, asks), (OrderSide::Buy, bids)] {
+        for price in prices {
+            let order = BookOrder::new(side, Price::from(price), Quantity::from("0.00000500"), 0);
+            book.add(order, 0, 1, 1.into());
+        }
+    }
+
+    assert_eq!(book.checksum(&BookChecksumConfig::kraken()), 974_947_235);
+}
+
+#[rstest]
+fn test_book_validate_checksum() {
+    let instrument_id = InstrumentId::from("BTC-USDT.OKX");
+    let mut book = OrderBook::new(instrument_id, BookType::L2_MBP);
+    let order = BookOrder::new(
+        OrderSide::Buy,
+        Price::from("100.00"),
+        Quantity::from("1.0"),
+        0,
+    );
+    book.add(order, 0, 1, 1.into());
+    let config = BookChecksumConfig::okx();
+    let checksum = book.checksum(&config);
+
+    assert_eq!(book.validate_checksum(checksum, &config), Ok(()));
+    assert_eq!(
+        book.validate_checksum(checksum.wrapping_add(1), &config),
+        Err(BookIntegrityError::ChecksumMismatch(
+            checksum.wrapping_add(1),
+            checksum
+        ))
+    );
+}
+
+fn delta_with_sequence(
+    action: BookAction,
+    price: &str,
+    flags: u8,
+    sequence: u64,
+) -> OrderBookDelta {
+    let order = BookOrder::new(OrderSide::Buy, Price::from(price), Quantity::from("1.0"), 0);
+    OrderBookDelta::new(
+        InstrumentId::from("BTC-USDT.OKX"),
+        action,
+        order,
+        flags,
+        sequence,
+        sequence.into(),
+        sequence.into(),
+    )
+}
+
+#[rstest]
+fn test_book_sequence_gap_detection_and_resync() {
+    let instrument_id = InstrumentId::from("BTC-USDT.OKX");
+    let mut book = OrderBook::new(instrument_id, BookType::L2_MBP);
+    book.set_gap_detection(true);
+
+    book.apply_delta(&delta_with_sequence(BookAction::Add, "100.00", 0, 1))
+        .unwrap();
+    book.apply_delta(&delta_with_sequence(BookAction::Add, "99.00", 0, 2))
+        .unwrap();
+    assert_eq!(
+        book.apply_delta(&delta_with_sequence(BookAction::Add, "98.00", 0, 5)),
+        Err(BookIntegrityError::SequenceGap(3, 5))
+    );
+    assert!(book.needs_resync());
+    assert_eq!(
+        book.apply_delta(&delta_with_sequence(BookAction::Add, "97.00", 0, 6)),
+        Err(BookIntegrityError::ResyncPending(6))
+    );
+    assert_eq!(book.bids(None).count(), 2);
+
+    let snapshot = RecordFlag::F_SNAPSHOT as u8;
+    book.apply_delta(&delta_with_sequence(BookAction::Clear, "0", snapshot, 10))
+        .unwrap();
+    book.apply_delta(&delta_with_sequence(
+        BookAction::Add,
+        "101.00",
+        snapshot,
+        10,
+    ))
+    .unwrap();
+    book.apply_delta(&delta_with_sequence(BookAction::Add, "100.50", 0, 11))
+        .unwrap();
+    assert!(!book.needs_resync());
+    assert_eq!(book.sequence, 11);
+    assert_eq!(book.bids(None).count(), 2);
+}
+
+#[rstest]
+fn test_book_apply_snapshot_clears_resync() {
+    let instrument_id = InstrumentId::from("BTC-USDT.OKX");
+    let mut book = OrderBook::new(instrument_id, BookType::L2_MBP);
+    book.set_gap_detection(true);
+    book.apply_delta(&delta_with_sequence(BookAction::Add, "100.00", 0, 1))
+        .unwrap();
+    let _ = book.apply_delta(&delta_with_sequence(BookAction::Add, "99.00", 0, 5));
+    assert!(book.needs_resync());
+
+    let mut snapshot = OrderBook::new(instrument_id, BookType::L2_MBP);
+    snapshot
+        .apply_delta(&delta_with_sequence(BookAction::Add, "101.00", 0, 8))
+        .unwrap();
+    book.apply_snapshot(&snapshot);
+
+    assert!(!book.needs_resync());
+    assert!(book.gap_detection());
+    assert_eq!(book.sequence, 8);
+    assert_eq!(book.best_bid_price(), Some(Price::from("101.00")));
+    assert_eq!(book.bids(None).count(), 1);
+    book.apply_delta(&delta_with_sequence(BookAction::Add, "100.50", 0, 9))
+        .unwrap();
+}
+
+#[rstest]
+fn test_book_sequence_gap_ignored_when_detection_disabled() {
+    let instrument_id = InstrumentId::from("BTC-USDT.OKX");
+    let mut book = OrderBook::new(instrument_id, BookType::L2_MBP);
+
+    book.apply_delta(&delta_with_sequence(BookAction::Add, "100.00", 0, 1))
+        .unwrap();
+    book.apply_delta(&delta_with_sequence(BookAction::Add, "99.00", 0, 5))
+        .unwrap();
+    assert!(!book.gap_detection());
+    assert!(!book.needs_resync());
+}
+
+fn resting_own_order(
+    client_order_id: &str,
+    side: OrderSideSpecified,
+    price: &str,
+    size: u64,
+    status: OrderStatus,
+) -> OwnBookOrder {
+    OwnBookOrder::new(
+        TraderId::from("TRADER-001"),
+        ClientOrderId::from(client_order_id),
+        None,
+        side,
+        Price::from(price),
+        Quantity::from(size),
+        OrderType::Limit,
+        TimeInForce::Gtc,
+        status,
+        1.into(),
+        1.into(),
+        1.into(),
+        1.into(),
+    )
+}
+
+#[fixture]
+fn book_with_own_orders() -> (OrderBook, OwnOrderBook) {
+    let instrument_id = InstrumentId::from("AAPL.XNAS");
+    let mut book = OrderBook::new(instrument_id, BookType::L3_MBO);
+    for (side, price, size, order_id) in [
+        (OrderSide::Buy, "100.00", 100, 1),
+        (OrderSide::Buy, "100.00", 50, 2),
+        (OrderSide::Buy, "99.00", 200, 3),
+        (OrderSide::Sell, "101.00", 100, 4),
+        (OrderSide::Sell, "102.00", 200, 5),
+    ] {
+        let order = BookOrder::new(side, Price::from(price), Quantity::from(size), order_id);
+        book.add(order, 0, order_id, order_id.into());
+    }
+
+    let mut own_book = OwnOrderBook::new(instrument_id);
+    own_book.add(resting_own_order(
+        "BID-1",
+        OrderSideSpecified::Buy,
+        "100.00",
+        50,
+        OrderStatus::Accepted,
+    ));
+    own_book.add(resting_own_order(
+        "ASK-1",
+        OrderSideSpecified::Sell,
+        "101.00",
+        100,
+        OrderStatus::Accepted,
+    ));
+    own_book.add(resting_own_order(
+        "ASK-2",
+        OrderSideSpecified::Sell,
+        "102.00",
+        50,
+        OrderStatus::Submitted,
+    ));
+    (book, own_book)
+}
+
+#[rstest]
+fn test_net_book_excludes_own_liquidity(book_with_own_orders: (OrderBook, OwnOrderBook)) {
+    let (book, own_book) = book_with_own_orders;
+    let net = NetOrderBook::new(&book, &own_book, None, None, None);
+
+    assert_eq!(net.book().book_type, BookType::L2_MBP);
+    assert_eq!(net.best_bid_price(), Some(Price::from("100.00")));
+    assert_eq!(net.best_bid_size(), Some(Quantity::from(100)));
+    // Own order is the whole of the public best ask
+    assert_eq!(net.best_ask_price(), Some(Price::from("102.00")));
+    assert_eq!(net.best_ask_size(), Some(Quantity::from(150)));
+    assert_eq!(net.spread(), Some(2.0));
+    assert_eq!(net.asks(None).count(), 1);
+    assert_eq!(
+        net.get_quantity_for_price(Price::from("102.00"), OrderSide::Buy),
+        150.0
+    );
+
+    let buy = BookOrder::new(
+        OrderSide::Buy,
+        Price::from("102.00"),
+        Quantity::from(200),
+        0,
+    );
+    assert_eq!(
+        net.simulate_fills(&buy),
+        vec![(Price::from("102.00"), Quantity::from(150))]
+    );
+
+    // Public book is unchanged
+    assert_eq!(book.best_ask_price(), Some(Price::from("101.00")));
+}
+
+#[rstest]
+fn test_net_book_with_status_filter(book_with_own_orders: (OrderBook, OwnOrderBook)) {
+    let (book, own_book) = book_with_own_orders;
+    let status = HashSet::from([OrderStatus::Accepted]);
+    let net = NetOrderBook::new(&book, &own_book, Some(status), None, None);
+
+    // Submitted order not yet resting so not subtracted
+    assert_eq!(net.best_ask_price(), Some(Price::from("102.00")));
+    assert_eq!(net.best_ask_size(), Some(Quantity::from(200)));
+    assert_eq!(
+        net.get_avg_px_for_quantity(Quantity::from(100), OrderSide::Sell),
+        100.0
+    );
+}
+
+#[rstest]
+fn test_net_book_updates_incrementally(book_with_own_orders: (OrderBook, OwnOrderBook)) {
+    let (mut book, own_book) = book_with_own_orders;
+    let mut net = NetOrderBook::new(&book, &own_book, None, None, None);
+
+    // Moves the public bid at 99.00 up to 100.00
+    let order = BookOrder::new(
+        OrderSide::Buy,
+        Price::from("100.00"),
+        Quantity::from(200),
+        3,
+    );
+    let delta = OrderBookDelta::new(
+        book.instrument_id,
+        BookAction::Update,
+        order,
+        0,
+        6,
+        6.into(),
+        6.into(),
+    );
+    net.apply_delta(&mut book, &delta).unwrap();
+
+    assert_eq!(net.bids(None).count(), 1);
+    assert_eq!(net.best_bid_size(), Some(Quantity::from(300)));
+    assert_eq!(
+        net.bids_as_map(None),
+        NetOrderBook::new(&book, &own_book, None, None, None).bids_as_map(None)
+    );
+    assert_eq!(net.book().sequence, 6);
+
+    net.remove_own_order(&book, &ClientOrderId::from("BID-1"));
+    assert_eq!(net.best_bid_size(), Some(Quantity::from(350)));
+
+    net.update_own_order(
+        &book,
+        ClientOrderId::from("ASK-2"),
+        OrderSideSpecified::Sell,
+        Price::from("102.00"),
+        Quantity::from(200),
+    );
+    assert_eq!(net.best_ask_price(), None);
+
+    net.remove_own_order(&book, &ClientOrderId::from("ASK-1"));
+    assert_eq!(net.best_ask_price(), Some(Price::from("101.00")));
+    assert_eq!(net.best_ask_size(), Some(Quantity::from(100)));
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // OwnOrderBook
 ////////////////////////////////////////////////////////////////////////////////
@@ -78,6 +78,15 @@ pub struct Position {
     pub avg_px_close: Option<f64>,
     pub realized_return: f64,
     pub realized_pnl: Option<Money>,
+    /// The cumulative funding payments applied to the position (included in `realized_pnl`).
+    #[serde(default)]
+    pub funding_pnl: Option<Money>,
+    /// The highest price observed while the position was open (from fills and market updates).
+    #[serde(default)]
+    pub max_px: Option<f64>,
+    /// The lowest price observed while the position was open (from fills and market updates).
+    #[serde(default)]
+    pub min_px: Option<f64>,
     pub trade_ids: Vec<TradeId>,
     pub buy_qty: Quantity,
     pub sell_qty: Quantity,
@@ -139,6 +148,9 @@ impl Position {
             avg_px_close: None,
             realized_return: 0.0,
             realized_pnl: None,
+            funding_pnl: None,
+            max_px: None,
+            min_px: None,
         };
         item.apply(&fill);
         item
@@ -181,6 +193,9 @@ impl Position {
             self.side = PositionSide::Flat;
             self.avg_px_close = None;
             self.realized_pnl = None;
+            self.funding_pnl = None;
+            self.max_px = None;
+            self.min_px = None;
             self.realized_return = 0.0;
             self.ts_opened = UnixNanos::default();
             self.ts_last = UnixNanos::default();
@@ -208,6 +223,8 @@ impl Position {
         self.avg_px_close = None;
         self.realized_pnl = None;
         self.realized_return = 0.0;
+        self.max_px = None;
+        self.min_px = None;
 
         // Use the first remaining event to set opening state
         let first_event = &filtered_events[0];
@@ -224,6 +241,11 @@ impl Position {
             self.apply(&event);
         }
 
+        // Funding is not derived from fills, so carry it over into the recalculated PnL
+        if let Some(funding_pnl) = self.funding_pnl {
+            self.add_realized_pnl(funding_pnl.as_f64());
+        }
+
         log::info!(
             "Purged fills for order {} from position {}; recalculated state: qty={}, signed_qty={}, side={:?}",
             client_order_id,
@@ -266,9 +288,13 @@ impl Position {
             self.avg_px_close = None;
             self.realized_return = 0.0;
             self.realized_pnl = None;
+            self.funding_pnl = None;
+            self.max_px = None;
+            self.min_px = None;
         }
 
         self.events.push(*fill);
+        self.track_excursion(fill.last_px.as_f64());
         self.trade_ids.push(fill.trade_id);
 
         // Calculate cumulative commissions
@@ -319,6 +345,88 @@ impl Position {
         self.ts_last = fill.ts_event;
     }
 
+    /// Applies a funding payment to this position (positive values were received).
+    ///
+    /// The payment is accumulated in `funding_pnl` and included in the realized PnL.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `payment` currency is not the position settlement currency.
+    pub fn apply_funding(&mut self, payment: Money) -> anyhow::Result<()> {
+        check_equal(
+            &payment.currency,
+            &self.settlement_currency,
+            "payment.currency",
+            "settlement_currency",
+        )?;
+
+        let current_funding = self.funding_pnl.map_or(0.0, |p| p.as_f64());
+        self.funding_pnl = Some(Money::new(
+            current_funding + payment.as_f64(),
+            self.settlement_currency,
+        ));
+        self.add_realized_pnl(payment.as_f64());
+        Ok(())
+    }
+
+    /// Updates the price excursions of the open position with the market `price`.
+    ///
+    /// Has no effect if the position is flat.
+    pub fn update_excursions(&mut self, price: Price) {
+        if self.side != PositionSide::Flat {
+            self.track_excursion(price.as_f64());
+        }
+    }
+
+    fn track_excursion(&mut self, price: f64) {
+        self.max_px = Some(self.max_px.map_or(price, |px| px.max(price)));
+        self.min_px = Some(self.min_px.map_or(price, |px| px.min(price)));
+    }
+
+    /// Returns the maximum adverse excursion (MAE) as a fraction of the average open price.
+    ///
+    /// The value is the largest unfavorable move observed while the position was open, so is
+    /// zero or negative. Returns `None` if no prices were observed.
+    #[must_use]
+    pub fn max_adverse_excursion(&self) -> Option<f64> {
+        let worst_px = match self.entry {
+            OrderSide::Buy => self.min_px?,
+            OrderSide::Sell => self.max_px?,
+            OrderSide::NoOrderSide => return None,
+        };
+        Some(self.excursion(worst_px).min(0.0))
+    }
+
+    /// Returns the maximum favorable excursion (MFE) as a fraction of the average open price.
+    ///
+    /// The value is the largest favorable move observed while the position was open, so is
+    /// zero or positive. Returns `None` if no prices were observed.
+    #[must_use]
+    pub fn max_favorable_excursion(&self) -> Option<f64> {
+        let best_px = match self.entry {
+            OrderSide::Buy => self.max_px?,
+            OrderSide::Sell => self.min_px?,
+            OrderSide::NoOrderSide => return None,
+        };
+        Some(self.excursion(best_px).max(0.0))
+    }
+
+    fn excursion(&self, price: f64) -> f64 {
+        if self.avg_px_open == 0.0 {
+            return 0.0;
+        }
+        let change = (price - self.avg_px_open) / self.avg_px_open;
+        match self.entry {
+            OrderSide::Sell => -change,
+            _ => change,
+        }
+    }
+
+    fn add_realized_pnl(&mut self, amount: f64) {
+        let current_pnl = self.realized_pnl.map_or(0.0, |p| p.as_f64());
+        self.realized_pnl = Some(Money::new(current_pnl + amount, self.settlement_currency));
+    }
+
     fn handle_buy_order_fill(&mut self, fill: &OrderFilled) {
         // Handle case where commission could be None or not settlement currency
         let mut realized_pnl = if let Some(commission) = fill.commission {
@@ -899,6 +1007,80 @@ mod tests {
         );
     }
 
+    #[rstest]
+    fn test_position_apply_funding(audusd_sim: CurrencyPair) {
+        let audusd_sim = InstrumentAny::CurrencyPair(audusd_sim);
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(audusd_sim.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from(100_000))
+            .build();
+        let fill = TestOrderEventStubs::filled(
+            &order,
+            &audusd_sim,
+            None,
+            None,
+            Some(Price::from("1.00001")),
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let mut position = Position::new(&audusd_sim, fill.into());
+
+        position.apply_funding(Money::from("-1.5 USD")).unwrap();
+        position.apply_funding(Money::from("0.5 USD")).unwrap();
+
+        assert_eq!(position.funding_pnl, Some(Money::from("-1.0 USD")));
+        assert_eq!(position.realized_pnl, Some(Money::from("-3.0 USD")));
+    }
+
+    #[rstest]
+    fn test_position_apply_funding_wrong_currency(stub_position_long: Position) {
+        let mut position = stub_position_long;
+
+        let result = position.apply_funding(Money::from("1.0 AUD"));
+
+        assert!(result.is_err());
+        assert_eq!(position.funding_pnl, None);
+    }
+
+    #[rstest]
+    fn test_position_excursions_long(stub_position_long: Position) {
+        let mut position = stub_position_long;
+        assert_eq!(position.max_adverse_excursion(), Some(0.0));
+        assert_eq!(position.max_favorable_excursion(), Some(0.0));
+
+        position.update_excursions(Price::from("1.0102"));
+        position.update_excursions(Price::from("0.9952"));
+        position.update_excursions(Price::from("1.0000"));
+
+        assert_eq!(position.max_px, Some(1.0102));
+        assert_eq!(position.min_px, Some(0.9952));
+        assert!((position.max_favorable_excursion().unwrap() - 0.01 / 1.0002).abs() < 1e-12);
+        assert!((position.max_adverse_excursion().unwrap() + 0.005 / 1.0002).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_position_excursions_short(stub_position_short: Position) {
+        let mut position = stub_position_short;
+        position.update_excursions(Price::from("22220.0"));
+        position.update_excursions(Price::from("21780.0"));
+
+        assert!((position.max_adverse_excursion().unwrap() + 0.01).abs() < 1e-12);
+        assert!((position.max_favorable_excursion().unwrap() - 0.01).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_position_excursions_ignored_when_flat(stub_position_long: Position) {
+        let mut position = stub_position_long;
+        position.side = PositionSide::Flat;
+        position.update_excursions(Price::from("2.0000"));
+
+        assert_eq!(position.max_px, Some(1.0002));
+    }
+
     #[rstest]
     fn test_position_filled_with_sell_order(audusd_sim: CurrencyPair) {
         let audusd_sim = InstrumentAny::CurrencyPair(audusd_sim);
@@ -226,6 +226,24 @@ impl Position {
         self.realized_pnl
     }
 
+    #[getter]
+    #[pyo3(name = "funding_pnl")]
+    fn py_funding_pnl(&self) -> Option<Money> {
+        self.funding_pnl
+    }
+
+    #[getter]
+    #[pyo3(name = "max_px")]
+    fn py_max_px(&self) -> Option<f64> {
+        self.max_px
+    }
+
+    #[getter]
+    #[pyo3(name = "min_px")]
+    fn py_min_px(&self) -> Option<f64> {
+        self.min_px
+    }
+
     #[getter]
     #[pyo3(name = "events")]
     fn py_events(&self) -> Vec<OrderFilled> {
@@ -297,6 +315,21 @@ impl Position {
         self.unrealized_pnl(last)
     }
 
+    #[pyo3(name = "update_excursions")]
+    fn py_update_excursions(&mut self, price: Price) {
+        self.update_excursions(price);
+    }
+
+    #[pyo3(name = "max_adverse_excursion")]
+    fn py_max_adverse_excursion(&self) -> Option<f64> {
+        self.max_adverse_excursion()
+    }
+
+    #[pyo3(name = "max_favorable_excursion")]
+    fn py_max_favorable_excursion(&self) -> Option<f64> {
+        self.max_favorable_excursion()
+    }
+
     #[pyo3(name = "total_pnl")]
     fn py_total_pnl(&self, last: Price) -> Money {
         self.total_pnl(last)
@@ -0,0 +1,584 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! In-process FIX acceptor acting as a simulated venue.
+//!
+//! The [`FixAcceptor`] accepts logons, responds to orders according to a configurable script,
+//! and can inject sequence gaps and disconnects through a [`FixAcceptorHandle`]. It listens
+//! with [`crate::net::TcpListener`], so it runs on real sockets or inside a turmoil simulation
+//! when the `turmoil` feature is enabled, allowing FIX clients to be tested end-to-end.
+
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
+
+use nautilus_core::{MUTEX_POISONED, UnixNanos, time::get_atomic_clock_realtime};
+use rust_decimal::Decimal;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    sync::mpsc::{UnboundedReceiver, UnboundedSender, unbounded_channel},
+};
+
+use super::{
+    message::{FixMessage, msg_types, tags},
+    messages::{
+        ExecutionReport, FixExecType, FixOrdStatus, FixOrdType, NewOrderSingle, OrderCancelRequest,
+    },
+    process_fix_buffer,
+    session::{FixSession, FixSessionAction, FixSessionConfig},
+    store::MemoryMessageStore,
+};
+use crate::{net::TcpListener, socket::TcpMessageHandler};
+
+const TIMER_INTERVAL_MS: u64 = 1_000;
+const READ_BUFFER_SIZE: usize = 8_192;
+
+/// A scripted response of the simulated venue to a `NewOrderSingle`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FixOrderResponse {
+    /// Acknowledge the order (`ExecType` New).
+    Ack,
+    /// Fill the remaining quantity at the `price` (the order price if `None`).
+    Fill { price: Option<Decimal> },
+    /// Fill `qty` of the order at the `price` (the order price if `None`).
+    PartialFill {
+        qty: Decimal,
+        price: Option<Decimal>,
+    },
+    /// Reject the order with the `reason`.
+    Reject { reason: String },
+}
+
+/// Returns the responses to send, in order, for a received `NewOrderSingle`.
+pub type FixOrderScript = Arc<dyn Fn(&NewOrderSingle) -> Vec<FixOrderResponse> + Send + Sync>;
+
+/// Configuration for a [`FixAcceptor`].
+#[derive(Clone)]
+pub struct FixAcceptorConfig {
+    /// The session configuration of the venue (sender is the venue, target is the client).
+    pub session: FixSessionConfig,
+    /// The script deciding the responses to each order.
+    pub order_script: FixOrderScript,
+}
+
+impl Debug for FixAcceptorConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(FixAcceptorConfig))
+            .field("session", &self.session)
+            .finish_non_exhaustive()
+    }
+}
+
+impl FixAcceptorConfig {
+    /// Creates a new [`FixAcceptorConfig`] instance which acknowledges every order.
+    #[must_use]
+    pub fn new(session: FixSessionConfig) -> Self {
+        Self {
+            session,
+            order_script: Arc::new(|_| vec![FixOrderResponse::Ack]),
+        }
+    }
+
+    /// Sets the script deciding the responses to each order.
+    #[must_use]
+    pub fn with_order_script(
+        mut self,
+        script: impl Fn(&NewOrderSingle) -> Vec<FixOrderResponse> + Send + Sync + 'static,
+    ) -> Self {
+        self.order_script = Arc::new(script);
+        self
+    }
+}
+
+#[derive(Debug)]
+enum FixAcceptorCommand {
+    Send(FixMessage),
+    Logout,
+    Disconnect,
+}
+
+#[derive(Debug)]
+struct OpenOrder {
+    order: NewOrderSingle,
+    order_id: String,
+    cum_qty: Decimal,
+    cum_notional: Decimal,
+}
+
+#[derive(Debug)]
+struct FixAcceptorState {
+    session: FixSession,
+    orders: HashMap<String, OpenOrder>,
+    received: Vec<FixMessage>,
+}
+
+/// A handle for inspecting and injecting faults into a running [`FixAcceptor`].
+#[derive(Clone, Debug)]
+pub struct FixAcceptorHandle {
+    state: Arc<Mutex<FixAcceptorState>>,
+    command_tx: UnboundedSender<FixAcceptorCommand>,
+    drop_outgoing: Arc<AtomicUsize>,
+    connection_count: Arc<AtomicUsize>,
+}
+
+impl FixAcceptorHandle {
+    /// Returns the application messages received from the client, in sequence order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the state mutex is poisoned.
+    #[must_use]
+    pub fn received(&self) -> Vec<FixMessage> {
+        self.state.lock().expect(MUTEX_POISONED).received.clone()
+    }
+
+    /// Returns whether the venue session is logged on.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the state mutex is poisoned.
+    #[must_use]
+    pub fn is_logged_on(&self) -> bool {
+        self.state
+            .lock()
+            .expect(MUTEX_POISONED)
+            .session
+            .is_logged_on()
+    }
+
+    /// Returns the number of client connections accepted.
+    #[must_use]
+    pub fn connection_count(&self) -> usize {
+        self.connection_count.load(Ordering::SeqCst)
+    }
+
+    /// Drops the next `count` outgoing application messages after assigning their sequence
+    /// numbers, creating a sequence gap the client must recover with a `ResendRequest`.
+    pub fn drop_outgoing(&self, count: usize) {
+        self.drop_outgoing.fetch_add(count, Ordering::SeqCst);
+    }
+
+    /// Sends an unsolicited application `message` to the client.
+    pub fn send(&self, message: FixMessage) {
+        if self
+            .command_tx
+            .send(FixAcceptorCommand::Send(message))
+            .is_err()
+        {
+            tracing::error!("FIX acceptor stopped, dropping message");
+        }
+    }
+
+    /// Logs out the client session, as a venue does ahead of maintenance.
+    pub fn logout(&self) {
+        if self.command_tx.send(FixAcceptorCommand::Logout).is_err() {
+            tracing::error!("FIX acceptor stopped, cannot log out");
+        }
+    }
+
+    /// Drops the current client connection without a logout.
+    pub fn disconnect(&self) {
+        if self
+            .command_tx
+            .send(FixAcceptorCommand::Disconnect)
+            .is_err()
+        {
+            tracing::error!("FIX acceptor stopped, cannot disconnect");
+        }
+    }
+}
+
+/// An in-process FIX acceptor acting as a simulated venue.
+///
+/// Serves one client connection at a time, keeping sequence numbers and sent messages
+/// across reconnections.
+#[derive(Clone)]
+pub struct FixAcceptor {
+    handle: FixAcceptorHandle,
+    order_script: FixOrderScript,
+    command_rx: Arc<tokio::sync::Mutex<UnboundedReceiver<FixAcceptorCommand>>>,
+    next_id: Arc<AtomicU64>,
+}
+
+impl Debug for FixAcceptor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(FixAcceptor))
+            .field("handle", &self.handle)
+            .finish_non_exhaustive()
+    }
+}
+
+impl FixAcceptor {
+    /// Creates a new [`FixAcceptor`] instance.
+    #[must_use]
+    pub fn new(config: FixAcceptorConfig) -> Self {
+        let (command_tx, command_rx) = unbounded_channel();
+        let session = FixSession::new(config.session, Box::new(MemoryMessageStore::new()));
+        let state = FixAcceptorState {
+            session,
+            orders: HashMap::new(),
+            received: Vec::new(),
+        };
+
+        Self {
+            handle: FixAcceptorHandle {
+                state: Arc::new(Mutex::new(state)),
+                command_tx,
+                drop_outgoing: Arc::new(AtomicUsize::new(0)),
+                connection_count: Arc::new(AtomicUsize::new(0)),
+            },
+            order_script: config.order_script,
+            command_rx: Arc::new(tokio::sync::Mutex::new(command_rx)),
+            next_id: Arc::new(AtomicU64::new(1)),
+        }
+    }
+
+    /// Returns a handle to the acceptor.
+    #[must_use]
+    pub fn handle(&self) -> FixAcceptorHandle {
+        self.handle.clone()
+    }
+
+    /// Listens on `addr` and serves client connections until an error occurs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if binding or accepting fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the state mutex is poisoned.
+    pub async fn run(&self, addr: &str) -> anyhow::Result<()> {
+        let listener = TcpListener::bind(addr).await?;
+        let mut command_rx = self.command_rx.lock().await;
+
+        loop {
+            let (stream, peer) = listener.accept().await?;
+            self.handle.connection_count.fetch_add(1, Ordering::SeqCst);
+            tracing::info!("FIX acceptor connection from {peer}");
+
+            self.serve(stream, &mut command_rx).await;
+
+            let mut state = self.handle.state.lock().expect(MUTEX_POISONED);
+            state.session.on_disconnected();
+            tracing::info!("FIX acceptor connection from {peer} closed");
+        }
+    }
+
+    async fn serve<S>(&self, stream: S, command_rx: &mut UnboundedReceiver<FixAcceptorCommand>)
+    where
+        S: tokio::io::AsyncRead + tokio::io::AsyncWrite,
+    {
+        let (mut reader, mut writer) = tokio::io::split(stream);
+        let mut read_buf = vec![0; READ_BUFFER_SIZE];
+        let mut buf = Vec::new();
+        let mut interval = tokio::time::interval(Duration::from_millis(TIMER_INTERVAL_MS));
+
+        let frames = Arc::new(Mutex::new(Vec::new()));
+        let frames_clone = frames.clone();
+        let frame_handler: TcpMessageHandler = Arc::new(move |data: &[u8]| {
+            frames_clone
+                .lock()
+                .expect(MUTEX_POISONED)
+                .push(data.to_vec());
+        });
+
+        loop {
+            let (outgoing, disconnect) = tokio::select! {
+                read = reader.read(&mut read_buf) => match read {
+                    Ok(0) | Err(_) => break,
+                    Ok(n) => {
+                        buf.extend_from_slice(&read_buf[..n]);
+                        process_fix_buffer(&mut buf, &frame_handler);
+                        let received: Vec<Vec<u8>> =
+                            std::mem::take(&mut *frames.lock().expect(MUTEX_POISONED));
+                        self.on_frames(&received)
+                    }
+                },
+                _ = interval.tick() => {
+                    let mut state = self.handle.state.lock().expect(MUTEX_POISONED);
+                    let actions = state.session.on_timer(now());
+                    drop(state);
+                    self.handle_actions(actions)
+                }
+                command = command_rx.recv() => match command {
+                    Some(FixAcceptorCommand::Send(message)) => {
+                        let mut state = self.handle.state.lock().expect(MUTEX_POISONED);
+                        (self.send_application(&mut state, message).into_iter().collect(), false)
+                    }
+                    Some(FixAcceptorCommand::Logout) => {
+                        let mut state = self.handle.state.lock().expect(MUTEX_POISONED);
+                        match state.session.logout(Some("Maintenance"), now()) {
+                            Ok(bytes) => (vec![bytes], false),
+                            Err(e) => {
+                                tracing::error!("FIX acceptor failed to log out: {e}");
+                                (Vec::new(), false)
+                            }
+                        }
+                    }
+                    Some(FixAcceptorCommand::Disconnect) | None => (Vec::new(), true),
+                },
+            };
+
+            for bytes in outgoing {
+                if let Err(e) = writer.write_all(&bytes).await {
+                    tracing::error!("FIX acceptor write failed: {e}");
+                    return;
+                }
+            }
+            if disconnect {
+                let _ = writer.shutdown().await;
+                break;
+            }
+        }
+    }
+
+    fn on_frames(&self, frames: &[Vec<u8>]) -> (Vec<Vec<u8>>, bool) {
+        let mut outgoing = Vec::new();
+        let mut disconnect = false;
+
+        for frame in frames {
+            let message = match FixMessage::decode(frame) {
+                Ok(message) => message,
+                Err(e) => {
+                    tracing::error!("FIX acceptor failed to decode message: {e}");
+                    continue;
+                }
+            };
+
+            let actions = {
+                let mut state = self.handle.state.lock().expect(MUTEX_POISONED);
+                state.session.on_message(message, now())
+            };
+            let (bytes, should_disconnect) = self.handle_actions(actions);
+            outgoing.extend(bytes);
+            disconnect |= should_disconnect;
+        }
+
+        (outgoing, disconnect)
+    }
+
+    fn handle_actions(&self, actions: Vec<FixSessionAction>) -> (Vec<Vec<u8>>, bool) {
+        let mut outgoing = Vec::new();
+        let mut disconnect = false;
+
+        for action in actions {
+            match action {
+                FixSessionAction::Send(bytes) => outgoing.push(bytes),
+                FixSessionAction::Deliver(message) => {
+                    let mut state = self.handle.state.lock().expect(MUTEX_POISONED);
+                    state.received.push(message.clone());
+                    for response in self.on_application_message(&mut state, &message) {
+                        outgoing.extend(self.send_application(&mut state, response));
+                    }
+                }
+                FixSessionAction::Disconnect(_) => disconnect = true,
+            }
+        }
+
+        (outgoing, disconnect)
+    }
+
+    fn on_application_message(
+        &self,
+        state: &mut FixAcceptorState,
+        message: &FixMessage,
+    ) -> Vec<FixMessage> {
+        match message.msg_type() {
+            msg_types::NEW_ORDER_SINGLE => match NewOrderSingle::from_message(message) {
+                Ok(order) => self.on_new_order(state, order),
+                Err(e) => vec![business_reject(message, &e.to_string())],
+            },
+            msg_types::ORDER_CANCEL_REQUEST => match OrderCancelRequest::from_message(message) {
+                Ok(request) => self.on_cancel_request(state, &request),
+                Err(e) => vec![business_reject(message, &e.to_string())],
+            },
+            _ => vec![business_reject(message, "Unsupported message type")],
+        }
+    }
+
+    fn on_new_order(&self, state: &mut FixAcceptorState, order: NewOrderSingle) -> Vec<FixMessage> {
+        let version = state.session.config().version;
+        let mut open_order = OpenOrder {
+            order,
+            order_id: self.next_id("O"),
+            cum_qty: Decimal::ZERO,
+            cum_notional: Decimal::ZERO,
+        };
+
+        let mut responses = Vec::new();
+        for response in (self.order_script)(&open_order.order) {
+            let (exec_type, last) = match response {
+                FixOrderResponse::Ack => (FixExecType::New, None),
+                FixOrderResponse::Fill { price } => {
+                    let qty = open_order.order.order_qty - open_order.cum_qty;
+                    (FixExecType::Trade, Some((qty, price)))
+                }
+                FixOrderResponse::PartialFill { qty, price } => {
+                    (FixExecType::Trade, Some((qty, price)))
+                }
+                FixOrderResponse::Reject { reason } => {
+                    let mut report = execution_report(
+                        &open_order,
+                        self.next_id("E"),
+                        FixExecType::Rejected,
+                        None,
+                    );
+                    report.ord_status = FixOrdStatus::Rejected;
+                    report.leaves_qty = Decimal::ZERO;
+                    report.ord_rej_reason = Some("0".to_string());
+                    report.text = Some(reason);
+                    responses.push(report.to_message_for(version));
+                    return responses;
+                }
+            };
+
+            let last = last.map(|(qty, price)| {
+                let price = price.or(open_order.order.price).unwrap_or_default();
+                open_order.cum_qty += qty;
+                open_order.cum_notional += qty * price;
+                (qty, price)
+            });
+            let exec_id = self.next_id("E");
+            let report = execution_report(&open_order, exec_id, exec_type, last);
+            responses.push(report.to_message_for(version));
+        }
+
+        if open_order.cum_qty < open_order.order.order_qty {
+            state
+                .orders
+                .insert(open_order.order.cl_ord_id.clone(), open_order);
+        }
+        responses
+    }
+
+    fn on_cancel_request(
+        &self,
+        state: &mut FixAcceptorState,
+        request: &OrderCancelRequest,
+    ) -> Vec<FixMessage> {
+        let Some(open_order) = state.orders.remove(&request.orig_cl_ord_id) else {
+            let mut reject = FixMessage::new(msg_types::ORDER_CANCEL_REJECT);
+            reject
+                .set(tags::ORDER_ID, "NONE")
+                .set(tags::CL_ORD_ID, &request.cl_ord_id)
+                .set(tags::ORIG_CL_ORD_ID, &request.orig_cl_ord_id)
+                .set(tags::ORD_STATUS, FixOrdStatus::Rejected)
+                .set(tags::CXL_REJ_RESPONSE_TO, 1)
+                .set(tags::CXL_REJ_REASON, 1)
+                .set(tags::TEXT, "Unknown order");
+            return vec![reject];
+        };
+
+        let mut report =
+            execution_report(&open_order, self.next_id("E"), FixExecType::Canceled, None);
+        report.cl_ord_id = Some(request.cl_ord_id.clone());
+        report.orig_cl_ord_id = Some(request.orig_cl_ord_id.clone());
+        report.ord_status = FixOrdStatus::Canceled;
+        report.leaves_qty = Decimal::ZERO;
+        vec![report.to_message_for(state.session.config().version)]
+    }
+
+    fn next_id(&self, prefix: &str) -> String {
+        format!(
+            "SIM-{prefix}-{}",
+            self.next_id.fetch_add(1, Ordering::SeqCst)
+        )
+    }
+
+    fn send_application(
+        &self,
+        state: &mut FixAcceptorState,
+        message: FixMessage,
+    ) -> Option<Vec<u8>> {
+        match state.session.send(message, now()) {
+            Ok(bytes) => {
+                let dropped = self
+                    .handle
+                    .drop_outgoing
+                    .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+                    .is_ok();
+                if dropped {
+                    tracing::warn!("FIX acceptor dropping outgoing message");
+                    None
+                } else {
+                    Some(bytes)
+                }
+            }
+            Err(e) => {
+                tracing::error!("FIX acceptor cannot send message: {e}");
+                None
+            }
+        }
+    }
+}
+
+fn execution_report(
+    open_order: &OpenOrder,
+    exec_id: String,
+    exec_type: FixExecType,
+    last: Option<(Decimal, Decimal)>,
+) -> ExecutionReport {
+    let order = &open_order.order;
+    let leaves_qty = order.order_qty - open_order.cum_qty;
+    let ord_status = match exec_type {
+        FixExecType::Trade if leaves_qty.is_zero() => FixOrdStatus::Filled,
+        FixExecType::Trade => FixOrdStatus::PartiallyFilled,
+        _ => FixOrdStatus::New,
+    };
+    let avg_px =
+        (!open_order.cum_qty.is_zero()).then(|| open_order.cum_notional / open_order.cum_qty);
+
+    ExecutionReport {
+        order_id: open_order.order_id.clone(),
+        cl_ord_id: Some(order.cl_ord_id.clone()),
+        orig_cl_ord_id: None,
+        exec_id,
+        exec_type,
+        ord_status,
+        symbol: order.symbol.clone(),
+        side: order.side,
+        order_qty: Some(order.order_qty),
+        price: order.price.filter(|_| order.ord_type != FixOrdType::Market),
+        last_qty: last.map(|(qty, _)| qty),
+        last_px: last.map(|(_, price)| price),
+        leaves_qty,
+        cum_qty: open_order.cum_qty,
+        avg_px,
+        ord_rej_reason: None,
+        text: None,
+        transact_time: Some(now()),
+    }
+}
+
+fn business_reject(message: &FixMessage, text: &str) -> FixMessage {
+    let mut reject = FixMessage::new(msg_types::BUSINESS_MESSAGE_REJECT);
+    reject
+        .set(tags::REF_SEQ_NUM, message.msg_seq_num().unwrap_or_default())
+        .set(tags::REF_MSG_TYPE, message.msg_type())
+        .set(tags::BUSINESS_REJECT_REASON, 0)
+        .set(tags::TEXT, text);
+    reject
+}
+
+fn now() -> UnixNanos {
+    get_atomic_clock_realtime().get_time_ns()
+}
@@ -0,0 +1,367 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! FIX initiator client driving a [`FixSession`] over a [`SocketClient`].
+
+use std::{
+    fmt::Debug,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
+
+use nautilus_core::{MUTEX_POISONED, time::get_atomic_clock_realtime};
+use tokio::{
+    sync::mpsc::{UnboundedReceiver, UnboundedSender, unbounded_channel},
+    time::Instant,
+};
+
+use super::{
+    message::FixMessage,
+    session::{FixSession, FixSessionAction, FixSessionState},
+};
+use crate::{
+    mode::ConnectionMode,
+    socket::{SocketClient, SocketConfig, TcpMessageHandler},
+};
+
+const TIMER_INTERVAL_MS: u64 = 1_000;
+const STATE_CHECK_INTERVAL_MS: u64 = 10;
+const LOGOUT_TIMEOUT_SECS: u64 = 2;
+const RELOGON_DELAY_SECS: u64 = 10;
+
+/// Handler for application messages delivered in sequence by the session.
+pub type FixMessageHandler = Arc<dyn Fn(FixMessage) + Send + Sync>;
+
+#[derive(Debug)]
+enum FixClientCommand {
+    Send(Vec<u8>),
+    Logon,
+    Disconnect(String),
+}
+
+/// A FIX initiator client.
+///
+/// Encoded messages from the session are written by a single driver task in sequence number
+/// order, which also ticks the session timer and logs on again after the socket reconnects.
+/// After a session level disconnect (venue logout, unanswered `TestRequest`, sequence error or
+/// unacknowledged logon) the socket is reconnected and the logon retried every
+/// `RELOGON_DELAY_SECS` until the client is closed.
+pub struct FixClient {
+    session: Arc<Mutex<FixSession>>,
+    socket: Arc<SocketClient>,
+    command_tx: UnboundedSender<FixClientCommand>,
+    closing: Arc<AtomicBool>,
+    driver_task: tokio::task::JoinHandle<()>,
+}
+
+impl Debug for FixClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(FixClient))
+            .field("session", &self.session)
+            .finish_non_exhaustive()
+    }
+}
+
+impl FixClient {
+    /// Connects the socket and logs on, waiting up to `logon_timeout` for the response.
+    ///
+    /// The `message_handler` of the `socket_config` is replaced with the session handler;
+    /// the socket level `heartbeat` should be `None` as heartbeats are managed by the session.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if connecting fails or the logon is not acknowledged in time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the session mutex is poisoned.
+    pub async fn connect(
+        mut socket_config: SocketConfig,
+        session: FixSession,
+        handler: FixMessageHandler,
+        logon_timeout: Duration,
+    ) -> anyhow::Result<Self> {
+        let session = Arc::new(Mutex::new(session));
+        let (command_tx, command_rx) = unbounded_channel();
+
+        let session_clone = session.clone();
+        let command_tx_clone = command_tx.clone();
+        let message_handler: TcpMessageHandler = Arc::new(move |data: &[u8]| {
+            let message = match FixMessage::decode(data) {
+                Ok(message) => message,
+                Err(e) => {
+                    tracing::error!("Failed to decode FIX message: {e}");
+                    return;
+                }
+            };
+
+            let delivered = {
+                let mut session = session_clone.lock().expect(MUTEX_POISONED);
+                let actions =
+                    session.on_message(message, get_atomic_clock_realtime().get_time_ns());
+                forward_actions(actions, &command_tx_clone)
+            };
+            for message in delivered {
+                handler(message);
+            }
+        });
+        socket_config.message_handler = Some(message_handler);
+
+        let command_tx_clone = command_tx.clone();
+        let post_reconnection = Arc::new(move || {
+            if let Err(e) = command_tx_clone.send(FixClientCommand::Logon) {
+                tracing::error!("Failed to request logon after reconnect: {e}");
+            }
+        });
+
+        let socket = Arc::new(
+            SocketClient::connect(socket_config, None, Some(post_reconnection), None).await?,
+        );
+        command_tx.send(FixClientCommand::Logon)?;
+
+        let closing = Arc::new(AtomicBool::new(false));
+        let driver_task = tokio::task::spawn(Self::run_driver(
+            session.clone(),
+            socket.clone(),
+            command_tx.clone(),
+            command_rx,
+            closing.clone(),
+        ));
+
+        let client = Self {
+            session,
+            socket,
+            command_tx,
+            closing,
+            driver_task,
+        };
+
+        let logged_on = tokio::time::timeout(logon_timeout, async {
+            while !client.is_logged_on() {
+                tokio::time::sleep(Duration::from_millis(STATE_CHECK_INTERVAL_MS)).await;
+            }
+        })
+        .await;
+
+        if logged_on.is_err() {
+            client.closing.store(true, Ordering::SeqCst);
+            client.socket.close().await;
+            client.driver_task.abort();
+            anyhow::bail!("Logon not acknowledged within {logon_timeout:?}");
+        }
+
+        Ok(client)
+    }
+
+    async fn run_driver(
+        session: Arc<Mutex<FixSession>>,
+        socket: Arc<SocketClient>,
+        command_tx: UnboundedSender<FixClientCommand>,
+        mut command_rx: UnboundedReceiver<FixClientCommand>,
+        closing: Arc<AtomicBool>,
+    ) {
+        let relogon_delay = Duration::from_secs(RELOGON_DELAY_SECS);
+        let mut interval = tokio::time::interval(Duration::from_millis(TIMER_INTERVAL_MS));
+        // Logons are held back until this deadline after a session level disconnect
+        let mut relogon_at: Option<Instant> = None;
+        let mut logon_deferred = false;
+        let mut logon_sent_at: Option<Instant> = None;
+
+        loop {
+            tokio::select! {
+                command = command_rx.recv() => match command {
+                    Some(FixClientCommand::Send(bytes)) => {
+                        if let Err(e) = socket.send_bytes(bytes).await {
+                            tracing::error!("Failed to send FIX message: {e}");
+                        }
+                    }
+                    Some(FixClientCommand::Logon) => {
+                        if relogon_at.is_some_and(|deadline| Instant::now() < deadline) {
+                            tracing::debug!("Deferring Logon until the re-logon delay elapses");
+                            logon_deferred = true;
+                            continue;
+                        }
+                        send_logon(&session, &command_tx);
+                        logon_sent_at = Some(Instant::now());
+                    }
+                    Some(FixClientCommand::Disconnect(reason)) => {
+                        session.lock().expect(MUTEX_POISONED).on_disconnected();
+                        logon_sent_at = None;
+
+                        if closing.load(Ordering::SeqCst) {
+                            tracing::info!("Closing FIX connection: {reason}");
+                            socket.close().await;
+                            break;
+                        }
+
+                        tracing::warn!(
+                            "FIX session disconnected: {reason}, logging on again in {}s",
+                            relogon_delay.as_secs()
+                        );
+                        relogon_at = Some(Instant::now() + relogon_delay);
+                        logon_deferred = false;
+                    }
+                    None => break,
+                },
+                _ = interval.tick() => {
+                    let now = Instant::now();
+
+                    if relogon_at.is_some_and(|deadline| now >= deadline) {
+                        relogon_at = None;
+                        if std::mem::take(&mut logon_deferred) && socket.is_active() {
+                            // The socket has reconnected since the session ended
+                            send_logon(&session, &command_tx);
+                            logon_sent_at = Some(now);
+                        } else if socket
+                            .connection_mode
+                            .compare_exchange(
+                                ConnectionMode::Active.as_u8(),
+                                ConnectionMode::Reconnect.as_u8(),
+                                Ordering::SeqCst,
+                                Ordering::SeqCst,
+                            )
+                            .is_ok()
+                        {
+                            // A new logon requires a new connection, logging on once reconnected
+                            tracing::info!("Reconnecting FIX socket to log on again");
+                        }
+                    }
+
+                    let mut session = session.lock().expect(MUTEX_POISONED);
+                    if session.state() == FixSessionState::LogonSent
+                        && logon_sent_at.is_some_and(|sent| now >= sent + relogon_delay)
+                    {
+                        logon_sent_at = None;
+                        let reason = "Logon not acknowledged".to_string();
+                        let _ = command_tx.send(FixClientCommand::Disconnect(reason));
+                        continue;
+                    }
+
+                    let actions = session.on_timer(get_atomic_clock_realtime().get_time_ns());
+                    forward_actions(actions, &command_tx);
+                }
+            }
+        }
+    }
+
+    /// Returns whether the session is logged on.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the session mutex is poisoned.
+    #[must_use]
+    pub fn is_logged_on(&self) -> bool {
+        self.session.lock().expect(MUTEX_POISONED).is_logged_on()
+    }
+
+    /// Returns the current session state.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the session mutex is poisoned.
+    #[must_use]
+    pub fn state(&self) -> FixSessionState {
+        self.session.lock().expect(MUTEX_POISONED).state()
+    }
+
+    /// Sends an application `message` on the session.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the session is not logged on or the client is closed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the session mutex is poisoned.
+    pub fn send(&self, message: FixMessage) -> anyhow::Result<()> {
+        let mut session = self.session.lock().expect(MUTEX_POISONED);
+        let bytes = session.send(message, get_atomic_clock_realtime().get_time_ns())?;
+        self.command_tx
+            .send(FixClientCommand::Send(bytes))
+            .map_err(|_| anyhow::anyhow!("FIX client closed"))
+    }
+
+    /// Logs out (waiting briefly for the counterparty's response) and closes the socket.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the session mutex is poisoned.
+    pub async fn close(&self) {
+        self.closing.store(true, Ordering::SeqCst);
+
+        if self.is_logged_on() {
+            let mut session = self.session.lock().expect(MUTEX_POISONED);
+            match session.logout(None, get_atomic_clock_realtime().get_time_ns()) {
+                Ok(bytes) => {
+                    let _ = self.command_tx.send(FixClientCommand::Send(bytes));
+                }
+                Err(e) => tracing::error!("Failed to send Logout: {e}"),
+            }
+        } else {
+            let reason = "Client closed".to_string();
+            let _ = self.command_tx.send(FixClientCommand::Disconnect(reason));
+        }
+
+        let _ = tokio::time::timeout(Duration::from_secs(LOGOUT_TIMEOUT_SECS), async {
+            while !self.driver_task.is_finished() {
+                tokio::time::sleep(Duration::from_millis(STATE_CHECK_INTERVAL_MS)).await;
+            }
+        })
+        .await;
+
+        if !self.socket.is_closed() {
+            self.socket.close().await;
+        }
+        self.driver_task.abort();
+        self.session.lock().expect(MUTEX_POISONED).on_disconnected();
+    }
+}
+
+/// Requests a `Logon` from the session and queues it for sending.
+fn send_logon(session: &Mutex<FixSession>, command_tx: &UnboundedSender<FixClientCommand>) {
+    let mut session = session.lock().expect(MUTEX_POISONED);
+    match session.logon(get_atomic_clock_realtime().get_time_ns()) {
+        Ok(bytes) => {
+            let _ = command_tx.send(FixClientCommand::Send(bytes));
+        }
+        Err(e) => tracing::error!("Failed to send Logon: {e}"),
+    }
+}
+
+/// Forwards the sends and disconnects to the driver (preserving sequence order while the
+/// session lock is held) and returns the messages to deliver.
+fn forward_actions(
+    actions: Vec<FixSessionAction>,
+    command_tx: &UnboundedSender<FixClientCommand>,
+) -> Vec<FixMessage> {
+    let mut delivered = Vec::new();
+    for action in actions {
+        let command = match action {
+            FixSessionAction::Send(bytes) => FixClientCommand::Send(bytes),
+            FixSessionAction::Disconnect(reason) => FixClientCommand::Disconnect(reason),
+            FixSessionAction::Deliver(message) => {
+                delivered.push(message);
+                continue;
+            }
+        };
+        if command_tx.send(command).is_err() {
+            tracing::error!("FIX client closed, dropping session action");
+        }
+    }
+    delivered
+}
@@ -0,0 +1,445 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! FIX protocol versions and message dictionaries.
+//!
+//! A [`FixDictionary`] defines the message types a session accepts and the body tags each
+//! requires. The standard dictionaries cover the session messages and common order entry
+//! messages of each [`FixVersion`], and can be extended with venue specific messages and tags.
+
+use std::{collections::HashMap, fmt::Display, str::FromStr};
+
+use strum::{AsRefStr, Display, EnumString};
+
+use super::message::{FixMessage, msg_types, tags};
+
+/// The FIX protocol versions supported by the session layer.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum FixVersion {
+    /// FIX 4.2.
+    Fix42,
+    /// FIX 4.4.
+    #[default]
+    Fix44,
+    /// FIX 5.0 SP2 over the FIXT 1.1 session protocol.
+    Fix50Sp2,
+}
+
+impl FixVersion {
+    /// Returns the `BeginString(8)` of the version.
+    #[must_use]
+    pub const fn begin_string(self) -> &'static str {
+        match self {
+            Self::Fix42 => "FIX.4.2",
+            Self::Fix44 => "FIX.4.4",
+            Self::Fix50Sp2 => "FIXT.1.1",
+        }
+    }
+
+    /// Returns the `DefaultApplVerID(1137)` sent at logon, for FIXT based versions.
+    #[must_use]
+    pub const fn default_appl_ver_id(self) -> Option<&'static str> {
+        match self {
+            Self::Fix42 | Self::Fix44 => None,
+            Self::Fix50Sp2 => Some("9"),
+        }
+    }
+}
+
+impl Display for FixVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.begin_string())
+    }
+}
+
+impl FromStr for FixVersion {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s {
+            "FIX.4.2" => Ok(Self::Fix42),
+            "FIX.4.4" => Ok(Self::Fix44),
+            "FIXT.1.1" | "FIX.5.0SP2" => Ok(Self::Fix50Sp2),
+            _ => anyhow::bail!("Unsupported FIX version '{s}'"),
+        }
+    }
+}
+
+/// FIX `SessionRejectReason(373)` values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, AsRefStr, Display, EnumString)]
+pub enum FixSessionRejectReason {
+    #[strum(serialize = "1")]
+    RequiredTagMissing,
+    #[strum(serialize = "4")]
+    TagSpecifiedWithoutValue,
+    #[strum(serialize = "11")]
+    InvalidMsgType,
+}
+
+/// A message which failed validation against a [`FixDictionary`].
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+#[error("{text}")]
+pub struct FixValidationError {
+    /// The reason to report in the session `Reject`.
+    pub reason: FixSessionRejectReason,
+    /// The tag which failed validation (if any).
+    pub ref_tag: Option<u32>,
+    /// The description of the failure.
+    pub text: String,
+}
+
+/// The definition of a message type in a [`FixDictionary`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixMessageSpec {
+    /// The message name, e.g. "NewOrderSingle".
+    pub name: String,
+    /// The body tags which must be present.
+    pub required_tags: Vec<u32>,
+}
+
+/// A FIX message dictionary used to validate incoming messages.
+#[derive(Clone, Debug)]
+pub struct FixDictionary {
+    version: FixVersion,
+    messages: HashMap<String, FixMessageSpec>,
+    allow_unknown_msg_types: bool,
+}
+
+impl FixDictionary {
+    /// Creates a new [`FixDictionary`] instance with the standard messages of the `version`.
+    ///
+    /// Only the tags required by the specification for the session and order entry messages
+    /// are defined; message types not in the dictionary are accepted unless disallowed with
+    /// [`FixDictionary::set_allow_unknown_msg_types`].
+    #[must_use]
+    pub fn new(version: FixVersion) -> Self {
+        let mut dictionary = Self {
+            version,
+            messages: HashMap::new(),
+            allow_unknown_msg_types: true,
+        };
+
+        let mut logon = vec![tags::ENCRYPT_METHOD, tags::HEART_BT_INT];
+        if version.default_appl_ver_id().is_some() {
+            logon.push(tags::DEFAULT_APPL_VER_ID);
+        }
+        let mut new_order_single = vec![
+            tags::CL_ORD_ID,
+            tags::SYMBOL,
+            tags::SIDE,
+            tags::TRANSACT_TIME,
+            tags::ORD_TYPE,
+        ];
+        let mut cancel_replace = vec![
+            tags::ORIG_CL_ORD_ID,
+            tags::CL_ORD_ID,
+            tags::SYMBOL,
+            tags::SIDE,
+            tags::TRANSACT_TIME,
+            tags::ORD_TYPE,
+        ];
+        let mut execution_report = vec![
+            tags::ORDER_ID,
+            tags::EXEC_ID,
+            tags::EXEC_TYPE,
+            tags::ORD_STATUS,
+            tags::SYMBOL,
+            tags::SIDE,
+            tags::LEAVES_QTY,
+            tags::CUM_QTY,
+        ];
+        if version == FixVersion::Fix42 {
+            new_order_single.push(tags::HANDL_INST);
+            cancel_replace.push(tags::HANDL_INST);
+            execution_report.push(tags::EXEC_TRANS_TYPE);
+        }
+
+        dictionary
+            .add_message(msg_types::HEARTBEAT, "Heartbeat", &[])
+            .add_message(msg_types::TEST_REQUEST, "TestRequest", &[tags::TEST_REQ_ID])
+            .add_message(
+                msg_types::RESEND_REQUEST,
+                "ResendRequest",
+                &[tags::BEGIN_SEQ_NO, tags::END_SEQ_NO],
+            )
+            .add_message(msg_types::REJECT, "Reject", &[tags::REF_SEQ_NUM])
+            .add_message(
+                msg_types::SEQUENCE_RESET,
+                "SequenceReset",
+                &[tags::NEW_SEQ_NO],
+            )
+            .add_message(msg_types::LOGOUT, "Logout", &[])
+            .add_message(msg_types::LOGON, "Logon", &logon)
+            .add_message(
+                msg_types::EXECUTION_REPORT,
+                "ExecutionReport",
+                &execution_report,
+            )
+            .add_message(
+                msg_types::ORDER_CANCEL_REJECT,
+                "OrderCancelReject",
+                &[
+                    tags::ORDER_ID,
+                    tags::CL_ORD_ID,
+                    tags::ORIG_CL_ORD_ID,
+                    tags::ORD_STATUS,
+                    tags::CXL_REJ_RESPONSE_TO,
+                ],
+            )
+            .add_message(
+                msg_types::NEW_ORDER_SINGLE,
+                "NewOrderSingle",
+                &new_order_single,
+            )
+            .add_message(
+                msg_types::ORDER_CANCEL_REQUEST,
+                "OrderCancelRequest",
+                &[
+                    tags::ORIG_CL_ORD_ID,
+                    tags::CL_ORD_ID,
+                    tags::SYMBOL,
+                    tags::SIDE,
+                    tags::TRANSACT_TIME,
+                ],
+            )
+            .add_message(
+                msg_types::ORDER_CANCEL_REPLACE_REQUEST,
+                "OrderCancelReplaceRequest",
+                &cancel_replace,
+            )
+            .add_message(
+                msg_types::ORDER_STATUS_REQUEST,
+                "OrderStatusRequest",
+                &[tags::CL_ORD_ID, tags::SYMBOL, tags::SIDE],
+            )
+            .add_message(
+                msg_types::BUSINESS_MESSAGE_REJECT,
+                "BusinessMessageReject",
+                &[tags::REF_MSG_TYPE, tags::BUSINESS_REJECT_REASON],
+            );
+
+        dictionary
+    }
+
+    /// Returns the protocol version of the dictionary.
+    #[must_use]
+    pub const fn version(&self) -> FixVersion {
+        self.version
+    }
+
+    /// Returns the definition of the `msg_type` (if defined).
+    #[must_use]
+    pub fn message(&self, msg_type: &str) -> Option<&FixMessageSpec> {
+        self.messages.get(msg_type)
+    }
+
+    /// Adds (or replaces) the definition of the `msg_type`.
+    pub fn add_message(&mut self, msg_type: &str, name: &str, required_tags: &[u32]) -> &mut Self {
+        self.messages.insert(
+            msg_type.to_string(),
+            FixMessageSpec {
+                name: name.to_string(),
+                required_tags: required_tags.to_vec(),
+            },
+        );
+        self
+    }
+
+    /// Adds a required `tag` to the definition of the `msg_type`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `msg_type` is not defined.
+    pub fn add_required_tag(&mut self, msg_type: &str, tag: u32) -> anyhow::Result<&mut Self> {
+        let spec = self.message_mut(msg_type)?;
+        if !spec.required_tags.contains(&tag) {
+            spec.required_tags.push(tag);
+        }
+        Ok(self)
+    }
+
+    /// Removes a required `tag` from the definition of the `msg_type`, for venues which
+    /// omit a standard field.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `msg_type` is not defined.
+    pub fn remove_required_tag(&mut self, msg_type: &str, tag: u32) -> anyhow::Result<&mut Self> {
+        self.message_mut(msg_type)?
+            .required_tags
+            .retain(|required| *required != tag);
+        Ok(self)
+    }
+
+    /// Sets whether message types not in the dictionary are accepted.
+    pub fn set_allow_unknown_msg_types(&mut self, allow: bool) -> &mut Self {
+        self.allow_unknown_msg_types = allow;
+        self
+    }
+
+    /// Validates the `message` against the dictionary.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error describing the first failure, if the message type is not allowed, a
+    /// field has an empty value or a required tag is missing.
+    pub fn validate(&self, message: &FixMessage) -> Result<(), FixValidationError> {
+        let msg_type = message.msg_type();
+
+        if let Some((tag, _)) = message.fields().find(|(_, value)| value.is_empty()) {
+            return Err(FixValidationError {
+                reason: FixSessionRejectReason::TagSpecifiedWithoutValue,
+                ref_tag: Some(tag),
+                text: format!("Tag {tag} specified without a value"),
+            });
+        }
+
+        let Some(spec) = self.messages.get(msg_type) else {
+            if self.allow_unknown_msg_types {
+                return Ok(());
+            }
+            return Err(FixValidationError {
+                reason: FixSessionRejectReason::InvalidMsgType,
+                ref_tag: Some(tags::MSG_TYPE),
+                text: format!("Invalid MsgType '{msg_type}'"),
+            });
+        };
+
+        if let Some(tag) = spec
+            .required_tags
+            .iter()
+            .find(|tag| message.get(**tag).is_none())
+        {
+            return Err(FixValidationError {
+                reason: FixSessionRejectReason::RequiredTagMissing,
+                ref_tag: Some(*tag),
+                text: format!("Required tag {tag} missing in {} message", spec.name),
+            });
+        }
+
+        Ok(())
+    }
+
+    fn message_mut(&mut self, msg_type: &str) -> anyhow::Result<&mut FixMessageSpec> {
+        self.messages
+            .get_mut(msg_type)
+            .ok_or_else(|| anyhow::anyhow!("MsgType '{msg_type}' not defined in dictionary"))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn execution_report() -> FixMessage {
+        let mut message = FixMessage::new(msg_types::EXECUTION_REPORT);
+        message
+            .set(tags::ORDER_ID, "V-1")
+            .set(tags::EXEC_ID, "E-1")
+            .set(tags::EXEC_TYPE, "0")
+            .set(tags::ORD_STATUS, "0")
+            .set(tags::SYMBOL, "BTC-USD")
+            .set(tags::SIDE, "1")
+            .set(tags::LEAVES_QTY, 1)
+            .set(tags::CUM_QTY, 0);
+        message
+    }
+
+    #[rstest]
+    #[case(FixVersion::Fix42, "FIX.4.2", None)]
+    #[case(FixVersion::Fix44, "FIX.4.4", None)]
+    #[case(FixVersion::Fix50Sp2, "FIXT.1.1", Some("9"))]
+    fn test_version_begin_string(
+        #[case] version: FixVersion,
+        #[case] begin_string: &str,
+        #[case] default_appl_ver_id: Option<&str>,
+    ) {
+        assert_eq!(version.begin_string(), begin_string);
+        assert_eq!(version.default_appl_ver_id(), default_appl_ver_id);
+        assert_eq!(FixVersion::from_str(begin_string).unwrap(), version);
+        assert!(FixVersion::from_str("FIX.4.0").is_err());
+    }
+
+    #[rstest]
+    fn test_validate_version_specific_required_tags() {
+        let message = execution_report();
+
+        assert!(
+            FixDictionary::new(FixVersion::Fix44)
+                .validate(&message)
+                .is_ok()
+        );
+
+        let error = FixDictionary::new(FixVersion::Fix42)
+            .validate(&message)
+            .unwrap_err();
+        assert_eq!(error.reason, FixSessionRejectReason::RequiredTagMissing);
+        assert_eq!(error.ref_tag, Some(tags::EXEC_TRANS_TYPE));
+    }
+
+    #[rstest]
+    fn test_validate_empty_value() {
+        let mut message = execution_report();
+        message.set(tags::TEXT, "");
+
+        let error = FixDictionary::new(FixVersion::Fix44)
+            .validate(&message)
+            .unwrap_err();
+
+        assert_eq!(
+            error.reason,
+            FixSessionRejectReason::TagSpecifiedWithoutValue
+        );
+        assert_eq!(error.ref_tag, Some(tags::TEXT));
+    }
+
+    #[rstest]
+    fn test_custom_messages_and_tags() {
+        let mut dictionary = FixDictionary::new(FixVersion::Fix50Sp2);
+        let mut message = execution_report();
+
+        dictionary
+            .add_required_tag(msg_types::EXECUTION_REPORT, tags::TRD_MATCH_ID)
+            .unwrap();
+        assert_eq!(
+            dictionary.validate(&message).unwrap_err().ref_tag,
+            Some(tags::TRD_MATCH_ID)
+        );
+
+        dictionary
+            .remove_required_tag(msg_types::EXECUTION_REPORT, tags::TRD_MATCH_ID)
+            .unwrap()
+            .remove_required_tag(msg_types::EXECUTION_REPORT, tags::LEAVES_QTY)
+            .unwrap();
+        message.remove(tags::LEAVES_QTY);
+        assert!(dictionary.validate(&message).is_ok());
+
+        let custom = FixMessage::new("U1");
+        assert!(dictionary.validate(&custom).is_ok());
+        dictionary.set_allow_unknown_msg_types(false);
+        assert_eq!(
+            dictionary.validate(&custom).unwrap_err().reason,
+            FixSessionRejectReason::InvalidMsgType
+        );
+        dictionary.add_message("U1", "VenueStatus", &[]);
+        assert!(dictionary.validate(&custom).is_ok());
+        assert!(dictionary.add_required_tag("U2", 5000).is_err());
+    }
+}
@@ -132,7 +132,7 @@ mod process_fix_buffer_tests {
     use nautilus_core::MUTEX_POISONED;
     use rstest::rstest;
 
-    use crate::{fix::process_fix_buffer, socket::TcpMessageHandler};
+    use crate::{fix::framing::process_fix_buffer, socket::TcpMessageHandler};
 
     #[rstest]
     fn test_process_empty_buffer() {
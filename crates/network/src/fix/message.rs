@@ -0,0 +1,515 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! FIX tag-value message encoding and decoding.
+
+use std::{fmt::Display, str::FromStr};
+
+use chrono::NaiveDateTime;
+use nautilus_core::UnixNanos;
+
+/// The FIX field delimiter (SOH).
+pub const SOH: u8 = b'\x01';
+
+/// The timestamp format for `UTCTimestamp` fields (millisecond precision).
+const TIMESTAMP_FORMAT: &str = "%Y%m%d-%H:%M:%S%.3f";
+
+/// Standard FIX tags.
+pub mod tags {
+    // Standard header and trailer
+    pub const BEGIN_STRING: u32 = 8;
+    pub const BODY_LENGTH: u32 = 9;
+    pub const CHECKSUM: u32 = 10;
+    pub const MSG_SEQ_NUM: u32 = 34;
+    pub const MSG_TYPE: u32 = 35;
+    pub const POSS_DUP_FLAG: u32 = 43;
+    pub const SENDER_COMP_ID: u32 = 49;
+    pub const SENDING_TIME: u32 = 52;
+    pub const TARGET_COMP_ID: u32 = 56;
+    pub const POSS_RESEND: u32 = 97;
+    pub const ON_BEHALF_OF_COMP_ID: u32 = 115;
+    pub const ORIG_SENDING_TIME: u32 = 122;
+    pub const DELIVER_TO_COMP_ID: u32 = 128;
+
+    // Session level
+    pub const BEGIN_SEQ_NO: u32 = 7;
+    pub const END_SEQ_NO: u32 = 16;
+    pub const NEW_SEQ_NO: u32 = 36;
+    pub const REF_SEQ_NUM: u32 = 45;
+    pub const TEXT: u32 = 58;
+    pub const ENCRYPT_METHOD: u32 = 98;
+    pub const HEART_BT_INT: u32 = 108;
+    pub const TEST_REQ_ID: u32 = 112;
+    pub const GAP_FILL_FLAG: u32 = 123;
+    pub const RESET_SEQ_NUM_FLAG: u32 = 141;
+    pub const REF_TAG_ID: u32 = 371;
+    pub const SESSION_REJECT_REASON: u32 = 373;
+    pub const USERNAME: u32 = 553;
+    pub const PASSWORD: u32 = 554;
+    pub const APPL_VER_ID: u32 = 1128;
+    pub const DEFAULT_APPL_VER_ID: u32 = 1137;
+
+    // Application level
+    pub const ACCOUNT: u32 = 1;
+    pub const AVG_PX: u32 = 6;
+    pub const CL_ORD_ID: u32 = 11;
+    pub const CUM_QTY: u32 = 14;
+    pub const CURRENCY: u32 = 15;
+    pub const EXEC_ID: u32 = 17;
+    pub const EXEC_INST: u32 = 18;
+    pub const EXEC_TRANS_TYPE: u32 = 20;
+    pub const HANDL_INST: u32 = 21;
+    pub const LAST_PX: u32 = 31;
+    pub const LAST_QTY: u32 = 32;
+    pub const ORDER_ID: u32 = 37;
+    pub const ORDER_QTY: u32 = 38;
+    pub const ORD_STATUS: u32 = 39;
+    pub const ORD_TYPE: u32 = 40;
+    pub const ORIG_CL_ORD_ID: u32 = 41;
+    pub const PRICE: u32 = 44;
+    pub const SIDE: u32 = 54;
+    pub const SYMBOL: u32 = 55;
+    pub const TIME_IN_FORCE: u32 = 59;
+    pub const TRANSACT_TIME: u32 = 60;
+    pub const STOP_PX: u32 = 99;
+    pub const CXL_REJ_REASON: u32 = 102;
+    pub const ORD_REJ_REASON: u32 = 103;
+    pub const EXPIRE_TIME: u32 = 126;
+    pub const NO_MISC_FEES: u32 = 136;
+    pub const MISC_FEE_AMT: u32 = 137;
+    pub const MISC_FEE_CURR: u32 = 138;
+    pub const MISC_FEE_TYPE: u32 = 139;
+    pub const EXEC_TYPE: u32 = 150;
+    pub const LEAVES_QTY: u32 = 151;
+    pub const REF_MSG_TYPE: u32 = 372;
+    pub const BUSINESS_REJECT_REASON: u32 = 380;
+    pub const CXL_REJ_RESPONSE_TO: u32 = 434;
+    pub const PARTY_ID_SOURCE: u32 = 447;
+    pub const PARTY_ID: u32 = 448;
+    pub const PARTY_ROLE: u32 = 452;
+    pub const NO_PARTY_IDS: u32 = 453;
+    pub const LAST_LIQUIDITY_IND: u32 = 851;
+    pub const TRD_MATCH_ID: u32 = 880;
+}
+
+/// Standard FIX message types.
+pub mod msg_types {
+    // Session (admin) messages
+    pub const HEARTBEAT: &str = "0";
+    pub const TEST_REQUEST: &str = "1";
+    pub const RESEND_REQUEST: &str = "2";
+    pub const REJECT: &str = "3";
+    pub const SEQUENCE_RESET: &str = "4";
+    pub const LOGOUT: &str = "5";
+    pub const LOGON: &str = "A";
+
+    // Application messages
+    pub const EXECUTION_REPORT: &str = "8";
+    pub const ORDER_CANCEL_REJECT: &str = "9";
+    pub const NEW_ORDER_SINGLE: &str = "D";
+    pub const ORDER_CANCEL_REQUEST: &str = "F";
+    pub const ORDER_CANCEL_REPLACE_REQUEST: &str = "G";
+    pub const ORDER_STATUS_REQUEST: &str = "H";
+    pub const BUSINESS_MESSAGE_REJECT: &str = "j";
+
+    /// Returns whether the `msg_type` is a session level (admin) message.
+    #[must_use]
+    pub fn is_admin(msg_type: &str) -> bool {
+        matches!(
+            msg_type,
+            HEARTBEAT | TEST_REQUEST | RESEND_REQUEST | REJECT | SEQUENCE_RESET | LOGOUT | LOGON
+        )
+    }
+}
+
+/// Header tags which are encoded immediately after `MsgType(35)`, in this order.
+const HEADER_TAGS: [u32; 8] = [
+    tags::SENDER_COMP_ID,
+    tags::TARGET_COMP_ID,
+    tags::ON_BEHALF_OF_COMP_ID,
+    tags::DELIVER_TO_COMP_ID,
+    tags::MSG_SEQ_NUM,
+    tags::POSS_DUP_FLAG,
+    tags::POSS_RESEND,
+    tags::SENDING_TIME,
+];
+
+/// A FIX message as an ordered list of tag-value fields.
+///
+/// The `BeginString(8)`, `BodyLength(9)` and `CheckSum(10)` fields are not stored as fields,
+/// they are written by [`FixMessage::encode`] and validated by [`FixMessage::decode`].
+/// Repeated tags are preserved in order, so repeating groups can be built with
+/// [`FixMessage::push`] and read with [`FixMessage::get_all`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixMessage {
+    begin_string: String,
+    fields: Vec<(u32, String)>,
+}
+
+impl FixMessage {
+    /// Creates a new [`FixMessage`] instance of the given `msg_type`.
+    #[must_use]
+    pub fn new(msg_type: &str) -> Self {
+        Self {
+            begin_string: String::new(),
+            fields: vec![(tags::MSG_TYPE, msg_type.to_string())],
+        }
+    }
+
+    /// Returns the `BeginString(8)` of the message (empty if not yet set).
+    #[must_use]
+    pub fn begin_string(&self) -> &str {
+        &self.begin_string
+    }
+
+    /// Sets the `BeginString(8)` of the message.
+    pub fn set_begin_string(&mut self, begin_string: &str) {
+        self.begin_string = begin_string.to_string();
+    }
+
+    /// Returns the `MsgType(35)` of the message.
+    #[must_use]
+    pub fn msg_type(&self) -> &str {
+        self.get(tags::MSG_TYPE).unwrap_or_default()
+    }
+
+    /// Returns the `MsgSeqNum(34)` of the message (if set and valid).
+    #[must_use]
+    pub fn msg_seq_num(&self) -> Option<u64> {
+        self.get(tags::MSG_SEQ_NUM)?.parse().ok()
+    }
+
+    /// Returns whether the message is a session level (admin) message.
+    #[must_use]
+    pub fn is_admin(&self) -> bool {
+        msg_types::is_admin(self.msg_type())
+    }
+
+    /// Returns whether the message has `PossDupFlag(43)` set.
+    #[must_use]
+    pub fn is_poss_dup(&self) -> bool {
+        self.get(tags::POSS_DUP_FLAG) == Some("Y")
+    }
+
+    /// Returns the value of the first field with the `tag` (if found).
+    #[must_use]
+    pub fn get(&self, tag: u32) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(field_tag, _)| *field_tag == tag)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns the values of all fields with the `tag`, in message order.
+    #[must_use]
+    pub fn get_all(&self, tag: u32) -> Vec<&str> {
+        self.fields
+            .iter()
+            .filter(|(field_tag, _)| *field_tag == tag)
+            .map(|(_, value)| value.as_str())
+            .collect()
+    }
+
+    /// Returns the value of the field with the `tag`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the field is missing.
+    pub fn get_required(&self, tag: u32) -> anyhow::Result<&str> {
+        self.get(tag)
+            .ok_or_else(|| anyhow::anyhow!("Missing tag {tag} in {} message", self.msg_type()))
+    }
+
+    /// Returns the value of the field with the `tag` parsed as `T` (if found).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the field value cannot be parsed.
+    pub fn get_parsed<T>(&self, tag: u32) -> anyhow::Result<Option<T>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.get(tag)
+            .map(|value| {
+                value
+                    .parse()
+                    .map_err(|e| anyhow::anyhow!("Invalid value '{value}' for tag {tag}: {e}"))
+            })
+            .transpose()
+    }
+
+    /// Returns the fields of the message in order.
+    pub fn fields(&self) -> impl Iterator<Item = (u32, &str)> {
+        self.fields
+            .iter()
+            .map(|(tag, value)| (*tag, value.as_str()))
+    }
+
+    /// Sets the value of the first field with the `tag`, or appends the field if not found.
+    pub fn set(&mut self, tag: u32, value: impl ToString) -> &mut Self {
+        let value = value.to_string();
+        match self
+            .fields
+            .iter_mut()
+            .find(|(field_tag, _)| *field_tag == tag)
+        {
+            Some((_, existing)) => *existing = value,
+            None => self.fields.push((tag, value)),
+        }
+        self
+    }
+
+    /// Appends a field, retaining any existing fields with the same `tag`.
+    pub fn push(&mut self, tag: u32, value: impl ToString) -> &mut Self {
+        self.fields.push((tag, value.to_string()));
+        self
+    }
+
+    /// Removes all fields with the `tag`.
+    pub fn remove(&mut self, tag: u32) -> &mut Self {
+        self.fields.retain(|(field_tag, _)| *field_tag != tag);
+        self
+    }
+
+    /// Encodes the message to FIX wire format, computing `BodyLength(9)` and `CheckSum(10)`.
+    ///
+    /// The `MsgType(35)` and standard header fields are written first, followed by the
+    /// remaining fields in insertion order.
+    #[must_use]
+    pub fn encode(&self) -> Vec<u8> {
+        let mut body = Vec::with_capacity(256);
+        let mut write_field = |tag: u32, value: &str| {
+            body.extend_from_slice(tag.to_string().as_bytes());
+            body.push(b'=');
+            body.extend_from_slice(value.as_bytes());
+            body.push(SOH);
+        };
+
+        write_field(tags::MSG_TYPE, self.msg_type());
+        for header_tag in HEADER_TAGS {
+            if let Some(value) = self.get(header_tag) {
+                write_field(header_tag, value);
+            }
+        }
+        for (tag, value) in &self.fields {
+            if *tag != tags::MSG_TYPE && !HEADER_TAGS.contains(tag) {
+                write_field(*tag, value);
+            }
+        }
+
+        let mut buffer = Vec::with_capacity(body.len() + 32);
+        buffer.extend_from_slice(format!("8={}", self.begin_string).as_bytes());
+        buffer.push(SOH);
+        buffer.extend_from_slice(format!("9={}", body.len()).as_bytes());
+        buffer.push(SOH);
+        buffer.extend_from_slice(&body);
+
+        let checksum = checksum(&buffer);
+        buffer.extend_from_slice(format!("10={checksum:03}").as_bytes());
+        buffer.push(SOH);
+        buffer
+    }
+
+    /// Decodes a complete FIX message, validating `BodyLength(9)` and `CheckSum(10)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the message is malformed or fails validation.
+    pub fn decode(data: &[u8]) -> anyhow::Result<Self> {
+        let mut begin_string = None;
+        let mut body_start = 0;
+        let mut body_length = None;
+        let mut fields = Vec::new();
+        let mut position = 0;
+
+        while position < data.len() {
+            let Some(end) = memchr::memchr(SOH, &data[position..]).map(|i| position + i) else {
+                anyhow::bail!("Field not terminated at byte {position}");
+            };
+            let field = std::str::from_utf8(&data[position..end])?;
+            let (tag, value) = field
+                .split_once('=')
+                .ok_or_else(|| anyhow::anyhow!("Invalid field '{field}'"))?;
+            let tag: u32 = tag
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Invalid tag '{tag}': {e}"))?;
+
+            match (tag, fields.is_empty()) {
+                (tags::BEGIN_STRING, _) if begin_string.is_none() && position == 0 => {
+                    begin_string = Some(value.to_string());
+                }
+                (tags::BODY_LENGTH, true) if body_length.is_none() => {
+                    body_length = Some(value.parse::<usize>()?);
+                    body_start = end + 1;
+                }
+                (tags::CHECKSUM, _) => {
+                    let Some(body_length) = body_length else {
+                        anyhow::bail!("Missing BodyLength(9)");
+                    };
+                    if position - body_start != body_length {
+                        anyhow::bail!(
+                            "Invalid BodyLength(9): expected {body_length}, was {}",
+                            position - body_start
+                        );
+                    }
+                    let expected = checksum(&data[..position]);
+                    let received: u32 = value.parse()?;
+                    if received != expected {
+                        anyhow::bail!("Invalid CheckSum(10): expected {expected:03}, was {value}");
+                    }
+                    if end + 1 != data.len() {
+                        anyhow::bail!("Unexpected data after CheckSum(10)");
+                    }
+
+                    let begin_string =
+                        begin_string.ok_or_else(|| anyhow::anyhow!("Missing BeginString(8)"))?;
+                    let message = Self {
+                        begin_string,
+                        fields,
+                    };
+                    if message.get(tags::MSG_TYPE).is_none() {
+                        anyhow::bail!("Missing MsgType(35)");
+                    }
+                    return Ok(message);
+                }
+                _ => {
+                    if begin_string.is_none() || body_length.is_none() {
+                        anyhow::bail!("Message must start with BeginString(8) and BodyLength(9)");
+                    }
+                    fields.push((tag, value.to_string()));
+                }
+            }
+
+            position = end + 1;
+        }
+
+        anyhow::bail!("Missing CheckSum(10)")
+    }
+}
+
+impl Display for FixMessage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "8={}|", self.begin_string)?;
+        for (tag, value) in &self.fields {
+            write!(f, "{tag}={value}|")?;
+        }
+        Ok(())
+    }
+}
+
+fn checksum(data: &[u8]) -> u32 {
+    data.iter().map(|&b| u32::from(b)).sum::<u32>() % 256
+}
+
+/// Formats the UNIX nanoseconds `timestamp` as a FIX `UTCTimestamp` with millisecond precision.
+#[must_use]
+pub fn format_timestamp(timestamp: UnixNanos) -> String {
+    timestamp
+        .to_datetime_utc()
+        .format(TIMESTAMP_FORMAT)
+        .to_string()
+}
+
+/// Parses a FIX `UTCTimestamp` (with or without fractional seconds) to UNIX nanoseconds.
+///
+/// # Errors
+///
+/// Returns an error if the value is not a valid `UTCTimestamp`.
+pub fn parse_timestamp(value: &str) -> anyhow::Result<UnixNanos> {
+    let datetime = NaiveDateTime::parse_from_str(value, "%Y%m%d-%H:%M:%S%.f")
+        .map_err(|e| anyhow::anyhow!("Invalid UTCTimestamp '{value}': {e}"))?;
+    let nanos = datetime
+        .and_utc()
+        .timestamp_nanos_opt()
+        .ok_or_else(|| anyhow::anyhow!("UTCTimestamp '{value}' out of range"))?;
+    Ok(UnixNanos::from(u64::try_from(nanos)?))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn logon() -> FixMessage {
+        let mut message = FixMessage::new(msg_types::LOGON);
+        message.set_begin_string("FIX.4.4");
+        message
+            .set(tags::ENCRYPT_METHOD, 0)
+            .set(tags::HEART_BT_INT, 30)
+            .set(tags::SENDER_COMP_ID, "CLIENT")
+            .set(tags::TARGET_COMP_ID, "VENUE")
+            .set(tags::MSG_SEQ_NUM, 1)
+            .set(tags::SENDING_TIME, "20250322-12:34:56.000");
+        message
+    }
+
+    #[rstest]
+    fn test_encode_orders_header_fields_first() {
+        let encoded = String::from_utf8(logon().encode()).unwrap();
+
+        assert_eq!(
+            encoded,
+            "8=FIX.4.4\x019=66\x0135=A\x0149=CLIENT\x0156=VENUE\x0134=1\x0152=20250322-12:34:56.000\x0198=0\x01108=30\x0110=057\x01"
+        );
+    }
+
+    #[rstest]
+    fn test_decode_round_trip_with_repeating_group() {
+        let mut message = logon();
+        message.push(448, "PARTY1").push(448, "PARTY2");
+
+        let decoded = FixMessage::decode(&message.encode()).unwrap();
+
+        assert_eq!(decoded.begin_string(), "FIX.4.4");
+        assert_eq!(decoded.msg_type(), msg_types::LOGON);
+        assert_eq!(decoded.msg_seq_num(), Some(1));
+        assert_eq!(decoded.get_all(448), vec!["PARTY1", "PARTY2"]);
+        assert_eq!(
+            decoded.get_parsed::<u64>(tags::HEART_BT_INT).unwrap(),
+            Some(30)
+        );
+        assert!(decoded.is_admin());
+    }
+
+    #[rstest]
+    #[case(b"8=FIX.4.4\x019=5\x0135=0\x0110=000\x01".as_slice(), "Invalid CheckSum(10)")]
+    #[case(b"8=FIX.4.4\x019=9\x0135=0\x0110=163\x01".as_slice(), "Invalid BodyLength(9)")]
+    #[case(b"9=5\x0135=0\x0110=000\x01".as_slice(), "must start with BeginString")]
+    #[case(b"8=FIX.4.4\x019=5\x0135=0\x01".as_slice(), "Missing CheckSum(10)")]
+    fn test_decode_invalid_messages(#[case] data: &[u8], #[case] error: &str) {
+        let result = FixMessage::decode(data);
+
+        assert!(result.unwrap_err().to_string().contains(error));
+    }
+
+    #[rstest]
+    fn test_timestamp_round_trip() {
+        let timestamp = UnixNanos::from(1_742_646_896_123_000_000);
+
+        let formatted = format_timestamp(timestamp);
+
+        assert_eq!(formatted, "20250322-12:34:56.123");
+        assert_eq!(parse_timestamp(&formatted).unwrap(), timestamp);
+        assert_eq!(
+            parse_timestamp("20250322-12:34:56").unwrap(),
+            UnixNanos::from(1_742_646_896_000_000_000)
+        );
+    }
+}
@@ -0,0 +1,670 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Typed codecs for common FIX application messages.
+//!
+//! Enumerated field values serialize to (and parse from) their FIX wire values. Messages are
+//! encoded in the FIX 4.4 form by `to_message`, and `to_message_for` encodes the fields which
+//! differ for other [`FixVersion`]s (e.g. `HandlInst(21)` and `ExecTransType(20)` for FIX 4.2).
+
+use std::{fmt::Display, str::FromStr};
+
+use nautilus_core::UnixNanos;
+use rust_decimal::Decimal;
+use strum::{AsRefStr, Display, EnumString};
+
+use super::{
+    dictionary::FixVersion,
+    message::{FixMessage, format_timestamp, msg_types, parse_timestamp, tags},
+};
+
+/// The `HandlInst(21)` value for automated execution with no broker intervention.
+const HANDL_INST_AUTOMATED: &str = "1";
+
+/// The `ExecTransType(20)` value for a new execution.
+const EXEC_TRANS_TYPE_NEW: &str = "0";
+
+/// FIX `Side(54)` values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, AsRefStr, Display, EnumString)]
+pub enum FixSide {
+    #[strum(serialize = "1")]
+    Buy,
+    #[strum(serialize = "2")]
+    Sell,
+}
+
+/// FIX `OrdType(40)` values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, AsRefStr, Display, EnumString)]
+pub enum FixOrdType {
+    #[strum(serialize = "1")]
+    Market,
+    #[strum(serialize = "2")]
+    Limit,
+    #[strum(serialize = "3")]
+    Stop,
+    #[strum(serialize = "4")]
+    StopLimit,
+}
+
+/// FIX `TimeInForce(59)` values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, AsRefStr, Display, EnumString)]
+pub enum FixTimeInForce {
+    #[strum(serialize = "0")]
+    Day,
+    #[strum(serialize = "1")]
+    GoodTillCancel,
+    #[strum(serialize = "3")]
+    ImmediateOrCancel,
+    #[strum(serialize = "4")]
+    FillOrKill,
+    #[strum(serialize = "6")]
+    GoodTillDate,
+}
+
+/// FIX `ExecType(150)` values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, AsRefStr, Display, EnumString)]
+pub enum FixExecType {
+    #[strum(serialize = "0")]
+    New,
+    /// Superseded by [`FixExecType::Trade`] in FIX 4.3 and later.
+    #[strum(serialize = "1")]
+    PartialFill,
+    /// Superseded by [`FixExecType::Trade`] in FIX 4.3 and later.
+    #[strum(serialize = "2")]
+    Fill,
+    #[strum(serialize = "3")]
+    DoneForDay,
+    #[strum(serialize = "4")]
+    Canceled,
+    #[strum(serialize = "5")]
+    Replaced,
+    #[strum(serialize = "6")]
+    PendingCancel,
+    #[strum(serialize = "8")]
+    Rejected,
+    #[strum(serialize = "A")]
+    PendingNew,
+    #[strum(serialize = "C")]
+    Expired,
+    #[strum(serialize = "E")]
+    PendingReplace,
+    #[strum(serialize = "F")]
+    Trade,
+    #[strum(serialize = "I")]
+    OrderStatus,
+}
+
+impl FixExecType {
+    /// Returns whether the execution is a trade, for any protocol version.
+    #[must_use]
+    pub const fn is_trade(self) -> bool {
+        matches!(self, Self::PartialFill | Self::Fill | Self::Trade)
+    }
+}
+
+/// FIX `OrdStatus(39)` values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, AsRefStr, Display, EnumString)]
+pub enum FixOrdStatus {
+    #[strum(serialize = "0")]
+    New,
+    #[strum(serialize = "1")]
+    PartiallyFilled,
+    #[strum(serialize = "2")]
+    Filled,
+    #[strum(serialize = "3")]
+    DoneForDay,
+    #[strum(serialize = "4")]
+    Canceled,
+    #[strum(serialize = "5")]
+    Replaced,
+    #[strum(serialize = "6")]
+    PendingCancel,
+    #[strum(serialize = "8")]
+    Rejected,
+    #[strum(serialize = "A")]
+    PendingNew,
+    #[strum(serialize = "C")]
+    Expired,
+    #[strum(serialize = "E")]
+    PendingReplace,
+}
+
+/// A FIX `NewOrderSingle (D)` message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NewOrderSingle {
+    pub cl_ord_id: String,
+    pub account: Option<String>,
+    pub symbol: String,
+    pub side: FixSide,
+    pub order_qty: Decimal,
+    pub ord_type: FixOrdType,
+    pub price: Option<Decimal>,
+    pub stop_px: Option<Decimal>,
+    pub time_in_force: Option<FixTimeInForce>,
+    pub exec_inst: Option<String>,
+    pub expire_time: Option<UnixNanos>,
+    pub transact_time: UnixNanos,
+}
+
+impl NewOrderSingle {
+    /// Encodes the order as a FIX 4.4 [`FixMessage`] (without session header fields).
+    #[must_use]
+    pub fn to_message(&self) -> FixMessage {
+        self.to_message_for(FixVersion::Fix44)
+    }
+
+    /// Encodes the order as a [`FixMessage`] for the protocol `version`.
+    #[must_use]
+    pub fn to_message_for(&self, version: FixVersion) -> FixMessage {
+        let mut message = FixMessage::new(msg_types::NEW_ORDER_SINGLE);
+        message.set(tags::CL_ORD_ID, &self.cl_ord_id);
+        set_optional(&mut message, tags::ACCOUNT, self.account.as_ref());
+        if version == FixVersion::Fix42 {
+            message.set(tags::HANDL_INST, HANDL_INST_AUTOMATED);
+        }
+        message
+            .set(tags::SYMBOL, &self.symbol)
+            .set(tags::SIDE, self.side)
+            .set(tags::ORDER_QTY, self.order_qty)
+            .set(tags::ORD_TYPE, self.ord_type);
+        set_optional(&mut message, tags::PRICE, self.price.as_ref());
+        set_optional(&mut message, tags::STOP_PX, self.stop_px.as_ref());
+        set_optional(
+            &mut message,
+            tags::TIME_IN_FORCE,
+            self.time_in_force.as_ref(),
+        );
+        set_optional(&mut message, tags::EXEC_INST, self.exec_inst.as_ref());
+        if let Some(expire_time) = self.expire_time {
+            message.set(tags::EXPIRE_TIME, format_timestamp(expire_time));
+        }
+        message.set(tags::TRANSACT_TIME, format_timestamp(self.transact_time));
+        message
+    }
+
+    /// Decodes a `NewOrderSingle` from the `message`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the message is not a `NewOrderSingle` or a field is missing or invalid.
+    pub fn from_message(message: &FixMessage) -> anyhow::Result<Self> {
+        check_msg_type(message, msg_types::NEW_ORDER_SINGLE)?;
+        Ok(Self {
+            cl_ord_id: message.get_required(tags::CL_ORD_ID)?.to_string(),
+            account: message.get(tags::ACCOUNT).map(ToString::to_string),
+            symbol: message.get_required(tags::SYMBOL)?.to_string(),
+            side: parse_required(message, tags::SIDE)?,
+            order_qty: parse_required(message, tags::ORDER_QTY)?,
+            ord_type: parse_required(message, tags::ORD_TYPE)?,
+            price: message.get_parsed(tags::PRICE)?,
+            stop_px: message.get_parsed(tags::STOP_PX)?,
+            time_in_force: message.get_parsed(tags::TIME_IN_FORCE)?,
+            exec_inst: message.get(tags::EXEC_INST).map(ToString::to_string),
+            expire_time: message
+                .get(tags::EXPIRE_TIME)
+                .map(parse_timestamp)
+                .transpose()?,
+            transact_time: parse_timestamp(message.get_required(tags::TRANSACT_TIME)?)?,
+        })
+    }
+}
+
+/// A FIX `OrderCancelRequest (F)` message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OrderCancelRequest {
+    pub orig_cl_ord_id: String,
+    pub cl_ord_id: String,
+    pub order_id: Option<String>,
+    pub symbol: String,
+    pub side: FixSide,
+    pub order_qty: Option<Decimal>,
+    pub transact_time: UnixNanos,
+}
+
+impl OrderCancelRequest {
+    /// Encodes the request as a [`FixMessage`] (without session header fields).
+    #[must_use]
+    pub fn to_message(&self) -> FixMessage {
+        let mut message = FixMessage::new(msg_types::ORDER_CANCEL_REQUEST);
+        message
+            .set(tags::ORIG_CL_ORD_ID, &self.orig_cl_ord_id)
+            .set(tags::CL_ORD_ID, &self.cl_ord_id);
+        set_optional(&mut message, tags::ORDER_ID, self.order_id.as_ref());
+        message
+            .set(tags::SYMBOL, &self.symbol)
+            .set(tags::SIDE, self.side);
+        set_optional(&mut message, tags::ORDER_QTY, self.order_qty.as_ref());
+        message.set(tags::TRANSACT_TIME, format_timestamp(self.transact_time));
+        message
+    }
+
+    /// Decodes an `OrderCancelRequest` from the `message`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the message is not an `OrderCancelRequest` or a field is missing or
+    /// invalid.
+    pub fn from_message(message: &FixMessage) -> anyhow::Result<Self> {
+        check_msg_type(message, msg_types::ORDER_CANCEL_REQUEST)?;
+        Ok(Self {
+            orig_cl_ord_id: message.get_required(tags::ORIG_CL_ORD_ID)?.to_string(),
+            cl_ord_id: message.get_required(tags::CL_ORD_ID)?.to_string(),
+            order_id: message.get(tags::ORDER_ID).map(ToString::to_string),
+            symbol: message.get_required(tags::SYMBOL)?.to_string(),
+            side: parse_required(message, tags::SIDE)?,
+            order_qty: message.get_parsed(tags::ORDER_QTY)?,
+            transact_time: parse_timestamp(message.get_required(tags::TRANSACT_TIME)?)?,
+        })
+    }
+}
+
+/// A FIX `OrderCancelReplaceRequest (G)` message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OrderCancelReplaceRequest {
+    pub orig_cl_ord_id: String,
+    pub cl_ord_id: String,
+    pub order_id: Option<String>,
+    pub account: Option<String>,
+    pub symbol: String,
+    pub side: FixSide,
+    pub order_qty: Decimal,
+    pub ord_type: FixOrdType,
+    pub price: Option<Decimal>,
+    pub stop_px: Option<Decimal>,
+    pub time_in_force: Option<FixTimeInForce>,
+    pub expire_time: Option<UnixNanos>,
+    pub transact_time: UnixNanos,
+}
+
+impl OrderCancelReplaceRequest {
+    /// Encodes the request as a FIX 4.4 [`FixMessage`] (without session header fields).
+    #[must_use]
+    pub fn to_message(&self) -> FixMessage {
+        self.to_message_for(FixVersion::Fix44)
+    }
+
+    /// Encodes the request as a [`FixMessage`] for the protocol `version`.
+    #[must_use]
+    pub fn to_message_for(&self, version: FixVersion) -> FixMessage {
+        let mut message = FixMessage::new(msg_types::ORDER_CANCEL_REPLACE_REQUEST);
+        message
+            .set(tags::ORIG_CL_ORD_ID, &self.orig_cl_ord_id)
+            .set(tags::CL_ORD_ID, &self.cl_ord_id);
+        set_optional(&mut message, tags::ORDER_ID, self.order_id.as_ref());
+        set_optional(&mut message, tags::ACCOUNT, self.account.as_ref());
+        if version == FixVersion::Fix42 {
+            message.set(tags::HANDL_INST, HANDL_INST_AUTOMATED);
+        }
+        message
+            .set(tags::SYMBOL, &self.symbol)
+            .set(tags::SIDE, self.side)
+            .set(tags::ORDER_QTY, self.order_qty)
+            .set(tags::ORD_TYPE, self.ord_type);
+        set_optional(&mut message, tags::PRICE, self.price.as_ref());
+        set_optional(&mut message, tags::STOP_PX, self.stop_px.as_ref());
+        set_optional(
+            &mut message,
+            tags::TIME_IN_FORCE,
+            self.time_in_force.as_ref(),
+        );
+        if let Some(expire_time) = self.expire_time {
+            message.set(tags::EXPIRE_TIME, format_timestamp(expire_time));
+        }
+        message.set(tags::TRANSACT_TIME, format_timestamp(self.transact_time));
+        message
+    }
+
+    /// Decodes an `OrderCancelReplaceRequest` from the `message`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the message is not an `OrderCancelReplaceRequest` or a field is
+    /// missing or invalid.
+    pub fn from_message(message: &FixMessage) -> anyhow::Result<Self> {
+        check_msg_type(message, msg_types::ORDER_CANCEL_REPLACE_REQUEST)?;
+        Ok(Self {
+            orig_cl_ord_id: message.get_required(tags::ORIG_CL_ORD_ID)?.to_string(),
+            cl_ord_id: message.get_required(tags::CL_ORD_ID)?.to_string(),
+            order_id: message.get(tags::ORDER_ID).map(ToString::to_string),
+            account: message.get(tags::ACCOUNT).map(ToString::to_string),
+            symbol: message.get_required(tags::SYMBOL)?.to_string(),
+            side: parse_required(message, tags::SIDE)?,
+            order_qty: parse_required(message, tags::ORDER_QTY)?,
+            ord_type: parse_required(message, tags::ORD_TYPE)?,
+            price: message.get_parsed(tags::PRICE)?,
+            stop_px: message.get_parsed(tags::STOP_PX)?,
+            time_in_force: message.get_parsed(tags::TIME_IN_FORCE)?,
+            expire_time: message
+                .get(tags::EXPIRE_TIME)
+                .map(parse_timestamp)
+                .transpose()?,
+            transact_time: parse_timestamp(message.get_required(tags::TRANSACT_TIME)?)?,
+        })
+    }
+}
+
+/// A FIX `ExecutionReport (8)` message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExecutionReport {
+    pub order_id: String,
+    pub cl_ord_id: Option<String>,
+    pub orig_cl_ord_id: Option<String>,
+    pub exec_id: String,
+    pub exec_type: FixExecType,
+    pub ord_status: FixOrdStatus,
+    pub symbol: String,
+    pub side: FixSide,
+    pub order_qty: Option<Decimal>,
+    pub price: Option<Decimal>,
+    pub last_qty: Option<Decimal>,
+    pub last_px: Option<Decimal>,
+    pub leaves_qty: Decimal,
+    pub cum_qty: Decimal,
+    pub avg_px: Option<Decimal>,
+    pub ord_rej_reason: Option<String>,
+    pub text: Option<String>,
+    pub transact_time: Option<UnixNanos>,
+}
+
+impl ExecutionReport {
+    /// Encodes the report as a FIX 4.4 [`FixMessage`] (without session header fields).
+    #[must_use]
+    pub fn to_message(&self) -> FixMessage {
+        self.to_message_for(FixVersion::Fix44)
+    }
+
+    /// Encodes the report as a [`FixMessage`] for the protocol `version`.
+    ///
+    /// For FIX 4.2 a [`FixExecType::Trade`] is encoded as a partial fill or fill
+    /// (depending on the `ord_status`).
+    #[must_use]
+    pub fn to_message_for(&self, version: FixVersion) -> FixMessage {
+        let exec_type = match self.exec_type {
+            FixExecType::Trade if version == FixVersion::Fix42 => {
+                if self.ord_status == FixOrdStatus::Filled {
+                    FixExecType::Fill
+                } else {
+                    FixExecType::PartialFill
+                }
+            }
+            exec_type => exec_type,
+        };
+
+        let mut message = FixMessage::new(msg_types::EXECUTION_REPORT);
+        message.set(tags::ORDER_ID, &self.order_id);
+        set_optional(&mut message, tags::CL_ORD_ID, self.cl_ord_id.as_ref());
+        set_optional(
+            &mut message,
+            tags::ORIG_CL_ORD_ID,
+            self.orig_cl_ord_id.as_ref(),
+        );
+        message.set(tags::EXEC_ID, &self.exec_id);
+        if version == FixVersion::Fix42 {
+            message.set(tags::EXEC_TRANS_TYPE, EXEC_TRANS_TYPE_NEW);
+        }
+        message
+            .set(tags::EXEC_TYPE, exec_type)
+            .set(tags::ORD_STATUS, self.ord_status)
+            .set(tags::SYMBOL, &self.symbol)
+            .set(tags::SIDE, self.side);
+        set_optional(&mut message, tags::ORDER_QTY, self.order_qty.as_ref());
+        set_optional(&mut message, tags::PRICE, self.price.as_ref());
+        set_optional(&mut message, tags::LAST_QTY, self.last_qty.as_ref());
+        set_optional(&mut message, tags::LAST_PX, self.last_px.as_ref());
+        message
+            .set(tags::LEAVES_QTY, self.leaves_qty)
+            .set(tags::CUM_QTY, self.cum_qty);
+        set_optional(&mut message, tags::AVG_PX, self.avg_px.as_ref());
+        set_optional(
+            &mut message,
+            tags::ORD_REJ_REASON,
+            self.ord_rej_reason.as_ref(),
+        );
+        set_optional(&mut message, tags::TEXT, self.text.as_ref());
+        if let Some(transact_time) = self.transact_time {
+            message.set(tags::TRANSACT_TIME, format_timestamp(transact_time));
+        }
+        message
+    }
+
+    /// Decodes an `ExecutionReport` from the `message`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the message is not an `ExecutionReport` or a field is missing or
+    /// invalid.
+    pub fn from_message(message: &FixMessage) -> anyhow::Result<Self> {
+        check_msg_type(message, msg_types::EXECUTION_REPORT)?;
+        Ok(Self {
+            order_id: message.get_required(tags::ORDER_ID)?.to_string(),
+            cl_ord_id: message.get(tags::CL_ORD_ID).map(ToString::to_string),
+            orig_cl_ord_id: message.get(tags::ORIG_CL_ORD_ID).map(ToString::to_string),
+            exec_id: message.get_required(tags::EXEC_ID)?.to_string(),
+            exec_type: parse_required(message, tags::EXEC_TYPE)?,
+            ord_status: parse_required(message, tags::ORD_STATUS)?,
+            symbol: message.get_required(tags::SYMBOL)?.to_string(),
+            side: parse_required(message, tags::SIDE)?,
+            order_qty: message.get_parsed(tags::ORDER_QTY)?,
+            price: message.get_parsed(tags::PRICE)?,
+            last_qty: message.get_parsed(tags::LAST_QTY)?,
+            last_px: message.get_parsed(tags::LAST_PX)?,
+            leaves_qty: parse_required(message, tags::LEAVES_QTY)?,
+            cum_qty: parse_required(message, tags::CUM_QTY)?,
+            avg_px: message.get_parsed(tags::AVG_PX)?,
+            ord_rej_reason: message.get(tags::ORD_REJ_REASON).map(ToString::to_string),
+            text: message.get(tags::TEXT).map(ToString::to_string),
+            transact_time: message
+                .get(tags::TRANSACT_TIME)
+                .map(parse_timestamp)
+                .transpose()?,
+        })
+    }
+}
+
+fn check_msg_type(message: &FixMessage, expected: &str) -> anyhow::Result<()> {
+    if message.msg_type() != expected {
+        anyhow::bail!(
+            "Invalid MsgType(35): expected '{expected}', was '{}'",
+            message.msg_type()
+        );
+    }
+    Ok(())
+}
+
+fn parse_required<T>(message: &FixMessage, tag: u32) -> anyhow::Result<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    message
+        .get_parsed(tag)?
+        .ok_or_else(|| anyhow::anyhow!("Missing tag {tag} in {} message", message.msg_type()))
+}
+
+fn set_optional<T: ToString>(message: &mut FixMessage, tag: u32, value: Option<&T>) {
+    if let Some(value) = value {
+        message.set(tag, value.to_string());
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use rust_decimal::Decimal;
+
+    use super::*;
+
+    #[rstest]
+    fn test_new_order_single_round_trip() {
+        let order = NewOrderSingle {
+            cl_ord_id: "O-1".to_string(),
+            account: Some("ACC-1".to_string()),
+            symbol: "BTC-USD".to_string(),
+            side: FixSide::Sell,
+            order_qty: Decimal::new(15, 1),
+            ord_type: FixOrdType::Limit,
+            price: Some(Decimal::new(5000025, 2)),
+            stop_px: None,
+            time_in_force: Some(FixTimeInForce::GoodTillCancel),
+            exec_inst: Some("6".to_string()),
+            expire_time: None,
+            transact_time: UnixNanos::from(1_742_646_896_123_000_000),
+        };
+
+        let message = FixMessage::decode(&order.to_message().encode()).unwrap();
+
+        assert_eq!(message.get(tags::SIDE), Some("2"));
+        assert_eq!(message.get(tags::ORD_TYPE), Some("2"));
+        assert_eq!(message.get(tags::PRICE), Some("50000.25"));
+        assert_eq!(NewOrderSingle::from_message(&message).unwrap(), order);
+    }
+
+    #[rstest]
+    fn test_order_cancel_request_round_trip() {
+        let request = OrderCancelRequest {
+            orig_cl_ord_id: "O-1".to_string(),
+            cl_ord_id: "O-1-C".to_string(),
+            order_id: Some("V-1".to_string()),
+            symbol: "BTC-USD".to_string(),
+            side: FixSide::Buy,
+            order_qty: None,
+            transact_time: UnixNanos::from(1_742_646_896_000_000_000),
+        };
+
+        let message = request.to_message();
+
+        assert_eq!(message.msg_type(), msg_types::ORDER_CANCEL_REQUEST);
+        assert_eq!(OrderCancelRequest::from_message(&message).unwrap(), request);
+    }
+
+    #[rstest]
+    fn test_execution_report_round_trip() {
+        let report = ExecutionReport {
+            order_id: "V-1".to_string(),
+            cl_ord_id: Some("O-1".to_string()),
+            orig_cl_ord_id: None,
+            exec_id: "E-1".to_string(),
+            exec_type: FixExecType::Trade,
+            ord_status: FixOrdStatus::PartiallyFilled,
+            symbol: "BTC-USD".to_string(),
+            side: FixSide::Buy,
+            order_qty: Some(Decimal::from(2)),
+            price: Some(Decimal::from(50_000)),
+            last_qty: Some(Decimal::from(1)),
+            last_px: Some(Decimal::from(49_999)),
+            leaves_qty: Decimal::from(1),
+            cum_qty: Decimal::from(1),
+            avg_px: Some(Decimal::from(49_999)),
+            ord_rej_reason: None,
+            text: None,
+            transact_time: Some(UnixNanos::from(1_742_646_896_000_000_000)),
+        };
+
+        let message = FixMessage::decode(&report.to_message().encode()).unwrap();
+
+        assert_eq!(message.get(tags::EXEC_TYPE), Some("F"));
+        assert_eq!(message.get(tags::ORD_STATUS), Some("1"));
+        assert_eq!(ExecutionReport::from_message(&message).unwrap(), report);
+    }
+
+    #[rstest]
+    fn test_order_cancel_replace_request_round_trip() {
+        let request = OrderCancelReplaceRequest {
+            orig_cl_ord_id: "O-1".to_string(),
+            cl_ord_id: "O-1-R".to_string(),
+            order_id: Some("V-1".to_string()),
+            account: None,
+            symbol: "BTC-USD".to_string(),
+            side: FixSide::Buy,
+            order_qty: Decimal::from(3),
+            ord_type: FixOrdType::StopLimit,
+            price: Some(Decimal::from(51_000)),
+            stop_px: Some(Decimal::from(50_500)),
+            time_in_force: Some(FixTimeInForce::Day),
+            expire_time: None,
+            transact_time: UnixNanos::from(1_742_646_896_000_000_000),
+        };
+
+        let message = FixMessage::decode(&request.to_message().encode()).unwrap();
+
+        assert_eq!(message.msg_type(), msg_types::ORDER_CANCEL_REPLACE_REQUEST);
+        assert_eq!(message.get(tags::HANDL_INST), None);
+        assert_eq!(
+            OrderCancelReplaceRequest::from_message(&message).unwrap(),
+            request
+        );
+
+        let message = request.to_message_for(FixVersion::Fix42);
+
+        assert_eq!(message.get(tags::HANDL_INST), Some("1"));
+        assert_eq!(
+            OrderCancelReplaceRequest::from_message(&message).unwrap(),
+            request
+        );
+    }
+
+    #[rstest]
+    #[case(FixOrdStatus::PartiallyFilled, "1")]
+    #[case(FixOrdStatus::Filled, "2")]
+    fn test_execution_report_trade_for_fix42(
+        #[case] ord_status: FixOrdStatus,
+        #[case] expected_exec_type: &str,
+    ) {
+        let report = ExecutionReport {
+            order_id: "V-1".to_string(),
+            cl_ord_id: None,
+            orig_cl_ord_id: None,
+            exec_id: "E-1".to_string(),
+            exec_type: FixExecType::Trade,
+            ord_status,
+            symbol: "BTC-USD".to_string(),
+            side: FixSide::Sell,
+            order_qty: None,
+            price: None,
+            last_qty: Some(Decimal::from(1)),
+            last_px: Some(Decimal::from(50_000)),
+            leaves_qty: Decimal::ZERO,
+            cum_qty: Decimal::from(1),
+            avg_px: None,
+            ord_rej_reason: None,
+            text: None,
+            transact_time: None,
+        };
+
+        let message = report.to_message_for(FixVersion::Fix42);
+        let decoded = ExecutionReport::from_message(&message).unwrap();
+
+        assert_eq!(message.get(tags::EXEC_TRANS_TYPE), Some("0"));
+        assert_eq!(message.get(tags::EXEC_TYPE), Some(expected_exec_type));
+        assert!(decoded.exec_type.is_trade());
+    }
+
+    #[rstest]
+    fn test_from_message_with_wrong_msg_type_errors() {
+        let message = FixMessage::new(msg_types::HEARTBEAT);
+
+        let result = ExecutionReport::from_message(&message);
+
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Invalid MsgType(35)")
+        );
+    }
+}
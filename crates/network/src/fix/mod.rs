@@ -0,0 +1,39 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! FIX protocol support.
+//!
+//! The module is layered as follows:
+//!
+//! - `framing`: splits a TCP byte stream into checksum-terminated FIX frames.
+//! - [`dictionary`]: protocol versions and message dictionaries for validation.
+//! - [`message`]: tag-value encoding and decoding of individual messages.
+//! - [`store`]: sequence number and outgoing message persistence.
+//! - [`session`]: the FIX session layer (logon/logout, heartbeats, sequencing and recovery)
+//!   as a transport independent state machine.
+//! - [`messages`]: typed codecs for common application messages.
+//! - [`client`]: an initiator driving a [`session::FixSession`] over a `SocketClient`.
+//! - [`acceptor`]: an in-process acceptor simulating a venue, for testing FIX clients.
+
+pub mod acceptor;
+pub mod client;
+pub mod dictionary;
+pub(crate) mod framing;
+pub mod message;
+pub mod messages;
+pub mod session;
+pub mod store;
+
+pub(crate) use framing::process_fix_buffer;
@@ -0,0 +1,1030 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! FIX session layer.
+//!
+//! [`FixSession`] implements the session protocol as a state machine independent of the
+//! transport: incoming messages and timer ticks are fed in, and the session returns the
+//! [`FixSessionAction`]s to perform (bytes to send, application messages to deliver, or a
+//! request to disconnect).
+//!
+//! Sessions support FIX 4.2, 4.4 and 5.0 SP2 (over FIXT 1.1), selected with
+//! [`FixSessionConfig::version`], and optionally validate incoming messages against a
+//! [`FixDictionary`], rejecting invalid messages with a session level `Reject`.
+
+use std::{
+    collections::BTreeMap,
+    fmt::{Debug, Formatter},
+    sync::Arc,
+};
+
+use nautilus_core::{UnixNanos, datetime::NANOSECONDS_IN_SECOND};
+use strum::Display;
+
+use super::{
+    dictionary::{FixDictionary, FixValidationError, FixVersion},
+    message::{FixMessage, format_timestamp, msg_types, tags},
+    store::FixMessageStore,
+};
+
+/// A hook applied to each outgoing `Logon` message after the standard header is set, e.g.
+/// to add a venue specific signature over the `SendingTime(52)`.
+pub type FixLogonHook = Arc<dyn Fn(&mut FixMessage) -> anyhow::Result<()> + Send + Sync>;
+
+/// Configuration for a [`FixSession`].
+#[derive(Clone, Debug)]
+pub struct FixSessionConfig {
+    /// The protocol version, which determines the `BeginString(8)`.
+    pub version: FixVersion,
+    /// The `SenderCompID(49)` of this side of the session.
+    pub sender_comp_id: String,
+    /// The `TargetCompID(56)` of the counterparty.
+    pub target_comp_id: String,
+    /// The heartbeat interval (seconds) requested at logon.
+    pub heartbeat_interval_secs: u64,
+    /// If sequence numbers are reset to 1 (with `ResetSeqNumFlag(141)`) on each logon.
+    pub reset_seq_num_on_logon: bool,
+    /// Additional fields to include in the `Logon` message (e.g. `Username(553)`).
+    pub logon_fields: Vec<(u32, String)>,
+    /// The dictionary to validate incoming messages against (if any).
+    pub dictionary: Option<FixDictionary>,
+}
+
+impl FixSessionConfig {
+    /// Creates a new FIX 4.4 [`FixSessionConfig`] instance with a 30 second heartbeat.
+    #[must_use]
+    pub fn new(sender_comp_id: impl Into<String>, target_comp_id: impl Into<String>) -> Self {
+        Self {
+            version: FixVersion::Fix44,
+            sender_comp_id: sender_comp_id.into(),
+            target_comp_id: target_comp_id.into(),
+            heartbeat_interval_secs: 30,
+            reset_seq_num_on_logon: false,
+            logon_fields: Vec::new(),
+            dictionary: None,
+        }
+    }
+
+    /// Sets the protocol `version` of the configuration.
+    #[must_use]
+    pub fn with_version(mut self, version: FixVersion) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Sets the standard dictionary of the configured version, for validating incoming messages.
+    #[must_use]
+    pub fn with_standard_dictionary(mut self) -> Self {
+        self.dictionary = Some(FixDictionary::new(self.version));
+        self
+    }
+
+    /// Returns the session identifier `<BeginString>-<SenderCompID>-<TargetCompID>`.
+    #[must_use]
+    pub fn session_id(&self) -> String {
+        format!(
+            "{}-{}-{}",
+            self.version.begin_string(),
+            self.sender_comp_id,
+            self.target_comp_id
+        )
+    }
+}
+
+/// The state of a [`FixSession`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Display)]
+pub enum FixSessionState {
+    /// No transport connection or logon in progress.
+    Disconnected,
+    /// A `Logon` has been sent and the response is awaited.
+    LogonSent,
+    /// The session is established.
+    LoggedOn,
+    /// A `Logout` has been sent and the response is awaited.
+    LogoutSent,
+}
+
+/// An action to be performed by the transport driving a [`FixSession`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FixSessionAction {
+    /// Send the encoded message to the counterparty.
+    Send(Vec<u8>),
+    /// Deliver an in-sequence application (or `Reject`) message to the application.
+    Deliver(FixMessage),
+    /// Close the transport connection, with the reason.
+    Disconnect(String),
+}
+
+/// A FIX session.
+///
+/// Acts as an initiator when logging on with [`FixSession::logon`], or as an acceptor
+/// when a `Logon` is received while disconnected. Handles logon and logout, heartbeats and
+/// `TestRequest`s, and sequence number tracking with gap detection (`ResendRequest`) and
+/// recovery (replaying stored messages with `PossDupFlag(43)`, and `SequenceReset` gap fills
+/// for session messages).
+pub struct FixSession {
+    config: FixSessionConfig,
+    store: Box<dyn FixMessageStore>,
+    logon_hook: Option<FixLogonHook>,
+    state: FixSessionState,
+    heartbeat_interval_secs: u64,
+    last_sent_ns: UnixNanos,
+    last_received_ns: UnixNanos,
+    pending_test_request: Option<(String, UnixNanos)>,
+    test_request_count: u64,
+    resend_requested: bool,
+    queued: BTreeMap<u64, (FixMessage, bool)>,
+}
+
+impl Debug for FixSession {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(FixSession))
+            .field("config", &self.config)
+            .field("store", &self.store)
+            .field("state", &self.state)
+            .field("has_logon_hook", &self.logon_hook.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+impl FixSession {
+    /// Creates a new [`FixSession`] instance.
+    #[must_use]
+    pub fn new(config: FixSessionConfig, store: Box<dyn FixMessageStore>) -> Self {
+        Self {
+            heartbeat_interval_secs: config.heartbeat_interval_secs,
+            config,
+            store,
+            logon_hook: None,
+            state: FixSessionState::Disconnected,
+            last_sent_ns: UnixNanos::default(),
+            last_received_ns: UnixNanos::default(),
+            pending_test_request: None,
+            test_request_count: 0,
+            resend_requested: false,
+            queued: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the `hook` applied to each outgoing `Logon` message.
+    #[must_use]
+    pub fn with_logon_hook(mut self, hook: FixLogonHook) -> Self {
+        self.logon_hook = Some(hook);
+        self
+    }
+
+    /// Returns the session configuration.
+    #[must_use]
+    pub const fn config(&self) -> &FixSessionConfig {
+        &self.config
+    }
+
+    /// Returns the current session state.
+    #[must_use]
+    pub const fn state(&self) -> FixSessionState {
+        self.state
+    }
+
+    /// Returns the heartbeat interval (seconds) in effect, as negotiated at logon.
+    #[must_use]
+    pub const fn heartbeat_interval_secs(&self) -> u64 {
+        self.heartbeat_interval_secs
+    }
+
+    /// Returns whether the session is logged on.
+    #[must_use]
+    pub fn is_logged_on(&self) -> bool {
+        self.state == FixSessionState::LoggedOn
+    }
+
+    /// Returns the next sequence number to send.
+    #[must_use]
+    pub fn next_sender_seq_num(&self) -> u64 {
+        self.store.next_sender_seq_num()
+    }
+
+    /// Returns the next sequence number expected from the counterparty.
+    #[must_use]
+    pub fn next_target_seq_num(&self) -> u64 {
+        self.store.next_target_seq_num()
+    }
+
+    /// Returns an encoded `Logon` message and transitions to [`FixSessionState::LogonSent`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the message store fails.
+    pub fn logon(&mut self, ts_now: UnixNanos) -> anyhow::Result<Vec<u8>> {
+        self.on_disconnected();
+        self.heartbeat_interval_secs = self.config.heartbeat_interval_secs;
+
+        if self.config.reset_seq_num_on_logon {
+            self.store.reset()?;
+        }
+
+        let mut message = FixMessage::new(msg_types::LOGON);
+        message
+            .set(tags::ENCRYPT_METHOD, 0)
+            .set(tags::HEART_BT_INT, self.config.heartbeat_interval_secs);
+        if self.config.reset_seq_num_on_logon {
+            message.set(tags::RESET_SEQ_NUM_FLAG, "Y");
+        }
+        if let Some(appl_ver_id) = self.config.version.default_appl_ver_id() {
+            message.set(tags::DEFAULT_APPL_VER_ID, appl_ver_id);
+        }
+        for (tag, value) in &self.config.logon_fields {
+            message.set(*tag, value);
+        }
+
+        let bytes = self.encode_outgoing(message, ts_now)?;
+        self.state = FixSessionState::LogonSent;
+        Ok(bytes)
+    }
+
+    /// Returns an encoded `Logout` message and transitions to [`FixSessionState::LogoutSent`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the message store fails.
+    pub fn logout(&mut self, text: Option<&str>, ts_now: UnixNanos) -> anyhow::Result<Vec<u8>> {
+        let mut message = FixMessage::new(msg_types::LOGOUT);
+        if let Some(text) = text {
+            message.set(tags::TEXT, text);
+        }
+
+        let bytes = self.encode_outgoing(message, ts_now)?;
+        self.state = FixSessionState::LogoutSent;
+        Ok(bytes)
+    }
+
+    /// Assigns the next sequence number and standard header to the `message`, stores it for
+    /// resending, and returns it encoded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if sending an application message when not logged on,
+    /// or if the message store fails.
+    pub fn send(&mut self, message: FixMessage, ts_now: UnixNanos) -> anyhow::Result<Vec<u8>> {
+        if !message.is_admin() && !self.is_logged_on() {
+            anyhow::bail!(
+                "Cannot send {} message: session {} is {}",
+                message.msg_type(),
+                self.config.session_id(),
+                self.state
+            );
+        }
+        self.encode_outgoing(message, ts_now)
+    }
+
+    /// Notifies the session that the transport connection was lost.
+    pub fn on_disconnected(&mut self) {
+        self.state = FixSessionState::Disconnected;
+        self.pending_test_request = None;
+        self.resend_requested = false;
+        self.queued.clear();
+    }
+
+    /// Handles a timer tick, sending heartbeats and test requests as required.
+    ///
+    /// Returns [`FixSessionAction::Disconnect`] if a `TestRequest` is not answered within the
+    /// heartbeat interval.
+    pub fn on_timer(&mut self, ts_now: UnixNanos) -> Vec<FixSessionAction> {
+        let mut actions = Vec::new();
+        if self.state != FixSessionState::LoggedOn {
+            return actions;
+        }
+
+        let interval_ns = self.heartbeat_interval_secs * NANOSECONDS_IN_SECOND;
+        let now = ts_now.as_u64();
+
+        if let Some((test_req_id, ts_sent)) = &self.pending_test_request {
+            if now.saturating_sub(ts_sent.as_u64()) >= interval_ns {
+                let reason = format!("TestRequest {test_req_id} not answered");
+                tracing::warn!("{reason}");
+                self.state = FixSessionState::Disconnected;
+                actions.push(FixSessionAction::Disconnect(reason));
+                return actions;
+            }
+        } else if now.saturating_sub(self.last_received_ns.as_u64())
+            >= interval_ns + interval_ns / 5
+        {
+            self.test_request_count += 1;
+            let test_req_id = format!("TEST-{}", self.test_request_count);
+            let mut message = FixMessage::new(msg_types::TEST_REQUEST);
+            message.set(tags::TEST_REQ_ID, &test_req_id);
+            self.send_admin(message, ts_now, &mut actions);
+            self.pending_test_request = Some((test_req_id, ts_now));
+        }
+
+        if now.saturating_sub(self.last_sent_ns.as_u64()) >= interval_ns {
+            self.send_admin(FixMessage::new(msg_types::HEARTBEAT), ts_now, &mut actions);
+        }
+
+        actions
+    }
+
+    /// Handles a message received from the counterparty.
+    pub fn on_message(&mut self, message: FixMessage, ts_now: UnixNanos) -> Vec<FixSessionAction> {
+        let mut actions = Vec::new();
+        self.last_received_ns = ts_now;
+
+        if message.begin_string() != self.config.version.begin_string() {
+            let text = format!("Incorrect BeginString '{}'", message.begin_string());
+            self.logout_and_disconnect(&text, ts_now, &mut actions);
+            return actions;
+        }
+
+        if message.get(tags::SENDER_COMP_ID) != Some(self.config.target_comp_id.as_str())
+            || message.get(tags::TARGET_COMP_ID) != Some(self.config.sender_comp_id.as_str())
+        {
+            self.logout_and_disconnect("CompID problem", ts_now, &mut actions);
+            return actions;
+        }
+
+        let Some(seq_num) = message.msg_seq_num() else {
+            self.logout_and_disconnect("MsgSeqNum(34) missing", ts_now, &mut actions);
+            return actions;
+        };
+
+        let msg_type = message.msg_type().to_string();
+        if let Some(text) = self.check_logon_sequence(&msg_type) {
+            self.logout_and_disconnect(&text, ts_now, &mut actions);
+            return actions;
+        }
+        if msg_type == msg_types::SEQUENCE_RESET && message.get(tags::GAP_FILL_FLAG) != Some("Y") {
+            // Reset mode ignores the message sequence number
+            self.handle_sequence_reset(&message);
+            return actions;
+        }
+        if msg_type == msg_types::LOGON && message.get(tags::RESET_SEQ_NUM_FLAG) == Some("Y") {
+            self.set_next_target_seq_num(seq_num);
+        }
+
+        let expected = self.store.next_target_seq_num();
+        if seq_num > expected {
+            tracing::warn!("Sequence gap detected: expected {expected}, received {seq_num}");
+
+            // Process these immediately so the session cannot deadlock during recovery
+            let processed = matches!(
+                msg_type.as_str(),
+                msg_types::LOGON | msg_types::RESEND_REQUEST | msg_types::LOGOUT
+            );
+            if processed {
+                self.process(message.clone(), ts_now, &mut actions);
+            }
+            if msg_type == msg_types::LOGOUT {
+                return actions;
+            }
+            self.queued.insert(seq_num, (message, processed));
+
+            if !self.resend_requested {
+                let mut resend_request = FixMessage::new(msg_types::RESEND_REQUEST);
+                resend_request
+                    .set(tags::BEGIN_SEQ_NO, expected)
+                    .set(tags::END_SEQ_NO, 0);
+                self.send_admin(resend_request, ts_now, &mut actions);
+                self.resend_requested = true;
+            }
+        } else if seq_num < expected {
+            if message.is_poss_dup() {
+                tracing::debug!("Ignoring possible duplicate {msg_type} message {seq_num}");
+            } else {
+                let text =
+                    format!("MsgSeqNum too low, expecting {expected} but received {seq_num}");
+                self.logout_and_disconnect(&text, ts_now, &mut actions);
+            }
+        } else {
+            self.set_next_target_seq_num(seq_num + 1);
+            self.process(message, ts_now, &mut actions);
+            self.process_queued(ts_now, &mut actions);
+        }
+
+        actions
+    }
+
+    /// Returns the reason to disconnect if a message of `msg_type` is not permitted before the
+    /// session is established, where the first message received must be a `Logon`.
+    fn check_logon_sequence(&self, msg_type: &str) -> Option<String> {
+        let permitted = match self.state {
+            FixSessionState::Disconnected => msg_type == msg_types::LOGON,
+            // A counterparty may refuse our logon with a `Logout`
+            FixSessionState::LogonSent => {
+                msg_type == msg_types::LOGON || msg_type == msg_types::LOGOUT
+            }
+            FixSessionState::LoggedOn | FixSessionState::LogoutSent => true,
+        };
+
+        (!permitted).then(|| format!("First message not a Logon: received {msg_type}"))
+    }
+
+    fn process(
+        &mut self,
+        message: FixMessage,
+        ts_now: UnixNanos,
+        actions: &mut Vec<FixSessionAction>,
+    ) {
+        if let Some(dictionary) = &self.config.dictionary
+            && let Err(e) = dictionary.validate(&message)
+        {
+            self.reject(&message, &e, ts_now, actions);
+            return;
+        }
+
+        match message.msg_type() {
+            msg_types::LOGON => match self.state {
+                FixSessionState::LogonSent => {
+                    self.state = FixSessionState::LoggedOn;
+                    tracing::info!("Logged on to {}", self.config.target_comp_id);
+                }
+                FixSessionState::Disconnected => self.accept_logon(&message, ts_now, actions),
+                _ => tracing::warn!("Unexpected Logon in state {}", self.state),
+            },
+            msg_types::HEARTBEAT => {
+                if let Some((test_req_id, _)) = &self.pending_test_request
+                    && message.get(tags::TEST_REQ_ID) == Some(test_req_id.as_str())
+                {
+                    self.pending_test_request = None;
+                }
+            }
+            msg_types::TEST_REQUEST => {
+                let mut heartbeat = FixMessage::new(msg_types::HEARTBEAT);
+                if let Some(test_req_id) = message.get(tags::TEST_REQ_ID) {
+                    heartbeat.set(tags::TEST_REQ_ID, test_req_id);
+                }
+                self.send_admin(heartbeat, ts_now, actions);
+            }
+            msg_types::RESEND_REQUEST => self.handle_resend_request(&message, ts_now, actions),
+            msg_types::SEQUENCE_RESET => self.handle_sequence_reset(&message),
+            msg_types::LOGOUT => {
+                let text = message.get(tags::TEXT).unwrap_or_default().to_string();
+                tracing::info!("Received Logout: {text}");
+                if self.state != FixSessionState::LogoutSent {
+                    self.send_admin(FixMessage::new(msg_types::LOGOUT), ts_now, actions);
+                }
+                self.state = FixSessionState::Disconnected;
+                actions.push(FixSessionAction::Disconnect(format!("Logout: {text}")));
+            }
+            msg_types::REJECT => {
+                tracing::warn!(
+                    "Received session Reject for message {}: {}",
+                    message.get(tags::REF_SEQ_NUM).unwrap_or("?"),
+                    message.get(tags::TEXT).unwrap_or_default()
+                );
+                actions.push(FixSessionAction::Deliver(message));
+            }
+            _ => actions.push(FixSessionAction::Deliver(message)),
+        }
+    }
+
+    fn accept_logon(
+        &mut self,
+        message: &FixMessage,
+        ts_now: UnixNanos,
+        actions: &mut Vec<FixSessionAction>,
+    ) {
+        let reset = message.get(tags::RESET_SEQ_NUM_FLAG) == Some("Y");
+        if reset && let Err(e) = self.store.set_next_sender_seq_num(1) {
+            tracing::error!("Cannot reset sender sequence number: {e}");
+        }
+
+        // The initiator determines the heartbeat interval for the session
+        self.heartbeat_interval_secs = match message.get_parsed::<u64>(tags::HEART_BT_INT) {
+            Ok(Some(interval_secs)) if interval_secs > 0 => interval_secs,
+            _ => self.config.heartbeat_interval_secs,
+        };
+
+        let mut response = FixMessage::new(msg_types::LOGON);
+        response
+            .set(tags::ENCRYPT_METHOD, 0)
+            .set(tags::HEART_BT_INT, self.heartbeat_interval_secs);
+        if reset {
+            response.set(tags::RESET_SEQ_NUM_FLAG, "Y");
+        }
+        if let Some(appl_ver_id) = self.config.version.default_appl_ver_id() {
+            response.set(tags::DEFAULT_APPL_VER_ID, appl_ver_id);
+        }
+        self.send_admin(response, ts_now, actions);
+        self.state = FixSessionState::LoggedOn;
+        tracing::info!("Accepted Logon from {}", self.config.target_comp_id);
+    }
+
+    fn reject(
+        &mut self,
+        message: &FixMessage,
+        error: &FixValidationError,
+        ts_now: UnixNanos,
+        actions: &mut Vec<FixSessionAction>,
+    ) {
+        tracing::warn!(
+            "Rejecting {} message {}: {error}",
+            message.msg_type(),
+            message.msg_seq_num().unwrap_or_default()
+        );
+
+        let mut reject = FixMessage::new(msg_types::REJECT);
+        reject
+            .set(tags::REF_SEQ_NUM, message.msg_seq_num().unwrap_or_default())
+            .set(tags::REF_MSG_TYPE, message.msg_type())
+            .set(tags::SESSION_REJECT_REASON, error.reason)
+            .set(tags::TEXT, &error.text);
+        if let Some(ref_tag) = error.ref_tag {
+            reject.set(tags::REF_TAG_ID, ref_tag);
+        }
+        self.send_admin(reject, ts_now, actions);
+    }
+
+    fn process_queued(&mut self, ts_now: UnixNanos, actions: &mut Vec<FixSessionAction>) {
+        loop {
+            let expected = self.store.next_target_seq_num();
+            self.queued.retain(|seq_num, _| *seq_num >= expected);
+
+            let Some((message, processed)) = self.queued.remove(&expected) else {
+                break;
+            };
+            self.set_next_target_seq_num(expected + 1);
+            if !processed {
+                self.process(message, ts_now, actions);
+            }
+        }
+
+        if self.queued.is_empty() {
+            self.resend_requested = false;
+        }
+    }
+
+    fn handle_resend_request(
+        &mut self,
+        message: &FixMessage,
+        ts_now: UnixNanos,
+        actions: &mut Vec<FixSessionAction>,
+    ) {
+        let range = message
+            .get_parsed::<u64>(tags::BEGIN_SEQ_NO)
+            .and_then(|begin| Ok((begin, message.get_parsed::<u64>(tags::END_SEQ_NO)?)));
+        let (begin, end) = match range {
+            Ok((Some(begin), end)) => (begin, end.unwrap_or_default()),
+            Ok((None, _)) => {
+                tracing::error!("Invalid ResendRequest: missing BeginSeqNo(7)");
+                return;
+            }
+            Err(e) => {
+                tracing::error!("Invalid ResendRequest: {e}");
+                return;
+            }
+        };
+
+        let last_sent = self.store.next_sender_seq_num().saturating_sub(1);
+        let end = if end == 0 || end > last_sent {
+            last_sent
+        } else {
+            end
+        };
+        if begin == 0 || begin > end {
+            return;
+        }
+        tracing::info!("Resending messages {begin} to {end}");
+
+        let mut next = begin;
+        for (seq_num, bytes) in self.store.outgoing(begin, end) {
+            let mut resend = match FixMessage::decode(&bytes) {
+                Ok(resend) => resend,
+                Err(e) => {
+                    tracing::error!("Cannot resend stored message {seq_num}: {e}");
+                    continue;
+                }
+            };
+            if seq_num > next {
+                actions.push(FixSessionAction::Send(self.gap_fill(next, seq_num, ts_now)));
+            }
+
+            if let Some(sending_time) = resend.get(tags::SENDING_TIME).map(ToString::to_string) {
+                resend.set(tags::ORIG_SENDING_TIME, sending_time);
+            }
+            resend
+                .set(tags::POSS_DUP_FLAG, "Y")
+                .set(tags::SENDING_TIME, format_timestamp(ts_now));
+            actions.push(FixSessionAction::Send(resend.encode()));
+            next = seq_num + 1;
+        }
+
+        if next <= end {
+            actions.push(FixSessionAction::Send(self.gap_fill(next, end + 1, ts_now)));
+        }
+        self.last_sent_ns = ts_now;
+    }
+
+    fn handle_sequence_reset(&mut self, message: &FixMessage) {
+        let new_seq_num = match message.get_parsed::<u64>(tags::NEW_SEQ_NO) {
+            Ok(Some(new_seq_num)) => new_seq_num,
+            Ok(None) => {
+                tracing::error!("Invalid SequenceReset: missing NewSeqNo(36)");
+                return;
+            }
+            Err(e) => {
+                tracing::error!("Invalid SequenceReset: {e}");
+                return;
+            }
+        };
+
+        let expected = self.store.next_target_seq_num();
+        if new_seq_num < expected {
+            tracing::warn!(
+                "Ignoring SequenceReset to {new_seq_num}: less than expected {expected}"
+            );
+            return;
+        }
+        self.set_next_target_seq_num(new_seq_num);
+    }
+
+    fn gap_fill(&self, seq_num: u64, new_seq_num: u64, ts_now: UnixNanos) -> Vec<u8> {
+        let mut message = FixMessage::new(msg_types::SEQUENCE_RESET);
+        self.stamp(&mut message, seq_num, ts_now);
+        message
+            .set(tags::POSS_DUP_FLAG, "Y")
+            .set(tags::GAP_FILL_FLAG, "Y")
+            .set(tags::NEW_SEQ_NO, new_seq_num);
+        message.encode()
+    }
+
+    fn stamp(&self, message: &mut FixMessage, seq_num: u64, ts_now: UnixNanos) {
+        message.set_begin_string(self.config.version.begin_string());
+        message
+            .set(tags::SENDER_COMP_ID, &self.config.sender_comp_id)
+            .set(tags::TARGET_COMP_ID, &self.config.target_comp_id)
+            .set(tags::MSG_SEQ_NUM, seq_num)
+            .set(tags::SENDING_TIME, format_timestamp(ts_now));
+    }
+
+    fn encode_outgoing(
+        &mut self,
+        mut message: FixMessage,
+        ts_now: UnixNanos,
+    ) -> anyhow::Result<Vec<u8>> {
+        let seq_num = self.store.next_sender_seq_num();
+        self.stamp(&mut message, seq_num, ts_now);
+        if message.msg_type() == msg_types::LOGON
+            && let Some(hook) = &self.logon_hook
+        {
+            hook(&mut message)?;
+        }
+
+        let bytes = message.encode();
+        if !message.is_admin() {
+            self.store.store_outgoing(seq_num, &bytes)?;
+        }
+        self.store.set_next_sender_seq_num(seq_num + 1)?;
+        self.last_sent_ns = ts_now;
+        Ok(bytes)
+    }
+
+    fn send_admin(
+        &mut self,
+        message: FixMessage,
+        ts_now: UnixNanos,
+        actions: &mut Vec<FixSessionAction>,
+    ) {
+        let msg_type = message.msg_type().to_string();
+        match self.encode_outgoing(message, ts_now) {
+            Ok(bytes) => actions.push(FixSessionAction::Send(bytes)),
+            Err(e) => tracing::error!("Cannot send {msg_type} message: {e}"),
+        }
+    }
+
+    fn set_next_target_seq_num(&mut self, seq_num: u64) {
+        if let Err(e) = self.store.set_next_target_seq_num(seq_num) {
+            tracing::error!("Cannot store next target sequence number: {e}");
+        }
+    }
+
+    fn logout_and_disconnect(
+        &mut self,
+        text: &str,
+        ts_now: UnixNanos,
+        actions: &mut Vec<FixSessionAction>,
+    ) {
+        tracing::error!("Session error: {text}");
+        let mut logout = FixMessage::new(msg_types::LOGOUT);
+        logout.set(tags::TEXT, text);
+        self.send_admin(logout, ts_now, actions);
+        self.state = FixSessionState::Disconnected;
+        actions.push(FixSessionAction::Disconnect(text.to_string()));
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+    use crate::fix::store::MemoryMessageStore;
+
+    const SECOND: u64 = NANOSECONDS_IN_SECOND;
+
+    fn incoming(msg_type: &str, seq_num: u64) -> FixMessage {
+        let mut message = FixMessage::new(msg_type);
+        message.set_begin_string("FIX.4.4");
+        message
+            .set(tags::SENDER_COMP_ID, "VENUE")
+            .set(tags::TARGET_COMP_ID, "CLIENT")
+            .set(tags::MSG_SEQ_NUM, seq_num)
+            .set(tags::SENDING_TIME, "20250101-00:00:00.000");
+        message
+    }
+
+    fn sent(actions: &[FixSessionAction]) -> Vec<FixMessage> {
+        actions
+            .iter()
+            .filter_map(|action| match action {
+                FixSessionAction::Send(bytes) => Some(FixMessage::decode(bytes).unwrap()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[fixture]
+    fn session() -> FixSession {
+        let mut config = FixSessionConfig::new("CLIENT", "VENUE");
+        config.heartbeat_interval_secs = 10;
+        FixSession::new(config, Box::new(MemoryMessageStore::new()))
+    }
+
+    #[fixture]
+    fn logged_on(mut session: FixSession) -> FixSession {
+        session.logon(UnixNanos::default()).unwrap();
+        session.on_message(incoming(msg_types::LOGON, 1), UnixNanos::default());
+        session
+    }
+
+    #[rstest]
+    fn test_logon_handshake(mut session: FixSession) {
+        let logon = FixMessage::decode(&session.logon(UnixNanos::default()).unwrap()).unwrap();
+
+        assert_eq!(logon.msg_type(), msg_types::LOGON);
+        assert_eq!(logon.msg_seq_num(), Some(1));
+        assert_eq!(logon.get(tags::HEART_BT_INT), Some("10"));
+        assert_eq!(session.state(), FixSessionState::LogonSent);
+
+        let actions = session.on_message(incoming(msg_types::LOGON, 1), UnixNanos::default());
+
+        assert!(actions.is_empty());
+        assert!(session.is_logged_on());
+        assert_eq!(session.next_sender_seq_num(), 2);
+        assert_eq!(session.next_target_seq_num(), 2);
+    }
+
+    #[rstest]
+    fn test_logon_received_when_disconnected_is_accepted(mut session: FixSession) {
+        let mut logon = incoming(msg_types::LOGON, 1);
+        logon.set(tags::HEART_BT_INT, 5);
+
+        let actions = session.on_message(logon, UnixNanos::default());
+        let sent_messages = sent(&actions);
+
+        assert_eq!(sent_messages.len(), 1);
+        assert_eq!(sent_messages[0].msg_type(), msg_types::LOGON);
+        assert_eq!(sent_messages[0].get(tags::HEART_BT_INT), Some("5"));
+        assert!(session.is_logged_on());
+        assert_eq!(session.heartbeat_interval_secs(), 5);
+
+        // Timers run on the negotiated interval rather than the configured 10 seconds
+        let actions = session.on_timer(UnixNanos::from(5 * SECOND));
+        assert_eq!(sent(&actions)[0].msg_type(), msg_types::HEARTBEAT);
+    }
+
+    #[rstest]
+    #[case(FixSessionState::Disconnected)]
+    #[case(FixSessionState::LogonSent)]
+    fn test_first_message_not_logon_logs_out(
+        mut session: FixSession,
+        #[case] state: FixSessionState,
+    ) {
+        if state == FixSessionState::LogonSent {
+            session.logon(UnixNanos::default()).unwrap();
+        }
+
+        let actions = session.on_message(
+            incoming(msg_types::EXECUTION_REPORT, 1),
+            UnixNanos::default(),
+        );
+
+        assert!(
+            !actions
+                .iter()
+                .any(|action| matches!(action, FixSessionAction::Deliver(_)))
+        );
+        assert_eq!(sent(&actions)[0].msg_type(), msg_types::LOGOUT);
+        assert!(matches!(
+            actions.last(),
+            Some(FixSessionAction::Disconnect(reason)) if reason.contains("First message not a Logon")
+        ));
+        assert_eq!(session.state(), FixSessionState::Disconnected);
+        assert_eq!(session.next_target_seq_num(), 1);
+    }
+
+    #[rstest]
+    fn test_send_application_message_when_not_logged_on_errors(mut session: FixSession) {
+        let result = session.send(
+            FixMessage::new(msg_types::NEW_ORDER_SINGLE),
+            UnixNanos::default(),
+        );
+
+        assert!(result.is_err());
+        assert_eq!(session.next_sender_seq_num(), 1);
+    }
+
+    #[rstest]
+    fn test_test_request_answered_with_heartbeat(mut logged_on: FixSession) {
+        let mut test_request = incoming(msg_types::TEST_REQUEST, 2);
+        test_request.set(tags::TEST_REQ_ID, "PING");
+
+        let actions = logged_on.on_message(test_request, UnixNanos::default());
+        let sent = sent(&actions);
+
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].msg_type(), msg_types::HEARTBEAT);
+        assert_eq!(sent[0].get(tags::TEST_REQ_ID), Some("PING"));
+    }
+
+    #[rstest]
+    fn test_timer_sends_heartbeat_then_test_request_then_disconnects(mut logged_on: FixSession) {
+        let actions = logged_on.on_timer(UnixNanos::from(10 * SECOND));
+        assert_eq!(sent(&actions)[0].msg_type(), msg_types::HEARTBEAT);
+
+        let actions = logged_on.on_timer(UnixNanos::from(12 * SECOND));
+        let sent_messages = sent(&actions);
+        assert_eq!(sent_messages[0].msg_type(), msg_types::TEST_REQUEST);
+        assert_eq!(sent_messages[0].get(tags::TEST_REQ_ID), Some("TEST-1"));
+
+        let actions = logged_on.on_timer(UnixNanos::from(22 * SECOND));
+        assert!(matches!(
+            actions.last(),
+            Some(FixSessionAction::Disconnect(reason)) if reason.contains("TEST-1")
+        ));
+        assert_eq!(logged_on.state(), FixSessionState::Disconnected);
+    }
+
+    #[rstest]
+    fn test_sequence_gap_requests_resend_and_delivers_in_order(mut logged_on: FixSession) {
+        let actions = logged_on.on_message(
+            incoming(msg_types::EXECUTION_REPORT, 4),
+            UnixNanos::default(),
+        );
+        let sent_messages = sent(&actions);
+
+        assert_eq!(sent_messages.len(), 1);
+        assert_eq!(sent_messages[0].msg_type(), msg_types::RESEND_REQUEST);
+        assert_eq!(sent_messages[0].get(tags::BEGIN_SEQ_NO), Some("2"));
+        assert_eq!(sent_messages[0].get(tags::END_SEQ_NO), Some("0"));
+
+        let mut resent = incoming(msg_types::EXECUTION_REPORT, 2);
+        resent.set(tags::POSS_DUP_FLAG, "Y");
+        let actions = logged_on.on_message(resent, UnixNanos::default());
+        assert!(
+            matches!(&actions[..], [FixSessionAction::Deliver(m)] if m.msg_seq_num() == Some(2))
+        );
+
+        let mut gap_fill = incoming(msg_types::SEQUENCE_RESET, 3);
+        gap_fill
+            .set(tags::GAP_FILL_FLAG, "Y")
+            .set(tags::NEW_SEQ_NO, 4);
+        let actions = logged_on.on_message(gap_fill, UnixNanos::default());
+
+        assert!(
+            matches!(&actions[..], [FixSessionAction::Deliver(m)] if m.msg_seq_num() == Some(4))
+        );
+        assert_eq!(logged_on.next_target_seq_num(), 5);
+    }
+
+    #[rstest]
+    fn test_resend_request_replays_application_messages_and_gap_fills_admin(
+        mut logged_on: FixSession,
+    ) {
+        let mut order = FixMessage::new(msg_types::NEW_ORDER_SINGLE);
+        order.set(tags::CL_ORD_ID, "O-1");
+        logged_on.send(order, UnixNanos::from(SECOND)).unwrap(); // Seq 2
+        logged_on.on_timer(UnixNanos::from(20 * SECOND)); // Heartbeat seq 3 (and TestRequest seq 4)
+
+        let mut resend_request = incoming(msg_types::RESEND_REQUEST, 2);
+        resend_request
+            .set(tags::BEGIN_SEQ_NO, 1)
+            .set(tags::END_SEQ_NO, 0);
+        let actions = logged_on.on_message(resend_request, UnixNanos::from(21 * SECOND));
+        let sent_messages = sent(&actions);
+
+        assert_eq!(sent_messages.len(), 3);
+        assert_eq!(sent_messages[0].msg_type(), msg_types::SEQUENCE_RESET);
+        assert_eq!(sent_messages[0].msg_seq_num(), Some(1));
+        assert_eq!(sent_messages[0].get(tags::NEW_SEQ_NO), Some("2"));
+        assert_eq!(sent_messages[1].msg_type(), msg_types::NEW_ORDER_SINGLE);
+        assert_eq!(sent_messages[1].msg_seq_num(), Some(2));
+        assert!(sent_messages[1].is_poss_dup());
+        assert_eq!(
+            sent_messages[1].get(tags::ORIG_SENDING_TIME),
+            Some("19700101-00:00:01.000")
+        );
+        assert_eq!(sent_messages[2].msg_type(), msg_types::SEQUENCE_RESET);
+        assert_eq!(sent_messages[2].msg_seq_num(), Some(3));
+        assert_eq!(sent_messages[2].get(tags::GAP_FILL_FLAG), Some("Y"));
+        assert_eq!(
+            sent_messages[2].get(tags::NEW_SEQ_NO),
+            Some(logged_on.next_sender_seq_num().to_string().as_str())
+        );
+    }
+
+    #[rstest]
+    fn test_sequence_too_low_logs_out(mut logged_on: FixSession) {
+        let actions = logged_on.on_message(incoming(msg_types::HEARTBEAT, 1), UnixNanos::default());
+
+        assert_eq!(sent(&actions)[0].msg_type(), msg_types::LOGOUT);
+        assert!(matches!(
+            actions.last(),
+            Some(FixSessionAction::Disconnect(reason)) if reason.contains("MsgSeqNum too low")
+        ));
+        assert_eq!(logged_on.state(), FixSessionState::Disconnected);
+    }
+
+    #[rstest]
+    fn test_fixt_logon_with_hook() {
+        let config = FixSessionConfig::new("CLIENT", "VENUE").with_version(FixVersion::Fix50Sp2);
+        let hook: FixLogonHook = Arc::new(|message: &mut FixMessage| {
+            let sending_time = message.get_required(tags::SENDING_TIME)?.to_string();
+            message.set(tags::TEXT, format!("signed:{sending_time}"));
+            Ok(())
+        });
+        let mut session =
+            FixSession::new(config, Box::new(MemoryMessageStore::new())).with_logon_hook(hook);
+
+        let logon = FixMessage::decode(&session.logon(UnixNanos::from(SECOND)).unwrap()).unwrap();
+
+        assert_eq!(logon.begin_string(), "FIXT.1.1");
+        assert_eq!(logon.get(tags::DEFAULT_APPL_VER_ID), Some("9"));
+        assert_eq!(logon.get(tags::TEXT), Some("signed:19700101-00:00:01.000"));
+        assert_eq!(session.config().session_id(), "FIXT.1.1-CLIENT-VENUE");
+    }
+
+    #[rstest]
+    fn test_incorrect_begin_string_logs_out(mut logged_on: FixSession) {
+        let mut heartbeat = incoming(msg_types::HEARTBEAT, 2);
+        heartbeat.set_begin_string("FIX.4.2");
+
+        let actions = logged_on.on_message(heartbeat, UnixNanos::default());
+
+        assert_eq!(sent(&actions)[0].msg_type(), msg_types::LOGOUT);
+        assert!(matches!(
+            actions.last(),
+            Some(FixSessionAction::Disconnect(reason)) if reason.contains("BeginString")
+        ));
+    }
+
+    #[rstest]
+    fn test_invalid_message_is_rejected_with_dictionary() {
+        let config = FixSessionConfig::new("CLIENT", "VENUE").with_standard_dictionary();
+        let mut session = FixSession::new(config, Box::new(MemoryMessageStore::new()));
+        session.logon(UnixNanos::default()).unwrap();
+        let mut logon = incoming(msg_types::LOGON, 1);
+        logon
+            .set(tags::ENCRYPT_METHOD, 0)
+            .set(tags::HEART_BT_INT, 30);
+        session.on_message(logon, UnixNanos::default());
+
+        let mut report = incoming(msg_types::EXECUTION_REPORT, 2);
+        report.set(tags::ORDER_ID, "V-1");
+        let actions = session.on_message(report, UnixNanos::default());
+        let sent_messages = sent(&actions);
+
+        assert_eq!(sent_messages.len(), 1);
+        assert_eq!(sent_messages[0].msg_type(), msg_types::REJECT);
+        assert_eq!(sent_messages[0].get(tags::REF_SEQ_NUM), Some("2"));
+        assert_eq!(sent_messages[0].get(tags::REF_MSG_TYPE), Some("8"));
+        assert_eq!(sent_messages[0].get(tags::SESSION_REJECT_REASON), Some("1"));
+        assert_eq!(sent_messages[0].get(tags::REF_TAG_ID), Some("17"));
+        assert_eq!(session.next_target_seq_num(), 3);
+        assert!(session.is_logged_on());
+    }
+
+    #[rstest]
+    fn test_logout_is_acknowledged(mut logged_on: FixSession) {
+        let actions = logged_on.on_message(incoming(msg_types::LOGOUT, 2), UnixNanos::default());
+
+        assert_eq!(sent(&actions)[0].msg_type(), msg_types::LOGOUT);
+        assert!(matches!(
+            actions.last(),
+            Some(FixSessionAction::Disconnect(_))
+        ));
+    }
+}
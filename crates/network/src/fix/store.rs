@@ -0,0 +1,402 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Sequence number and outgoing message stores for FIX sessions.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Debug,
+    fs::{File, OpenOptions},
+    io::{BufRead, BufReader, Read, Write},
+    path::Path,
+    sync::mpsc::{Receiver, Sender, channel},
+    thread::JoinHandle,
+};
+
+/// Stores the sequence numbers and sent messages of a FIX session.
+///
+/// Sent application messages are retained so they can be replayed in response to a
+/// `ResendRequest`.
+pub trait FixMessageStore: Debug + Send {
+    /// Returns the next sequence number to send.
+    fn next_sender_seq_num(&self) -> u64;
+
+    /// Returns the next sequence number expected from the counterparty.
+    fn next_target_seq_num(&self) -> u64;
+
+    /// Sets the next sequence number to send.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if persisting the sequence number fails.
+    fn set_next_sender_seq_num(&mut self, seq_num: u64) -> anyhow::Result<()>;
+
+    /// Sets the next sequence number expected from the counterparty.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if persisting the sequence number fails.
+    fn set_next_target_seq_num(&mut self, seq_num: u64) -> anyhow::Result<()>;
+
+    /// Stores an encoded outgoing message with sequence number `seq_num`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if persisting the message fails.
+    fn store_outgoing(&mut self, seq_num: u64, message: &[u8]) -> anyhow::Result<()>;
+
+    /// Returns the stored outgoing messages with sequence numbers in `begin..=end`.
+    fn outgoing(&self, begin: u64, end: u64) -> Vec<(u64, Vec<u8>)>;
+
+    /// Resets both sequence numbers to 1 and discards all stored messages.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if persisting the reset fails.
+    fn reset(&mut self) -> anyhow::Result<()>;
+}
+
+/// An in-memory [`FixMessageStore`], which does not survive a restart.
+#[derive(Debug)]
+pub struct MemoryMessageStore {
+    next_sender_seq_num: u64,
+    next_target_seq_num: u64,
+    messages: BTreeMap<u64, Vec<u8>>,
+}
+
+impl MemoryMessageStore {
+    /// Creates a new [`MemoryMessageStore`] instance.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            next_sender_seq_num: 1,
+            next_target_seq_num: 1,
+            messages: BTreeMap::new(),
+        }
+    }
+}
+
+impl Default for MemoryMessageStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FixMessageStore for MemoryMessageStore {
+    fn next_sender_seq_num(&self) -> u64 {
+        self.next_sender_seq_num
+    }
+
+    fn next_target_seq_num(&self) -> u64 {
+        self.next_target_seq_num
+    }
+
+    fn set_next_sender_seq_num(&mut self, seq_num: u64) -> anyhow::Result<()> {
+        self.next_sender_seq_num = seq_num;
+        Ok(())
+    }
+
+    fn set_next_target_seq_num(&mut self, seq_num: u64) -> anyhow::Result<()> {
+        self.next_target_seq_num = seq_num;
+        Ok(())
+    }
+
+    fn store_outgoing(&mut self, seq_num: u64, message: &[u8]) -> anyhow::Result<()> {
+        self.messages.insert(seq_num, message.to_vec());
+        Ok(())
+    }
+
+    fn outgoing(&self, begin: u64, end: u64) -> Vec<(u64, Vec<u8>)> {
+        if begin > end {
+            return Vec::new();
+        }
+        self.messages
+            .range(begin..=end)
+            .map(|(seq_num, message)| (*seq_num, message.clone()))
+            .collect()
+    }
+
+    fn reset(&mut self) -> anyhow::Result<()> {
+        *self = Self::new();
+        Ok(())
+    }
+}
+
+/// A file backed [`FixMessageStore`] which persists sequence numbers and sent messages
+/// across restarts.
+///
+/// Each session uses two files in the store directory: `<session_id>.seqnums` holding the
+/// next sender and target sequence numbers, and `<session_id>.body` holding the sent messages
+/// as length-prefixed records.
+///
+/// Writes are performed in order on a dedicated writer thread, so that updating the store
+/// never blocks the session on file I/O. The sequence numbers file is replaced atomically
+/// (written to a temporary file then renamed), and pending writes are completed on drop.
+#[derive(Debug)]
+pub struct FileMessageStore {
+    inner: MemoryMessageStore,
+    writer_tx: Option<Sender<StoreWrite>>,
+    writer_task: Option<JoinHandle<()>>,
+}
+
+#[derive(Debug)]
+enum StoreWrite {
+    SeqNums(u64, u64),
+    Message(u64, Vec<u8>),
+    Reset,
+}
+
+impl FileMessageStore {
+    /// Opens (or creates) the store for `session_id` in the `directory`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the store files cannot be created or are corrupt.
+    pub fn open(directory: impl AsRef<Path>, session_id: &str) -> anyhow::Result<Self> {
+        let directory = directory.as_ref();
+        std::fs::create_dir_all(directory)?;
+
+        let seqnums_path = directory.join(format!("{session_id}.seqnums"));
+        let body_path = directory.join(format!("{session_id}.body"));
+
+        let mut inner = MemoryMessageStore::new();
+        if seqnums_path.exists() {
+            let content = std::fs::read_to_string(&seqnums_path)?;
+            let (sender, target) = content
+                .trim()
+                .split_once(':')
+                .ok_or_else(|| anyhow::anyhow!("Corrupt sequence numbers file: '{content}'"))?;
+            inner.next_sender_seq_num = sender.parse()?;
+            inner.next_target_seq_num = target.parse()?;
+        }
+        if body_path.exists() {
+            inner.messages = Self::read_messages(&body_path)?;
+        }
+
+        let body = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&body_path)?;
+
+        let (writer_tx, writer_rx) = channel();
+        let writer_task = std::thread::Builder::new()
+            .name(format!("fix-store-{session_id}"))
+            .spawn(move || Self::run_writer(&seqnums_path, &body_path, body, &writer_rx))?;
+
+        Ok(Self {
+            inner,
+            writer_tx: Some(writer_tx),
+            writer_task: Some(writer_task),
+        })
+    }
+
+    fn read_messages(path: &Path) -> anyhow::Result<BTreeMap<u64, Vec<u8>>> {
+        let mut reader = BufReader::new(File::open(path)?);
+        let mut messages = BTreeMap::new();
+        let mut header = String::new();
+
+        loop {
+            header.clear();
+            if reader.read_line(&mut header)? == 0 {
+                break;
+            }
+            let (seq_num, len) = header
+                .trim_end()
+                .split_once(',')
+                .ok_or_else(|| anyhow::anyhow!("Corrupt message record header: '{header}'"))?;
+            let mut message = vec![0; len.parse()?];
+            reader.read_exact(&mut message)?;
+            messages.insert(seq_num.parse()?, message);
+        }
+
+        Ok(messages)
+    }
+
+    fn run_writer(
+        seqnums_path: &Path,
+        body_path: &Path,
+        mut body: File,
+        writer_rx: &Receiver<StoreWrite>,
+    ) {
+        while let Ok(write) = writer_rx.recv() {
+            let result = match write {
+                StoreWrite::SeqNums(sender, target) => {
+                    Self::write_seqnums(seqnums_path, sender, target)
+                }
+                StoreWrite::Message(seq_num, message) => {
+                    Self::write_message(&mut body, seq_num, &message)
+                }
+                StoreWrite::Reset => File::create(body_path)
+                    .map(|file| body = file)
+                    .map_err(anyhow::Error::from),
+            };
+            if let Err(e) = result {
+                tracing::error!("Failed to write FIX message store: {e}");
+            }
+        }
+    }
+
+    fn write_seqnums(path: &Path, sender: u64, target: u64) -> anyhow::Result<()> {
+        let tmp_path = path.with_extension("seqnums.tmp");
+        let mut file = File::create(&tmp_path)?;
+        file.write_all(format!("{sender}:{target}").as_bytes())?;
+        file.sync_all()?;
+        std::fs::rename(&tmp_path, path)?;
+        Ok(())
+    }
+
+    fn write_message(body: &mut File, seq_num: u64, message: &[u8]) -> anyhow::Result<()> {
+        body.write_all(format!("{seq_num},{}\n", message.len()).as_bytes())?;
+        body.write_all(message)?;
+        body.flush()?;
+        Ok(())
+    }
+
+    fn send_write(&self, write: StoreWrite) -> anyhow::Result<()> {
+        self.writer_tx
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("FIX message store closed"))?
+            .send(write)
+            .map_err(|_| anyhow::anyhow!("FIX message store writer stopped"))
+    }
+
+    fn send_seqnums(&self) -> anyhow::Result<()> {
+        self.send_write(StoreWrite::SeqNums(
+            self.inner.next_sender_seq_num,
+            self.inner.next_target_seq_num,
+        ))
+    }
+}
+
+impl Drop for FileMessageStore {
+    fn drop(&mut self) {
+        // Closing the channel stops the writer once pending writes are complete
+        self.writer_tx.take();
+        if let Some(writer_task) = self.writer_task.take()
+            && writer_task.join().is_err()
+        {
+            tracing::error!("FIX message store writer panicked");
+        }
+    }
+}
+
+impl FixMessageStore for FileMessageStore {
+    fn next_sender_seq_num(&self) -> u64 {
+        self.inner.next_sender_seq_num
+    }
+
+    fn next_target_seq_num(&self) -> u64 {
+        self.inner.next_target_seq_num
+    }
+
+    fn set_next_sender_seq_num(&mut self, seq_num: u64) -> anyhow::Result<()> {
+        self.inner.next_sender_seq_num = seq_num;
+        self.send_seqnums()
+    }
+
+    fn set_next_target_seq_num(&mut self, seq_num: u64) -> anyhow::Result<()> {
+        self.inner.next_target_seq_num = seq_num;
+        self.send_seqnums()
+    }
+
+    fn store_outgoing(&mut self, seq_num: u64, message: &[u8]) -> anyhow::Result<()> {
+        self.send_write(StoreWrite::Message(seq_num, message.to_vec()))?;
+        self.inner.store_outgoing(seq_num, message)
+    }
+
+    fn outgoing(&self, begin: u64, end: u64) -> Vec<(u64, Vec<u8>)> {
+        self.inner.outgoing(begin, end)
+    }
+
+    fn reset(&mut self) -> anyhow::Result<()> {
+        self.inner.reset()?;
+        self.send_write(StoreWrite::Reset)?;
+        self.send_seqnums()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_memory_store_outgoing_range() {
+        let mut store = MemoryMessageStore::new();
+        for seq_num in 1..=5 {
+            store
+                .store_outgoing(seq_num, format!("msg-{seq_num}").as_bytes())
+                .unwrap();
+        }
+
+        let messages = store.outgoing(2, 4);
+
+        assert_eq!(
+            messages.iter().map(|(seq, _)| *seq).collect::<Vec<_>>(),
+            vec![2, 3, 4]
+        );
+        assert!(store.outgoing(4, 2).is_empty());
+    }
+
+    #[rstest]
+    fn test_file_store_persists_across_reopen() {
+        let directory = tempfile::tempdir().unwrap();
+        {
+            let mut store = FileMessageStore::open(directory.path(), "FIX.4.4-A-B").unwrap();
+            store.set_next_sender_seq_num(3).unwrap();
+            store.set_next_target_seq_num(7).unwrap();
+            store.store_outgoing(1, b"first\nmessage").unwrap();
+            store.store_outgoing(2, b"second").unwrap();
+        }
+
+        let mut store = FileMessageStore::open(directory.path(), "FIX.4.4-A-B").unwrap();
+
+        assert_eq!(store.next_sender_seq_num(), 3);
+        assert_eq!(store.next_target_seq_num(), 7);
+        assert_eq!(
+            store.outgoing(1, 2),
+            vec![(1, b"first\nmessage".to_vec()), (2, b"second".to_vec())]
+        );
+
+        store.reset().unwrap();
+        drop(store);
+        let store = FileMessageStore::open(directory.path(), "FIX.4.4-A-B").unwrap();
+
+        assert_eq!(store.next_sender_seq_num(), 1);
+        assert_eq!(store.next_target_seq_num(), 1);
+        assert!(store.outgoing(1, 2).is_empty());
+    }
+
+    #[rstest]
+    fn test_file_store_replaces_seqnums_file() {
+        let directory = tempfile::tempdir().unwrap();
+        {
+            let mut store = FileMessageStore::open(directory.path(), "FIX.4.4-A-B").unwrap();
+            for seq_num in 2..=10 {
+                store.set_next_sender_seq_num(seq_num).unwrap();
+            }
+        }
+
+        let content =
+            std::fs::read_to_string(directory.path().join("FIX.4.4-A-B.seqnums")).unwrap();
+
+        assert_eq!(content, "10:1");
+        assert!(!directory.path().join("FIX.4.4-A-B.seqnums.tmp").exists());
+    }
+}
@@ -0,0 +1,307 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Turmoil integration tests for the FIX client against the simulated FIX acceptor.
+//!
+//! The acceptor acts as a fake venue so the full session layer (logon, order flow,
+//! sequence gap recovery and reconnection) is exercised with no real network.
+
+#![cfg(feature = "turmoil")]
+
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use nautilus_core::UnixNanos;
+use nautilus_network::{
+    fix::{
+        acceptor::{FixAcceptor, FixAcceptorConfig, FixAcceptorHandle, FixOrderResponse},
+        client::FixClient,
+        message::FixMessage,
+        messages::{
+            ExecutionReport, FixExecType, FixOrdStatus, FixOrdType, FixSide, NewOrderSingle,
+        },
+        session::{FixSession, FixSessionConfig},
+        store::MemoryMessageStore,
+    },
+    socket::SocketConfig,
+};
+use rstest::{fixture, rstest};
+use rust_decimal::Decimal;
+use tokio_tungstenite::tungstenite::stream::Mode;
+use turmoil::Builder;
+
+const VENUE_ADDR: &str = "0.0.0.0:9880";
+
+#[fixture]
+fn socket_config() -> SocketConfig {
+    SocketConfig {
+        url: "venue:9880".to_string(),
+        mode: Mode::Plain,
+        suffix: b"\r\n".to_vec(),
+        message_handler: None,
+        heartbeat: None,
+        reconnect_timeout_ms: Some(5_000),
+        reconnect_delay_initial_ms: Some(100),
+        reconnect_delay_max_ms: Some(500),
+        reconnect_backoff_factor: Some(1.5),
+        reconnect_jitter_ms: Some(10),
+        certs_dir: None,
+    }
+}
+
+fn client_session() -> FixSession {
+    FixSession::new(
+        FixSessionConfig::new("CLIENT", "VENUE"),
+        Box::new(MemoryMessageStore::new()),
+    )
+}
+
+fn acceptor_config() -> FixAcceptorConfig {
+    FixAcceptorConfig::new(FixSessionConfig::new("VENUE", "CLIENT"))
+}
+
+fn limit_order(cl_ord_id: &str) -> FixMessage {
+    NewOrderSingle {
+        cl_ord_id: cl_ord_id.to_string(),
+        account: None,
+        symbol: "BTC-USD".to_string(),
+        side: FixSide::Buy,
+        order_qty: Decimal::from(2),
+        ord_type: FixOrdType::Limit,
+        price: Some(Decimal::from(50_000)),
+        stop_px: None,
+        time_in_force: None,
+        exec_inst: None,
+        expire_time: None,
+        transact_time: UnixNanos::default(),
+    }
+    .to_message()
+}
+
+/// Builds a simulation hosting the `acceptor` as "venue", returning its handle.
+fn sim_with_venue(acceptor: FixAcceptor) -> (turmoil::Sim<'static>, FixAcceptorHandle) {
+    let handle = acceptor.handle();
+    let mut sim = Builder::new()
+        .simulation_duration(Duration::from_secs(30))
+        .build();
+    sim.host("venue", move || {
+        let acceptor = acceptor.clone();
+        async move {
+            acceptor.run(VENUE_ADDR).await?;
+            Ok(())
+        }
+    });
+    (sim, handle)
+}
+
+/// Connects a client collecting received execution reports.
+async fn connect_client(
+    socket_config: SocketConfig,
+) -> (FixClient, Arc<Mutex<Vec<ExecutionReport>>>) {
+    let reports = Arc::new(Mutex::new(Vec::new()));
+    let reports_clone = reports.clone();
+    let client = FixClient::connect(
+        socket_config,
+        client_session(),
+        Arc::new(move |message: FixMessage| {
+            if let Ok(report) = ExecutionReport::from_message(&message) {
+                reports_clone.lock().unwrap().push(report);
+            }
+        }),
+        Duration::from_secs(2),
+    )
+    .await
+    .expect("Should log on");
+    (client, reports)
+}
+
+async fn wait_for_reports(reports: &Mutex<Vec<ExecutionReport>>, count: usize) {
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while reports.lock().unwrap().len() < count {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("Should receive execution reports");
+}
+
+#[rstest]
+fn test_fix_logon_ack_and_fill(socket_config: SocketConfig) {
+    let acceptor = FixAcceptor::new(acceptor_config().with_order_script(|_| {
+        vec![
+            FixOrderResponse::Ack,
+            FixOrderResponse::PartialFill {
+                qty: Decimal::from(1),
+                price: None,
+            },
+            FixOrderResponse::Fill {
+                price: Some(Decimal::from(49_990)),
+            },
+        ]
+    }));
+    let (mut sim, handle) = sim_with_venue(acceptor);
+
+    sim.client("client", async move {
+        let (client, reports) = connect_client(socket_config).await;
+        assert!(client.is_logged_on());
+        assert!(handle.is_logged_on());
+
+        client.send(limit_order("O-1")).unwrap();
+        wait_for_reports(&reports, 3).await;
+
+        let reports = reports.lock().unwrap().clone();
+        assert_eq!(reports[0].exec_type, FixExecType::New);
+        assert_eq!(reports[1].ord_status, FixOrdStatus::PartiallyFilled);
+        assert_eq!(reports[1].last_px, Some(Decimal::from(50_000)));
+        assert_eq!(reports[2].ord_status, FixOrdStatus::Filled);
+        assert_eq!(reports[2].cum_qty, Decimal::from(2));
+        assert_eq!(reports[2].avg_px, Some(Decimal::from(49_995)));
+        assert_eq!(handle.received().len(), 1);
+
+        client.close().await;
+        Ok(())
+    });
+
+    sim.run().unwrap();
+}
+
+#[rstest]
+fn test_fix_order_rejected_by_script(socket_config: SocketConfig) {
+    let acceptor = FixAcceptor::new(acceptor_config().with_order_script(|order| {
+        if order.cl_ord_id == "O-BAD" {
+            vec![FixOrderResponse::Reject {
+                reason: "Insufficient margin".to_string(),
+            }]
+        } else {
+            vec![FixOrderResponse::Ack]
+        }
+    }));
+    let (mut sim, _handle) = sim_with_venue(acceptor);
+
+    sim.client("client", async move {
+        let (client, reports) = connect_client(socket_config).await;
+
+        client.send(limit_order("O-BAD")).unwrap();
+        client.send(limit_order("O-GOOD")).unwrap();
+        wait_for_reports(&reports, 2).await;
+
+        let reports = reports.lock().unwrap().clone();
+        assert_eq!(reports[0].exec_type, FixExecType::Rejected);
+        assert_eq!(reports[0].text.as_deref(), Some("Insufficient margin"));
+        assert_eq!(reports[1].exec_type, FixExecType::New);
+
+        client.close().await;
+        Ok(())
+    });
+
+    sim.run().unwrap();
+}
+
+#[rstest]
+fn test_fix_sequence_gap_recovered_with_resend(socket_config: SocketConfig) {
+    let acceptor = FixAcceptor::new(acceptor_config().with_order_script(|_| {
+        vec![
+            FixOrderResponse::Ack,
+            FixOrderResponse::Fill { price: None },
+        ]
+    }));
+    let (mut sim, handle) = sim_with_venue(acceptor);
+
+    sim.client("client", async move {
+        let (client, reports) = connect_client(socket_config).await;
+
+        // The ack is lost in transit, so the fill arrives first and is held until resent
+        handle.drop_outgoing(1);
+        client.send(limit_order("O-1")).unwrap();
+        wait_for_reports(&reports, 2).await;
+
+        let reports = reports.lock().unwrap().clone();
+        assert_eq!(reports[0].exec_type, FixExecType::New);
+        assert_eq!(reports[1].exec_type, FixExecType::Trade);
+
+        client.close().await;
+        Ok(())
+    });
+
+    sim.run().unwrap();
+}
+
+#[rstest]
+fn test_fix_reconnects_and_logs_on_after_disconnect(socket_config: SocketConfig) {
+    let acceptor = FixAcceptor::new(acceptor_config());
+    let (mut sim, handle) = sim_with_venue(acceptor);
+
+    sim.client("client", async move {
+        let (client, reports) = connect_client(socket_config).await;
+
+        handle.disconnect();
+        tokio::time::timeout(Duration::from_secs(10), async {
+            while handle.connection_count() < 2 || !handle.is_logged_on() || !client.is_logged_on()
+            {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("Should log on again");
+
+        client.send(limit_order("O-1")).unwrap();
+        wait_for_reports(&reports, 1).await;
+        assert_eq!(reports.lock().unwrap()[0].exec_type, FixExecType::New);
+
+        client.close().await;
+        Ok(())
+    });
+
+    sim.run().unwrap();
+}
+
+#[rstest]
+fn test_fix_logs_on_again_after_venue_logout(socket_config: SocketConfig) {
+    let acceptor = FixAcceptor::new(acceptor_config());
+    let (mut sim, handle) = sim_with_venue(acceptor);
+
+    sim.client("client", async move {
+        let (client, reports) = connect_client(socket_config).await;
+
+        handle.logout();
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while client.is_logged_on() {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("Should be logged out");
+
+        tokio::time::timeout(Duration::from_secs(15), async {
+            while handle.connection_count() < 2 || !handle.is_logged_on() || !client.is_logged_on()
+            {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("Should log on again");
+
+        client.send(limit_order("O-1")).unwrap();
+        wait_for_reports(&reports, 1).await;
+        assert_eq!(reports.lock().unwrap()[0].exec_type, FixExecType::New);
+
+        client.close().await;
+        Ok(())
+    });
+
+    sim.run().unwrap();
+}
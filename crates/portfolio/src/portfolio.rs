@@ -16,7 +16,7 @@
 //! Provides a generic `Portfolio` for all environments.
 
 use std::{
-    cell::RefCell,
+    cell::{Ref, RefCell, RefMut},
     collections::{HashMap, HashSet},
     fmt::Debug,
     rc::Rc,
@@ -34,7 +34,7 @@ use nautilus_common::{
 use nautilus_core::{WeakCell, datetime::NANOSECONDS_IN_MILLISECOND};
 use nautilus_model::{
     accounts::AccountAny,
-    data::{Bar, MarkPriceUpdate, QuoteTick},
+    data::{Bar, MarkPriceUpdate, QuoteTick, TradeTick},
     enums::{OmsType, OrderSide, OrderType, PositionSide, PriceType},
     events::{AccountState, OrderEventAny, position::PositionEvent},
     identifiers::{AccountId, InstrumentId, PositionId, Venue},
@@ -206,6 +206,15 @@ impl Portfolio {
             )))
         };
 
+        let update_trade_handler = {
+            let cache = cache.clone();
+            ShareableMessageHandler(Rc::new(TypedMessageHandler::from(
+                move |trade: &TradeTick| {
+                    update_trade_tick(&cache, trade);
+                },
+            )))
+        };
+
         let update_bar_handler = {
             let cache = cache.clone();
             let clock = clock.clone();
@@ -270,6 +279,7 @@ impl Portfolio {
         );
 
         msgbus::subscribe("data.quotes.*".into(), update_quote_handler, Some(10));
+        msgbus::subscribe("data.trades.*".into(), update_trade_handler, Some(10));
         if config.bar_updates {
             msgbus::subscribe("data.bars.*EXTERNAL".into(), update_bar_handler, Some(10));
         }
@@ -303,6 +313,18 @@ impl Portfolio {
         self.inner.borrow().initialized
     }
 
+    /// Returns a reference to the portfolio analyzer.
+    #[must_use]
+    pub fn analyzer(&self) -> Ref<'_, PortfolioAnalyzer> {
+        Ref::map(self.inner.borrow(), |inner| &inner.analyzer)
+    }
+
+    /// Returns a mutable reference to the portfolio analyzer.
+    #[must_use]
+    pub fn analyzer_mut(&self) -> RefMut<'_, PortfolioAnalyzer> {
+        RefMut::map(self.inner.borrow_mut(), |inner| &mut inner.analyzer)
+    }
+
     /// Returns the locked balances for the given venue.
     ///
     /// Locked balances represent funds reserved for open orders.
@@ -936,6 +958,11 @@ impl Portfolio {
         );
     }
 
+    /// Updates the price excursions of open positions based on a new trade tick.
+    pub fn update_trade_tick(&mut self, trade: &TradeTick) {
+        update_trade_tick(&self.cache, trade);
+    }
+
     /// Updates portfolio calculations based on a new bar.
     ///
     /// Updates cached bar close prices and recalculates unrealized PnL.
@@ -1597,9 +1624,18 @@ fn update_quote_tick(
     config: PortfolioConfig,
     quote: &QuoteTick,
 ) {
+    cache
+        .borrow_mut()
+        .update_position_excursions(&quote.instrument_id, quote.extract_price(PriceType::Mid));
     update_instrument_id(cache, clock.clone(), inner, config, &quote.instrument_id);
 }
 
+fn update_trade_tick(cache: &Rc<RefCell<Cache>>, trade: &TradeTick) {
+    cache
+        .borrow_mut()
+        .update_position_excursions(&trade.instrument_id, trade.price);
+}
+
 fn update_bar(
     cache: Rc<RefCell<Cache>>,
     clock: Rc<RefCell<dyn Clock>>,
@@ -1608,6 +1644,11 @@ fn update_bar(
     bar: &Bar,
 ) {
     let instrument_id = bar.bar_type.instrument_id();
+    {
+        let mut cache_ref = cache.borrow_mut();
+        cache_ref.update_position_excursions(&instrument_id, bar.high);
+        cache_ref.update_position_excursions(&instrument_id, bar.low);
+    }
     inner
         .borrow_mut()
         .bar_close_prices
@@ -18,8 +18,8 @@ use std::{cell::RefCell, rc::Rc};
 use nautilus_common::{cache::Cache, clock::TestClock};
 use nautilus_core::{UUID4, UnixNanos};
 use nautilus_model::{
-    data::{Bar, BarType, QuoteTick},
-    enums::{AccountType, LiquiditySide, OmsType, OrderSide, OrderType},
+    data::{Bar, BarType, QuoteTick, TradeTick},
+    enums::{AccountType, AggressorSide, LiquiditySide, OmsType, OrderSide, OrderType},
     events::{
         AccountState, OrderAccepted, OrderEventAny, OrderFilled, OrderSubmitted, PositionChanged,
         PositionClosed, PositionEvent, PositionOpened,
@@ -894,6 +894,43 @@ fn test_opening_one_long_position_updates_portfolio(
     assert!(!portfolio.is_completely_flat());
 }
 
+#[rstest]
+fn test_trade_ticks_update_position_excursions(
+    mut portfolio: Portfolio,
+    instrument_audusd: InstrumentAny,
+) {
+    let order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(instrument_audusd.id())
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from("10.00"))
+        .build();
+    let mut fill = fill_order(&order);
+    fill.position_id = Some(PositionId::new("SSD"));
+    let position = Position::new(&instrument_audusd, fill);
+    portfolio
+        .cache
+        .borrow_mut()
+        .add_position(position.clone(), OmsType::Hedging)
+        .unwrap();
+
+    for (i, multiplier) in [1.1, 0.9].into_iter().enumerate() {
+        portfolio.update_trade_tick(&TradeTick::new(
+            instrument_audusd.id(),
+            Price::new(position.avg_px_open * multiplier, 5),
+            Quantity::from("1.00"),
+            AggressorSide::Buyer,
+            TradeId::new(format!("T-{i}")),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        ));
+    }
+
+    let cache = portfolio.cache.borrow();
+    let position = cache.position(&position.id).unwrap();
+    assert!(position.max_favorable_excursion().unwrap() > 0.0);
+    assert!(position.max_adverse_excursion().unwrap() < 0.0);
+}
+
 #[rstest]
 fn test_opening_one_long_position_updates_portfolio_with_bar(
     mut portfolio: Portfolio,
@@ -19,9 +19,22 @@ use std::collections::HashMap;
 
 use nautilus_common::throttler::RateLimit;
 use nautilus_core::datetime::NANOSECONDS_IN_SECOND;
-use nautilus_model::identifiers::InstrumentId;
+use nautilus_model::{
+    enums::TradingState,
+    identifiers::{AccountId, InstrumentId},
+    types::Money,
+};
 use rust_decimal::Decimal;
 
+/// The maximum permitted deviation of an order price from the current market price.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PriceBand {
+    /// The maximum deviation in basis points of the market price.
+    Bps(Decimal),
+    /// The maximum deviation in multiples of the instrument price increment.
+    Ticks(u64),
+}
+
 #[derive(Debug, Clone)]
 /// Configuration for `RiskEngineConfig` instances.
 pub struct RiskEngineConfig {
@@ -29,6 +42,21 @@ pub struct RiskEngineConfig {
     pub max_order_submit: RateLimit,
     pub max_order_modify: RateLimit,
     pub max_notional_per_order: HashMap<InstrumentId, Decimal>,
+    /// The maximum deviation of order limit prices from the market mid (or last) price per instrument.
+    ///
+    /// Trigger prices are not checked, as stop and if-touched orders are expected to rest away
+    /// from the market.
+    pub max_price_deviation_per_instrument: HashMap<InstrumentId, PriceBand>,
+    /// The maximum absolute net position (in instrument quantity) per instrument.
+    pub max_position_per_instrument: HashMap<InstrumentId, Decimal>,
+    /// The maximum gross notional exposure of open positions per account.
+    pub max_gross_notional_per_account: HashMap<AccountId, Money>,
+    /// The maximum number of open orders per instrument.
+    pub max_open_orders_per_instrument: Option<usize>,
+    /// The maximum combined realized and unrealized loss per UTC day.
+    pub max_daily_loss: Option<Money>,
+    /// The trading state to transition to when the daily loss limit is breached.
+    pub daily_loss_trading_state: TradingState,
     pub debug: bool,
 }
 
@@ -40,6 +68,12 @@ impl Default for RiskEngineConfig {
             max_order_submit: RateLimit::new(100, NANOSECONDS_IN_SECOND),
             max_order_modify: RateLimit::new(100, NANOSECONDS_IN_SECOND),
             max_notional_per_order: HashMap::new(),
+            max_price_deviation_per_instrument: HashMap::new(),
+            max_position_per_instrument: HashMap::new(),
+            max_gross_notional_per_account: HashMap::new(),
+            max_open_orders_per_instrument: None,
+            max_daily_loss: None,
+            daily_loss_trading_state: TradingState::Halted,
             debug: false,
         }
     }
@@ -22,21 +22,28 @@ mod tests;
 
 use std::{cell::RefCell, collections::HashMap, fmt::Debug, rc::Rc};
 
-use config::RiskEngineConfig;
+use config::{PriceBand, RiskEngineConfig};
 use nautilus_common::{
     cache::Cache,
     clock::Clock,
     logging::{CMD, EVT, RECV},
     messages::execution::{ModifyOrder, SubmitOrder, SubmitOrderList, TradingCommand},
-    msgbus,
+    msgbus::{
+        self,
+        handler::{ShareableMessageHandler, TypedMessageHandler},
+    },
     throttler::Throttler,
 };
-use nautilus_core::UUID4;
+use nautilus_core::{UUID4, WeakCell, datetime::NANOSECONDS_IN_SECOND};
 use nautilus_model::{
     accounts::{Account, AccountAny},
-    enums::{InstrumentClass, OrderSide, OrderStatus, TimeInForce, TradingState},
-    events::{OrderDenied, OrderEventAny, OrderModifyRejected},
-    identifiers::InstrumentId,
+    data::QuoteTick,
+    enums::{
+        ContingencyType, InstrumentClass, OrderSide, OrderStatus, PositionSide, PriceType,
+        TimeInForce, TradingState,
+    },
+    events::{OrderDenied, OrderEventAny, OrderModifyRejected, PositionEvent},
+    identifiers::{AccountId, InstrumentId, Venue},
     instruments::{Instrument, InstrumentAny},
     orders::{Order, OrderAny, OrderList},
     types::{Currency, Money, Price, Quantity},
@@ -45,6 +52,8 @@ use nautilus_portfolio::Portfolio;
 use rust_decimal::{Decimal, prelude::ToPrimitive};
 use ustr::Ustr;
 
+const NANOSECONDS_IN_DAY: u64 = 86_400 * NANOSECONDS_IN_SECOND;
+
 type SubmitOrderFn = Box<dyn Fn(SubmitOrder)>;
 type ModifyOrderFn = Box<dyn Fn(ModifyOrder)>;
 
@@ -63,6 +72,9 @@ pub struct RiskEngine {
     pub throttled_modify_order: Throttler<ModifyOrder, ModifyOrderFn>,
     max_notional_per_order: HashMap<InstrumentId, Decimal>,
     trading_state: TradingState,
+    daily_pnl_start: Option<(u64, f64)>,
+    daily_loss_breached: bool,
+    daily_loss_state_set: bool,
     config: RiskEngineConfig,
 }
 
@@ -94,6 +106,9 @@ impl RiskEngine {
             throttled_modify_order,
             max_notional_per_order: HashMap::new(),
             trading_state: TradingState::Active,
+            daily_pnl_start: None,
+            daily_loss_breached: false,
+            daily_loss_state_set: false,
             config,
         }
     }
@@ -267,8 +282,58 @@ impl RiskEngine {
         self.handle_event(event);
     }
 
+    /// Subscribes the `engine` to quotes and position events on the message bus.
+    ///
+    /// Each message re-evaluates the daily loss limit, after the portfolio has been updated.
+    pub fn register_message_handlers(engine: &Rc<RefCell<Self>>) {
+        let engine_weak = WeakCell::from(Rc::downgrade(engine));
+
+        let quote_handler = {
+            let engine = engine_weak.clone();
+            ShareableMessageHandler(Rc::new(TypedMessageHandler::from(
+                move |quote: &QuoteTick| {
+                    if let Some(engine_rc) = engine.upgrade() {
+                        engine_rc.borrow_mut().process_quote(quote);
+                    }
+                },
+            )))
+        };
+
+        let position_handler = ShareableMessageHandler(Rc::new(TypedMessageHandler::from(
+            move |event: &PositionEvent| {
+                if let Some(engine_rc) = engine_weak.upgrade() {
+                    engine_rc.borrow_mut().process_position_event(event);
+                }
+            },
+        )));
+
+        // Lower priority than the portfolio handlers so the PnL is current
+        msgbus::subscribe("data.quotes.*".into(), quote_handler, Some(5));
+        msgbus::subscribe("events.position.*".into(), position_handler, Some(5));
+    }
+
+    /// Processes a quote for risk monitoring, re-evaluating the daily loss limit.
+    pub fn process_quote(&mut self, _quote: &QuoteTick) {
+        self.update_daily_loss();
+    }
+
+    /// Processes a position event for risk monitoring, re-evaluating the daily loss limit.
+    pub fn process_position_event(&mut self, event: &PositionEvent) {
+        if self.config.debug {
+            log::debug!("{RECV}{EVT} {event:?}");
+        }
+
+        self.update_daily_loss();
+    }
+
     /// Sets the trading state for risk control enforcement.
     pub fn set_trading_state(&mut self, state: TradingState) {
+        // An explicitly set state is never restored by the daily loss reset
+        self.daily_loss_state_set = false;
+        self.apply_trading_state(state);
+    }
+
+    fn apply_trading_state(&mut self, state: TradingState) {
         if state == self.trading_state {
             log::warn!("No change to trading state: already set to {state:?}");
             return;
@@ -377,6 +442,12 @@ impl RiskEngine {
             return; // Denied
         }
 
+        if !self.check_orders_limits(&instrument, std::slice::from_ref(order)) {
+            return; // Denied
+        }
+
+        self.update_daily_loss();
+
         // Route through execution gateway for TradingState checks & throttling
         self.execution_gateway(instrument, TradingCommand::SubmitOrder(command));
     }
@@ -419,6 +490,16 @@ impl RiskEngine {
             return; // Denied
         }
 
+        if !self.check_orders_limits(&instrument, &command.order_list.orders) {
+            self.deny_order_list(
+                command.order_list.clone(),
+                &format!("OrderList {} DENIED", command.order_list.id),
+            );
+            return; // Denied
+        }
+
+        self.update_daily_loss();
+
         self.execution_gateway(instrument, TradingCommand::SubmitOrderList(command));
     }
 
@@ -490,6 +571,13 @@ impl RiskEngine {
             return; // Denied
         }
 
+        // Check price band (limit prices only, trigger prices rest away from the market)
+        risk_msg = self.check_price_band(&instrument, command.price);
+        if let Some(risk_msg) = risk_msg {
+            self.reject_modify_order(order, &risk_msg);
+            return; // Denied
+        }
+
         // Check Quantity
         risk_msg = self.check_quantity(&instrument, command.quantity, order.is_quote_quantity());
         if let Some(risk_msg) = risk_msg {
@@ -573,6 +661,15 @@ impl RiskEngine {
             }
         }
 
+        ////////////////////////////////////////////////////////////////////////////////
+        // CHECK PRICE BAND
+        ////////////////////////////////////////////////////////////////////////////////
+        let risk_msg = self.check_price_band(&instrument, order.price());
+        if let Some(risk_msg) = risk_msg {
+            self.deny_order(order, &risk_msg);
+            return false; // Denied
+        }
+
         true
     }
 
@@ -927,6 +1024,221 @@ impl RiskEngine {
         true // Passed
     }
 
+    fn check_orders_limits(&self, instrument: &InstrumentAny, orders: &[OrderAny]) -> bool {
+        let instrument_id = instrument.id();
+        let (open_orders, mut net_position, account_id) = {
+            let cache = self.cache.borrow();
+            let open_orders = cache.orders_open_count(None, Some(&instrument_id), None, None);
+            let net_position: Decimal = cache
+                .positions_open(None, Some(&instrument_id), None, None)
+                .iter()
+                .map(|position| match position.side {
+                    PositionSide::Long => position.quantity.as_decimal(),
+                    PositionSide::Short => -position.quantity.as_decimal(),
+                    _ => Decimal::ZERO,
+                })
+                .sum();
+            let account_id = cache
+                .account_for_venue(&instrument_id.venue)
+                .map(|account| account.id());
+            (open_orders, net_position, account_id)
+        };
+
+        let max_position = self
+            .config
+            .max_position_per_instrument
+            .get(&instrument_id)
+            .copied();
+        let max_gross_notional = account_id.and_then(|account_id| {
+            self.config
+                .max_gross_notional_per_account
+                .get(&account_id)
+                .map(|max_gross_notional| (account_id, *max_gross_notional))
+        });
+        let other_gross_notional =
+            max_gross_notional.and_then(|(account_id, max_gross_notional)| {
+                self.gross_notional(account_id, &instrument_id, max_gross_notional.currency)
+            });
+
+        for (i, order) in orders.iter().enumerate() {
+            ////////////////////////////////////////////////////////////////////////////////
+            // CHECK OPEN ORDERS
+            ////////////////////////////////////////////////////////////////////////////////
+            if let Some(max_open_orders) = self.config.max_open_orders_per_instrument
+                && open_orders + i >= max_open_orders
+            {
+                self.deny_order(
+                    order.clone(),
+                    &format!(
+                        "MAX_OPEN_ORDERS_EXCEEDED: max_open_orders={max_open_orders}, open_orders={}",
+                        open_orders + i
+                    ),
+                );
+                return false; // Denied
+            }
+
+            if max_position.is_none() && other_gross_notional.is_none() {
+                continue;
+            }
+
+            let price = order
+                .price()
+                .or_else(|| order.trigger_price())
+                .or_else(|| self.last_price(&instrument_id));
+            let quantity = if order.is_quote_quantity() && !instrument.is_inverse() {
+                let Some(price) = price else {
+                    log::warn!(
+                        "Cannot check position limits for {}: no prices for {instrument_id}",
+                        order.client_order_id()
+                    );
+                    continue;
+                };
+                instrument.calculate_base_quantity(order.quantity(), price)
+            } else {
+                order.quantity()
+            };
+
+            let position = match order.order_side() {
+                OrderSide::Buy => net_position + quantity.as_decimal(),
+                OrderSide::Sell => net_position - quantity.as_decimal(),
+                OrderSide::NoOrderSide => {
+                    panic!("invalid `OrderSide`, was {}", order.order_side());
+                }
+            };
+            let previous_position = net_position;
+
+            // Only one of a set of OCO or OUO orders can be filled in full, so each is checked
+            // against the exposure before its siblings (e.g. bracket stop-loss and take-profit)
+            if !matches!(
+                order.contingency_type(),
+                Some(ContingencyType::Oco | ContingencyType::Ouo)
+            ) {
+                net_position = position;
+            }
+
+            if position.abs() <= previous_position.abs() {
+                continue; // Reduces exposure
+            }
+
+            ////////////////////////////////////////////////////////////////////////////////
+            // CHECK POSITION
+            ////////////////////////////////////////////////////////////////////////////////
+            if let Some(max_position) = max_position
+                && position.abs() > max_position
+            {
+                self.deny_order(
+                    order.clone(),
+                    &format!(
+                        "POSITION_EXCEEDS_MAX_PER_INSTRUMENT: max_position={max_position}, position={position}"
+                    ),
+                );
+                return false; // Denied
+            }
+
+            ////////////////////////////////////////////////////////////////////////////////
+            // CHECK GROSS NOTIONAL
+            ////////////////////////////////////////////////////////////////////////////////
+            if let (Some((_, max_gross_notional)), Some(other_gross_notional)) =
+                (max_gross_notional, other_gross_notional)
+            {
+                let Some(price) = price else {
+                    log::warn!(
+                        "Cannot check gross notional for {}: no prices for {instrument_id}",
+                        order.client_order_id()
+                    );
+                    continue;
+                };
+                let Ok(position_quantity) =
+                    Quantity::from_decimal(position.abs(), instrument.size_precision())
+                else {
+                    log::error!("Cannot check gross notional: invalid position {position}");
+                    continue;
+                };
+                let notional = instrument.calculate_notional_value(position_quantity, price, None);
+                let Some(notional) =
+                    self.convert_to(notional, max_gross_notional.currency, &instrument_id.venue)
+                else {
+                    continue;
+                };
+
+                let gross_notional =
+                    Money::new(other_gross_notional + notional, max_gross_notional.currency);
+                if gross_notional > max_gross_notional {
+                    self.deny_order(
+                        order.clone(),
+                        &format!(
+                            "GROSS_NOTIONAL_EXCEEDS_MAX_PER_ACCOUNT: max_gross_notional={max_gross_notional}, gross_notional={gross_notional}"
+                        ),
+                    );
+                    return false; // Denied
+                }
+            }
+        }
+
+        true // Passed
+    }
+
+    /// Returns the gross notional of the open positions for the `account_id`, excluding the
+    /// `instrument_id`, in the given `currency`.
+    fn gross_notional(
+        &self,
+        account_id: AccountId,
+        instrument_id: &InstrumentId,
+        currency: Currency,
+    ) -> Option<f64> {
+        let positions: Vec<(InstrumentAny, Quantity, f64)> = {
+            let cache = self.cache.borrow();
+            cache
+                .positions_open(None, None, None, None)
+                .into_iter()
+                .filter(|position| {
+                    position.account_id == account_id && position.instrument_id != *instrument_id
+                })
+                .filter_map(|position| {
+                    let instrument = cache.instrument(&position.instrument_id)?;
+                    Some((instrument.clone(), position.quantity, position.avg_px_open))
+                })
+                .collect()
+        };
+
+        let mut gross_notional = 0.0;
+        for (instrument, quantity, avg_px_open) in positions {
+            let price = self
+                .last_price(&instrument.id())
+                .unwrap_or_else(|| Price::new(avg_px_open, instrument.price_precision()));
+            let notional = instrument.calculate_notional_value(quantity, price, None);
+            gross_notional += self.convert_to(notional, currency, &instrument.id().venue)?;
+        }
+
+        Some(gross_notional)
+    }
+
+    fn last_price(&self, instrument_id: &InstrumentId) -> Option<Price> {
+        let cache = self.cache.borrow();
+        cache
+            .quote(instrument_id)
+            .map(|quote| quote.extract_price(PriceType::Mid))
+            .or_else(|| cache.trade(instrument_id).map(|trade| trade.price))
+    }
+
+    fn convert_to(&self, money: Money, currency: Currency, venue: &Venue) -> Option<f64> {
+        if money.currency == currency {
+            return Some(money.as_f64());
+        }
+
+        let xrate = self
+            .cache
+            .borrow()
+            .get_xrate(*venue, money.currency, currency, PriceType::Mid);
+        if xrate.is_none() {
+            log::warn!(
+                "Cannot convert {} to {currency}: no exchange rate for {venue}",
+                money.currency
+            );
+        }
+        xrate.map(|xrate| money.as_f64() * xrate)
+    }
+
     fn check_price(&self, instrument: &InstrumentAny, price: Option<Price>) -> Option<String> {
         let price_val = price?;
 
@@ -946,6 +1258,45 @@ impl RiskEngine {
         None
     }
 
+    fn check_price_band(&self, instrument: &InstrumentAny, price: Option<Price>) -> Option<String> {
+        let price_val = price?;
+        let price_band = self
+            .config
+            .max_price_deviation_per_instrument
+            .get(&instrument.id())?;
+
+        let Some(market_price) = self.last_price(&instrument.id()) else {
+            log::warn!(
+                "Cannot check price band: no market price for {}",
+                instrument.id()
+            );
+            return None;
+        };
+
+        let deviation = (price_val.as_decimal() - market_price.as_decimal()).abs();
+        let exceeded = match price_band {
+            PriceBand::Bps(max_bps) => {
+                market_price.raw > 0
+                    && deviation * Decimal::from(10_000) / market_price.as_decimal() > *max_bps
+            }
+            PriceBand::Ticks(max_ticks) => {
+                deviation > instrument.price_increment().as_decimal() * Decimal::from(*max_ticks)
+            }
+        };
+
+        if exceeded {
+            let max_deviation = match price_band {
+                PriceBand::Bps(max_bps) => format!("{max_bps} bps"),
+                PriceBand::Ticks(max_ticks) => format!("{max_ticks} ticks"),
+            };
+            return Some(format!(
+                "PRICE_OUTSIDE_BAND: price={price_val}, market_price={market_price}, max_deviation={max_deviation}"
+            ));
+        }
+
+        None
+    }
+
     fn check_quantity(
         &self,
         instrument: &InstrumentAny,
@@ -990,6 +1341,79 @@ impl RiskEngine {
         None
     }
 
+    // -- DAILY LOSS ------------------------------------------------------------------------------
+
+    /// Evaluates the daily realized and unrealized PnL against the `max_daily_loss` limit.
+    ///
+    /// The daily PnL is measured from the first evaluation of each UTC day. When the loss
+    /// limit is breached the trading state transitions to `daily_loss_trading_state`, and
+    /// is restored to `Active` at the start of the next day unless the trading state has
+    /// since been set explicitly.
+    fn update_daily_loss(&mut self) {
+        let Some(max_daily_loss) = self.config.max_daily_loss else {
+            return;
+        };
+
+        let day = self.clock.borrow().timestamp_ns().as_u64() / NANOSECONDS_IN_DAY;
+        let Some(total_pnl) = self.total_pnl(max_daily_loss.currency) else {
+            return;
+        };
+
+        let start_pnl = match self.daily_pnl_start {
+            Some((start_day, start_pnl)) if start_day == day => start_pnl,
+            _ => {
+                self.daily_pnl_start = Some((day, total_pnl));
+                self.daily_loss_breached = false;
+                if self.daily_loss_state_set {
+                    self.daily_loss_state_set = false;
+                    log::info!("Daily loss limit reset for new trading day");
+                    self.apply_trading_state(TradingState::Active);
+                }
+                total_pnl
+            }
+        };
+
+        let daily_pnl = total_pnl - start_pnl;
+        if self.daily_loss_breached || daily_pnl > -max_daily_loss.as_f64() {
+            return;
+        }
+
+        self.daily_loss_breached = true;
+        log::warn!(
+            "MAX_DAILY_LOSS breached: max_daily_loss={max_daily_loss}, daily_pnl={}",
+            Money::new(daily_pnl, max_daily_loss.currency)
+        );
+
+        if self.trading_state != TradingState::Halted
+            && self.trading_state != self.config.daily_loss_trading_state
+        {
+            self.apply_trading_state(self.config.daily_loss_trading_state);
+            self.daily_loss_state_set = true;
+        }
+    }
+
+    /// Returns the total realized and unrealized PnL across all venues in the given `currency`.
+    fn total_pnl(&mut self, currency: Currency) -> Option<f64> {
+        let mut venues: Vec<Venue> = self
+            .cache
+            .borrow()
+            .positions(None, None, None, None)
+            .iter()
+            .map(|position| position.instrument_id.venue)
+            .collect();
+        venues.sort();
+        venues.dedup();
+
+        let mut total_pnl = 0.0;
+        for venue in venues {
+            for pnl in self.portfolio.total_pnls(&venue).into_values() {
+                total_pnl += self.convert_to(pnl, currency, &venue)?;
+            }
+        }
+
+        Some(total_pnl)
+    }
+
     // -- DENIALS ---------------------------------------------------------------------------------
 
     fn deny_command(&self, command: TradingCommand, reason: &str) {
@@ -1151,5 +1575,9 @@ impl RiskEngine {
         if self.config.debug {
             log::debug!("{RECV}{EVT} {event:?}");
         }
+
+        if matches!(event, OrderEventAny::Filled(_)) {
+            self.update_daily_loss();
+        }
     }
 }
@@ -25,7 +25,7 @@ use nautilus_common::{
         self,
         handler::ShareableMessageHandler,
         stubs::{get_message_saving_handler, get_saved_messages},
-        switchboard::MessagingSwitchboard,
+        switchboard::{self, MessagingSwitchboard},
     },
     throttler::RateLimit,
 };
@@ -37,7 +37,10 @@ use nautilus_model::{
         stubs::{cash_account, margin_account},
     },
     data::{QuoteTick, stubs::quote_audusd},
-    enums::{AccountType, LiquiditySide, OrderSide, OrderType, TimeInForce, TradingState},
+    enums::{
+        AccountType, ContingencyType, LiquiditySide, OmsType, OrderSide, OrderType, TimeInForce,
+        TradingState,
+    },
     events::{
         AccountState, OrderAccepted, OrderEventAny, OrderEventType, OrderFilled, OrderSubmitted,
         account::stubs::cash_account_state_million_usd,
@@ -55,6 +58,7 @@ use nautilus_model::{
         stubs::{audusd_sim, crypto_perpetual_ethusdt, xbtusd_bitmex},
     },
     orders::{Order, OrderAny, OrderList, OrderTestBuilder},
+    position::Position,
     types::{AccountBalance, Currency, Money, Price, Quantity, fixed::FIXED_PRECISION},
 };
 use nautilus_portfolio::Portfolio;
@@ -164,6 +168,7 @@ fn test_deny_order_exceeding_max_notional(
         max_order_submit: RateLimit::new(10, 1000),
         max_order_modify: RateLimit::new(5, 1000),
         max_notional_per_order: HashMap::new(),
+        ..Default::default()
     };
 
     let mut risk_engine = get_risk_engine(
@@ -206,7 +211,10 @@ fn test_deny_order_exceeding_max_notional(
     matches!(saved_events[0], OrderEventAny::Denied(_));
 }
 
-use super::{RiskEngine, config::RiskEngineConfig};
+use super::{
+    RiskEngine,
+    config::{PriceBand, RiskEngineConfig},
+};
 
 #[fixture]
 fn process_order_event_handler() -> ShareableMessageHandler {
@@ -301,6 +309,7 @@ fn config_fixture(
         max_order_submit,
         max_order_modify,
         max_notional_per_order,
+        ..Default::default()
     }
 }
 
@@ -400,6 +409,7 @@ fn get_risk_engine(
         max_order_submit: RateLimit::new(10, 1000),
         max_order_modify: RateLimit::new(5, 1000),
         max_notional_per_order: HashMap::new(),
+        ..Default::default()
     });
     let clock = clock.unwrap_or(Rc::new(RefCell::new(TestClock::new())));
     let portfolio = Portfolio::new(cache.clone(), clock.clone(), None);
@@ -3944,3 +3954,760 @@ fn test_submit_order_with_quote_quantity_exceeds_max_after_conversion(
             .contains("QUANTITY_EXCEEDS_MAXIMUM")
     );
 }
+
+// RISK LIMIT TESTS
+fn position_from_fills(
+    instrument: &InstrumentAny,
+    fills: &[(OrderSide, &str, &str)], // (side, quantity, price)
+) -> Position {
+    let mut position: Option<Position> = None;
+    for (i, (side, quantity, price)) in fills.iter().enumerate() {
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(*side)
+            .quantity(Quantity::from(*quantity))
+            .client_order_id(ClientOrderId::from(format!("O-{i}").as_str()))
+            .build();
+        let mut fill = order_filled(
+            &order,
+            instrument,
+            None,
+            Some(account_id()),
+            None,
+            None,
+            None,
+            Some(Price::from(*price)),
+            None,
+            None,
+            None,
+        );
+        fill.position_id = Some(PositionId::from("P-001"));
+
+        match position.as_mut() {
+            Some(position) => position.apply(&fill),
+            None => position = Some(Position::new(instrument, fill)),
+        }
+    }
+    position.expect("at least one fill")
+}
+
+#[rstest]
+fn test_submit_order_when_position_would_exceed_max_then_denies(
+    strategy_id_ema_cross: StrategyId,
+    client_id_binance: ClientId,
+    trader_id: TraderId,
+    client_order_id: ClientOrderId,
+    instrument_audusd: InstrumentAny,
+    venue_order_id: VenueOrderId,
+    process_order_event_handler: ShareableMessageHandler,
+    cash_account_state_million_usd: AccountState,
+    mut simple_cache: Cache,
+) {
+    msgbus::register(
+        MessagingSwitchboard::exec_engine_process(),
+        process_order_event_handler.clone(),
+    );
+
+    simple_cache
+        .add_instrument(instrument_audusd.clone())
+        .unwrap();
+    simple_cache
+        .add_account(AccountAny::Cash(cash_account(
+            cash_account_state_million_usd,
+        )))
+        .unwrap();
+    simple_cache
+        .add_position(
+            position_from_fills(&instrument_audusd, &[(OrderSide::Buy, "100000", "1.0")]),
+            OmsType::Netting,
+        )
+        .unwrap();
+
+    let config = RiskEngineConfig {
+        max_position_per_instrument: HashMap::from([(
+            instrument_audusd.id(),
+            Decimal::from(150_000),
+        )]),
+        ..Default::default()
+    };
+    let mut risk_engine = get_risk_engine(
+        Some(Rc::new(RefCell::new(simple_cache))),
+        Some(config),
+        None,
+        false,
+    );
+
+    let order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_audusd.id())
+        .side(OrderSide::Buy)
+        .price(Price::from("1.00000"))
+        .quantity(Quantity::from("100000"))
+        .build();
+
+    let submit_order = SubmitOrder::new(
+        trader_id,
+        client_id_binance,
+        strategy_id_ema_cross,
+        instrument_audusd.id(),
+        client_order_id,
+        venue_order_id,
+        order,
+        None,
+        None,
+        None, // params
+        UUID4::new(),
+        risk_engine.clock.borrow().timestamp_ns(),
+    )
+    .unwrap();
+
+    risk_engine.execute(TradingCommand::SubmitOrder(submit_order));
+
+    let saved_process_messages =
+        get_process_order_event_handler_messages(process_order_event_handler);
+    assert_eq!(saved_process_messages.len(), 1);
+    assert_eq!(
+        saved_process_messages.first().unwrap().message().unwrap(),
+        Ustr::from("POSITION_EXCEEDS_MAX_PER_INSTRUMENT: max_position=150000, position=200000")
+    );
+}
+
+#[rstest]
+fn test_submit_order_list_checks_oco_orders_against_position_individually(
+    strategy_id_ema_cross: StrategyId,
+    client_id_binance: ClientId,
+    trader_id: TraderId,
+    client_order_id: ClientOrderId,
+    instrument_audusd: InstrumentAny,
+    venue_order_id: VenueOrderId,
+    process_order_event_handler: ShareableMessageHandler,
+    cash_account_state_million_usd: AccountState,
+    mut simple_cache: Cache,
+) {
+    msgbus::register(
+        MessagingSwitchboard::exec_engine_process(),
+        process_order_event_handler.clone(),
+    );
+
+    simple_cache
+        .add_instrument(instrument_audusd.clone())
+        .unwrap();
+    simple_cache
+        .add_account(AccountAny::Cash(cash_account(
+            cash_account_state_million_usd,
+        )))
+        .unwrap();
+    simple_cache
+        .add_position(
+            position_from_fills(&instrument_audusd, &[(OrderSide::Buy, "100000", "1.0")]),
+            OmsType::Netting,
+        )
+        .unwrap();
+
+    let config = RiskEngineConfig {
+        max_position_per_instrument: HashMap::from([(
+            instrument_audusd.id(),
+            Decimal::from(80_000),
+        )]),
+        ..Default::default()
+    };
+    let mut risk_engine = get_risk_engine(
+        Some(Rc::new(RefCell::new(simple_cache))),
+        Some(config),
+        None,
+        false,
+    );
+
+    // Stop-loss and take-profit each close the position, only one of them can fill
+    let stop_loss = OrderTestBuilder::new(OrderType::StopMarket)
+        .instrument_id(instrument_audusd.id())
+        .side(OrderSide::Sell)
+        .trigger_price(Price::from("0.90000"))
+        .quantity(Quantity::from("100000"))
+        .contingency_type(ContingencyType::Oco)
+        .build();
+    let take_profit = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_audusd.id())
+        .side(OrderSide::Sell)
+        .price(Price::from("1.10000"))
+        .quantity(Quantity::from("100000"))
+        .contingency_type(ContingencyType::Oco)
+        .build();
+
+    let order_list = OrderList::new(
+        OrderListId::new("1"),
+        instrument_audusd.id(),
+        StrategyId::new("S-001"),
+        vec![stop_loss, take_profit],
+        risk_engine.clock.borrow().timestamp_ns(),
+    );
+
+    let submit_order = SubmitOrderList::new(
+        trader_id,
+        client_id_binance,
+        strategy_id_ema_cross,
+        instrument_audusd.id(),
+        client_order_id,
+        venue_order_id,
+        order_list,
+        None,
+        None,
+        UUID4::new(),
+        risk_engine.clock.borrow().timestamp_ns(),
+    )
+    .unwrap();
+
+    risk_engine.execute(TradingCommand::SubmitOrderList(submit_order));
+
+    let saved_process_messages =
+        get_process_order_event_handler_messages(process_order_event_handler);
+    assert!(saved_process_messages.is_empty());
+}
+
+#[rstest]
+fn test_submit_order_list_when_max_open_orders_exceeded_then_denies(
+    strategy_id_ema_cross: StrategyId,
+    client_id_binance: ClientId,
+    trader_id: TraderId,
+    client_order_id: ClientOrderId,
+    instrument_audusd: InstrumentAny,
+    venue_order_id: VenueOrderId,
+    process_order_event_handler: ShareableMessageHandler,
+    cash_account_state_million_usd: AccountState,
+    mut simple_cache: Cache,
+) {
+    msgbus::register(
+        MessagingSwitchboard::exec_engine_process(),
+        process_order_event_handler.clone(),
+    );
+
+    simple_cache
+        .add_instrument(instrument_audusd.clone())
+        .unwrap();
+    simple_cache
+        .add_account(AccountAny::Cash(cash_account(
+            cash_account_state_million_usd,
+        )))
+        .unwrap();
+
+    let config = RiskEngineConfig {
+        max_open_orders_per_instrument: Some(1),
+        ..Default::default()
+    };
+    let mut risk_engine = get_risk_engine(
+        Some(Rc::new(RefCell::new(simple_cache))),
+        Some(config),
+        None,
+        false,
+    );
+
+    let order1 = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_audusd.id())
+        .side(OrderSide::Buy)
+        .price(Price::from("1.00000"))
+        .quantity(Quantity::from("1000"))
+        .build();
+    let order2 = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_audusd.id())
+        .side(OrderSide::Sell)
+        .price(Price::from("1.10000"))
+        .quantity(Quantity::from("1000"))
+        .build();
+
+    let order_list = OrderList::new(
+        OrderListId::new("1"),
+        instrument_audusd.id(),
+        StrategyId::new("S-001"),
+        vec![order1, order2],
+        risk_engine.clock.borrow().timestamp_ns(),
+    );
+
+    let submit_order = SubmitOrderList::new(
+        trader_id,
+        client_id_binance,
+        strategy_id_ema_cross,
+        instrument_audusd.id(),
+        client_order_id,
+        venue_order_id,
+        order_list,
+        None,
+        None,
+        UUID4::new(),
+        risk_engine.clock.borrow().timestamp_ns(),
+    )
+    .unwrap();
+
+    risk_engine.execute(TradingCommand::SubmitOrderList(submit_order));
+
+    let saved_process_messages =
+        get_process_order_event_handler_messages(process_order_event_handler);
+    assert_eq!(saved_process_messages.len(), 3);
+    for event in &saved_process_messages {
+        assert_eq!(event.event_type(), OrderEventType::Denied);
+    }
+    assert_eq!(
+        saved_process_messages.first().unwrap().message().unwrap(),
+        Ustr::from("MAX_OPEN_ORDERS_EXCEEDED: max_open_orders=1, open_orders=1")
+    );
+}
+
+#[rstest]
+fn test_submit_order_when_gross_notional_would_exceed_max_then_denies(
+    strategy_id_ema_cross: StrategyId,
+    client_id_binance: ClientId,
+    trader_id: TraderId,
+    client_order_id: ClientOrderId,
+    instrument_audusd: InstrumentAny,
+    venue_order_id: VenueOrderId,
+    process_order_event_handler: ShareableMessageHandler,
+    cash_account_state_million_usd: AccountState,
+    mut simple_cache: Cache,
+) {
+    msgbus::register(
+        MessagingSwitchboard::exec_engine_process(),
+        process_order_event_handler.clone(),
+    );
+
+    simple_cache
+        .add_instrument(instrument_audusd.clone())
+        .unwrap();
+    simple_cache
+        .add_account(AccountAny::Cash(cash_account(
+            cash_account_state_million_usd,
+        )))
+        .unwrap();
+
+    let config = RiskEngineConfig {
+        max_gross_notional_per_account: HashMap::from([(account_id(), Money::from("50000 USD"))]),
+        ..Default::default()
+    };
+    let mut risk_engine = get_risk_engine(
+        Some(Rc::new(RefCell::new(simple_cache))),
+        Some(config),
+        None,
+        false,
+    );
+
+    let order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_audusd.id())
+        .side(OrderSide::Sell)
+        .price(Price::from("0.80000"))
+        .quantity(Quantity::from("100000"))
+        .build();
+
+    let submit_order = SubmitOrder::new(
+        trader_id,
+        client_id_binance,
+        strategy_id_ema_cross,
+        instrument_audusd.id(),
+        client_order_id,
+        venue_order_id,
+        order,
+        None,
+        None,
+        None, // params
+        UUID4::new(),
+        risk_engine.clock.borrow().timestamp_ns(),
+    )
+    .unwrap();
+
+    risk_engine.execute(TradingCommand::SubmitOrder(submit_order));
+
+    let saved_process_messages =
+        get_process_order_event_handler_messages(process_order_event_handler);
+    assert_eq!(saved_process_messages.len(), 1);
+    assert_eq!(
+        saved_process_messages.first().unwrap().message().unwrap(),
+        Ustr::from(
+            "GROSS_NOTIONAL_EXCEEDS_MAX_PER_ACCOUNT: max_gross_notional=50000.00 USD, gross_notional=80000.00 USD"
+        )
+    );
+}
+
+#[rstest]
+fn test_daily_loss_breach_sets_trading_state_until_next_day(
+    instrument_audusd: InstrumentAny,
+    cash_account_state_million_usd: AccountState,
+    mut simple_cache: Cache,
+) {
+    simple_cache
+        .add_instrument(instrument_audusd.clone())
+        .unwrap();
+    simple_cache
+        .add_account(AccountAny::Cash(cash_account(
+            cash_account_state_million_usd,
+        )))
+        .unwrap();
+    let cache = Rc::new(RefCell::new(simple_cache));
+    let clock = Rc::new(RefCell::new(TestClock::new()));
+
+    let config = RiskEngineConfig {
+        max_daily_loss: Some(Money::from("1000 USD")),
+        daily_loss_trading_state: TradingState::Reducing,
+        ..Default::default()
+    };
+    let mut risk_engine = get_risk_engine(
+        Some(cache.clone()),
+        Some(config),
+        Some(clock.clone()),
+        false,
+    );
+
+    risk_engine.update_daily_loss();
+    assert_eq!(risk_engine.trading_state, TradingState::Active);
+
+    // Realize a 2,000 USD loss (plus commissions)
+    cache
+        .borrow_mut()
+        .add_position(
+            position_from_fills(
+                &instrument_audusd,
+                &[
+                    (OrderSide::Buy, "100000", "1.00000"),
+                    (OrderSide::Sell, "100000", "0.98000"),
+                ],
+            ),
+            OmsType::Netting,
+        )
+        .unwrap();
+
+    risk_engine.update_daily_loss();
+    assert_eq!(risk_engine.trading_state, TradingState::Reducing);
+
+    clock
+        .borrow_mut()
+        .advance_time(UnixNanos::from(86_400_000_000_000), true);
+    risk_engine.update_daily_loss();
+    assert_eq!(risk_engine.trading_state, TradingState::Active);
+}
+
+#[rstest]
+fn test_daily_loss_evaluated_on_quote(
+    instrument_audusd: InstrumentAny,
+    cash_account_state_million_usd: AccountState,
+    mut simple_cache: Cache,
+    quote_audusd: QuoteTick,
+) {
+    simple_cache
+        .add_instrument(instrument_audusd.clone())
+        .unwrap();
+    simple_cache
+        .add_account(AccountAny::Cash(cash_account(
+            cash_account_state_million_usd,
+        )))
+        .unwrap();
+    let cache = Rc::new(RefCell::new(simple_cache));
+
+    let config = RiskEngineConfig {
+        max_daily_loss: Some(Money::from("1000 USD")),
+        daily_loss_trading_state: TradingState::Reducing,
+        ..Default::default()
+    };
+    let mut risk_engine = get_risk_engine(Some(cache.clone()), Some(config), None, false);
+
+    risk_engine.process_quote(&quote_audusd);
+    assert_eq!(risk_engine.trading_state, TradingState::Active);
+
+    cache
+        .borrow_mut()
+        .add_position(
+            position_from_fills(
+                &instrument_audusd,
+                &[
+                    (OrderSide::Buy, "100000", "1.00000"),
+                    (OrderSide::Sell, "100000", "0.98000"),
+                ],
+            ),
+            OmsType::Netting,
+        )
+        .unwrap();
+
+    risk_engine.process_quote(&quote_audusd);
+    assert_eq!(risk_engine.trading_state, TradingState::Reducing);
+}
+
+#[rstest]
+fn test_daily_loss_evaluated_on_quote_published_on_msgbus(
+    instrument_audusd: InstrumentAny,
+    cash_account_state_million_usd: AccountState,
+    mut simple_cache: Cache,
+    quote_audusd: QuoteTick,
+) {
+    simple_cache
+        .add_instrument(instrument_audusd.clone())
+        .unwrap();
+    simple_cache
+        .add_account(AccountAny::Cash(cash_account(
+            cash_account_state_million_usd,
+        )))
+        .unwrap();
+    let cache = Rc::new(RefCell::new(simple_cache));
+
+    let config = RiskEngineConfig {
+        max_daily_loss: Some(Money::from("1000 USD")),
+        daily_loss_trading_state: TradingState::Reducing,
+        ..Default::default()
+    };
+    let risk_engine = Rc::new(RefCell::new(get_risk_engine(
+        Some(cache.clone()),
+        Some(config),
+        None,
+        false,
+    )));
+    RiskEngine::register_message_handlers(&risk_engine);
+    let topic = switchboard::get_quotes_topic(quote_audusd.instrument_id);
+
+    msgbus::publish(topic, &quote_audusd);
+    assert_eq!(risk_engine.borrow().trading_state, TradingState::Active);
+
+    cache
+        .borrow_mut()
+        .add_position(
+            position_from_fills(
+                &instrument_audusd,
+                &[
+                    (OrderSide::Buy, "100000", "1.00000"),
+                    (OrderSide::Sell, "100000", "0.98000"),
+                ],
+            ),
+            OmsType::Netting,
+        )
+        .unwrap();
+
+    msgbus::publish(topic, &quote_audusd);
+
+    assert_eq!(risk_engine.borrow().trading_state, TradingState::Reducing);
+}
+
+#[rstest]
+fn test_daily_loss_reset_does_not_restore_manual_halt(
+    instrument_audusd: InstrumentAny,
+    cash_account_state_million_usd: AccountState,
+    mut simple_cache: Cache,
+) {
+    simple_cache
+        .add_instrument(instrument_audusd.clone())
+        .unwrap();
+    simple_cache
+        .add_account(AccountAny::Cash(cash_account(
+            cash_account_state_million_usd,
+        )))
+        .unwrap();
+    let cache = Rc::new(RefCell::new(simple_cache));
+    let clock = Rc::new(RefCell::new(TestClock::new()));
+
+    let config = RiskEngineConfig {
+        max_daily_loss: Some(Money::from("1000 USD")),
+        daily_loss_trading_state: TradingState::Halted,
+        ..Default::default()
+    };
+    let mut risk_engine = get_risk_engine(
+        Some(cache.clone()),
+        Some(config),
+        Some(clock.clone()),
+        false,
+    );
+
+    risk_engine.update_daily_loss();
+    risk_engine.set_trading_state(TradingState::Halted);
+
+    // The daily loss is breached while the operator halt is in place
+    cache
+        .borrow_mut()
+        .add_position(
+            position_from_fills(
+                &instrument_audusd,
+                &[
+                    (OrderSide::Buy, "100000", "1.00000"),
+                    (OrderSide::Sell, "100000", "0.98000"),
+                ],
+            ),
+            OmsType::Netting,
+        )
+        .unwrap();
+    risk_engine.update_daily_loss();
+    assert_eq!(risk_engine.trading_state, TradingState::Halted);
+
+    clock
+        .borrow_mut()
+        .advance_time(UnixNanos::from(86_400_000_000_000), true);
+    risk_engine.update_daily_loss();
+    assert_eq!(risk_engine.trading_state, TradingState::Halted);
+}
+
+#[rstest]
+#[case(PriceBand::Bps(Decimal::from(100)), true)]
+#[case(PriceBand::Bps(Decimal::from(200)), false)]
+#[case(PriceBand::Ticks(100_000), true)]
+#[case(PriceBand::Ticks(200_000), false)]
+fn test_submit_order_when_price_outside_band_then_denies(
+    #[case] price_band: PriceBand,
+    #[case] expect_denied: bool,
+    strategy_id_ema_cross: StrategyId,
+    client_id_binance: ClientId,
+    trader_id: TraderId,
+    client_order_id: ClientOrderId,
+    instrument_audusd: InstrumentAny,
+    venue_order_id: VenueOrderId,
+    process_order_event_handler: ShareableMessageHandler,
+    execute_order_event_handler: ShareableMessageHandler,
+    cash_account_state_million_usd: AccountState,
+    quote_audusd: QuoteTick,
+    mut simple_cache: Cache,
+) {
+    msgbus::register(
+        MessagingSwitchboard::exec_engine_process(),
+        process_order_event_handler.clone(),
+    );
+    msgbus::register(
+        MessagingSwitchboard::exec_engine_execute(),
+        execute_order_event_handler.clone(),
+    );
+
+    simple_cache
+        .add_instrument(instrument_audusd.clone())
+        .unwrap();
+    simple_cache
+        .add_account(AccountAny::Cash(cash_account(
+            cash_account_state_million_usd,
+        )))
+        .unwrap();
+    simple_cache.add_quote(quote_audusd).unwrap(); // Mid 100.5
+
+    let config = RiskEngineConfig {
+        max_price_deviation_per_instrument: HashMap::from([(instrument_audusd.id(), price_band)]),
+        ..Default::default()
+    };
+    let mut risk_engine = get_risk_engine(
+        Some(Rc::new(RefCell::new(simple_cache))),
+        Some(config),
+        None,
+        false,
+    );
+
+    // Deviates 1.5 from the mid (~149 bps, 150,000 ticks)
+    let order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_audusd.id())
+        .side(OrderSide::Sell)
+        .price(Price::from("99.00000"))
+        .quantity(Quantity::from("1000"))
+        .build();
+
+    let submit_order = SubmitOrder::new(
+        trader_id,
+        client_id_binance,
+        strategy_id_ema_cross,
+        instrument_audusd.id(),
+        client_order_id,
+        venue_order_id,
+        order,
+        None,
+        None,
+        None, // params
+        UUID4::new(),
+        risk_engine.clock.borrow().timestamp_ns(),
+    )
+    .unwrap();
+
+    risk_engine.execute(TradingCommand::SubmitOrder(submit_order));
+
+    let saved_process_messages =
+        get_process_order_event_handler_messages(process_order_event_handler);
+    let saved_execute_messages =
+        get_execute_order_event_handler_messages(execute_order_event_handler);
+    if expect_denied {
+        assert_eq!(saved_process_messages.len(), 1);
+        assert!(
+            saved_process_messages
+                .first()
+                .unwrap()
+                .message()
+                .unwrap()
+                .starts_with("PRICE_OUTSIDE_BAND: price=99.00000")
+        );
+        assert!(saved_execute_messages.is_empty());
+    } else {
+        assert!(saved_process_messages.is_empty());
+        assert_eq!(saved_execute_messages.len(), 1);
+    }
+}
+
+#[rstest]
+fn test_submit_order_when_trigger_price_outside_band_then_executes(
+    strategy_id_ema_cross: StrategyId,
+    client_id_binance: ClientId,
+    trader_id: TraderId,
+    client_order_id: ClientOrderId,
+    instrument_audusd: InstrumentAny,
+    venue_order_id: VenueOrderId,
+    process_order_event_handler: ShareableMessageHandler,
+    execute_order_event_handler: ShareableMessageHandler,
+    cash_account_state_million_usd: AccountState,
+    quote_audusd: QuoteTick,
+    mut simple_cache: Cache,
+) {
+    msgbus::register(
+        MessagingSwitchboard::exec_engine_process(),
+        process_order_event_handler.clone(),
+    );
+    msgbus::register(
+        MessagingSwitchboard::exec_engine_execute(),
+        execute_order_event_handler.clone(),
+    );
+
+    simple_cache
+        .add_instrument(instrument_audusd.clone())
+        .unwrap();
+    simple_cache
+        .add_account(AccountAny::Cash(cash_account(
+            cash_account_state_million_usd,
+        )))
+        .unwrap();
+    simple_cache.add_quote(quote_audusd).unwrap(); // Mid 100.5
+
+    let config = RiskEngineConfig {
+        max_price_deviation_per_instrument: HashMap::from([(
+            instrument_audusd.id(),
+            PriceBand::Bps(Decimal::from(100)),
+        )]),
+        ..Default::default()
+    };
+    let mut risk_engine = get_risk_engine(
+        Some(Rc::new(RefCell::new(simple_cache))),
+        Some(config),
+        None,
+        false,
+    );
+
+    // Trigger deviates ~149 bps from the mid
+    let order = OrderTestBuilder::new(OrderType::StopMarket)
+        .instrument_id(instrument_audusd.id())
+        .side(OrderSide::Sell)
+        .trigger_price(Price::from("99.00000"))
+        .quantity(Quantity::from("1000"))
+        .build();
+
+    let submit_order = SubmitOrder::new(
+        trader_id,
+        client_id_binance,
+        strategy_id_ema_cross,
+        instrument_audusd.id(),
+        client_order_id,
+        venue_order_id,
+        order,
+        None,
+        None,
+        None, // params
+        UUID4::new(),
+        risk_engine.clock.borrow().timestamp_ns(),
+    )
+    .unwrap();
+
+    risk_engine.execute(TradingCommand::SubmitOrder(submit_order));
+
+    let saved_process_messages =
+        get_process_order_event_handler_messages(process_order_event_handler);
+    let saved_execute_messages =
+        get_execute_order_event_handler_messages(execute_order_event_handler);
+    assert!(saved_process_messages.is_empty());
+    assert_eq!(saved_execute_messages.len(), 1);
+}
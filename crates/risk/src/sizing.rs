@@ -100,6 +100,236 @@ fn calculate_riskable_money(equity: Decimal, risk: Decimal, commission_rate: Dec
     risk_money - commission
 }
 
+/// Calculates position sizes for instruments from account equity.
+///
+/// Sizes are rounded down to the instrument `size_increment`, capped at the instrument
+/// `max_quantity`, and are zero when below the instrument `min_quantity`.
+pub trait PositionSizer {
+    /// Returns the position size for the `instrument` when entering at the `entry` price.
+    ///
+    /// The `exchange_rate` is the rate from the instrument quote currency to the `equity`
+    /// currency (one if they are the same).
+    fn calculate(
+        &self,
+        instrument: &InstrumentAny,
+        entry: Price,
+        equity: Money,
+        exchange_rate: Decimal,
+    ) -> Quantity;
+}
+
+/// Sizes positions to a fixed notional value in the account currency.
+#[derive(Debug, Clone)]
+pub struct FixedNotionalSizer {
+    pub notional: Decimal,
+}
+
+impl FixedNotionalSizer {
+    /// Creates a new [`FixedNotionalSizer`] instance.
+    #[must_use]
+    pub const fn new(notional: Decimal) -> Self {
+        Self { notional }
+    }
+}
+
+impl PositionSizer for FixedNotionalSizer {
+    fn calculate(
+        &self,
+        instrument: &InstrumentAny,
+        entry: Price,
+        _equity: Money,
+        exchange_rate: Decimal,
+    ) -> Quantity {
+        let size = calculate_notional_size(instrument, entry, self.notional, exchange_rate);
+        round_to_instrument(instrument, size)
+    }
+}
+
+/// Sizes positions to a fraction of account equity.
+#[derive(Debug, Clone)]
+pub struct PercentEquitySizer {
+    pub fraction: Decimal,
+}
+
+impl PercentEquitySizer {
+    /// Creates a new [`PercentEquitySizer`] instance.
+    #[must_use]
+    pub const fn new(fraction: Decimal) -> Self {
+        Self { fraction }
+    }
+}
+
+impl PositionSizer for PercentEquitySizer {
+    fn calculate(
+        &self,
+        instrument: &InstrumentAny,
+        entry: Price,
+        equity: Money,
+        exchange_rate: Decimal,
+    ) -> Quantity {
+        let notional = equity.as_decimal().max(Decimal::ZERO) * self.fraction;
+        let size = calculate_notional_size(instrument, entry, notional, exchange_rate);
+        round_to_instrument(instrument, size)
+    }
+}
+
+/// Sizes positions so that an adverse move of `atr_multiple` average true ranges loses the
+/// `risk` fraction of account equity.
+///
+/// The latest ATR value must be provided with [`VolatilityTargetSizer::update_atr`], until
+/// then all sizes are zero.
+#[derive(Debug, Clone)]
+pub struct VolatilityTargetSizer {
+    pub risk: Decimal,
+    pub atr_multiple: Decimal,
+    atr: Option<Decimal>,
+}
+
+impl VolatilityTargetSizer {
+    /// Creates a new [`VolatilityTargetSizer`] instance.
+    #[must_use]
+    pub const fn new(risk: Decimal, atr_multiple: Decimal) -> Self {
+        Self {
+            risk,
+            atr_multiple,
+            atr: None,
+        }
+    }
+
+    /// Updates the average true range (in price units) used for sizing.
+    pub fn update_atr(&mut self, atr: f64) {
+        self.atr = Decimal::from_f64(atr);
+    }
+
+    /// Returns the current average true range (if any).
+    #[must_use]
+    pub const fn atr(&self) -> Option<Decimal> {
+        self.atr
+    }
+}
+
+impl PositionSizer for VolatilityTargetSizer {
+    fn calculate(
+        &self,
+        instrument: &InstrumentAny,
+        entry: Price,
+        equity: Money,
+        exchange_rate: Decimal,
+    ) -> Quantity {
+        let risk_points = self.atr.unwrap_or_default() * self.atr_multiple;
+        let multiplier = instrument.multiplier().as_decimal();
+        if exchange_rate <= Decimal::ZERO
+            || risk_points <= Decimal::ZERO
+            || multiplier <= Decimal::ZERO
+        {
+            return instrument.make_qty(0.0, None);
+        }
+
+        let risk_money = equity.as_decimal().max(Decimal::ZERO) * self.risk / exchange_rate;
+        let mut size = risk_money / (risk_points * multiplier);
+        if instrument.is_inverse() {
+            // Inverse PnL per contract is approximately `multiplier * move / price^2`
+            size *= entry.as_decimal() * entry.as_decimal();
+        }
+
+        round_to_instrument(instrument, size)
+    }
+}
+
+/// Sizes positions with a fraction of the Kelly criterion.
+///
+/// The Kelly fraction of equity is `p - (1 - p) / b` for a win probability `p` and payoff ratio
+/// `b` (average win / average loss). It is scaled by `fraction` (e.g. 0.5 for half Kelly) and
+/// clamped to between zero and all of equity.
+#[derive(Debug, Clone)]
+pub struct KellySizer {
+    pub win_probability: Decimal,
+    pub payoff_ratio: Decimal,
+    pub fraction: Decimal,
+}
+
+impl KellySizer {
+    /// Creates a new [`KellySizer`] instance.
+    #[must_use]
+    pub const fn new(win_probability: Decimal, payoff_ratio: Decimal, fraction: Decimal) -> Self {
+        Self {
+            win_probability,
+            payoff_ratio,
+            fraction,
+        }
+    }
+
+    /// Returns the fraction of equity to allocate.
+    #[must_use]
+    pub fn kelly_fraction(&self) -> Decimal {
+        if self.payoff_ratio <= Decimal::ZERO {
+            return Decimal::ZERO;
+        }
+
+        let kelly =
+            self.win_probability - (Decimal::ONE - self.win_probability) / self.payoff_ratio;
+        (kelly * self.fraction).clamp(Decimal::ZERO, Decimal::ONE)
+    }
+}
+
+impl PositionSizer for KellySizer {
+    fn calculate(
+        &self,
+        instrument: &InstrumentAny,
+        entry: Price,
+        equity: Money,
+        exchange_rate: Decimal,
+    ) -> Quantity {
+        let notional = equity.as_decimal().max(Decimal::ZERO) * self.kelly_fraction();
+        let size = calculate_notional_size(instrument, entry, notional, exchange_rate);
+        round_to_instrument(instrument, size)
+    }
+}
+
+/// Returns the unrounded quantity of the `instrument` with the given `notional` value.
+fn calculate_notional_size(
+    instrument: &InstrumentAny,
+    entry: Price,
+    notional: Decimal,
+    exchange_rate: Decimal,
+) -> Decimal {
+    let multiplier = instrument.multiplier().as_decimal();
+    if exchange_rate <= Decimal::ZERO || entry.raw <= 0 || multiplier <= Decimal::ZERO {
+        return Decimal::ZERO;
+    }
+
+    let notional = notional / exchange_rate;
+    if instrument.is_inverse() {
+        notional * entry.as_decimal() / multiplier
+    } else {
+        notional / (entry.as_decimal() * multiplier)
+    }
+}
+
+/// Rounds the `size` down to the instrument size increment within its quantity limits.
+fn round_to_instrument(instrument: &InstrumentAny, size: Decimal) -> Quantity {
+    let mut size = size.max(Decimal::ZERO);
+    if let Some(max_quantity) = instrument.max_quantity() {
+        size = size.min(max_quantity.as_decimal());
+    }
+
+    let size_increment = instrument.size_increment().as_decimal();
+    if size_increment > Decimal::ZERO {
+        size = (size / size_increment).floor() * size_increment;
+    }
+
+    if let Some(min_quantity) = instrument.min_quantity()
+        && size < min_quantity.as_decimal()
+    {
+        size = Decimal::ZERO;
+    }
+
+    Quantity::from_decimal(size, instrument.size_precision()).unwrap_or_else(|e| {
+        log::error!("Cannot create position size {size}: {e}");
+        instrument.make_qty(0.0, None)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use nautilus_model::{
@@ -334,4 +564,81 @@ mod tests {
 
         assert_eq!(result, Quantity::from("1000000.0"));
     }
+
+    #[rstest]
+    fn test_fixed_notional_sizer_rounds_down_to_size_increment(instrument_gbpusd: InstrumentAny) {
+        let sizer = FixedNotionalSizer::new(Decimal::from(10_000));
+        let entry = Price::new(1.30000, instrument_gbpusd.price_precision());
+        let equity = Money::new(1_000_000.0, Currency::USD());
+
+        let result = sizer.calculate(&instrument_gbpusd, entry, equity, EXCHANGE_RATE);
+
+        assert_eq!(result, Quantity::from("7692")); // 10,000 / 1.3 = 7,692.3
+    }
+
+    #[rstest]
+    #[case(Decimal::new(1, 1), "1000000")] // Capped at max quantity
+    #[case(Decimal::new(1, 3), "769230")]
+    #[case(Decimal::new(1, 7), "0")] // Below min quantity
+    fn test_percent_equity_sizer(
+        instrument_gbpusd: InstrumentAny,
+        #[case] fraction: Decimal,
+        #[case] expected: &str,
+    ) {
+        let sizer = PercentEquitySizer::new(fraction);
+        let entry = Price::new(1.30000, instrument_gbpusd.price_precision());
+        let equity = Money::new(1_000_000_000.0, Currency::USD());
+
+        let result = sizer.calculate(&instrument_gbpusd, entry, equity, EXCHANGE_RATE);
+
+        assert_eq!(result, Quantity::from(expected));
+    }
+
+    #[rstest]
+    fn test_volatility_target_sizer(instrument_gbpusd: InstrumentAny) {
+        let mut sizer = VolatilityTargetSizer::new(Decimal::new(1, 2), Decimal::TWO);
+        let entry = Price::new(1.30000, instrument_gbpusd.price_precision());
+        let equity = Money::new(1_000_000.0, Currency::USD());
+
+        let result = sizer.calculate(&instrument_gbpusd, entry, equity, EXCHANGE_RATE);
+        assert_eq!(result, Quantity::from("0")); // No ATR yet
+
+        sizer.update_atr(0.025);
+        let result = sizer.calculate(&instrument_gbpusd, entry, equity, EXCHANGE_RATE);
+
+        assert_eq!(sizer.atr(), Some(Decimal::new(25, 3)));
+        assert_eq!(result, Quantity::from("200000")); // 10,000 / (0.025 * 2)
+    }
+
+    #[rstest]
+    #[case(
+        Decimal::new(6, 1),
+        Decimal::TWO,
+        Decimal::new(5, 1),
+        Decimal::new(2, 1)
+    )]
+    #[case(Decimal::new(4, 1), Decimal::ONE, Decimal::ONE, Decimal::ZERO)] // Negative edge
+    #[case(Decimal::new(9, 1), Decimal::from(10), Decimal::TWO, Decimal::ONE)] // Clamped
+    fn test_kelly_fraction(
+        #[case] win_probability: Decimal,
+        #[case] payoff_ratio: Decimal,
+        #[case] fraction: Decimal,
+        #[case] expected: Decimal,
+    ) {
+        let sizer = KellySizer::new(win_probability, payoff_ratio, fraction);
+
+        assert_eq!(sizer.kelly_fraction(), expected);
+    }
+
+    #[rstest]
+    fn test_kelly_sizer(instrument_gbpusd: InstrumentAny) {
+        // Half Kelly of 0.6 - 0.4 / 2 = 0.4
+        let sizer = KellySizer::new(Decimal::new(6, 1), Decimal::TWO, Decimal::new(5, 1));
+        let entry = Price::new(2.00000, instrument_gbpusd.price_precision());
+        let equity = Money::new(100_000.0, Currency::USD());
+
+        let result = sizer.calculate(&instrument_gbpusd, entry, equity, EXCHANGE_RATE);
+
+        assert_eq!(result, Quantity::from("10000"));
+    }
 }
@@ -17,7 +17,7 @@ use std::{any::Any, cell::RefCell, collections::HashMap, fmt::Debug, rc::Rc};
 
 use nautilus_common::{cache::Cache, clock::Clock};
 use nautilus_data::client::DataClient;
-use nautilus_execution::client::ExecutionClient;
+use nautilus_execution::client::LiveExecutionClient;
 
 /// Configuration for creating client instances.
 ///
@@ -69,7 +69,7 @@ pub trait ExecutionClientFactory: Send + Sync + std::fmt::Debug {
         config: &dyn ClientConfig,
         cache: Rc<RefCell<Cache>>,
         clock: Rc<RefCell<dyn Clock>>,
-    ) -> anyhow::Result<Box<dyn ExecutionClient>>;
+    ) -> anyhow::Result<Box<dyn LiveExecutionClient>>;
 
     /// Returns the name of this factory.
     fn name(&self) -> &str;
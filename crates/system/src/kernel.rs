@@ -28,7 +28,7 @@ use nautilus_common::{
     cache::{Cache, CacheConfig, database::CacheDatabaseAdapter},
     clock::{Clock, LiveClock, TestClock},
     component::Component,
-    enums::Environment,
+    enums::{ComponentState, Environment},
     logging::{
         headers, init_logging, init_tracing,
         logger::{LogGuard, LoggerConfig},
@@ -77,7 +77,7 @@ pub struct NautilusKernel {
     /// The data engine instance.
     pub data_engine: Rc<RefCell<DataEngine>>,
     /// The risk engine instance.
-    pub risk_engine: RiskEngine,
+    pub risk_engine: Rc<RefCell<RiskEngine>>,
     /// The execution engine instance.
     pub exec_engine: ExecutionEngine,
     /// The trader component.
@@ -133,12 +133,13 @@ impl NautilusKernel {
         set_message_bus(msgbus);
 
         let portfolio = Portfolio::new(cache.clone(), clock.clone(), config.portfolio());
-        let risk_engine = RiskEngine::new(
+        let risk_engine = Rc::new(RefCell::new(RiskEngine::new(
             config.risk_engine().unwrap_or_default(),
             Portfolio::new(cache.clone(), clock.clone(), config.portfolio()),
             clock.clone(),
             cache.clone(),
-        );
+        )));
+        RiskEngine::register_message_handlers(&risk_engine);
         let exec_engine = ExecutionEngine::new(clock.clone(), cache.clone(), config.exec_engine());
 
         let data_engine = DataEngine::new(clock.clone(), cache.clone(), config.data_engine());
@@ -230,6 +231,12 @@ impl NautilusKernel {
         instance_id: UUID4,
         config: LoggerConfig,
     ) -> anyhow::Result<LogGuard> {
+        // Logging is process-wide and cannot be re-initialized, so any subsequent
+        // kernel (e.g. repeated backtest engines) shares the global logger sender
+        if let Some(log_guard) = LogGuard::new() {
+            return Ok(log_guard);
+        }
+
         init_tracing()?;
 
         let log_guard = init_logging(
@@ -366,8 +373,8 @@ impl NautilusKernel {
 
     /// Returns the kernel's risk engine.
     #[must_use]
-    pub const fn risk_engine(&self) -> &RiskEngine {
-        &self.risk_engine
+    pub fn risk_engine(&self) -> Ref<'_, RiskEngine> {
+        self.risk_engine.borrow()
     }
 
     /// Returns the kernel's execution engine.
@@ -428,6 +435,48 @@ impl NautilusKernel {
         log::info!("Stopped");
     }
 
+    /// Starts the Nautilus system kernel without connecting clients.
+    ///
+    /// Intended for backtesting, where all clients are simulated.
+    pub fn start(&mut self) {
+        log::info!("Starting");
+        self.start_engines();
+
+        if self.trader.state() == ComponentState::PreInitialized {
+            log::info!("Initializing trader");
+            if let Err(e) = self.trader.initialize() {
+                log::error!("Error initializing trader: {e:?}");
+                return;
+            }
+        }
+
+        if let Err(e) = self.trader.start() {
+            log::error!("Error starting trader: {e:?}");
+        }
+
+        self.ts_started = Some(self.clock.borrow().timestamp_ns());
+        log::info!("Started");
+    }
+
+    /// Stops the Nautilus system kernel without disconnecting clients.
+    ///
+    /// Intended for backtesting, where all clients are simulated.
+    pub fn stop(&mut self) {
+        log::info!("Stopping");
+
+        if self.trader.is_running()
+            && let Err(e) = self.trader.stop()
+        {
+            log::error!("Error stopping trader: {e:?}");
+        }
+
+        self.stop_engines();
+        self.cancel_timers();
+
+        self.ts_shutdown = Some(self.clock.borrow().timestamp_ns());
+        log::info!("Stopped");
+    }
+
     /// Resets the Nautilus system kernel to its initial state.
     pub fn reset(&mut self) {
         log::info!("Resetting");
@@ -438,6 +487,8 @@ impl NautilusKernel {
 
         // Reset engines
         self.data_engine.borrow_mut().reset();
+        self.exec_engine.reset();
+        self.portfolio.reset();
         // TODO: Reset other engines when reset methods are available
 
         self.ts_started = None;
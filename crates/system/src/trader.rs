@@ -187,6 +187,12 @@ impl Trader {
         self.exec_algorithms.keys().copied().collect()
     }
 
+    /// Returns the clocks for all registered components.
+    #[must_use]
+    pub fn get_component_clocks(&self) -> Vec<Rc<RefCell<dyn Clock>>> {
+        self.clocks.values().cloned().collect()
+    }
+
     /// Creates a clock for a component.
     ///
     /// Creates a test clock in backtest environment, otherwise returns a reference
@@ -376,6 +382,44 @@ impl Trader {
         Ok(())
     }
 
+    /// Clears all registered strategies from the trader.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the trader is not in a valid state for removing components.
+    pub fn clear_strategies(&mut self) -> anyhow::Result<()> {
+        self.validate_component_registration()?;
+
+        for (strategy_id, strategy) in self.strategies.drain() {
+            self.clocks.remove(&strategy.component_id());
+            log::info!(
+                "Removed strategy '{strategy_id}' from trader {}",
+                self.trader_id
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Clears all registered execution algorithms from the trader.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the trader is not in a valid state for removing components.
+    pub fn clear_exec_algorithms(&mut self) -> anyhow::Result<()> {
+        self.validate_component_registration()?;
+
+        for (exec_algorithm_id, exec_algorithm) in self.exec_algorithms.drain() {
+            self.clocks.remove(&exec_algorithm.component_id());
+            log::info!(
+                "Removed execution algorithm '{exec_algorithm_id}' from trader {}",
+                self.trader_id
+            );
+        }
+
+        Ok(())
+    }
+
     /// Validates that the trader is in a valid state for component registration.
     fn validate_component_registration(&self) -> anyhow::Result<()> {
         match self.state {
@@ -886,6 +930,32 @@ mod tests {
         assert!(trader.exec_algorithm_ids().contains(&exec_algorithm_id));
     }
 
+    #[rstest]
+    fn test_clear_strategies_and_exec_algorithms() {
+        let (_msgbus, cache, _portfolio, _data_engine, _risk_engine, _exec_engine, clock) =
+            create_trader_components();
+        let trader_id = TraderId::default();
+        let instance_id = UUID4::new();
+
+        let mut trader = Trader::new(trader_id, instance_id, Environment::Backtest, clock, cache);
+
+        trader
+            .add_strategy(Box::new(MockComponent::new("Test-Strategy")))
+            .unwrap();
+        trader
+            .add_exec_algorithm(Box::new(MockComponent::new("TestExecAlgorithm")))
+            .unwrap();
+        assert_eq!(trader.get_component_clocks().len(), 2);
+
+        trader.clear_strategies().unwrap();
+        assert_eq!(trader.strategy_count(), 0);
+        assert_eq!(trader.get_component_clocks().len(), 1);
+
+        trader.clear_exec_algorithms().unwrap();
+        assert_eq!(trader.exec_algorithm_count(), 0);
+        assert!(trader.get_component_clocks().is_empty());
+    }
+
     #[rstest]
     fn test_component_lifecycle() {
         let (_msgbus, cache, _portfolio, _data_engine, _risk_engine, _exec_engine, clock) =
@@ -519,6 +519,13 @@ class Position:
     def ts_closed(self) -> int | None: ...
     @property
     def avg_px_close(self) -> Price | None: ...
+    @property
+    def max_px(self) -> float | None: ...
+    @property
+    def min_px(self) -> float | None: ...
+    def update_excursions(self, price: Price) -> None: ...
+    def max_adverse_excursion(self) -> float | None: ...
+    def max_favorable_excursion(self) -> float | None: ...
     def unrealized_pnl(self, price: Price) -> Money: ...
     def total_pnl(self, price: Price) -> Money: ...
     def commissions(self) -> list[Money]: ...
@@ -5595,6 +5602,111 @@ class BookImbalanceRatio:
     def update(self, best_bid: Quantity | None, best_ask: Quantity) -> None: ...
     def reset(self) -> None: ...
 
+class WeightedBookImbalance:
+    def __init__(self, depth: int, decay: float | None = None) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def depth(self) -> int: ...
+    @property
+    def decay(self) -> float: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    def handle_book(self, book: OrderBook) -> None: ...
+    def handle_depth(self, depth: OrderBookDepth10) -> None: ...
+    def update(self, bids: list[tuple[float, float]], asks: list[tuple[float, float]]) -> None: ...
+    def reset(self) -> None: ...
+
+class Microprice:
+    def __init__(self) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    def handle_book(self, book: OrderBook) -> None: ...
+    def handle_depth(self, depth: OrderBookDepth10) -> None: ...
+    def handle_quote_tick(self, quote: QuoteTick) -> None: ...
+    def update(self, bid_price: float, bid_size: float, ask_price: float, ask_size: float) -> None: ...
+    def reset(self) -> None: ...
+
+class OrderFlowImbalance:
+    def __init__(self, period: int) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def period(self) -> int: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    def handle_book(self, book: OrderBook) -> None: ...
+    def handle_depth(self, depth: OrderBookDepth10) -> None: ...
+    def handle_quote_tick(self, quote: QuoteTick) -> None: ...
+    def update(self, bid_price: float, bid_size: float, ask_price: float, ask_size: float) -> None: ...
+    def reset(self) -> None: ...
+
+class BookPressure:
+    def __init__(self, depth: int) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def depth(self) -> int: ...
+    @property
+    def bid_pressure(self) -> float: ...
+    @property
+    def ask_pressure(self) -> float: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    def handle_book(self, book: OrderBook) -> None: ...
+    def handle_depth(self, depth: OrderBookDepth10) -> None: ...
+    def update(self, bids: list[tuple[float, float]], asks: list[tuple[float, float]]) -> None: ...
+    def reset(self) -> None: ...
+
+class DepthSlope:
+    def __init__(self, depth: int) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def depth(self) -> int: ...
+    @property
+    def bid_slope(self) -> float: ...
+    @property
+    def ask_slope(self) -> float: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    def handle_book(self, book: OrderBook) -> None: ...
+    def handle_depth(self, depth: OrderBookDepth10) -> None: ...
+    def update(self, bids: list[tuple[float, float]], asks: list[tuple[float, float]]) -> None: ...
+    def reset(self) -> None: ...
+
 ###################################################################################################
 # Adapters
 ###################################################################################################
@@ -7527,6 +7639,11 @@ class PortfolioAnalyzer:
     ) -> dict[str, float]: ...
     def get_performance_stats_general(self) -> dict[str, float]: ...
     def add_return(self, timestamp: int, value: float) -> None: ...
+    def cumulative_returns(self) -> dict[int, float]: ...
+    def equity_curve(self, currency: Currency | None = None) -> dict[int, float] | None: ...
+    def drawdown_series(self) -> dict[int, float]: ...
+    def underwater_durations(self) -> dict[int, int]: ...
+    def get_rolling_stats_returns(self, window_ns: int) -> dict[str, dict[int, float]]: ...
     def reset(self) -> None: ...
 
 class SharpeRatio:
@@ -7648,3 +7765,57 @@ class LongRatio:
     @property
     def name(self) -> str: ...
     def calculate_from_positions(self, positions: list[Position]) -> float | None: ...
+
+class OmegaRatio:
+    def __init__(self, threshold: float = 0.0) -> None: ...
+    @property
+    def name(self) -> str: ...
+    def calculate_from_returns(self, returns: dict[int, float]) -> float | None: ...
+
+class TailRatio:
+    def __init__(self) -> None: ...
+    @property
+    def name(self) -> str: ...
+    def calculate_from_returns(self, returns: dict[int, float]) -> float | None: ...
+
+class ValueAtRisk:
+    def __init__(self, confidence: float = 0.95, parametric: bool = False) -> None: ...
+    @property
+    def name(self) -> str: ...
+    def calculate_from_returns(self, returns: dict[int, float]) -> float | None: ...
+
+class ExpectedShortfall:
+    def __init__(self, confidence: float = 0.95, parametric: bool = False) -> None: ...
+    @property
+    def name(self) -> str: ...
+    def calculate_from_returns(self, returns: dict[int, float]) -> float | None: ...
+
+class UlcerIndex:
+    def __init__(self) -> None: ...
+    @property
+    def name(self) -> str: ...
+    def calculate_from_returns(self, returns: dict[int, float]) -> float | None: ...
+
+class MaxDrawdownDuration:
+    def __init__(self) -> None: ...
+    @property
+    def name(self) -> str: ...
+    def calculate_from_returns(self, returns: dict[int, float]) -> float | None: ...
+
+class AvgHoldingTime:
+    def __init__(self) -> None: ...
+    @property
+    def name(self) -> str: ...
+    def calculate_from_positions(self, positions: list[Position]) -> float | None: ...
+
+class AvgMAE:
+    def __init__(self) -> None: ...
+    @property
+    def name(self) -> str: ...
+    def calculate_from_positions(self, positions: list[Position]) -> float | None: ...
+
+class AvgMFE:
+    def __init__(self) -> None: ...
+    @property
+    def name(self) -> str: ...
+    def calculate_from_positions(self, positions: list[Position]) -> float | None: ...